@@ -0,0 +1,266 @@
+//! This module implements calendar dates and rendering events as iCalendar.
+//!
+//! Dates are ISO 8601 calendar dates like "2026-10-18", see [`parse_date`].
+//! Date-times are ISO 8601 UTC date-times like "2026-10-18T13:45:30Z", see [`format_date_time`].
+//! iCalendar is specified in [RFC 5545](https://datatracker.ietf.org/doc/html/rfc5545).
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+
+/// This is the product identifier of the iCalendar files, see [RFC 5545, section 3.7.3](https://datatracker.ietf.org/doc/html/rfc5545#section-3.7.3).
+const PRODUCT_IDENTIFIER: &str = "-//Recipe Book//Meal Plan//EN";
+
+/// The maximum length of a content line in octets, excluding the line break.
+const MAX_LINE_LENGTH: usize = 75;
+
+/// This struct represents an all-day event.
+#[derive(Debug)]
+pub struct Event {
+    /// the globally unique identifier of the event, which stays the same across exports
+    pub uid: String,
+    /// the ISO 8601 calendar date of the event
+    pub date: String,
+    pub summary: String,
+    pub description: Option<String>,
+}
+
+/// Parse an ISO 8601 calendar date like "2026-10-18" into its year, month, and day.
+///
+/// Returns [`None`] if the date is not in this exact format or does not exist, e.g. "2026-02-29".
+pub fn parse_date(date: &str) -> Option<(i64, u32, u32)> {
+    let bytes = date.as_bytes();
+    if bytes.len() != 10
+        || bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || !bytes
+            .iter()
+            .enumerate()
+            .all(|(i, byte)| i == 4 || i == 7 || byte.is_ascii_digit())
+    {
+        return None;
+    }
+    let year = date[0..4].parse().ok()?;
+    let month = date[5..7].parse().ok()?;
+    let day = date[8..10].parse().ok()?;
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some((year, month, day))
+}
+
+/// Ensure that a date is a valid ISO 8601 calendar date, so it can be compared with others.
+pub fn validate_date(date: String) -> Result<String> {
+    parse_date(&date).ok_or_else(|| anyhow!("\"{date}\" is not a date like \"2026-10-18\"."))?;
+    Ok(date)
+}
+
+/// Format a time as a UTC date-time like "20261018T134530Z", see [RFC 5545, section 3.3.5](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.5).
+///
+/// Times before the unix epoch are formatted as the unix epoch.
+pub fn format_timestamp(time: SystemTime) -> String {
+    let (year, month, day, hour, minute, second) = utc_date_time(time);
+    format!("{year:04}{month:02}{day:02}T{hour:02}{minute:02}{second:02}Z")
+}
+
+/// Format a time as an ISO 8601 UTC date-time like "2026-10-18T13:45:30Z", so date-times are ordered like strings.
+///
+/// Times before the unix epoch are formatted as the unix epoch.
+pub fn format_date_time(time: SystemTime) -> String {
+    let (year, month, day, hour, minute, second) = utc_date_time(time);
+    format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}Z")
+}
+
+/// Render all-day events as an iCalendar object.
+///
+/// The timestamp is the creation time of the iCalendar object, see [`format_timestamp`].
+pub fn render(events: &[Event], timestamp: &str) -> String {
+    let mut lines = vec![
+        String::from("BEGIN:VCALENDAR"),
+        String::from("VERSION:2.0"),
+        format!("PRODID:{PRODUCT_IDENTIFIER}"),
+    ];
+    for event in events {
+        lines.push(String::from("BEGIN:VEVENT"));
+        lines.push(format!("UID:{}", event.uid));
+        lines.push(format!("DTSTAMP:{timestamp}"));
+        lines.push(format!(
+            "DTSTART;VALUE=DATE:{}",
+            event.date.replace('-', "")
+        ));
+        lines.push(format!("SUMMARY:{}", escape_text(&event.summary)));
+        if let Some(description) = &event.description {
+            lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+        }
+        lines.push(String::from("END:VEVENT"));
+    }
+    lines.push(String::from("END:VCALENDAR"));
+    lines
+        .iter()
+        .map(|line| format!("{}\r\n", fold_line(line)))
+        .collect()
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Get the UTC year, month, day, hour, minute, and second of a time.
+///
+/// Times before the unix epoch are the unix epoch.
+fn utc_date_time(time: SystemTime) -> (i64, u32, u32, i64, i64, i64) {
+    let seconds = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86400));
+    let seconds_of_day = seconds.rem_euclid(86400);
+    (
+        year,
+        month,
+        day,
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60,
+        seconds_of_day % 60,
+    )
+}
+
+/// Get the year, month, and day of a number of days since the unix epoch.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Escape a text value, see [RFC 5545, section 3.3.11](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.11).
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Fold a content line longer than [`MAX_LINE_LENGTH`] octets, see [RFC 5545, section 3.1](https://datatracker.ietf.org/doc/html/rfc5545#section-3.1).
+///
+/// Multi-octet characters are never split.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut line_length = 0;
+    for character in line.chars() {
+        if line_length + character.len_utf8() > MAX_LINE_LENGTH {
+            folded.push_str("\r\n ");
+            line_length = 1;
+        }
+        folded.push(character);
+        line_length += character.len_utf8();
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(parse_date("2026-10-18"), Some((2026, 10, 18)));
+        assert_eq!(parse_date("2024-02-29"), Some((2024, 2, 29)));
+        assert_eq!(parse_date("2026-02-29"), None);
+        assert_eq!(parse_date("1900-02-29"), None);
+        assert_eq!(parse_date("2026-13-01"), None);
+        assert_eq!(parse_date("2026-04-31"), None);
+        assert_eq!(parse_date("2026-1-18"), None);
+        assert_eq!(parse_date("+026-10-18"), None);
+        assert_eq!(parse_date("18.10.2026"), None);
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(UNIX_EPOCH), "19700101T000000Z");
+        assert_eq!(
+            format_timestamp(UNIX_EPOCH + Duration::from_secs(1792331130)),
+            "20261018T134530Z"
+        );
+        assert_eq!(
+            format_timestamp(UNIX_EPOCH + Duration::from_secs(951868799)),
+            "20000229T235959Z"
+        );
+    }
+
+    #[test]
+    fn test_format_date_time() {
+        assert_eq!(format_date_time(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+        assert_eq!(
+            format_date_time(UNIX_EPOCH + Duration::from_secs(1792331130)),
+            "2026-10-18T13:45:30Z"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let events = vec![
+            Event {
+                uid: String::from("meal-plan-entry-1@recipe-book"),
+                date: String::from("2026-10-18"),
+                summary: String::from("Dinner: Pizza, Salat; Nachtisch"),
+                description: None,
+            },
+            Event {
+                uid: String::from("meal-plan-entry-2@recipe-book"),
+                date: String::from("2026-10-19"),
+                summary: String::from("Lunch: Käsespätzle"),
+                description: Some(String::from(
+                    "4 servings\nDie Spätzle werden mit viel geriebenem Bergkäse überbacken.",
+                )),
+            },
+        ];
+        assert_eq!(
+            render(&events, "20261018T134530Z"),
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//Recipe Book//Meal Plan//EN\r\n\
+            BEGIN:VEVENT\r\n\
+            UID:meal-plan-entry-1@recipe-book\r\n\
+            DTSTAMP:20261018T134530Z\r\n\
+            DTSTART;VALUE=DATE:20261018\r\n\
+            SUMMARY:Dinner: Pizza\\, Salat\\; Nachtisch\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            UID:meal-plan-entry-2@recipe-book\r\n\
+            DTSTAMP:20261018T134530Z\r\n\
+            DTSTART;VALUE=DATE:20261019\r\n\
+            SUMMARY:Lunch: Käsespätzle\r\n\
+            DESCRIPTION:4 servings\\nDie Spätzle werden mit viel geriebenem Bergkäse \r\n \
+            überbacken.\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n"
+        );
+    }
+}
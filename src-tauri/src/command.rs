@@ -3,6 +3,18 @@
 pub mod entity;
 mod error;
 pub mod external_recipe;
+pub mod ingredient_draft;
+pub mod ingredient_nutrition_import;
+pub mod meal_plan;
 pub mod ocr;
+pub mod pantry_match;
+pub mod preference;
+pub mod recipe_normalize;
+pub mod recipe_nutrition;
+pub mod recipe_refresh;
+pub mod recipe_scale;
+pub mod recipe_step_description;
+pub mod search;
+pub mod shopping_list_add;
 pub mod unit_conversion;
 pub mod unit_list;
@@ -0,0 +1,50 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::collection::Model,
+    entity_crud::{
+        collection::{
+            CollectionCondition, CollectionCreate, CollectionCrud, CollectionFilter,
+            CollectionUpdate,
+        },
+        EntityCrudTrait, Page,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_collection(create: CollectionCreate) -> Result<i64, CommandError> {
+    let id = CollectionCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_collection(id: i64) -> Result<Model, CommandError> {
+    let model_option = CollectionCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_collection(update: CollectionUpdate) -> Result<(), CommandError> {
+    CollectionCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_collection(id: i64) -> Result<(), CommandError> {
+    CollectionCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_collection(filter: CollectionFilter) -> Result<Page<i64>, CommandError> {
+    let list = CollectionCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_count_collection(
+    condition: Option<CollectionCondition>,
+) -> Result<i64, CommandError> {
+    let count = CollectionCrud::count(condition).await?;
+    Ok(count)
+}
@@ -5,7 +5,7 @@ use crate::{
     entity::file::Model,
     entity_crud::{
         file::{FileCondition, FileCreate, FileCrud, FileFilter, FileUpdate},
-        EntityCrudTrait,
+        EntityCrudTrait, Page,
     },
 };
 
@@ -36,7 +36,7 @@ pub async fn entity_delete_file(id: i64) -> Result<(), CommandError> {
 }
 
 #[tauri::command]
-pub async fn entity_list_file(filter: FileFilter) -> Result<Vec<i64>, CommandError> {
+pub async fn entity_list_file(filter: FileFilter) -> Result<Page<i64>, CommandError> {
     let list = FileCrud::list(filter).await?;
     Ok(list)
 }
@@ -6,7 +6,7 @@ use crate::{
             IngredientCondition, IngredientCreate, IngredientCrud, IngredientFilter,
             IngredientUpdate,
         },
-        EntityCrudTrait,
+        EntityCrudTrait, Page,
     },
 };
 
@@ -36,7 +36,7 @@ pub async fn entity_delete_ingredient(id: i64) -> Result<(), CommandError> {
 }
 
 #[tauri::command]
-pub async fn entity_list_ingredient(filter: IngredientFilter) -> Result<Vec<i64>, CommandError> {
+pub async fn entity_list_ingredient(filter: IngredientFilter) -> Result<Page<i64>, CommandError> {
     let list = IngredientCrud::list(filter).await?;
     Ok(list)
 }
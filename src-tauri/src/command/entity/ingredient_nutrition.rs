@@ -0,0 +1,56 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::ingredient_nutrition::Model,
+    entity_crud::{
+        ingredient_nutrition::{
+            IngredientNutritionCondition, IngredientNutritionCreate, IngredientNutritionCrud,
+            IngredientNutritionFilter, IngredientNutritionUpdate,
+        },
+        EntityCrudTrait, Page,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_ingredient_nutrition(
+    create: IngredientNutritionCreate,
+) -> Result<i64, CommandError> {
+    let id = IngredientNutritionCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_ingredient_nutrition(id: i64) -> Result<Model, CommandError> {
+    let model_option = IngredientNutritionCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_ingredient_nutrition(
+    update: IngredientNutritionUpdate,
+) -> Result<(), CommandError> {
+    IngredientNutritionCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_ingredient_nutrition(id: i64) -> Result<(), CommandError> {
+    IngredientNutritionCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_ingredient_nutrition(
+    filter: IngredientNutritionFilter,
+) -> Result<Page<i64>, CommandError> {
+    let list = IngredientNutritionCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_count_ingredient_nutrition(
+    condition: Option<IngredientNutritionCondition>,
+) -> Result<i64, CommandError> {
+    let count = IngredientNutritionCrud::count(condition).await?;
+    Ok(count)
+}
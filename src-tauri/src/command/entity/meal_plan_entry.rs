@@ -0,0 +1,56 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::meal_plan_entry::Model,
+    entity_crud::{
+        meal_plan_entry::{
+            MealPlanEntryCondition, MealPlanEntryCreate, MealPlanEntryCrud,
+            MealPlanEntryFilter, MealPlanEntryUpdate,
+        },
+        EntityCrudTrait, Page,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_meal_plan_entry(
+    create: MealPlanEntryCreate,
+) -> Result<i64, CommandError> {
+    let id = MealPlanEntryCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_meal_plan_entry(id: i64) -> Result<Model, CommandError> {
+    let model_option = MealPlanEntryCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_meal_plan_entry(
+    update: MealPlanEntryUpdate,
+) -> Result<(), CommandError> {
+    MealPlanEntryCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_meal_plan_entry(id: i64) -> Result<(), CommandError> {
+    MealPlanEntryCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_meal_plan_entry(
+    filter: MealPlanEntryFilter,
+) -> Result<Page<i64>, CommandError> {
+    let list = MealPlanEntryCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_count_meal_plan_entry(
+    condition: Option<MealPlanEntryCondition>,
+) -> Result<i64, CommandError> {
+    let count = MealPlanEntryCrud::count(condition).await?;
+    Ok(count)
+}
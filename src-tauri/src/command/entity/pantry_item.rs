@@ -0,0 +1,50 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::pantry_item::Model,
+    entity_crud::{
+        pantry_item::{
+            PantryItemCondition, PantryItemCreate, PantryItemCrud, PantryItemFilter,
+            PantryItemUpdate,
+        },
+        EntityCrudTrait, Page,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_pantry_item(create: PantryItemCreate) -> Result<i64, CommandError> {
+    let id = PantryItemCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_pantry_item(id: i64) -> Result<Model, CommandError> {
+    let model_option = PantryItemCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_pantry_item(update: PantryItemUpdate) -> Result<(), CommandError> {
+    PantryItemCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_pantry_item(id: i64) -> Result<(), CommandError> {
+    PantryItemCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_pantry_item(filter: PantryItemFilter) -> Result<Page<i64>, CommandError> {
+    let list = PantryItemCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_count_pantry_item(
+    condition: Option<PantryItemCondition>,
+) -> Result<i64, CommandError> {
+    let count = PantryItemCrud::count(condition).await?;
+    Ok(count)
+}
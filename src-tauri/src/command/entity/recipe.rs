@@ -3,7 +3,7 @@ use crate::{
     entity::recipe::Model,
     entity_crud::{
         recipe::{RecipeCondition, RecipeCreate, RecipeCrud, RecipeFilter, RecipeUpdate},
-        EntityCrudTrait,
+        EntityCrudTrait, Page,
     },
 };
 
@@ -33,7 +33,7 @@ pub async fn entity_delete_recipe(id: i64) -> Result<(), CommandError> {
 }
 
 #[tauri::command]
-pub async fn entity_list_recipe(filter: RecipeFilter) -> Result<Vec<i64>, CommandError> {
+pub async fn entity_list_recipe(filter: RecipeFilter) -> Result<Page<i64>, CommandError> {
     let list = RecipeCrud::list(filter).await?;
     Ok(list)
 }
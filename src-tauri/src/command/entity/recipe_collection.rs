@@ -0,0 +1,56 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::recipe_collection::Model,
+    entity_crud::{
+        recipe_collection::{
+            RecipeCollectionCondition, RecipeCollectionCreate, RecipeCollectionCrud,
+            RecipeCollectionFilter, RecipeCollectionUpdate,
+        },
+        EntityCrudTrait, Page,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_recipe_collection(
+    create: RecipeCollectionCreate,
+) -> Result<i64, CommandError> {
+    let id = RecipeCollectionCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_recipe_collection(id: i64) -> Result<Model, CommandError> {
+    let model_option = RecipeCollectionCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_recipe_collection(
+    update: RecipeCollectionUpdate,
+) -> Result<(), CommandError> {
+    RecipeCollectionCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_recipe_collection(id: i64) -> Result<(), CommandError> {
+    RecipeCollectionCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_recipe_collection(
+    filter: RecipeCollectionFilter,
+) -> Result<Page<i64>, CommandError> {
+    let list = RecipeCollectionCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_count_recipe_collection(
+    condition: Option<RecipeCollectionCondition>,
+) -> Result<i64, CommandError> {
+    let count = RecipeCollectionCrud::count(condition).await?;
+    Ok(count)
+}
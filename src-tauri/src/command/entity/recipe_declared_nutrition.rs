@@ -0,0 +1,57 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::recipe_declared_nutrition::Model,
+    entity_crud::{
+        recipe_declared_nutrition::{
+            RecipeDeclaredNutritionCondition, RecipeDeclaredNutritionCreate,
+            RecipeDeclaredNutritionCrud, RecipeDeclaredNutritionFilter,
+            RecipeDeclaredNutritionUpdate,
+        },
+        EntityCrudTrait, Page,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_recipe_declared_nutrition(
+    create: RecipeDeclaredNutritionCreate,
+) -> Result<i64, CommandError> {
+    let id = RecipeDeclaredNutritionCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_recipe_declared_nutrition(id: i64) -> Result<Model, CommandError> {
+    let model_option = RecipeDeclaredNutritionCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_recipe_declared_nutrition(
+    update: RecipeDeclaredNutritionUpdate,
+) -> Result<(), CommandError> {
+    RecipeDeclaredNutritionCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_recipe_declared_nutrition(id: i64) -> Result<(), CommandError> {
+    RecipeDeclaredNutritionCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_recipe_declared_nutrition(
+    filter: RecipeDeclaredNutritionFilter,
+) -> Result<Page<i64>, CommandError> {
+    let list = RecipeDeclaredNutritionCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_count_recipe_declared_nutrition(
+    condition: Option<RecipeDeclaredNutritionCondition>,
+) -> Result<i64, CommandError> {
+    let count = RecipeDeclaredNutritionCrud::count(condition).await?;
+    Ok(count)
+}
@@ -6,7 +6,7 @@ use crate::{
             RecipeFileCondition, RecipeFileCreate, RecipeFileCrud, RecipeFileFilter,
             RecipeFileUpdate,
         },
-        EntityCrudTrait,
+        EntityCrudTrait, Page,
     },
 };
 
@@ -36,7 +36,7 @@ pub async fn entity_delete_recipe_file(id: i64) -> Result<(), CommandError> {
 }
 
 #[tauri::command]
-pub async fn entity_list_recipe_file(filter: RecipeFileFilter) -> Result<Vec<i64>, CommandError> {
+pub async fn entity_list_recipe_file(filter: RecipeFileFilter) -> Result<Page<i64>, CommandError> {
     let list = RecipeFileCrud::list(filter).await?;
     Ok(list)
 }
@@ -6,7 +6,7 @@ use crate::{
             RecipeIngredientDraftCondition, RecipeIngredientDraftCreate, RecipeIngredientDraftCrud,
             RecipeIngredientDraftFilter, RecipeIngredientDraftUpdate,
         },
-        EntityCrudTrait,
+        EntityCrudTrait, Page,
     },
 };
 
@@ -42,7 +42,7 @@ pub async fn entity_delete_recipe_ingredient_draft(id: i64) -> Result<(), Comman
 #[tauri::command]
 pub async fn entity_list_recipe_ingredient_draft(
     filter: RecipeIngredientDraftFilter,
-) -> Result<Vec<i64>, CommandError> {
+) -> Result<Page<i64>, CommandError> {
     let list = RecipeIngredientDraftCrud::list(filter).await?;
     Ok(list)
 }
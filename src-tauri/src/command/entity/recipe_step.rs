@@ -6,7 +6,7 @@ use crate::{
             RecipeStepCondition, RecipeStepCreate, RecipeStepCrud, RecipeStepFilter,
             RecipeStepUpdate,
         },
-        EntityCrudTrait,
+        EntityCrudTrait, Page,
     },
 };
 
@@ -36,7 +36,7 @@ pub async fn entity_delete_recipe_step(id: i64) -> Result<(), CommandError> {
 }
 
 #[tauri::command]
-pub async fn entity_list_recipe_step(filter: RecipeStepFilter) -> Result<Vec<i64>, CommandError> {
+pub async fn entity_list_recipe_step(filter: RecipeStepFilter) -> Result<Page<i64>, CommandError> {
     let list = RecipeStepCrud::list(filter).await?;
     Ok(list)
 }
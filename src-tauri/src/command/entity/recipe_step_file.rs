@@ -6,7 +6,7 @@ use crate::{
             RecipeStepFileCondition, RecipeStepFileCreate, RecipeStepFileCrud,
             RecipeStepFileFilter, RecipeStepFileUpdate,
         },
-        EntityCrudTrait,
+        EntityCrudTrait, Page,
     },
 };
 
@@ -42,7 +42,7 @@ pub async fn entity_delete_recipe_step_file(id: i64) -> Result<(), CommandError>
 #[tauri::command]
 pub async fn entity_list_recipe_step_file(
     filter: RecipeStepFileFilter,
-) -> Result<Vec<i64>, CommandError> {
+) -> Result<Page<i64>, CommandError> {
     let list = RecipeStepFileCrud::list(filter).await?;
     Ok(list)
 }
@@ -6,7 +6,7 @@ use crate::{
             RecipeStepIngredientCondition, RecipeStepIngredientCreate, RecipeStepIngredientCrud,
             RecipeStepIngredientFilter, RecipeStepIngredientUpdate,
         },
-        EntityCrudTrait,
+        EntityCrudTrait, Page,
     },
 };
 
@@ -42,7 +42,7 @@ pub async fn entity_delete_recipe_step_ingredient(id: i64) -> Result<(), Command
 #[tauri::command]
 pub async fn entity_list_recipe_step_ingredient(
     filter: RecipeStepIngredientFilter,
-) -> Result<Vec<i64>, CommandError> {
+) -> Result<Page<i64>, CommandError> {
     let list = RecipeStepIngredientCrud::list(filter).await?;
     Ok(list)
 }
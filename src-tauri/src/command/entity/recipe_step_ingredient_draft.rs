@@ -7,7 +7,7 @@ use crate::{
             RecipeStepIngredientDraftCrud, RecipeStepIngredientDraftFilter,
             RecipeStepIngredientDraftUpdate,
         },
-        EntityCrudTrait,
+        EntityCrudTrait, Page,
     },
 };
 
@@ -43,7 +43,7 @@ pub async fn entity_delete_recipe_step_ingredient_draft(id: i64) -> Result<(), C
 #[tauri::command]
 pub async fn entity_list_recipe_step_ingredient_draft(
     filter: RecipeStepIngredientDraftFilter,
-) -> Result<Vec<i64>, CommandError> {
+) -> Result<Page<i64>, CommandError> {
     let list = RecipeStepIngredientDraftCrud::list(filter).await?;
     Ok(list)
 }
@@ -0,0 +1,49 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::recipe_tag::Model,
+    entity_crud::{
+        recipe_tag::{
+            RecipeTagCondition, RecipeTagCreate, RecipeTagCrud, RecipeTagFilter, RecipeTagUpdate,
+        },
+        EntityCrudTrait, Page,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_recipe_tag(create: RecipeTagCreate) -> Result<i64, CommandError> {
+    let id = RecipeTagCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_recipe_tag(id: i64) -> Result<Model, CommandError> {
+    let model_option = RecipeTagCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_recipe_tag(update: RecipeTagUpdate) -> Result<(), CommandError> {
+    RecipeTagCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_recipe_tag(id: i64) -> Result<(), CommandError> {
+    RecipeTagCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_recipe_tag(filter: RecipeTagFilter) -> Result<Page<i64>, CommandError> {
+    let list = RecipeTagCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_count_recipe_tag(
+    condition: Option<RecipeTagCondition>,
+) -> Result<i64, CommandError> {
+    let count = RecipeTagCrud::count(condition).await?;
+    Ok(count)
+}
@@ -0,0 +1,52 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::shopping_list::Model,
+    entity_crud::{
+        shopping_list::{
+            ShoppingListCondition, ShoppingListCreate, ShoppingListCrud,
+            ShoppingListFilter, ShoppingListUpdate,
+        },
+        EntityCrudTrait, Page,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_shopping_list(create: ShoppingListCreate) -> Result<i64, CommandError> {
+    let id = ShoppingListCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_shopping_list(id: i64) -> Result<Model, CommandError> {
+    let model_option = ShoppingListCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_shopping_list(update: ShoppingListUpdate) -> Result<(), CommandError> {
+    ShoppingListCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_shopping_list(id: i64) -> Result<(), CommandError> {
+    ShoppingListCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_shopping_list(
+    filter: ShoppingListFilter,
+) -> Result<Page<i64>, CommandError> {
+    let list = ShoppingListCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_count_shopping_list(
+    condition: Option<ShoppingListCondition>,
+) -> Result<i64, CommandError> {
+    let count = ShoppingListCrud::count(condition).await?;
+    Ok(count)
+}
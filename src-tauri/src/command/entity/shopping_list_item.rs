@@ -0,0 +1,56 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::shopping_list_item::Model,
+    entity_crud::{
+        shopping_list_item::{
+            ShoppingListItemCondition, ShoppingListItemCreate, ShoppingListItemCrud,
+            ShoppingListItemFilter, ShoppingListItemUpdate,
+        },
+        EntityCrudTrait, Page,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_shopping_list_item(
+    create: ShoppingListItemCreate,
+) -> Result<i64, CommandError> {
+    let id = ShoppingListItemCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_shopping_list_item(id: i64) -> Result<Model, CommandError> {
+    let model_option = ShoppingListItemCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_shopping_list_item(
+    update: ShoppingListItemUpdate,
+) -> Result<(), CommandError> {
+    ShoppingListItemCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_shopping_list_item(id: i64) -> Result<(), CommandError> {
+    ShoppingListItemCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_shopping_list_item(
+    filter: ShoppingListItemFilter,
+) -> Result<Page<i64>, CommandError> {
+    let list = ShoppingListItemCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_count_shopping_list_item(
+    condition: Option<ShoppingListItemCondition>,
+) -> Result<i64, CommandError> {
+    let count = ShoppingListItemCrud::count(condition).await?;
+    Ok(count)
+}
@@ -0,0 +1,45 @@
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    entity::tag::Model,
+    entity_crud::{
+        tag::{TagCondition, TagCreate, TagCrud, TagFilter, TagUpdate},
+        EntityCrudTrait, Page,
+    },
+};
+
+#[tauri::command]
+pub async fn entity_create_tag(create: TagCreate) -> Result<i64, CommandError> {
+    let id = TagCrud::create(create).await?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub async fn entity_read_tag(id: i64) -> Result<Model, CommandError> {
+    let model_option = TagCrud::read(id).await?;
+    let model = model_option.ok_or(NotFound)?;
+    Ok(model)
+}
+
+#[tauri::command]
+pub async fn entity_update_tag(update: TagUpdate) -> Result<(), CommandError> {
+    TagCrud::update(update).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_delete_tag(id: i64) -> Result<(), CommandError> {
+    TagCrud::delete(id).await?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn entity_list_tag(filter: TagFilter) -> Result<Page<i64>, CommandError> {
+    let list = TagCrud::list(filter).await?;
+    Ok(list)
+}
+
+#[tauri::command]
+pub async fn entity_count_tag(condition: Option<TagCondition>) -> Result<i64, CommandError> {
+    let count = TagCrud::count(condition).await?;
+    Ok(count)
+}
@@ -5,7 +5,7 @@ use crate::{
         unit_name::{
             UnitNameCondition, UnitNameCreate, UnitNameCrud, UnitNameFilter, UnitNameUpdate,
         },
-        EntityCrudTrait,
+        EntityCrudTrait, Page,
     },
 };
 
@@ -35,7 +35,7 @@ pub async fn entity_delete_unit_name(id: String) -> Result<(), CommandError> {
 }
 
 #[tauri::command]
-pub async fn entity_list_unit_name(filter: UnitNameFilter) -> Result<Vec<String>, CommandError> {
+pub async fn entity_list_unit_name(filter: UnitNameFilter) -> Result<Page<String>, CommandError> {
     let list = UnitNameCrud::list(filter).await?;
     Ok(list)
 }
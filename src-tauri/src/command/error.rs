@@ -4,7 +4,10 @@ use serde::Serialize;
 use serde_with::{serde_as, DisplayFromStr};
 use thiserror::Error;
 
-use crate::external_recipe::error::ExternalRecipeError;
+use crate::{
+    external_recipe::error::ExternalRecipeError, fetch::error::FetchError,
+    unit_conversion::ConversionError,
+};
 
 #[serde_as]
 #[derive(Debug, Error, Serialize)]
@@ -40,6 +43,12 @@ pub enum CommandError {
         reqwest::Error,
     ),
     #[error(transparent)]
+    Fetch(
+        #[serde_as(as = "DisplayFromStr")]
+        #[from]
+        FetchError,
+    ),
+    #[error(transparent)]
     Tauri(
         #[serde_as(as = "DisplayFromStr")]
         #[from]
@@ -53,16 +62,31 @@ pub enum CommandError {
     ),
     #[error("The external recipe url \"{0}\" is not supported.")]
     ExternalRecipeUrlNotSupported(String),
+    #[error("The external recipe was already imported as recipe {0}.")]
+    ExternalRecipeDuplicate(i64),
+    #[error("The external recipe import was cancelled.")]
+    ExternalRecipeImportCancelled,
     #[error(transparent)]
     ExternalRecipeParseError(#[serde_as(as = "DisplayFromStr")] ExternalRecipeError),
+    #[error(transparent)]
+    UnitConversion(
+        #[serde_as(as = "DisplayFromStr")]
+        #[from]
+        ConversionError,
+    ),
     #[error("Entity was not found.")]
     NotFound,
+    #[error("The recipe has no yield to scale from.")]
+    RecipeWithoutYield,
+    #[error("The recipe was not imported from a source.")]
+    RecipeWithoutSource,
 }
 
 impl From<ExternalRecipeError> for CommandError {
     fn from(value: ExternalRecipeError) -> Self {
         match value {
             ExternalRecipeError::Reqwest(reqwest) => Self::Reqwest(reqwest),
+            ExternalRecipeError::Fetch(fetch) => Self::Fetch(fetch),
             ExternalRecipeError::Anyhow(anyhow) => Self::Anyhow(anyhow),
             ExternalRecipeError::UrlNotSupported(url) => Self::ExternalRecipeUrlNotSupported(url),
             ExternalRecipeError::ParseError(_) => Self::ExternalRecipeParseError(value),
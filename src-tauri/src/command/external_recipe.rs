@@ -1,92 +1,1175 @@
+//! This module implements the [`tauri::command`] for importing external recipes.
+//!
+//! An imported recipe remembers its source, so that importing the same URL again is detected as a duplicate.
+
+use std::{
+    collections::HashMap,
+    io::Write,
+    path::Path,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::SystemTime,
+};
+
+use anyhow::anyhow;
+use indexmap::IndexSet;
+use sea_orm::{
+    sea_query::{Expr, Func},
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
+    IntoActiveModel, PaginatorTrait, QueryFilter, QueryOrder, TransactionTrait,
+};
+use serde::Serialize;
+use tempfile::NamedTempFile;
+use tokio::sync::Semaphore;
+use url::Url;
+use uuid::Uuid;
+
 use crate::{
-    command::error::CommandError,
+    calendar::format_date_time,
+    command::{
+        error::CommandError,
+        ingredient_draft::{select_ingredient_ids, select_ingredient_names},
+    },
+    database,
+    entity::{file, ingredient, recipe, recipe_declared_nutrition, recipe_tag, tag},
     entity_crud::{
-        file::{FileCreate, FileCreateUri, FileCrud},
-        recipe::{RecipeCreate, RecipeCrud},
-        recipe_file::{RecipeFileCreate, RecipeFileCrud},
-        recipe_ingredient_draft::{RecipeIngredientDraftCreate, RecipeIngredientDraftCrud},
-        recipe_step::{RecipeStepCreate, RecipeStepCrud},
-        recipe_step_file::{RecipeStepFileCreate, RecipeStepFileCrud},
-        recipe_step_ingredient_draft::{
-            RecipeStepIngredientDraftCreate, RecipeStepIngredientDraftCrud,
-        },
-        EntityCrudTrait,
+        file::{FileCreate, FileCreateUri},
+        recipe::RecipeCreate,
+        recipe_declared_nutrition::RecipeDeclaredNutritionCreate,
+        recipe_file::RecipeFileCreate,
+        recipe_ingredient_draft::RecipeIngredientDraftCreate,
+        recipe_step::RecipeStepCreate,
+        recipe_step_file::RecipeStepFileCreate,
+        recipe_step_ingredient::RecipeStepIngredientCreate,
+        recipe_step_ingredient_draft::RecipeStepIngredientDraftCreate,
+        recipe_tag::RecipeTagCreate,
+        tag::TagCreate,
+        TryIntoActiveModel,
+    },
+    event::channel::{
+        ENTITY_ACTION_CREATED_FILE, ENTITY_ACTION_CREATED_INGREDIENT, ENTITY_ACTION_CREATED_RECIPE,
+        ENTITY_ACTION_CREATED_RECIPE_DECLARED_NUTRITION, ENTITY_ACTION_CREATED_RECIPE_FILE,
+        ENTITY_ACTION_CREATED_RECIPE_INGREDIENT_DRAFT, ENTITY_ACTION_CREATED_RECIPE_STEP,
+        ENTITY_ACTION_CREATED_RECIPE_STEP_FILE, ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT,
+        ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT_DRAFT, ENTITY_ACTION_CREATED_RECIPE_TAG,
+        ENTITY_ACTION_CREATED_TAG, EXTERNAL_RECIPE_IMPORT_FINISHED,
+        EXTERNAL_RECIPE_IMPORT_PROGRESS,
+    },
+    external_recipe::{
+        details::ExternalRecipeDetails,
+        get_saved_with_getter_name_observed, get_with_getter_name_observed,
+        saved_page::{SavedPage, SavedResource},
+        ExternalRecipe, ExternalRecipeIngredient, ExternalRecipeStep,
     },
+    file_storage,
+    ingredient_parser::parse_yield,
+    window::get_window,
 };
 
-#[tauri::command]
-pub async fn external_recipe(url: String) -> Result<i64, CommandError> {
-    let external_recipe = crate::external_recipe::get(url).await?;
-    let recipe_id = RecipeCrud::create(RecipeCreate {
-        name: external_recipe.name,
+/// Normalize a URL to compare it with the source URLs of recipes.
+///
+/// The fragment is removed, because it does not change which page is imported.
+pub fn normalize_source_url(url: &str) -> Result<String, CommandError> {
+    let mut url = Url::from_str(url).map_err(anyhow::Error::from)?;
+    url.set_fragment(None);
+    Ok(url.to_string())
+}
+
+/// Select the id of the first recipe imported from a normalized source URL.
+async fn select_duplicate<C>(db: &C, source_url: &str) -> Result<Option<i64>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let recipe_option = recipe::Entity::find()
+        .filter(recipe::Column::SourceUrl.eq(source_url))
+        .order_by_asc(recipe::Column::Id)
+        .one(db)
+        .await?;
+    Ok(recipe_option.map(|recipe| recipe.id))
+}
+
+/// This struct represents a file of an external recipe which could not be downloaded.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FailedFile {
+    pub url: String,
+    pub error: String,
+}
+
+/// This struct represents an imported external recipe.
+///
+/// The import succeeds even if some files could not be downloaded, these are reported instead.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalRecipeImport {
+    pub recipe_id: i64,
+    pub failed_files: Vec<FailedFile>,
+}
+
+/// This enum represents the stage of a running import, see [`EXTERNAL_RECIPE_IMPORT_PROGRESS`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "stage", rename_all = "camelCase")]
+pub enum ImportStage {
+    /// the page of the external recipe is fetched
+    Fetching,
+    /// the fetched page is parsed
+    Parsing,
+    /// file `file` of `files` is downloaded
+    Downloading { file: usize, files: usize },
+    /// the recipe is saved
+    Saving,
+}
+
+/// This enum represents how an import ended, see [`EXTERNAL_RECIPE_IMPORT_FINISHED`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "outcome", rename_all = "camelCase")]
+pub enum ImportOutcome {
+    Imported(ExternalRecipeImport),
+    Failed { error: String },
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ImportPayload<T> {
+    import_id: String,
+    #[serde(flatten)]
+    data: T,
+}
+
+/// This struct represents a running import, which emits its progress and can be cancelled.
+#[derive(Debug, Clone)]
+pub struct Import {
+    id: String,
+    cancelled: Arc<AtomicBool>,
+}
+
+/// These are the imports started by [`external_recipe_import_start`] or [`external_recipe_saved_import_start`] which did not finish yet.
+static RUNNING_IMPORTS: Mutex<Vec<Import>> = Mutex::new(Vec::new());
+
+impl Default for Import {
+    fn default() -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+impl Import {
+    /// Emit the stage of this import, ignoring failures because the progress is only informative.
+    fn emit(&self, stage: ImportStage) {
+        get_window()
+            .emit(
+                EXTERNAL_RECIPE_IMPORT_PROGRESS,
+                ImportPayload {
+                    import_id: self.id.clone(),
+                    data: stage,
+                },
+            )
+            .ok();
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    fn check_cancelled(&self) -> Result<(), CommandError> {
+        if self.is_cancelled() {
+            return Err(CommandError::ExternalRecipeImportCancelled);
+        }
+        Ok(())
+    }
+}
+
+/// This struct contains the downloaded files of an external recipe, see [`download_files`].
+pub struct DownloadedFiles {
+    /// the files by their URL, which are already in the [`crate::file_storage`] but not yet inserted
+    files: HashMap<String, file::ActiveModel>,
+    pub failed_files: Vec<FailedFile>,
+}
+
+impl DownloadedFiles {
+    /// Delete the downloaded files from the [`crate::file_storage`], e.g. when their insertion was rolled back.
+    pub async fn discard(self) {
+        for active_model in self.files.into_values() {
+            let ActiveValue::Set(path) = active_model.path else {
+                continue;
+            };
+            if let Err(err) = file_storage::delete(&path).await {
+                log::warn!("Could not delete discarded file from storage: {}", err);
+            }
+        }
+    }
+
+    /// Check whether the file of a URL was downloaded.
+    pub fn contains(&self, url: &str) -> bool {
+        self.files.contains_key(url)
+    }
+}
+
+/// This is the maximum number of files downloaded at the same time.
+const MAX_CONCURRENT_DOWNLOADS: usize = 4;
+
+/// Store a resource embedded into a saved page in the [`crate::file_storage`] like a downloaded file.
+async fn store_resource(
+    name: String,
+    resource: SavedResource,
+) -> anyhow::Result<file::ActiveModel> {
+    let mut named_temp_file = NamedTempFile::new()?;
+    named_temp_file.write_all(&resource.content)?;
+    let path =
+        file_storage::create(&named_temp_file.path().to_string_lossy(), &resource.mime).await?;
+    Ok(file::ActiveModel {
+        id: ActiveValue::NotSet,
+        name: ActiveValue::Set(name),
+        mime: ActiveValue::Set(resource.mime),
+        path: ActiveValue::Set(path.to_string_lossy().to_string()),
     })
-    .await?;
-    for (i, ingredient) in external_recipe.ingredients.into_iter().enumerate() {
-        tokio::spawn(async move {
-            RecipeIngredientDraftCrud::create(RecipeIngredientDraftCreate {
+}
+
+/// Download the files of an external recipe and its steps into the [`crate::file_storage`].
+///
+/// Each URL is only downloaded once, even if it is referenced several times.
+/// Files embedded into the saved page are taken from it instead of downloading them.
+/// The failed files are in the order of their first reference.
+///
+/// The progress is emitted for the import if there is one.
+/// Once the import is cancelled, no more downloads are started, but running downloads finish,
+/// so that all stored files can be discarded.
+pub async fn download_files(
+    external_recipe: &ExternalRecipe,
+    saved_page: Option<&SavedPage>,
+    import_option: Option<&Import>,
+) -> DownloadedFiles {
+    let urls: IndexSet<String> = external_recipe
+        .files
+        .iter()
+        .chain(external_recipe.steps.iter().flat_map(|step| &step.files))
+        .cloned()
+        .collect();
+    let files = urls.len();
+    let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_DOWNLOADS));
+    let join_handles: Vec<_> = urls
+        .into_iter()
+        .map(|url| {
+            let semaphore = semaphore.clone();
+            let import_option = import_option.cloned();
+            let resource_option = saved_page
+                .and_then(|saved_page| saved_page.resource(&url))
+                .cloned();
+            let file_create = FileCreate {
+                name: url.clone(),
+                uri: FileCreateUri::Url(url.clone()),
+            };
+            let join_handle = tokio::spawn(async move {
+                let _permit = semaphore.acquire_owned().await?;
+                if import_option.as_ref().is_some_and(Import::is_cancelled) {
+                    return Err(anyhow!("The import was cancelled."));
+                }
+                if let Some(resource) = resource_option {
+                    return store_resource(file_create.name, resource).await;
+                }
+                TryIntoActiveModel::<file::ActiveModel>::try_into_active_model(file_create).await
+            });
+            (url, join_handle)
+        })
+        .collect();
+    let mut downloaded_files = DownloadedFiles {
+        files: HashMap::new(),
+        failed_files: vec![],
+    };
+    for (i, (url, join_handle)) in join_handles.into_iter().enumerate() {
+        if let Some(import) = import_option {
+            import.emit(ImportStage::Downloading { file: i + 1, files });
+        }
+        match join_handle.await.map_err(anyhow::Error::from) {
+            Ok(Ok(active_model)) => {
+                downloaded_files.files.insert(url, active_model);
+            }
+            Ok(Err(err)) | Err(err) => downloaded_files.failed_files.push(FailedFile {
+                url,
+                error: err.to_string(),
+            }),
+        }
+    }
+    downloaded_files
+}
+
+/// Insert a downloaded file once and get its id, or [`None`] if it could not be downloaded.
+async fn insert_file<'a, C>(
+    db: &C,
+    downloaded_files: &DownloadedFiles,
+    file_ids: &mut HashMap<&'a str, i64>,
+    url: &'a str,
+) -> Result<Option<i64>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    if let Some(file_id) = file_ids.get(url) {
+        return Ok(Some(*file_id));
+    }
+    let Some(active_model) = downloaded_files.files.get(url) else {
+        return Ok(None);
+    };
+    let file = active_model.clone().insert(db).await?;
+    file_ids.insert(url, file.id);
+    Ok(Some(file.id))
+}
+
+/// Get an external recipe in the form its content is stored in by [`insert_content`].
+///
+/// Parsed ingredients are named like the ingredients they are matched with by their lowercase name, see [`select_ingredient_names`].
+/// A parsed ingredient whose ingredient is already used in its step is moved to the ingredient drafts of the step,
+/// because an ingredient can only be used once in a step.
+/// Files which were not downloaded are skipped.
+pub fn normalize_content<F>(
+    external_recipe: &ExternalRecipe,
+    ingredient_names: &HashMap<String, String>,
+    is_downloaded: F,
+) -> ExternalRecipe
+where
+    F: Fn(&str) -> bool,
+{
+    let mut ingredient_names = ingredient_names.clone();
+    let downloaded = |files: &[String]| -> Vec<String> {
+        files
+            .iter()
+            .filter(|url| is_downloaded(url))
+            .cloned()
+            .collect()
+    };
+    let mut steps = vec![];
+    for step in &external_recipe.steps {
+        let mut ingredients = step.ingredients.clone();
+        let mut parsed_ingredients: Vec<ExternalRecipeIngredient> = vec![];
+        for parsed_ingredient in &step.parsed_ingredients {
+            let name = ingredient_names
+                .entry(parsed_ingredient.name.to_lowercase())
+                .or_insert_with(|| parsed_ingredient.name.clone())
+                .clone();
+            if parsed_ingredients
+                .iter()
+                .any(|used_ingredient| used_ingredient.name == name)
+            {
+                ingredients.push(parsed_ingredient.text());
+                continue;
+            }
+            parsed_ingredients.push(ExternalRecipeIngredient {
+                name,
+                ..parsed_ingredient.clone()
+            });
+        }
+        steps.push(ExternalRecipeStep {
+            ingredients,
+            description: step.description.clone(),
+            files: downloaded(&step.files),
+            parsed_ingredients,
+        });
+    }
+    ExternalRecipe {
+        name: external_recipe.name.clone(),
+        ingredients: external_recipe.ingredients.clone(),
+        files: downloaded(&external_recipe.files),
+        steps,
+        details: external_recipe.details.clone(),
+    }
+}
+
+/// Insert the ingredient drafts, files and steps of an external recipe for a recipe.
+///
+/// The content is inserted in the order of the external recipe and in the form of [`normalize_content`].
+/// Parsed ingredients are inserted as recipe step ingredients, whose ingredients are matched by their name ignoring case, or created.
+pub async fn insert_content<C>(
+    db: &C,
+    recipe_id: i64,
+    external_recipe: &ExternalRecipe,
+    downloaded_files: &DownloadedFiles,
+) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    let ingredient_names = select_ingredient_names(db).await?;
+    let external_recipe = normalize_content(external_recipe, &ingredient_names, |url| {
+        downloaded_files.contains(url)
+    });
+    let mut file_ids = HashMap::new();
+    let mut ingredient_ids = select_ingredient_ids(db).await?;
+    for (i, ingredient) in external_recipe.ingredients.iter().enumerate() {
+        RecipeIngredientDraftCreate {
+            order: (i + 1) as i64,
+            text: ingredient.clone(),
+            recipe_id,
+        }
+        .into_active_model()
+        .insert(db)
+        .await?;
+    }
+    let mut order = 0;
+    for url in &external_recipe.files {
+        let Some(file_id) = insert_file(db, downloaded_files, &mut file_ids, url).await? else {
+            continue;
+        };
+        order += 1;
+        RecipeFileCreate {
+            order,
+            recipe_id,
+            file_id,
+        }
+        .into_active_model()
+        .insert(db)
+        .await?;
+    }
+    for (i, step) in external_recipe.steps.iter().enumerate() {
+        let recipe_step = RecipeStepCreate {
+            recipe_id,
+            description: step.description.clone(),
+            order: (i + 1) as i64,
+        }
+        .into_active_model()
+        .insert(db)
+        .await?;
+        for (i, parsed_ingredient) in step.parsed_ingredients.iter().enumerate() {
+            let ingredient_id = match ingredient_ids.get(&parsed_ingredient.name.to_lowercase()) {
+                Some(ingredient_id) => *ingredient_id,
+                None => {
+                    let ingredient = ingredient::ActiveModel {
+                        name: ActiveValue::Set(parsed_ingredient.name.clone()),
+                        ..Default::default()
+                    }
+                    .insert(db)
+                    .await?;
+                    ingredient_ids.insert(ingredient.name.to_lowercase(), ingredient.id);
+                    ingredient.id
+                }
+            };
+            RecipeStepIngredientCreate {
                 order: (i + 1) as i64,
-                text: ingredient,
-                recipe_id,
-            })
+                quantity: parsed_ingredient.quantity,
+                unit: parsed_ingredient.unit.clone(),
+                quality: parsed_ingredient.quality.clone(),
+                recipe_step_id: recipe_step.id,
+                ingredient_id,
+            }
+            .into_active_model()
+            .insert(db)
+            .await?;
+        }
+        for (i, text) in step.ingredients.iter().enumerate() {
+            RecipeStepIngredientDraftCreate {
+                order: (i + 1) as i64,
+                text: text.clone(),
+                recipe_step_id: recipe_step.id,
+            }
+            .into_active_model()
+            .insert(db)
+            .await?;
+        }
+        let mut order = 0;
+        for url in &step.files {
+            let Some(file_id) = insert_file(db, downloaded_files, &mut file_ids, url).await? else {
+                continue;
+            };
+            order += 1;
+            RecipeStepFileCreate {
+                order,
+                recipe_step_id: recipe_step.id,
+                file_id,
+            }
+            .into_active_model()
+            .insert(db)
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+/// Set the columns of a recipe which are taken from the details of an external recipe.
+///
+/// The yield amount and unit are parsed from the yield text, see [`parse_yield`].
+pub fn set_details(recipe: &mut recipe::ActiveModel, details: &ExternalRecipeDetails) {
+    let (yield_amount, yield_unit) = match details.recipe_yield.as_deref().and_then(parse_yield) {
+        Some((quantity, unit)) => (Some(quantity.value()), unit),
+        None => (None, None),
+    };
+    recipe.yield_amount = ActiveValue::Set(yield_amount);
+    recipe.yield_unit = ActiveValue::Set(yield_unit);
+    recipe.description = ActiveValue::Set(details.description.clone());
+    recipe.author = ActiveValue::Set(details.author.clone());
+    recipe.prep_time = ActiveValue::Set(details.prep_time);
+    recipe.cook_time = ActiveValue::Set(details.cook_time);
+    recipe.total_time = ActiveValue::Set(details.total_time);
+}
+
+/// Find the tag with a name ignoring ASCII case, see [`ExternalRecipeDetails::tag_names`].
+pub async fn find_tag<C>(db: &C, tag_name: &str) -> Result<Option<tag::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    tag::Entity::find()
+        .filter(
+            Expr::expr(Func::lower(Expr::col((tag::Entity, tag::Column::Name))))
+                .eq(Func::lower(Expr::val(tag_name))),
+        )
+        .one(db)
+        .await
+}
+
+/// Insert the tags and the declared nutrition of the details of an external recipe for a recipe.
+///
+/// Tags are matched by their name ignoring ASCII case, see [`find_tag`].
+/// Tags which do not exist yet are created, and tags the recipe is already tagged with are kept.
+/// The declared nutrition replaces the one of the recipe, whose id is returned if there was one.
+pub async fn insert_details<C>(
+    db: &C,
+    recipe_id: i64,
+    details: &ExternalRecipeDetails,
+) -> Result<Option<i64>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    for tag_name in details.tag_names() {
+        let tag_id = match find_tag(db, tag_name).await? {
+            Some(tag) => tag.id,
+            None => {
+                TagCreate {
+                    name: tag_name.to_string(),
+                }
+                .into_active_model()
+                .insert(db)
+                .await?
+                .id
+            }
+        };
+        let tagged_count = recipe_tag::Entity::find()
+            .filter(recipe_tag::Column::RecipeId.eq(recipe_id))
+            .filter(recipe_tag::Column::TagId.eq(tag_id))
+            .count(db)
+            .await?;
+        if tagged_count == 0 {
+            RecipeTagCreate { recipe_id, tag_id }
+                .into_active_model()
+                .insert(db)
+                .await?;
+        }
+    }
+    let deleted_declared_nutrition_option = recipe_declared_nutrition::Entity::find()
+        .filter(recipe_declared_nutrition::Column::RecipeId.eq(recipe_id))
+        .one(db)
+        .await?;
+    if let Some(deleted_declared_nutrition) = &deleted_declared_nutrition_option {
+        recipe_declared_nutrition::Entity::delete_by_id(deleted_declared_nutrition.id)
+            .exec(db)
+            .await?;
+    }
+    if let Some(nutrition) = &details.nutrition {
+        RecipeDeclaredNutritionCreate {
+            energy: nutrition.energy,
+            protein: nutrition.protein,
+            fat: nutrition.fat,
+            carbohydrates: nutrition.carbohydrates,
+            fibre: nutrition.fibre,
+            salt: nutrition.salt,
+            recipe_id,
+        }
+        .into_active_model()
+        .insert(db)
+        .await?;
+    }
+    Ok(deleted_declared_nutrition_option
+        .map(|deleted_declared_nutrition| deleted_declared_nutrition.id))
+}
+
+/// Emit the created events of the content and details inserted by [`insert_content`] and [`insert_details`].
+pub fn emit_content_created() -> Result<(), tauri::Error> {
+    let window = get_window();
+    window.emit(ENTITY_ACTION_CREATED_TAG, ())?;
+    window.emit(ENTITY_ACTION_CREATED_RECIPE_TAG, ())?;
+    window.emit(ENTITY_ACTION_CREATED_RECIPE_DECLARED_NUTRITION, ())?;
+    window.emit(ENTITY_ACTION_CREATED_RECIPE_INGREDIENT_DRAFT, ())?;
+    window.emit(ENTITY_ACTION_CREATED_FILE, ())?;
+    window.emit(ENTITY_ACTION_CREATED_RECIPE_FILE, ())?;
+    window.emit(ENTITY_ACTION_CREATED_RECIPE_STEP, ())?;
+    window.emit(ENTITY_ACTION_CREATED_INGREDIENT, ())?;
+    window.emit(ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT, ())?;
+    window.emit(ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT_DRAFT, ())?;
+    window.emit(ENTITY_ACTION_CREATED_RECIPE_STEP_FILE, ())?;
+    Ok(())
+}
+
+/// Insert a recipe with the content and details of an external recipe.
+async fn insert<C>(
+    db: &C,
+    recipe_create: RecipeCreate,
+    external_recipe: &ExternalRecipe,
+    downloaded_files: &DownloadedFiles,
+) -> Result<i64, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let mut recipe_active_model = recipe_create.into_active_model();
+    set_details(&mut recipe_active_model, &external_recipe.details);
+    let recipe = recipe_active_model.insert(db).await?;
+    insert_content(db, recipe.id, external_recipe, downloaded_files).await?;
+    insert_details(db, recipe.id, &external_recipe.details).await?;
+    Ok(recipe.id)
+}
+
+/// Normalize the URL of an external recipe and check whether it was already imported.
+///
+/// Unless `allow_duplicate` is `true`, this fails with [`CommandError::ExternalRecipeDuplicate`]
+/// if a recipe was already imported from the same URL.
+async fn prepare(url: &str, allow_duplicate: Option<bool>) -> Result<String, CommandError> {
+    let source_url = normalize_source_url(url)?;
+    if !allow_duplicate.unwrap_or_default() {
+        let db = database::connect().await;
+        if let Some(recipe_id) = select_duplicate(db, &source_url).await? {
+            return Err(CommandError::ExternalRecipeDuplicate(recipe_id));
+        }
+    }
+    Ok(source_url)
+}
+
+/// This enum represents what an external recipe is imported from.
+enum ImportSource {
+    Url(String),
+    /// a saved page, whose embedded files are not downloaded again
+    SavedPage(Arc<SavedPage>),
+}
+
+/// Import an external recipe, emitting the progress of the import.
+///
+/// The files are downloaded first, then the recipe and its content are inserted in one transaction.
+/// A cancelled import discards the downloaded files, but it can not be cancelled anymore once it is saved.
+async fn import(
+    import: &Import,
+    source: ImportSource,
+    source_url: String,
+) -> Result<ExternalRecipeImport, CommandError> {
+    import.emit(ImportStage::Fetching);
+    let (getter_name, external_recipe) = {
+        let import = import.clone();
+        let on_fetched = move || import.emit(ImportStage::Parsing);
+        match &source {
+            ImportSource::Url(url) => {
+                get_with_getter_name_observed(url.clone(), on_fetched).await?
+            }
+            ImportSource::SavedPage(saved_page) => {
+                get_saved_with_getter_name_observed(saved_page.clone(), on_fetched).await?
+            }
+        }
+    };
+    import.check_cancelled()?;
+    let saved_page_option = match &source {
+        ImportSource::Url(_) => None,
+        ImportSource::SavedPage(saved_page) => Some(saved_page.as_ref()),
+    };
+    let downloaded_files = download_files(&external_recipe, saved_page_option, Some(import)).await;
+    if import.is_cancelled() {
+        downloaded_files.discard().await;
+        return Err(CommandError::ExternalRecipeImportCancelled);
+    }
+    import.emit(ImportStage::Saving);
+    // the yield and the other details are set by set_details
+    let recipe_create = RecipeCreate {
+        name: external_recipe.name.clone(),
+        yield_amount: None,
+        yield_unit: None,
+        source_url: Some(source_url),
+        source_getter: Some(getter_name),
+        imported_at: Some(format_date_time(SystemTime::now())),
+        description: None,
+        author: None,
+        prep_time: None,
+        cook_time: None,
+        total_time: None,
+    };
+    let db = database::connect_writing().await;
+    let insert_result = async {
+        let txn = db.begin().await?;
+        let recipe_id = insert(&txn, recipe_create, &external_recipe, &downloaded_files).await?;
+        txn.commit().await?;
+        Ok::<_, DbErr>(recipe_id)
+    }
+    .await;
+    let recipe_id = match insert_result {
+        Ok(recipe_id) => recipe_id,
+        Err(err) => {
+            downloaded_files.discard().await;
+            return Err(err.into());
+        }
+    };
+    get_window().emit(ENTITY_ACTION_CREATED_RECIPE, ())?;
+    emit_content_created()?;
+    Ok(ExternalRecipeImport {
+        recipe_id,
+        failed_files: downloaded_files.failed_files,
+    })
+}
+
+/// Import an external recipe from a URL and wait for the import to finish.
+///
+/// See [`external_recipe_import_start`] for an import which can be cancelled.
+#[tauri::command]
+pub async fn external_recipe(
+    url: String,
+    allow_duplicate: Option<bool>,
+) -> Result<ExternalRecipeImport, CommandError> {
+    let source_url = prepare(&url, allow_duplicate).await?;
+    import(&Import::default(), ImportSource::Url(url), source_url).await
+}
+
+/// Import an external recipe from a page saved as HTML, MHTML or web archive file and wait for the import to finish.
+///
+/// The original URL of the page chooses the getter and is remembered as the source of the recipe.
+/// See [`external_recipe_saved_import_start`] for an import which can be cancelled.
+#[tauri::command]
+pub async fn external_recipe_saved(
+    path: String,
+    allow_duplicate: Option<bool>,
+) -> Result<ExternalRecipeImport, CommandError> {
+    let saved_page = SavedPage::read(Path::new(&path)).await?;
+    let source_url = prepare(saved_page.url.as_str(), allow_duplicate).await?;
+    import(
+        &Import::default(),
+        ImportSource::SavedPage(Arc::new(saved_page)),
+        source_url,
+    )
+    .await
+}
+
+/// Start importing an external recipe from a URL in the background and return the id of the import.
+///
+/// The import emits its progress on [`EXTERNAL_RECIPE_IMPORT_PROGRESS`] and how it ended on [`EXTERNAL_RECIPE_IMPORT_FINISHED`].
+/// It can be cancelled via [`external_recipe_import_cancel`].
+#[tauri::command]
+pub async fn external_recipe_import_start(
+    url: String,
+    allow_duplicate: Option<bool>,
+) -> Result<String, CommandError> {
+    let source_url = prepare(&url, allow_duplicate).await?;
+    Ok(start(ImportSource::Url(url), source_url))
+}
+
+/// Start importing an external recipe from a saved page in the background and return the id of the import.
+///
+/// See [`external_recipe_saved`] for the saved pages and [`external_recipe_import_start`] for the running import.
+#[tauri::command]
+pub async fn external_recipe_saved_import_start(
+    path: String,
+    allow_duplicate: Option<bool>,
+) -> Result<String, CommandError> {
+    let saved_page = SavedPage::read(Path::new(&path)).await?;
+    let source_url = prepare(saved_page.url.as_str(), allow_duplicate).await?;
+    Ok(start(
+        ImportSource::SavedPage(Arc::new(saved_page)),
+        source_url,
+    ))
+}
+
+/// Start an import in the background and return its id.
+fn start(source: ImportSource, source_url: String) -> String {
+    let running_import = Import::default();
+    RUNNING_IMPORTS.lock().unwrap().push(running_import.clone());
+    let import_id = running_import.id.clone();
+    tokio::spawn(async move {
+        let outcome = match import(&running_import, source, source_url).await {
+            Ok(external_recipe_import) => ImportOutcome::Imported(external_recipe_import),
+            Err(CommandError::ExternalRecipeImportCancelled) => ImportOutcome::Cancelled,
+            Err(err) => ImportOutcome::Failed {
+                error: err.to_string(),
+            },
+        };
+        RUNNING_IMPORTS
+            .lock()
+            .unwrap()
+            .retain(|import| import.id != running_import.id);
+        get_window()
+            .emit(
+                EXTERNAL_RECIPE_IMPORT_FINISHED,
+                ImportPayload {
+                    import_id: running_import.id,
+                    data: outcome,
+                },
+            )
+            .ok();
+    });
+    import_id
+}
+
+/// Cancel an import started by [`external_recipe_import_start`].
+///
+/// # Errors
+///
+/// - [`CommandError::NotFound`] when the import already finished or never existed
+#[tauri::command]
+pub async fn external_recipe_import_cancel(import_id: String) -> Result<(), CommandError> {
+    let running_imports = RUNNING_IMPORTS.lock().unwrap();
+    let running_import = running_imports
+        .iter()
+        .find(|import| import.id == import_id)
+        .ok_or(CommandError::NotFound)?;
+    running_import.cancelled.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        entity::{
+            ingredient, recipe_file, recipe_ingredient_draft, recipe_step, recipe_step_file,
+            recipe_step_ingredient, recipe_step_ingredient_draft,
+        },
+        external_recipe::details::ExternalRecipeNutrition,
+        migrator::tests::get_memory_database_migrated,
+    };
+
+    #[test]
+    fn test_normalize_source_url() {
+        assert_eq!(
+            normalize_source_url("HTTPS://Example.com:443/rezept?id=1#kommentare").unwrap(),
+            "https://example.com/rezept?id=1"
+        );
+        assert!(normalize_source_url("kein Link").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_select_duplicate() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`, `source_url`) VALUES \
+            (1, 'Pfannkuchen', NULL), (2, 'Stollen', 'https://example.com/stollen'), (3, 'Stollen', 'https://example.com/stollen');",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            select_duplicate(&db, "https://example.com/stollen")
+                .await
+                .unwrap(),
+            Some(2)
+        );
+        assert_eq!(
+            select_duplicate(&db, "https://example.com/pfannkuchen")
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_normalize_content() {
+        let ingredient_names = HashMap::from([(String::from("butter"), String::from("Butter"))]);
+        let external_recipe = ExternalRecipe {
+            name: String::from("Pfannkuchen"),
+            files: vec![
+                String::from("https://example.com/kaputt.jpg"),
+                String::from("https://example.com/pfannkuchen.jpg"),
+            ],
+            steps: vec![ExternalRecipeStep {
+                ingredients: vec![String::from("1 Prise Zucker")],
+                description: String::from("Braten"),
+                files: vec![String::from("https://example.com/kaputt.jpg")],
+                parsed_ingredients: vec![
+                    ExternalRecipeIngredient {
+                        quantity: Some(1.0),
+                        unit: Some(String::from("EL")),
+                        name: String::from("butter"),
+                        quality: None,
+                    },
+                    ExternalRecipeIngredient {
+                        name: String::from("Salz"),
+                        ..Default::default()
+                    },
+                    ExternalRecipeIngredient {
+                        quantity: Some(1.0),
+                        unit: Some(String::from("TL")),
+                        name: String::from("BUTTER"),
+                        quality: Some(String::from("zum Servieren")),
+                    },
+                ],
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            normalize_content(&external_recipe, &ingredient_names, |url| {
+                url == "https://example.com/pfannkuchen.jpg"
+            }),
+            ExternalRecipe {
+                name: String::from("Pfannkuchen"),
+                files: vec![String::from("https://example.com/pfannkuchen.jpg")],
+                steps: vec![ExternalRecipeStep {
+                    ingredients: vec![
+                        String::from("1 Prise Zucker"),
+                        String::from("1 TL BUTTER (zum Servieren)"),
+                    ],
+                    description: String::from("Braten"),
+                    files: vec![],
+                    parsed_ingredients: vec![
+                        ExternalRecipeIngredient {
+                            quantity: Some(1.0),
+                            unit: Some(String::from("EL")),
+                            name: String::from("Butter"),
+                            quality: None,
+                        },
+                        ExternalRecipeIngredient {
+                            name: String::from("Salz"),
+                            ..Default::default()
+                        },
+                    ],
+                }],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert() {
+        let db = get_memory_database_migrated().await;
+        let external_recipe = ExternalRecipe {
+            name: String::from("Pfannkuchen"),
+            ingredients: vec![String::from("200 g Mehl"), String::from("2 Eier")],
+            files: vec![
+                String::from("https://example.com/kaputt.jpg"),
+                String::from("https://example.com/pfannkuchen.jpg"),
+            ],
+            steps: vec![
+                ExternalRecipeStep {
+                    ingredients: vec![String::from("200 g Mehl")],
+                    description: String::from("Teig rühren"),
+                    files: vec![String::from("https://example.com/pfannkuchen.jpg")],
+                    parsed_ingredients: vec![],
+                },
+                ExternalRecipeStep {
+                    description: String::from("Braten"),
+                    parsed_ingredients: vec![
+                        ExternalRecipeIngredient {
+                            quantity: Some(1.0),
+                            unit: Some(String::from("EL")),
+                            name: String::from("Butter"),
+                            quality: None,
+                        },
+                        ExternalRecipeIngredient {
+                            name: String::from("Salz"),
+                            ..Default::default()
+                        },
+                        ExternalRecipeIngredient {
+                            quantity: Some(1.0),
+                            unit: Some(String::from("TL")),
+                            name: String::from("butter"),
+                            quality: Some(String::from("zum Servieren")),
+                        },
+                    ],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let downloaded_files = DownloadedFiles {
+            files: HashMap::from([(
+                String::from("https://example.com/pfannkuchen.jpg"),
+                file::ActiveModel {
+                    id: ActiveValue::NotSet,
+                    name: ActiveValue::Set(String::from("https://example.com/pfannkuchen.jpg")),
+                    mime: ActiveValue::Set(String::from("image/jpeg")),
+                    path: ActiveValue::Set(String::from("/pfannkuchen.jpg")),
+                },
+            )]),
+            failed_files: vec![FailedFile {
+                url: String::from("https://example.com/kaputt.jpg"),
+                error: String::from("404"),
+            }],
+        };
+        let recipe_create = RecipeCreate {
+            name: external_recipe.name.clone(),
+            yield_amount: None,
+            yield_unit: None,
+            source_url: Some(String::from("https://example.com/pfannkuchen")),
+            source_getter: Some(String::from("yoast_schema_graph")),
+            imported_at: Some(String::from("2026-10-18T13:45:30Z")),
+            description: None,
+            author: None,
+            prep_time: None,
+            cook_time: None,
+            total_time: None,
+        };
+        let recipe_id = insert(&db, recipe_create, &external_recipe, &downloaded_files)
             .await
             .unwrap();
-        });
-    }
-    for (i, file) in external_recipe.files.into_iter().enumerate() {
-        tokio::spawn(async move {
-            let file_id = FileCrud::create(FileCreate {
-                name: file.clone(),
-                uri: FileCreateUri::Url(file),
-            })
+        let recipe_ingredient_drafts = recipe_ingredient_draft::Entity::find()
+            .filter(recipe_ingredient_draft::Column::RecipeId.eq(recipe_id))
+            .order_by_asc(recipe_ingredient_draft::Column::Order)
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|recipe_ingredient_draft| recipe_ingredient_draft.text)
+            .collect::<Vec<String>>();
+        assert_eq!(recipe_ingredient_drafts, external_recipe.ingredients);
+        let files = file::Entity::find().all(&db).await.unwrap();
+        assert_eq!(files.len(), 1);
+        let recipe_files = recipe_file::Entity::find()
+            .filter(recipe_file::Column::RecipeId.eq(recipe_id))
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|recipe_file| (recipe_file.order, recipe_file.file_id))
+            .collect::<Vec<(i64, i64)>>();
+        assert_eq!(recipe_files, vec![(1, files[0].id)]);
+        let recipe_steps = recipe_step::Entity::find()
+            .filter(recipe_step::Column::RecipeId.eq(recipe_id))
+            .order_by_asc(recipe_step::Column::Order)
+            .all(&db)
             .await
             .unwrap();
-            RecipeFileCrud::create(RecipeFileCreate {
-                order: (i + 1) as i64,
-                recipe_id,
-                file_id,
+        assert_eq!(
+            recipe_steps
+                .iter()
+                .map(|recipe_step| recipe_step.description.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["Teig rühren", "Braten"]
+        );
+        let recipe_step_files = recipe_step_file::Entity::find()
+            .filter(recipe_step_file::Column::RecipeStepId.eq(recipe_steps[0].id))
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|recipe_step_file| (recipe_step_file.order, recipe_step_file.file_id))
+            .collect::<Vec<(i64, i64)>>();
+        assert_eq!(recipe_step_files, vec![(1, files[0].id)]);
+        let recipe_step_ingredients = recipe_step_ingredient::Entity::find()
+            .find_also_related(ingredient::Entity)
+            .filter(recipe_step_ingredient::Column::RecipeStepId.eq(recipe_steps[1].id))
+            .order_by_asc(recipe_step_ingredient::Column::Order)
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|(recipe_step_ingredient, ingredient_option)| {
+                (
+                    recipe_step_ingredient.quantity,
+                    recipe_step_ingredient.unit,
+                    ingredient_option.unwrap().name,
+                )
             })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            recipe_step_ingredients,
+            vec![
+                (Some(1.0), Some(String::from("EL")), String::from("Butter")),
+                (None, None, String::from("Salz")),
+            ]
+        );
+        let recipe_step_ingredient_drafts = recipe_step_ingredient_draft::Entity::find()
+            .filter(recipe_step_ingredient_draft::Column::RecipeStepId.eq(recipe_steps[1].id))
+            .all(&db)
             .await
-            .unwrap();
-        });
+            .unwrap()
+            .into_iter()
+            .map(|recipe_step_ingredient_draft| recipe_step_ingredient_draft.text)
+            .collect::<Vec<String>>();
+        assert_eq!(
+            recipe_step_ingredient_drafts,
+            vec![String::from("1 TL butter (zum Servieren)")]
+        );
     }
-    for (i, step) in external_recipe.steps.into_iter().enumerate() {
-        tokio::spawn(async move {
-            let recipe_step_id = RecipeStepCrud::create(RecipeStepCreate {
-                recipe_id,
-                description: step.description,
-                order: (i + 1) as i64,
-            })
+
+    #[test]
+    fn test_set_details() {
+        let mut recipe = recipe::ActiveModel::default();
+        set_details(
+            &mut recipe,
+            &ExternalRecipeDetails {
+                description: Some(String::from("Fluffig")),
+                author: Some(String::from("Oma")),
+                prep_time: Some(600),
+                total_time: Some(1_800),
+                recipe_yield: Some(String::from("Für 4 Portionen")),
+                ..Default::default()
+            },
+        );
+        assert_eq!(recipe.yield_amount, ActiveValue::Set(Some(4.0)));
+        assert_eq!(
+            recipe.yield_unit,
+            ActiveValue::Set(Some(String::from("Portionen")))
+        );
+        assert_eq!(
+            recipe.description,
+            ActiveValue::Set(Some(String::from("Fluffig")))
+        );
+        assert_eq!(recipe.author, ActiveValue::Set(Some(String::from("Oma"))));
+        assert_eq!(recipe.prep_time, ActiveValue::Set(Some(600)));
+        assert_eq!(recipe.cook_time, ActiveValue::Set(None));
+        assert_eq!(recipe.total_time, ActiveValue::Set(Some(1_800)));
+        set_details(&mut recipe, &ExternalRecipeDetails::default());
+        assert_eq!(recipe.yield_amount, ActiveValue::Set(None));
+        assert_eq!(recipe.yield_unit, ActiveValue::Set(None));
+    }
+
+    #[tokio::test]
+    async fn test_insert_details() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`) VALUES (1, 'Pfannkuchen'); \
+            INSERT INTO `tag` (`id`, `name`) VALUES (1, 'Dessert'), (2, 'schnell'); \
+            INSERT INTO `recipe_tag` (`recipe_id`, `tag_id`) VALUES (1, 2);",
+        )
+        .await
+        .unwrap();
+        let details = ExternalRecipeDetails {
+            categories: vec![String::from("Dessert")],
+            cuisines: vec![String::from("Französisch")],
+            keywords: vec![String::from("Schnell")],
+            nutrition: Some(ExternalRecipeNutrition {
+                energy: Some(240.0),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(insert_details(&db, 1, &details).await.unwrap(), None);
+        let tag_names = tag::Entity::find()
+            .inner_join(recipe_tag::Entity)
+            .filter(recipe_tag::Column::RecipeId.eq(1))
+            .order_by_asc(tag::Column::Id)
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|tag| tag.name)
+            .collect::<Vec<String>>();
+        assert_eq!(tag_names, vec!["Dessert", "schnell", "Französisch"]);
+        assert_eq!(tag::Entity::find().count(&db).await.unwrap(), 3);
+        let declared_nutrition = recipe_declared_nutrition::Entity::find()
+            .one(&db)
             .await
+            .unwrap()
             .unwrap();
-            for (i, ingredient) in step.ingredients.into_iter().enumerate() {
-                tokio::spawn(async move {
-                    RecipeStepIngredientDraftCrud::create(RecipeStepIngredientDraftCreate {
-                        order: (i + 1) as i64,
-                        text: ingredient,
-                        recipe_step_id,
-                    })
-                    .await
-                    .unwrap();
-                });
-            }
-            for (i, file) in step.files.into_iter().enumerate() {
-                tokio::spawn(async move {
-                    let file_id = FileCrud::create(FileCreate {
-                        name: file.clone(),
-                        uri: FileCreateUri::Url(file),
-                    })
-                    .await
-                    .unwrap();
-                    RecipeStepFileCrud::create(RecipeStepFileCreate {
-                        order: (i + 1) as i64,
-                        recipe_step_id,
-                        file_id,
-                    })
-                    .await
-                    .unwrap();
-                });
-            }
-        });
+        assert_eq!(declared_nutrition.energy, Some(240.0));
+        assert_eq!(declared_nutrition.protein, None);
+
+        // the details of a refresh replace the declared nutrition
+        let details = ExternalRecipeDetails {
+            keywords: vec![String::from("Dessert")],
+            ..Default::default()
+        };
+        assert_eq!(
+            insert_details(&db, 1, &details).await.unwrap(),
+            Some(declared_nutrition.id)
+        );
+        assert_eq!(recipe_tag::Entity::find().count(&db).await.unwrap(), 3);
+        assert_eq!(
+            recipe_declared_nutrition::Entity::find()
+                .count(&db)
+                .await
+                .unwrap(),
+            0
+        );
     }
-    Ok(recipe_id)
 }
@@ -0,0 +1,423 @@
+//! This module implements the [`tauri::command`] for converting ingredient drafts into recipe step ingredients.
+//!
+//! The draft texts are parsed with [`crate::ingredient_parser::parse`].
+
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
+    IntoActiveModel, QueryFilter, QueryOrder, TransactionTrait,
+};
+use serde::Serialize;
+
+use crate::{
+    command::{error::CommandError, unit_list::select_unit_names},
+    database,
+    entity::{
+        ingredient, recipe_ingredient_draft, recipe_step, recipe_step_ingredient,
+        recipe_step_ingredient_draft,
+    },
+    entity_crud::recipe_step_ingredient::RecipeStepIngredientCreate,
+    event::channel::{
+        ENTITY_ACTION_CREATED_INGREDIENT, ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT,
+        ENTITY_ACTION_DELETED_RECIPE_INGREDIENT_DRAFT,
+        ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT_DRAFT,
+    },
+    ingredient_parser::{self, Quantity},
+    window::get_window,
+};
+
+/// This enum represents the entity of an ingredient draft.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DraftSource {
+    RecipeIngredientDraft,
+    RecipeStepIngredientDraft,
+}
+
+/// This struct represents a draft which was converted and deleted.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertedDraft {
+    pub source: DraftSource,
+    pub id: i64,
+    pub recipe_step_ingredient_id: i64,
+}
+
+/// This struct represents a draft which could not be converted and is kept.
+///
+/// A draft can not be converted if its ingredient is already used in the recipe step.
+/// A [recipe ingredient draft](recipe_ingredient_draft::Model) can not be converted if the recipe has no steps.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnconvertedDraft {
+    pub source: DraftSource,
+    pub id: i64,
+    pub text: String,
+}
+
+/// This struct represents the result of converting the ingredient drafts of a recipe.
+#[derive(Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngredientDraftConversion {
+    pub converted: Vec<ConvertedDraft>,
+    pub unconverted: Vec<UnconvertedDraft>,
+    pub created_ingredient_ids: Vec<i64>,
+}
+
+/// This struct represents an ingredient draft of either entity.
+struct Draft {
+    source: DraftSource,
+    id: i64,
+    text: String,
+    /// the recipe step to add the recipe step ingredient to
+    recipe_step_id: Option<i64>,
+}
+
+/// This struct represents the existing recipe step ingredients of a recipe step.
+struct RecipeStepIngredients {
+    next_order: i64,
+    /// the ingredients already used in the recipe step, each can only be used once
+    ingredient_ids: HashSet<i64>,
+}
+
+/// Get the existing recipe step ingredients of a recipe step.
+async fn select_recipe_step_ingredients<C>(
+    db: &C,
+    recipe_step_id: i64,
+) -> Result<RecipeStepIngredients, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let models = recipe_step_ingredient::Entity::find()
+        .filter(recipe_step_ingredient::Column::RecipeStepId.eq(recipe_step_id))
+        .all(db)
+        .await?;
+    Ok(RecipeStepIngredients {
+        next_order: models.iter().map(|model| model.order).max().unwrap_or(0) + 1,
+        ingredient_ids: models.iter().map(|model| model.ingredient_id).collect(),
+    })
+}
+
+/// Get the ingredient drafts of a recipe.
+///
+/// The recipe ingredient drafts are assigned to the first recipe step.
+async fn select_drafts<C>(db: &C, recipe_id: i64) -> Result<Vec<Draft>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let recipe_steps = recipe_step::Entity::find()
+        .filter(recipe_step::Column::RecipeId.eq(recipe_id))
+        .order_by_asc(recipe_step::Column::Order)
+        .all(db)
+        .await?;
+    let first_recipe_step_id = recipe_steps.first().map(|recipe_step| recipe_step.id);
+    let mut drafts: Vec<Draft> = recipe_ingredient_draft::Entity::find()
+        .filter(recipe_ingredient_draft::Column::RecipeId.eq(recipe_id))
+        .order_by_asc(recipe_ingredient_draft::Column::Order)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| Draft {
+            source: DraftSource::RecipeIngredientDraft,
+            id: model.id,
+            text: model.text,
+            recipe_step_id: first_recipe_step_id,
+        })
+        .collect();
+    for recipe_step in recipe_steps {
+        let recipe_step_drafts = recipe_step_ingredient_draft::Entity::find()
+            .filter(recipe_step_ingredient_draft::Column::RecipeStepId.eq(recipe_step.id))
+            .order_by_asc(recipe_step_ingredient_draft::Column::Order)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|model| Draft {
+                source: DraftSource::RecipeStepIngredientDraft,
+                id: model.id,
+                text: model.text,
+                recipe_step_id: Some(recipe_step.id),
+            });
+        drafts.extend(recipe_step_drafts);
+    }
+    Ok(drafts)
+}
+
+/// Get the names of all ingredients by their lowercase names.
+pub async fn select_ingredient_names<C>(db: &C) -> Result<HashMap<String, String>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Ok(ingredient::Entity::find()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| (model.name.to_lowercase(), model.name))
+        .collect())
+}
+
+/// Get the ids of all ingredients by their lowercase names.
+pub async fn select_ingredient_ids<C>(db: &C) -> Result<HashMap<String, i64>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Ok(ingredient::Entity::find()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| (model.name.to_lowercase(), model.id))
+        .collect())
+}
+
+/// Convert the ingredient drafts of a recipe into recipe step ingredients.
+///
+/// Ingredients are matched by their name ignoring case, or created.
+/// The recipe step ingredients are appended to the existing ones of their recipe step.
+async fn convert<C>(db: &C, recipe_id: i64) -> Result<IngredientDraftConversion, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let unit_names = select_unit_names(db).await?;
+    let mut ingredient_ids = select_ingredient_ids(db).await?;
+    let mut recipe_step_ingredients_by_step: HashMap<i64, RecipeStepIngredients> = HashMap::new();
+    let mut conversion = IngredientDraftConversion::default();
+    for draft in select_drafts(db, recipe_id).await? {
+        let parsed_ingredient = ingredient_parser::parse(&draft.text, &unit_names);
+        let (Some(recipe_step_id), Some(parsed_ingredient)) =
+            (draft.recipe_step_id, parsed_ingredient)
+        else {
+            conversion.unconverted.push(UnconvertedDraft {
+                source: draft.source,
+                id: draft.id,
+                text: draft.text,
+            });
+            continue;
+        };
+        let ingredient_id = match ingredient_ids.get(&parsed_ingredient.name.to_lowercase()) {
+            Some(ingredient_id) => *ingredient_id,
+            None => {
+                let ingredient = ingredient::ActiveModel {
+                    name: ActiveValue::Set(parsed_ingredient.name.clone()),
+                    ..Default::default()
+                }
+                .insert(db)
+                .await?;
+                ingredient_ids.insert(ingredient.name.to_lowercase(), ingredient.id);
+                conversion.created_ingredient_ids.push(ingredient.id);
+                ingredient.id
+            }
+        };
+        let recipe_step_ingredients = match recipe_step_ingredients_by_step.entry(recipe_step_id) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                entry.insert(select_recipe_step_ingredients(db, recipe_step_id).await?)
+            }
+        };
+        if !recipe_step_ingredients.ingredient_ids.insert(ingredient_id) {
+            conversion.unconverted.push(UnconvertedDraft {
+                source: draft.source,
+                id: draft.id,
+                text: draft.text,
+            });
+            continue;
+        }
+        let recipe_step_ingredient = RecipeStepIngredientCreate {
+            order: recipe_step_ingredients.next_order,
+            quantity: parsed_ingredient.quantity.as_ref().map(Quantity::value),
+            unit: parsed_ingredient.unit.clone(),
+            quality: parsed_ingredient.quality(),
+            recipe_step_id,
+            ingredient_id,
+        }
+        .into_active_model()
+        .insert(db)
+        .await?;
+        recipe_step_ingredients.next_order += 1;
+        match draft.source {
+            DraftSource::RecipeIngredientDraft => {
+                recipe_ingredient_draft::Entity::delete_by_id(draft.id)
+                    .exec(db)
+                    .await?;
+            }
+            DraftSource::RecipeStepIngredientDraft => {
+                recipe_step_ingredient_draft::Entity::delete_by_id(draft.id)
+                    .exec(db)
+                    .await?;
+            }
+        }
+        conversion.converted.push(ConvertedDraft {
+            source: draft.source,
+            id: draft.id,
+            recipe_step_ingredient_id: recipe_step_ingredient.id,
+        });
+    }
+    Ok(conversion)
+}
+
+/// Convert all ingredient drafts of a recipe into recipe step ingredients in one transaction.
+///
+/// Drafts which can not be parsed are kept and returned as [unconverted](IngredientDraftConversion::unconverted).
+#[tauri::command]
+pub async fn ingredient_draft_convert(
+    recipe_id: i64,
+) -> Result<IngredientDraftConversion, CommandError> {
+    let db = database::connect_writing().await;
+    let txn = db.begin().await?;
+    let conversion = convert(&txn, recipe_id).await?;
+    txn.commit().await?;
+    let window = get_window();
+    if !conversion.created_ingredient_ids.is_empty() {
+        window.emit(ENTITY_ACTION_CREATED_INGREDIENT, ())?;
+    }
+    if !conversion.converted.is_empty() {
+        window.emit(ENTITY_ACTION_CREATED_RECIPE_STEP_INGREDIENT, ())?;
+    }
+    for converted_draft in &conversion.converted {
+        let channel = match converted_draft.source {
+            DraftSource::RecipeIngredientDraft => ENTITY_ACTION_DELETED_RECIPE_INGREDIENT_DRAFT,
+            DraftSource::RecipeStepIngredientDraft => {
+                ENTITY_ACTION_DELETED_RECIPE_STEP_INGREDIENT_DRAFT
+            }
+        };
+        window.emit(channel, converted_draft.id)?;
+    }
+    Ok(conversion)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sea_orm::Statement;
+
+    use super::*;
+    use crate::migrator::tests::get_memory_database_migrated;
+
+    #[tokio::test]
+    async fn test_convert() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`) VALUES (1, 'Pizza'); \
+            INSERT INTO `recipe_step` (`id`, `order`, `description`, `recipe_id`) VALUES (1, 1, 'Teig kneten', 1); \
+            INSERT INTO `recipe_step` (`id`, `order`, `description`, `recipe_id`) VALUES (2, 2, 'Belegen', 1); \
+            INSERT INTO `ingredient` (`id`, `name`) VALUES (1, 'Mehl'); \
+            INSERT INTO `recipe_step_ingredient` (`id`, `order`, `recipe_step_id`, `ingredient_id`) VALUES (1, 1, 1, 1); \
+            INSERT INTO `recipe_ingredient_draft` (`id`, `order`, `text`, `recipe_id`) VALUES (1, 1, '1TL Honig', 1); \
+            INSERT INTO `recipe_ingredient_draft` (`id`, `order`, `text`, `recipe_id`) VALUES (2, 2, '500 g', 1); \
+            INSERT INTO `recipe_step_ingredient_draft` (`id`, `order`, `text`, `recipe_step_id`) VALUES (1, 1, '2 EL Mehl', 1); \
+            INSERT INTO `recipe_step_ingredient_draft` (`id`, `order`, `text`, `recipe_step_id`) VALUES (2, 1, '450g mehl (Typ 550)', 2); \
+            INSERT INTO `recipe_step_ingredient_draft` (`id`, `order`, `text`, `recipe_step_id`) VALUES (3, 2, '1-2 TL Salz', 2);",
+        )
+        .await
+        .unwrap();
+        let conversion = convert(&db, 1).await.unwrap();
+        assert_eq!(
+            conversion,
+            IngredientDraftConversion {
+                converted: vec![
+                    ConvertedDraft {
+                        source: DraftSource::RecipeIngredientDraft,
+                        id: 1,
+                        recipe_step_ingredient_id: 2,
+                    },
+                    ConvertedDraft {
+                        source: DraftSource::RecipeStepIngredientDraft,
+                        id: 2,
+                        recipe_step_ingredient_id: 3,
+                    },
+                    ConvertedDraft {
+                        source: DraftSource::RecipeStepIngredientDraft,
+                        id: 3,
+                        recipe_step_ingredient_id: 4,
+                    },
+                ],
+                unconverted: vec![
+                    UnconvertedDraft {
+                        source: DraftSource::RecipeIngredientDraft,
+                        id: 2,
+                        text: String::from("500 g"),
+                    },
+                    UnconvertedDraft {
+                        source: DraftSource::RecipeStepIngredientDraft,
+                        id: 1,
+                        text: String::from("2 EL Mehl"),
+                    },
+                ],
+                created_ingredient_ids: vec![2, 3],
+            }
+        );
+        let recipe_step_ingredients: Vec<(
+            i64,
+            Option<f64>,
+            Option<String>,
+            Option<String>,
+            i64,
+            i64,
+        )> = db
+            .query_all(Statement::from_string(
+                db.get_database_backend(),
+                "SELECT `order`, `quantity`, `unit`, `quality`, `recipe_step_id`, `ingredient_id` \
+                FROM `recipe_step_ingredient` ORDER BY `id`",
+            ))
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|query_result| query_result.try_get_many_by_index().unwrap())
+            .collect();
+        assert_eq!(
+            recipe_step_ingredients,
+            vec![
+                (1, None, None, None, 1, 1),
+                (2, Some(1.0), Some(String::from("TL")), None, 1, 2),
+                (
+                    1,
+                    Some(450.0),
+                    Some(String::from("g")),
+                    Some(String::from("Typ 550")),
+                    2,
+                    1
+                ),
+                (
+                    2,
+                    Some(1.0),
+                    Some(String::from("TL")),
+                    Some(String::from("1-2")),
+                    2,
+                    3
+                ),
+            ]
+        );
+        let remaining_drafts = db
+            .query_all(Statement::from_string(
+                db.get_database_backend(),
+                "SELECT `id` FROM `recipe_ingredient_draft` \
+                UNION ALL SELECT `id` FROM `recipe_step_ingredient_draft`",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(remaining_drafts.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_convert_without_recipe_steps() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`) VALUES (1, 'Pizza'); \
+            INSERT INTO `recipe_ingredient_draft` (`id`, `order`, `text`, `recipe_id`) VALUES (1, 1, '1TL Honig', 1);",
+        )
+        .await
+        .unwrap();
+        let conversion = convert(&db, 1).await.unwrap();
+        assert_eq!(
+            conversion,
+            IngredientDraftConversion {
+                converted: vec![],
+                unconverted: vec![UnconvertedDraft {
+                    source: DraftSource::RecipeIngredientDraft,
+                    id: 1,
+                    text: String::from("1TL Honig"),
+                }],
+                created_ingredient_ids: vec![],
+            }
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! This module implements the [`tauri::command`] for importing the nutrition facts of ingredients from a [nutrition dataset](crate::nutrition).
+
+use std::collections::HashMap;
+
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ConnectionTrait, EntityTrait, QueryOrder, TransactionTrait,
+};
+
+use crate::{
+    command::error::CommandError,
+    database,
+    entity::{ingredient, ingredient_nutrition},
+    event::channel::ENTITY_ACTION_CREATED_INGREDIENT_NUTRITION,
+    nutrition::{parse_dataset, Nutrients, BUNDLED_DATASET},
+    window::get_window,
+};
+
+/// Import the nutrition facts of all ingredients without ingredient nutrition from a nutrition dataset.
+///
+/// The ingredients are matched with the rows of the dataset by their names, ignoring case.
+/// Existing ingredient nutritions are never changed.
+///
+/// Returns the ids of the created ingredient nutritions.
+async fn import<C>(db: &C, dataset: &str) -> Result<Vec<i64>, CommandError>
+where
+    C: ConnectionTrait,
+{
+    let nutrients_by_name: HashMap<String, Nutrients> = parse_dataset(dataset)?
+        .into_iter()
+        .map(|(name, nutrients)| (name.to_lowercase(), nutrients))
+        .collect();
+    let ingredients = ingredient::Entity::find()
+        .find_also_related(ingredient_nutrition::Entity)
+        .order_by_asc(ingredient::Column::Id)
+        .all(db)
+        .await?;
+    let mut ids = vec![];
+    for (ingredient, ingredient_nutrition) in ingredients {
+        if ingredient_nutrition.is_some() {
+            continue;
+        }
+        let Some(nutrients) = nutrients_by_name.get(&ingredient.name.to_lowercase()) else {
+            continue;
+        };
+        let model = ingredient_nutrition::ActiveModel {
+            id: ActiveValue::NotSet,
+            energy: ActiveValue::Set(nutrients.energy),
+            protein: ActiveValue::Set(nutrients.protein),
+            fat: ActiveValue::Set(nutrients.fat),
+            carbohydrates: ActiveValue::Set(nutrients.carbohydrates),
+            fibre: ActiveValue::Set(nutrients.fibre),
+            salt: ActiveValue::Set(nutrients.salt),
+            ingredient_id: ActiveValue::Set(ingredient.id),
+        }
+        .insert(db)
+        .await?;
+        ids.push(model.id);
+    }
+    Ok(ids)
+}
+
+/// Import the nutrition facts of ingredients from a CSV file, or from the bundled dataset of common ingredients if no path is given.
+///
+/// See [`parse_dataset`] for the format of the CSV file.
+///
+/// Returns the ids of the created ingredient nutritions.
+///
+/// # Errors
+///
+/// - when the file can not be read
+/// - when the file is not a valid nutrition dataset
+#[tauri::command]
+pub async fn ingredient_nutrition_import(path: Option<String>) -> Result<Vec<i64>, CommandError> {
+    let dataset = match path {
+        Some(path) => tokio::fs::read_to_string(path)
+            .await
+            .map_err(anyhow::Error::from)?,
+        None => BUNDLED_DATASET.to_string(),
+    };
+    let db = database::connect_writing().await;
+    let txn = db.begin().await?;
+    let ids = import(&txn, &dataset).await?;
+    txn.commit().await?;
+    if !ids.is_empty() {
+        get_window().emit(ENTITY_ACTION_CREATED_INGREDIENT_NUTRITION, ())?;
+    }
+    Ok(ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sea_orm::Statement;
+
+    use super::*;
+    use crate::migrator::tests::get_memory_database_migrated;
+
+    #[tokio::test]
+    async fn test_import() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `ingredient` (`id`, `name`) VALUES (1, 'Mehl'), (2, 'Chorizo'), (3, 'MILCH'), (4, 'Zucker'); \
+            INSERT INTO `ingredient_nutrition` (`id`, `energy`, `protein`, `fat`, `carbohydrates`, `fibre`, `salt`, `ingredient_id`) VALUES \
+            (1, 400, 0, 0, 100, 0, 0, 4);",
+        )
+        .await
+        .unwrap();
+        let ids = import(&db, BUNDLED_DATASET).await.unwrap();
+        assert_eq!(ids, vec![2, 3]);
+        let ingredient_nutritions: Vec<(i64, f64)> = db
+            .query_all(Statement::from_string(
+                db.get_database_backend(),
+                "SELECT `ingredient_id`, `energy` FROM `ingredient_nutrition` ORDER BY `id`",
+            ))
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|query_result| query_result.try_get_many_by_index().unwrap())
+            .collect();
+        assert_eq!(
+            ingredient_nutritions,
+            vec![(4, 400.0), (1, 364.0), (3, 61.0)]
+        );
+        let ids = import(&db, BUNDLED_DATASET).await.unwrap();
+        assert_eq!(ids, Vec::<i64>::new());
+        assert!(import(&db, "Mehl,364").await.is_err());
+    }
+}
@@ -0,0 +1,284 @@
+//! This module implements the [`tauri::command`]s for working with the meal plan of a date range.
+//!
+//! The meal plan consists of the [meal plan entries](meal_plan_entry::Model) in the date range.
+
+use std::time::SystemTime;
+
+use anyhow::anyhow;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter,
+    QueryOrder, TransactionTrait,
+};
+
+use crate::{
+    calendar,
+    calendar::parse_date,
+    command::{
+        error::CommandError,
+        shopping_list_add::{add_recipes, ShoppingListRecipe},
+    },
+    database,
+    entity::{meal_plan_entry, meal_plan_entry::slot::Slot, recipe, shopping_list},
+    event::channel::{
+        ENTITY_ACTION_CREATED_SHOPPING_LIST, ENTITY_ACTION_CREATED_SHOPPING_LIST_ITEM,
+    },
+    window::get_window,
+};
+
+/// Select the meal plan entries of a date range with their recipes, ordered by date and slot.
+///
+/// # Errors
+///
+/// - when there is any problem with the database
+/// - when a date is invalid
+async fn select_meal_plan<C>(
+    db: &C,
+    date_from: &str,
+    date_to: &str,
+) -> Result<Vec<(meal_plan_entry::Model, recipe::Model)>, CommandError>
+where
+    C: ConnectionTrait,
+{
+    for date in [date_from, date_to] {
+        parse_date(date).ok_or_else(|| anyhow!("\"{date}\" is not a date like \"2026-10-18\"."))?;
+    }
+    let mut meal_plan = meal_plan_entry::Entity::find()
+        .find_also_related(recipe::Entity)
+        .filter(meal_plan_entry::Column::Date.gte(date_from))
+        .filter(meal_plan_entry::Column::Date.lte(date_to))
+        .order_by_asc(meal_plan_entry::Column::Date)
+        .order_by_asc(meal_plan_entry::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .filter_map(|(meal_plan_entry, recipe)| Some((meal_plan_entry, recipe?)))
+        .collect::<Vec<_>>();
+    meal_plan.sort_by_key(|(meal_plan_entry, _)| {
+        (
+            meal_plan_entry.date.clone(),
+            slot_position(&meal_plan_entry.slot),
+        )
+    });
+    Ok(meal_plan)
+}
+
+/// Get the position of a slot in a day, custom slots are last.
+fn slot_position(slot: &Slot) -> u8 {
+    match slot {
+        Slot::Breakfast => 0,
+        Slot::Lunch => 1,
+        Slot::Dinner => 2,
+        Slot::Custom => 3,
+    }
+}
+
+fn slot_label(meal_plan_entry: &meal_plan_entry::Model) -> &str {
+    match meal_plan_entry.slot {
+        Slot::Breakfast => "Breakfast",
+        Slot::Lunch => "Lunch",
+        Slot::Dinner => "Dinner",
+        Slot::Custom => meal_plan_entry.slot_name.as_deref().unwrap_or("Meal"),
+    }
+}
+
+/// Create a shopping list with the recipes of the meal plan of a date range.
+///
+/// The recipes are scaled to the servings of their meal plan entries, see [`add_recipes`].
+///
+/// Returns the id of the shopping list and the ids of its items.
+async fn create_shopping_list<C>(
+    db: &C,
+    date_from: &str,
+    date_to: &str,
+) -> Result<(i64, Vec<i64>), CommandError>
+where
+    C: ConnectionTrait,
+{
+    let meal_plan = select_meal_plan(db, date_from, date_to).await?;
+    let shopping_list = shopping_list::ActiveModel {
+        name: ActiveValue::Set(format!("Meal plan {date_from} – {date_to}")),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    let recipes: Vec<ShoppingListRecipe> = meal_plan
+        .into_iter()
+        .map(|(meal_plan_entry, recipe)| ShoppingListRecipe {
+            recipe_id: recipe.id,
+            yield_amount: meal_plan_entry.servings,
+        })
+        .collect();
+    let addition = add_recipes(db, shopping_list.id, &recipes).await?;
+    Ok((shopping_list.id, addition.created_shopping_list_item_ids))
+}
+
+/// Render the meal plan of a date range as an iCalendar object with one all-day event per meal plan entry.
+async fn render_ics<C>(
+    db: &C,
+    date_from: &str,
+    date_to: &str,
+    timestamp: &str,
+) -> Result<String, CommandError>
+where
+    C: ConnectionTrait,
+{
+    let events: Vec<calendar::Event> = select_meal_plan(db, date_from, date_to)
+        .await?
+        .into_iter()
+        .map(|(meal_plan_entry, recipe)| calendar::Event {
+            uid: format!("meal-plan-entry-{}@recipe-book", meal_plan_entry.id),
+            summary: format!("{}: {}", slot_label(&meal_plan_entry), recipe.name),
+            description: meal_plan_entry
+                .servings
+                .map(|servings| format!("{servings} servings")),
+            date: meal_plan_entry.date,
+        })
+        .collect();
+    Ok(calendar::render(&events, timestamp))
+}
+
+/// Create a shopping list for the meal plan of a date range, both dates inclusive.
+///
+/// Returns the id of the created shopping list.
+///
+/// # Errors
+///
+/// - when a date is invalid
+/// - when a recipe with servings has no yield
+#[tauri::command]
+pub async fn meal_plan_shopping_list_create(
+    date_from: String,
+    date_to: String,
+) -> Result<i64, CommandError> {
+    let db = database::connect_writing().await;
+    let txn = db.begin().await?;
+    let (shopping_list_id, shopping_list_item_ids) =
+        create_shopping_list(&txn, &date_from, &date_to).await?;
+    txn.commit().await?;
+    let window = get_window();
+    window.emit(ENTITY_ACTION_CREATED_SHOPPING_LIST, ())?;
+    if !shopping_list_item_ids.is_empty() {
+        window.emit(ENTITY_ACTION_CREATED_SHOPPING_LIST_ITEM, ())?;
+    }
+    Ok(shopping_list_id)
+}
+
+/// Export the meal plan of a date range, both dates inclusive, as an iCalendar file.
+///
+/// # Errors
+///
+/// - when a date is invalid
+/// - when the file can not be written
+#[tauri::command]
+pub async fn meal_plan_export_ics(
+    date_from: String,
+    date_to: String,
+    path: String,
+) -> Result<(), CommandError> {
+    let db = database::connect().await;
+    let timestamp = calendar::format_timestamp(SystemTime::now());
+    let ics = render_ics(db, &date_from, &date_to, &timestamp).await?;
+    tokio::fs::write(path, ics)
+        .await
+        .map_err(anyhow::Error::from)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sea_orm::Statement;
+
+    use super::*;
+    use crate::migrator::tests::get_memory_database_migrated;
+
+    async fn get_meal_plan_database() -> sea_orm::DatabaseConnection {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`, `yield_amount`) VALUES (1, 'Pizza', 2), (2, 'Porridge', 1); \
+            INSERT INTO `recipe_step` (`id`, `order`, `description`, `recipe_id`) VALUES (1, 1, 'Teig kneten', 1), (2, 1, 'Kochen', 2); \
+            INSERT INTO `ingredient` (`id`, `name`) VALUES (1, 'Mehl'), (2, 'Haferflocken'), (3, 'Milch'); \
+            INSERT INTO `recipe_step_ingredient` (`id`, `order`, `quantity`, `unit`, `recipe_step_id`, `ingredient_id`) VALUES \
+            (1, 1, 500, 'g', 1, 1), \
+            (2, 2, 200, 'ml', 1, 3), \
+            (3, 1, 50, 'g', 2, 2), \
+            (4, 2, 300, 'ml', 2, 3); \
+            INSERT INTO `meal_plan_entry` (`id`, `date`, `slot`, `slot_name`, `servings`, `recipe_id`) VALUES \
+            (1, '2026-10-19', 'Dinner', NULL, 4, 1), \
+            (2, '2026-10-19', 'Breakfast', NULL, NULL, 2), \
+            (3, '2026-10-20', 'Custom', 'Snack', NULL, 2), \
+            (4, '2026-10-26', 'Lunch', NULL, NULL, 1);",
+        )
+        .await
+        .unwrap();
+        db
+    }
+
+    #[tokio::test]
+    async fn test_create_shopping_list() {
+        let db = get_meal_plan_database().await;
+        let (shopping_list_id, shopping_list_item_ids) =
+            create_shopping_list(&db, "2026-10-19", "2026-10-25")
+                .await
+                .unwrap();
+        assert_eq!(shopping_list_id, 1);
+        assert_eq!(shopping_list_item_ids, vec![1, 2, 3]);
+        let shopping_list_items: Vec<(Option<f64>, Option<String>, Option<i64>)> = db
+            .query_all(Statement::from_string(
+                db.get_database_backend(),
+                "SELECT `quantity`, `unit`, `ingredient_id` FROM `shopping_list_item` ORDER BY `order`",
+            ))
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|query_result| query_result.try_get_many_by_index().unwrap())
+            .collect();
+        assert_eq!(
+            shopping_list_items,
+            vec![
+                (Some(100.0), Some(String::from("g")), Some(2)),
+                (Some(1.0), Some(String::from("l")), Some(3)),
+                (Some(1000.0), Some(String::from("g")), Some(1)),
+            ]
+        );
+        assert!(
+            create_shopping_list(&db, "2026-10-19", "next week")
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_render_ics() {
+        let db = get_meal_plan_database().await;
+        let ics = render_ics(&db, "2026-10-19", "2026-10-20", "20261018T134530Z")
+            .await
+            .unwrap();
+        assert_eq!(
+            ics,
+            "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            PRODID:-//Recipe Book//Meal Plan//EN\r\n\
+            BEGIN:VEVENT\r\n\
+            UID:meal-plan-entry-2@recipe-book\r\n\
+            DTSTAMP:20261018T134530Z\r\n\
+            DTSTART;VALUE=DATE:20261019\r\n\
+            SUMMARY:Breakfast: Porridge\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            UID:meal-plan-entry-1@recipe-book\r\n\
+            DTSTAMP:20261018T134530Z\r\n\
+            DTSTART;VALUE=DATE:20261019\r\n\
+            SUMMARY:Dinner: Pizza\r\n\
+            DESCRIPTION:4 servings\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            UID:meal-plan-entry-3@recipe-book\r\n\
+            DTSTAMP:20261018T134530Z\r\n\
+            DTSTART;VALUE=DATE:20261020\r\n\
+            SUMMARY:Snack: Porridge\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n"
+        );
+    }
+}
@@ -0,0 +1,344 @@
+//! This module implements the [`tauri::command`] for matching recipes with the pantry.
+//!
+//! A recipe matches the pantry by the share of its recipe step ingredients which are in stock, so the recipes which use up the most of the pantry come first.
+
+use std::collections::{BTreeMap, HashMap};
+
+use sea_orm::{ConnectionTrait, EntityTrait, QueryOrder};
+use serde::Serialize;
+
+use crate::{
+    command::{error::CommandError, unit_conversion::CommonDensities, unit_list::UnitNames},
+    database,
+    entity::{ingredient, pantry_item, recipe_step, recipe_step_ingredient},
+    unit_conversion::Unit,
+};
+
+/// This struct represents a recipe step ingredient which is not in stock.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingIngredient {
+    pub recipe_step_ingredient_id: i64,
+    pub ingredient_id: i64,
+    /// the quantity which is missing in the unit of the recipe step ingredient, less than its quantity if some of it is in stock
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+}
+
+/// This struct represents how much of a recipe is in stock.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PantryMatch {
+    pub recipe_id: i64,
+    /// the share of the recipe step ingredients which are in stock, from 0 to 1
+    pub coverage: f64,
+    /// the earliest best-before date of the pantry items of the ingredients of the recipe
+    pub best_before: Option<String>,
+    pub missing_ingredients: Vec<MissingIngredient>,
+}
+
+/// This struct represents the stock of an ingredient in the pantry while matching a recipe.
+#[derive(Clone, Debug, Default)]
+struct Stock {
+    /// the stock with a mass unit in g
+    grams: f64,
+    /// the stock with a volume unit in ml
+    millilitres: f64,
+    /// the stock with another unit by unit name, e.g. 3 eggs without unit
+    others: HashMap<Option<String>, f64>,
+    /// whether some of the stock has no quantity, so there is an unknown amount of the ingredient
+    unquantified: bool,
+    /// the density of the ingredient in g/ml
+    density: Option<f64>,
+    best_before: Option<String>,
+}
+
+impl Stock {
+    fn add(&mut self, pantry_item: pantry_item::Model, unit_names: &UnitNames) {
+        self.best_before = earliest(self.best_before.take(), pantry_item.best_before);
+        let Some(quantity) = pantry_item.quantity else {
+            self.unquantified = true;
+            return;
+        };
+        let known_unit = pantry_item
+            .unit
+            .as_deref()
+            .and_then(|name| unit_names.unit(name))
+            .map(Unit::from);
+        match known_unit.map(|unit| unit.to_metric(quantity)) {
+            Some((grams, Unit::Mass(_))) => self.grams += grams,
+            Some((millilitres, Unit::Volume(_))) => self.millilitres += millilitres,
+            _ => *self.others.entry(pantry_item.unit).or_default() += quantity,
+        }
+    }
+
+    /// Take a quantity of the ingredient out of the stock.
+    ///
+    /// Quantities with known units are compared in g or ml, and between mass and volume if the density is known.
+    /// Quantities with other units are only compared with the stock of the same unit.
+    /// An unknown amount of the ingredient covers any quantity.
+    ///
+    /// Returns the quantity which is missing in the given unit, zero if the stock covers it.
+    fn take(&mut self, quantity: f64, unit: Option<&str>, unit_names: &UnitNames) -> f64 {
+        if self.unquantified || quantity <= 0.0 {
+            return 0.0;
+        }
+        let known_unit = unit.and_then(|name| unit_names.unit(name)).map(Unit::from);
+        match known_unit.map(|unit| unit.to_metric(quantity)) {
+            Some((grams, Unit::Mass(_))) => {
+                let other_stock = self.density.map(|density| (&mut self.millilitres, density));
+                let missing = take_amount(grams, &mut self.grams, other_stock);
+                quantity * missing / grams
+            }
+            Some((millilitres, Unit::Volume(_))) => {
+                let other_stock = self.density.map(|density| (&mut self.grams, 1.0 / density));
+                let missing = take_amount(millilitres, &mut self.millilitres, other_stock);
+                quantity * missing / millilitres
+            }
+            _ => {
+                let stock = self.others.entry(unit.map(String::from)).or_default();
+                take_amount(quantity, stock, None)
+            }
+        }
+    }
+}
+
+/// Get the earlier of two ISO 8601 calendar dates.
+fn earliest(a: Option<String>, b: Option<String>) -> Option<String> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Take a positive amount out of a stock and the rest out of another stock, which converts into the amount by a factor.
+///
+/// Returns the amount which is missing.
+fn take_amount(amount: f64, stock: &mut f64, other_stock: Option<(&mut f64, f64)>) -> f64 {
+    let taken = amount.min(*stock);
+    *stock -= taken;
+    let mut missing = amount - taken;
+    if let Some((other_stock, factor)) = other_stock {
+        let taken = missing.min(*other_stock * factor);
+        *other_stock -= taken / factor;
+        missing -= taken;
+    }
+    // conversions between units are not exact, e.g. 2 cups are 473.176 ml
+    if missing <= amount * 1e-9 {
+        return 0.0;
+    }
+    missing
+}
+
+/// Match all recipes with recipe step ingredients with the pantry.
+///
+/// Each recipe is matched with the whole pantry, see [`Stock::take`].
+/// The matches are ordered by their coverage, then by their best-before date, so recipes with expiring ingredients come first.
+async fn match_recipes<C>(db: &C) -> Result<Vec<PantryMatch>, CommandError>
+where
+    C: ConnectionTrait,
+{
+    let unit_names = UnitNames::select(db).await?;
+    let common_densities = CommonDensities::select(db).await?;
+    let mut stocks: HashMap<i64, Stock> = HashMap::new();
+    for (pantry_item, ingredient) in pantry_item::Entity::find()
+        .find_also_related(ingredient::Entity)
+        .order_by_asc(pantry_item::Column::Id)
+        .all(db)
+        .await?
+    {
+        let stock = stocks.entry(pantry_item.ingredient_id).or_default();
+        stock.density = ingredient
+            .as_ref()
+            .and_then(|ingredient| common_densities.density(ingredient));
+        stock.add(pantry_item, &unit_names);
+    }
+    let mut recipe_step_ingredients_by_recipe: BTreeMap<i64, Vec<recipe_step_ingredient::Model>> =
+        BTreeMap::new();
+    for (recipe_step_ingredient, recipe_step) in recipe_step_ingredient::Entity::find()
+        .find_also_related(recipe_step::Entity)
+        .order_by_asc(recipe_step::Column::Order)
+        .order_by_asc(recipe_step_ingredient::Column::Order)
+        .all(db)
+        .await?
+    {
+        if let Some(recipe_step) = recipe_step {
+            recipe_step_ingredients_by_recipe
+                .entry(recipe_step.recipe_id)
+                .or_default()
+                .push(recipe_step_ingredient);
+        }
+    }
+    let mut pantry_matches = vec![];
+    for (recipe_id, recipe_step_ingredients) in recipe_step_ingredients_by_recipe {
+        let mut recipe_stocks = stocks.clone();
+        let mut pantry_match = PantryMatch {
+            recipe_id,
+            coverage: 0.0,
+            best_before: None,
+            missing_ingredients: vec![],
+        };
+        for model in &recipe_step_ingredients {
+            let missing_quantity = match recipe_stocks.get_mut(&model.ingredient_id) {
+                Some(stock) => {
+                    pantry_match.best_before =
+                        earliest(pantry_match.best_before.take(), stock.best_before.clone());
+                    match model.quantity {
+                        Some(quantity) => {
+                            let missing = stock.take(quantity, model.unit.as_deref(), &unit_names);
+                            (missing > 0.0).then_some(Some(missing))
+                        }
+                        None => None,
+                    }
+                }
+                None => Some(model.quantity),
+            };
+            if let Some(quantity) = missing_quantity {
+                pantry_match.missing_ingredients.push(MissingIngredient {
+                    recipe_step_ingredient_id: model.id,
+                    ingredient_id: model.ingredient_id,
+                    quantity,
+                    unit: model.unit.clone(),
+                });
+            }
+        }
+        let covered = recipe_step_ingredients.len() - pantry_match.missing_ingredients.len();
+        pantry_match.coverage = covered as f64 / recipe_step_ingredients.len() as f64;
+        pantry_matches.push(pantry_match);
+    }
+    pantry_matches.sort_by(|a, b| {
+        b.coverage
+            .total_cmp(&a.coverage)
+            .then_with(|| {
+                (a.best_before.is_none(), &a.best_before)
+                    .cmp(&(b.best_before.is_none(), &b.best_before))
+            })
+            .then_with(|| a.recipe_id.cmp(&b.recipe_id))
+    });
+    Ok(pantry_matches)
+}
+
+/// Rank the recipes by how much of them is in the pantry and list their missing ingredients.
+///
+/// Recipes without recipe step ingredients are not ranked.
+#[tauri::command]
+pub async fn pantry_match() -> Result<Vec<PantryMatch>, CommandError> {
+    let db = database::connect().await;
+    let pantry_matches = match_recipes(db).await?;
+    Ok(pantry_matches)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::migrator::tests::get_memory_database_migrated;
+
+    fn missing(
+        recipe_step_ingredient_id: i64,
+        ingredient_id: i64,
+        quantity: Option<f64>,
+        unit: Option<&str>,
+    ) -> MissingIngredient {
+        MissingIngredient {
+            recipe_step_ingredient_id,
+            ingredient_id,
+            quantity,
+            unit: unit.map(String::from),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_match_recipes() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`) VALUES (1, 'Pfannkuchen'), (2, 'Kuchen'), (3, 'Milchreis'), (4, 'Brot'), (5, 'Wasser'); \
+            INSERT INTO `recipe_step` (`id`, `order`, `description`, `recipe_id`) VALUES \
+            (1, 1, 'Teig rühren', 1), (2, 1, 'Teig rühren', 2), (3, 1, 'Kochen', 3), (4, 1, 'Teig kneten', 4), (5, 2, 'Backen', 4); \
+            INSERT INTO `ingredient` (`id`, `name`, `density`) VALUES \
+            (1, 'Mehl', NULL), (2, 'Milch', 1.03), (3, 'Eier', NULL), (4, 'Salz', NULL), (5, 'Zucker', NULL); \
+            INSERT INTO `recipe_step_ingredient` (`id`, `order`, `quantity`, `unit`, `recipe_step_id`, `ingredient_id`) VALUES \
+            (1, 1, 250, 'g', 1, 1), \
+            (2, 2, 0.5, 'l', 1, 2), \
+            (3, 3, 3, NULL, 1, 3), \
+            (4, 4, 1, 'Prise', 1, 4), \
+            (5, 1, 500, 'g', 2, 1), \
+            (6, 2, 200, 'g', 2, 5), \
+            (7, 3, 103, 'g', 2, 2), \
+            (8, 1, 1, 'l', 3, 2), \
+            (9, 2, 50, 'g', 3, 5), \
+            (10, 1, 2, 'lb', 4, 1), \
+            (11, 1, NULL, NULL, 5, 4); \
+            INSERT INTO `pantry_item` (`id`, `quantity`, `unit`, `best_before`, `ingredient_id`) VALUES \
+            (1, 1, 'kg', '2027-03-01', 1), \
+            (2, 250, 'ml', '2026-10-22', 2), \
+            (3, 0.25, 'l', '2026-10-20', 2), \
+            (4, 2, NULL, '2026-10-25', 3), \
+            (5, NULL, NULL, NULL, 4);",
+        )
+        .await
+        .unwrap();
+        let pantry_matches = match_recipes(&db).await.unwrap();
+        assert_eq!(
+            pantry_matches,
+            vec![
+                PantryMatch {
+                    recipe_id: 4,
+                    coverage: 1.0,
+                    best_before: Some(String::from("2027-03-01")),
+                    missing_ingredients: vec![],
+                },
+                PantryMatch {
+                    recipe_id: 1,
+                    coverage: 0.75,
+                    best_before: Some(String::from("2026-10-20")),
+                    missing_ingredients: vec![missing(3, 3, Some(1.0), None)],
+                },
+                PantryMatch {
+                    recipe_id: 2,
+                    coverage: 2.0 / 3.0,
+                    best_before: Some(String::from("2026-10-20")),
+                    missing_ingredients: vec![missing(6, 5, Some(200.0), Some("g"))],
+                },
+                PantryMatch {
+                    recipe_id: 3,
+                    coverage: 0.0,
+                    best_before: Some(String::from("2026-10-20")),
+                    missing_ingredients: vec![
+                        missing(8, 2, Some(0.5), Some("l")),
+                        missing(9, 5, Some(50.0), Some("g")),
+                    ],
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_match_recipes_common_density() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`) VALUES (1, 'Pfannkuchen'); \
+            INSERT INTO `recipe_step` (`id`, `order`, `description`, `recipe_id`) VALUES (1, 1, 'Teig rühren', 1); \
+            INSERT INTO `ingredient` (`id`, `name`, `density`) VALUES (1, 'Mehl', NULL), (2, 'Kichererbsen', NULL); \
+            INSERT INTO `recipe_step_ingredient` (`id`, `order`, `quantity`, `unit`, `recipe_step_id`, `ingredient_id`) VALUES \
+            (1, 1, 250, 'g', 1, 1), \
+            (2, 2, 100, 'g', 1, 2); \
+            INSERT INTO `pantry_item` (`id`, `quantity`, `unit`, `best_before`, `ingredient_id`) VALUES \
+            (1, 500, 'ml', NULL, 1), \
+            (2, 500, 'ml', NULL, 2);",
+        )
+        .await
+        .unwrap();
+        let pantry_matches = match_recipes(&db).await.unwrap();
+        assert_eq!(
+            pantry_matches,
+            vec![PantryMatch {
+                recipe_id: 1,
+                coverage: 0.5,
+                best_before: None,
+                missing_ingredients: vec![missing(2, 2, Some(100.0), Some("g"))],
+            }]
+        );
+    }
+}
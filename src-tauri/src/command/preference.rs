@@ -0,0 +1,133 @@
+//! This module implements the [`tauri::command`]s for the preferences of the user.
+//!
+//! Each preference is stored as JSON in a [preference](preference::Model) with its key.
+
+use sea_orm::{sea_query::OnConflict, ActiveValue, ConnectionTrait, EntityTrait};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    command::error::CommandError,
+    database,
+    entity::preference,
+    event::channel::{PREFERENCE_UPDATED_FETCH_SETTINGS, PREFERENCE_UPDATED_UNIT_SYSTEM},
+    fetch::FetchSettings,
+    unit_conversion::UnitSystem,
+    window::get_window,
+};
+
+/// This is the key of the preferred [unit system](UnitSystem).
+pub const UNIT_SYSTEM: &str = "unit_system";
+
+/// This is the key of the [fetch settings](FetchSettings).
+pub const FETCH_SETTINGS: &str = "fetch_settings";
+
+/// Select the value of a preference.
+///
+/// Returns [`None`] if the preference was never set.
+///
+/// # Errors
+///
+/// - when there is any problem with the database
+/// - when the stored value can not be deserialized
+pub async fn select_preference<C, T>(db: &C, key: &str) -> Result<Option<T>, CommandError>
+where
+    C: ConnectionTrait,
+    T: DeserializeOwned,
+{
+    let Some(model) = preference::Entity::find_by_id(key).one(db).await? else {
+        return Ok(None);
+    };
+    let value = serde_json::from_str(&model.value).map_err(anyhow::Error::from)?;
+    Ok(Some(value))
+}
+
+/// Insert or update the value of a preference.
+///
+/// # Errors
+///
+/// - when there is any problem with the database
+/// - when the value can not be serialized
+pub async fn upsert_preference<C, T>(db: &C, key: &str, value: &T) -> Result<(), CommandError>
+where
+    C: ConnectionTrait,
+    T: Serialize,
+{
+    let value = serde_json::to_string(value).map_err(anyhow::Error::from)?;
+    preference::Entity::insert(preference::ActiveModel {
+        key: ActiveValue::Set(key.to_string()),
+        value: ActiveValue::Set(value),
+    })
+    .on_conflict(
+        OnConflict::column(preference::Column::Key)
+            .update_column(preference::Column::Value)
+            .to_owned(),
+    )
+    .exec_without_returning(db)
+    .await?;
+    Ok(())
+}
+
+/// Get the preferred [unit system](UnitSystem), which is [`UnitSystem::Metric`] if it was never set.
+#[tauri::command]
+pub async fn preference_get_unit_system() -> Result<UnitSystem, CommandError> {
+    let db = database::connect().await;
+    let unit_system = select_preference(db, UNIT_SYSTEM).await?;
+    Ok(unit_system.unwrap_or_default())
+}
+
+/// Set the preferred [unit system](UnitSystem).
+#[tauri::command]
+pub async fn preference_set_unit_system(unit_system: UnitSystem) -> Result<(), CommandError> {
+    let db = database::connect_writing().await;
+    upsert_preference(*db, UNIT_SYSTEM, &unit_system).await?;
+    get_window().emit(PREFERENCE_UPDATED_UNIT_SYSTEM, unit_system)?;
+    Ok(())
+}
+
+/// Get the [fetch settings](FetchSettings), which are the default ones if they were never set.
+#[tauri::command]
+pub async fn preference_get_fetch_settings() -> Result<FetchSettings, CommandError> {
+    let db = database::connect().await;
+    let fetch_settings = select_preference(db, FETCH_SETTINGS).await?;
+    Ok(fetch_settings.unwrap_or_default())
+}
+
+/// Set the [fetch settings](FetchSettings).
+#[tauri::command]
+pub async fn preference_set_fetch_settings(
+    fetch_settings: FetchSettings,
+) -> Result<(), CommandError> {
+    let db = database::connect_writing().await;
+    upsert_preference(*db, FETCH_SETTINGS, &fetch_settings).await?;
+    get_window().emit(PREFERENCE_UPDATED_FETCH_SETTINGS, fetch_settings)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::migrator::tests::get_memory_database_migrated;
+
+    #[tokio::test]
+    async fn test_preference() {
+        let db = get_memory_database_migrated().await;
+        let unit_system: Option<UnitSystem> = select_preference(&db, UNIT_SYSTEM).await.unwrap();
+        assert_eq!(unit_system, None);
+        upsert_preference(&db, UNIT_SYSTEM, &UnitSystem::Imperial)
+            .await
+            .unwrap();
+        upsert_preference(&db, UNIT_SYSTEM, &UnitSystem::UsCustomary)
+            .await
+            .unwrap();
+        let unit_system: Option<UnitSystem> = select_preference(&db, UNIT_SYSTEM).await.unwrap();
+        assert_eq!(unit_system, Some(UnitSystem::UsCustomary));
+        let model = preference::Entity::find_by_id(UNIT_SYSTEM)
+            .one(&db)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(model.value, "\"usCustomary\"");
+    }
+}
@@ -0,0 +1,292 @@
+//! This module implements the [`tauri::command`] for converting the quantities of a recipe into a [unit system](UnitSystem).
+//!
+//! The units of the recipe step ingredients are mapped to [units](Unit) via their [unit names](UnitNames), so quantities with an unknown unit are kept.
+
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    command::{
+        error::{CommandError, CommandError::NotFound},
+        preference::{select_preference, UNIT_SYSTEM},
+        unit_list::UnitNames,
+    },
+    database,
+    entity::{recipe, recipe_step, recipe_step_ingredient, unit_name::unit::Unit},
+    event::channel::ENTITY_ACTION_UPDATED_RECIPE_STEP_INGREDIENT,
+    unit_conversion,
+    unit_conversion::{round_kitchen_friendly, UnitSystem},
+    window::get_window,
+};
+
+/// This struct represents the options of a recipe normalization.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct RecipeNormalizeOptions {
+    /// the unit system to convert into, the preferred unit system if not given
+    pub unit_system: Option<UnitSystem>,
+    /// whether to round quantities with [`round_kitchen_friendly`]
+    pub round: bool,
+    /// whether to permanently store the converted quantities and units
+    pub rewrite: bool,
+}
+
+/// This struct represents a recipe step ingredient with a quantity converted into a unit system.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NormalizedRecipeStepIngredient {
+    pub id: i64,
+    pub recipe_step_id: i64,
+    pub ingredient_id: i64,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+}
+
+/// This struct represents the recipe step ingredients of a recipe converted into a unit system.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NormalizedRecipe {
+    pub recipe_id: i64,
+    pub unit_system: UnitSystem,
+    pub recipe_step_ingredients: Vec<NormalizedRecipeStepIngredient>,
+    /// the ids of the recipe step ingredients which were changed when [rewriting](RecipeNormalizeOptions::rewrite)
+    pub rewritten_recipe_step_ingredient_ids: Vec<i64>,
+}
+
+/// Convert a quantity into the unit of a unit system which fits its size best.
+///
+/// Returns [`None`] if there is no name for the target unit.
+fn normalize_quantity(
+    quantity: f64,
+    unit: Unit,
+    unit_system: &UnitSystem,
+    round: bool,
+    unit_names: &UnitNames,
+) -> Option<(f64, String)> {
+    let (mut quantity, unit) =
+        unit_conversion::Unit::from(unit).to_unit_system(quantity, unit_system);
+    if round {
+        quantity = round_kitchen_friendly(quantity);
+    }
+    let name = unit_names.name(&Unit::from(unit))?;
+    Some((quantity, name.to_string()))
+}
+
+/// Convert the recipe step ingredients of a recipe into a unit system and optionally rewrite them.
+///
+/// The recipe step ingredients are ordered by their recipe step.
+async fn normalize<C>(
+    db: &C,
+    recipe_id: i64,
+    options: &RecipeNormalizeOptions,
+) -> Result<NormalizedRecipe, CommandError>
+where
+    C: ConnectionTrait,
+{
+    recipe::Entity::find_by_id(recipe_id)
+        .one(db)
+        .await?
+        .ok_or(NotFound)?;
+    let unit_system = match options.unit_system {
+        Some(unit_system) => unit_system,
+        None => select_preference(db, UNIT_SYSTEM)
+            .await?
+            .unwrap_or_default(),
+    };
+    let unit_names = UnitNames::select(db).await?;
+    let models = recipe_step_ingredient::Entity::find()
+        .inner_join(recipe_step::Entity)
+        .filter(recipe_step::Column::RecipeId.eq(recipe_id))
+        .order_by_asc(recipe_step::Column::Order)
+        .order_by_asc(recipe_step_ingredient::Column::Order)
+        .all(db)
+        .await?;
+    let mut normalized_recipe = NormalizedRecipe {
+        recipe_id,
+        unit_system,
+        recipe_step_ingredients: vec![],
+        rewritten_recipe_step_ingredient_ids: vec![],
+    };
+    for model in models {
+        let known_unit = model.unit.as_deref().and_then(|name| unit_names.unit(name));
+        let normalized = match (model.quantity, known_unit) {
+            (Some(quantity), Some(known_unit)) => normalize_quantity(
+                quantity,
+                known_unit,
+                &unit_system,
+                options.round,
+                &unit_names,
+            ),
+            _ => None,
+        };
+        let (quantity, unit) = match normalized {
+            Some((quantity, unit)) => (Some(quantity), Some(unit)),
+            None => (model.quantity, model.unit.clone()),
+        };
+        if options.rewrite && (quantity != model.quantity || unit != model.unit) {
+            recipe_step_ingredient::ActiveModel {
+                id: ActiveValue::Unchanged(model.id),
+                quantity: ActiveValue::Set(quantity),
+                unit: ActiveValue::Set(unit.clone()),
+                ..Default::default()
+            }
+            .update(db)
+            .await?;
+            normalized_recipe
+                .rewritten_recipe_step_ingredient_ids
+                .push(model.id);
+        }
+        normalized_recipe
+            .recipe_step_ingredients
+            .push(NormalizedRecipeStepIngredient {
+                id: model.id,
+                recipe_step_id: model.recipe_step_id,
+                ingredient_id: model.ingredient_id,
+                quantity,
+                unit,
+            });
+    }
+    Ok(normalized_recipe)
+}
+
+/// Convert the quantities of a recipe into a unit system, by default the preferred one.
+///
+/// Small amounts are converted into small units, e.g. 250 g instead of 0.25 kg.
+/// When [rewriting](RecipeNormalizeOptions::rewrite), all changed recipe step ingredients are stored in one transaction.
+///
+/// # Errors
+///
+/// - when the recipe does not exist
+#[tauri::command]
+pub async fn recipe_normalize(
+    recipe_id: i64,
+    options: Option<RecipeNormalizeOptions>,
+) -> Result<NormalizedRecipe, CommandError> {
+    let options = options.unwrap_or_default();
+    if !options.rewrite {
+        let db = database::connect().await;
+        let normalized_recipe = normalize(db, recipe_id, &options).await?;
+        return Ok(normalized_recipe);
+    }
+    let db = database::connect_writing().await;
+    let txn = db.begin().await?;
+    let normalized_recipe = normalize(&txn, recipe_id, &options).await?;
+    txn.commit().await?;
+    let window = get_window();
+    for id in &normalized_recipe.rewritten_recipe_step_ingredient_ids {
+        window.emit(ENTITY_ACTION_UPDATED_RECIPE_STEP_INGREDIENT, id)?;
+    }
+    Ok(normalized_recipe)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sea_orm::Statement;
+
+    use super::*;
+    use crate::{
+        command::preference::upsert_preference, migrator::tests::get_memory_database_migrated,
+    };
+
+    fn normalized(
+        id: i64,
+        quantity: Option<f64>,
+        unit: Option<&str>,
+    ) -> NormalizedRecipeStepIngredient {
+        NormalizedRecipeStepIngredient {
+            id,
+            recipe_step_id: 1,
+            ingredient_id: id,
+            quantity,
+            unit: unit.map(String::from),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_normalize() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`) VALUES (1, 'Brot'); \
+            INSERT INTO `recipe_step` (`id`, `order`, `description`, `recipe_id`) VALUES (1, 1, 'Teig kneten', 1); \
+            INSERT INTO `ingredient` (`id`, `name`) VALUES (1, 'Mehl'), (2, 'Wasser'), (3, 'Salz'), (4, 'Tomaten'), (5, 'Hefe'); \
+            INSERT INTO `recipe_step_ingredient` (`id`, `order`, `quantity`, `unit`, `recipe_step_id`, `ingredient_id`) VALUES \
+            (1, 1, 1, 'lb', 1, 1), \
+            (2, 2, 2, 'cups', 1, 2), \
+            (3, 3, 1, 'TL', 1, 3), \
+            (4, 4, 3, 'Dose', 1, 4), \
+            (5, 5, NULL, 'g', 1, 5);",
+        )
+        .await
+        .unwrap();
+        let options = RecipeNormalizeOptions {
+            unit_system: None,
+            round: true,
+            rewrite: true,
+        };
+        let normalized_recipe = normalize(&db, 1, &options).await.unwrap();
+        assert_eq!(
+            normalized_recipe,
+            NormalizedRecipe {
+                recipe_id: 1,
+                unit_system: UnitSystem::Metric,
+                recipe_step_ingredients: vec![
+                    normalized(1, Some(450.0), Some("g")),
+                    normalized(2, Some(470.0), Some("ml")),
+                    normalized(3, Some(5.0), Some("ml")),
+                    normalized(4, Some(3.0), Some("Dose")),
+                    normalized(5, None, Some("g")),
+                ],
+                rewritten_recipe_step_ingredient_ids: vec![1, 2, 3],
+            }
+        );
+        let recipe_step_ingredients: Vec<(Option<f64>, Option<String>)> = db
+            .query_all(Statement::from_string(
+                db.get_database_backend(),
+                "SELECT `quantity`, `unit` FROM `recipe_step_ingredient` ORDER BY `id`",
+            ))
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|query_result| query_result.try_get_many_by_index().unwrap())
+            .collect();
+        assert_eq!(
+            recipe_step_ingredients,
+            vec![
+                (Some(450.0), Some(String::from("g"))),
+                (Some(470.0), Some(String::from("ml"))),
+                (Some(5.0), Some(String::from("ml"))),
+                (Some(3.0), Some(String::from("Dose"))),
+                (None, Some(String::from("g"))),
+            ]
+        );
+        upsert_preference(&db, UNIT_SYSTEM, &UnitSystem::UsCustomary)
+            .await
+            .unwrap();
+        let options = RecipeNormalizeOptions {
+            unit_system: None,
+            round: true,
+            rewrite: false,
+        };
+        let normalized_recipe = normalize(&db, 1, &options).await.unwrap();
+        assert_eq!(
+            normalized_recipe,
+            NormalizedRecipe {
+                recipe_id: 1,
+                unit_system: UnitSystem::UsCustomary,
+                recipe_step_ingredients: vec![
+                    normalized(1, Some(16.0), Some("oz")),
+                    normalized(2, Some(2.0), Some("cup")),
+                    normalized(3, Some(1.0), Some("tsp")),
+                    normalized(4, Some(3.0), Some("Dose")),
+                    normalized(5, None, Some("g")),
+                ],
+                rewritten_recipe_step_ingredient_ids: vec![],
+            }
+        );
+        assert!(matches!(normalize(&db, 2, &options).await, Err(NotFound)));
+    }
+}
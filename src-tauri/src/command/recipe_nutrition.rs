@@ -0,0 +1,260 @@
+//! This module implements the [`tauri::command`] for computing the nutrition facts of a recipe.
+
+use std::collections::HashMap;
+
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+use serde::Serialize;
+
+use crate::{
+    command::{
+        error::{CommandError, CommandError::NotFound},
+        unit_conversion::select_density,
+        unit_list::UnitNames,
+    },
+    database,
+    entity::{ingredient_nutrition, recipe, recipe_step, recipe_step_ingredient},
+    nutrition::Nutrients,
+    unit_conversion::Unit,
+};
+
+/// This enum represents why the nutrients of a recipe step ingredient are unknown.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UnresolvedReason {
+    /// the ingredient has no ingredient nutrition
+    NutritionUnknown,
+    /// the recipe step ingredient has no quantity
+    QuantityUnknown,
+    /// the unit is neither a mass nor a volume unit, e.g. 3 eggs without unit
+    UnitUnknown,
+    /// the unit is a volume unit and the ingredient has no known density
+    DensityUnknown,
+}
+
+/// This struct represents a recipe step ingredient whose nutrients are not included in the nutrition facts.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnresolvedIngredient {
+    pub recipe_step_ingredient_id: i64,
+    pub ingredient_id: i64,
+    pub reason: UnresolvedReason,
+}
+
+/// This struct represents the nutrition facts of a recipe.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeNutrition {
+    pub recipe_id: i64,
+    /// the nutrients of the whole recipe
+    pub total: Nutrients,
+    /// the nutrients per yield unit, e.g. per serving, if the recipe has a yield
+    pub per_serving: Option<Nutrients>,
+    pub unresolved_ingredients: Vec<UnresolvedIngredient>,
+}
+
+/// Compute the nutrition facts of a recipe from the nutrients of its recipe step ingredients.
+///
+/// The quantities are converted into g, volumes by the density of the ingredient or the common ingredient density.
+/// Recipe step ingredients which can not be converted or have no ingredient nutrition are listed as unresolved.
+async fn compute<C>(db: &C, recipe_id: i64) -> Result<RecipeNutrition, CommandError>
+where
+    C: ConnectionTrait,
+{
+    let recipe = recipe::Entity::find_by_id(recipe_id)
+        .one(db)
+        .await?
+        .ok_or(NotFound)?;
+    let unit_names = UnitNames::select(db).await?;
+    let recipe_step_ingredients = recipe_step_ingredient::Entity::find()
+        .inner_join(recipe_step::Entity)
+        .filter(recipe_step::Column::RecipeId.eq(recipe_id))
+        .order_by_asc(recipe_step::Column::Order)
+        .order_by_asc(recipe_step_ingredient::Column::Order)
+        .all(db)
+        .await?;
+    let nutrients_by_ingredient: HashMap<i64, Nutrients> = ingredient_nutrition::Entity::find()
+        .filter(
+            ingredient_nutrition::Column::IngredientId.is_in(
+                recipe_step_ingredients
+                    .iter()
+                    .map(|model| model.ingredient_id),
+            ),
+        )
+        .all(db)
+        .await?
+        .iter()
+        .map(|model| (model.ingredient_id, Nutrients::from(model)))
+        .collect();
+    let mut recipe_nutrition = RecipeNutrition {
+        recipe_id,
+        total: Nutrients::default(),
+        per_serving: None,
+        unresolved_ingredients: vec![],
+    };
+    for model in recipe_step_ingredients {
+        let known_unit = model
+            .unit
+            .as_deref()
+            .and_then(|name| unit_names.unit(name))
+            .map(Unit::from);
+        let grams = match (model.quantity, known_unit) {
+            (None, _) => Err(UnresolvedReason::QuantityUnknown),
+            (Some(quantity), Some(unit)) => match unit.to_metric(quantity) {
+                (grams, Unit::Mass(_)) => Ok(grams),
+                (millilitres, Unit::Volume(_)) => select_density(db, model.ingredient_id)
+                    .await?
+                    .map(|density| millilitres * density)
+                    .ok_or(UnresolvedReason::DensityUnknown),
+                _ => Err(UnresolvedReason::UnitUnknown),
+            },
+            (Some(_), None) => Err(UnresolvedReason::UnitUnknown),
+        };
+        let nutrients = nutrients_by_ingredient
+            .get(&model.ingredient_id)
+            .ok_or(UnresolvedReason::NutritionUnknown)
+            .and_then(|nutrients| grams.map(|grams| nutrients.scale(grams / 100.0)));
+        match nutrients {
+            Ok(nutrients) => recipe_nutrition.total += nutrients,
+            Err(reason) => recipe_nutrition
+                .unresolved_ingredients
+                .push(UnresolvedIngredient {
+                    recipe_step_ingredient_id: model.id,
+                    ingredient_id: model.ingredient_id,
+                    reason,
+                }),
+        }
+    }
+    recipe_nutrition.per_serving = recipe
+        .yield_amount
+        .filter(|yield_amount| *yield_amount > 0.0)
+        .map(|yield_amount| recipe_nutrition.total.scale(1.0 / yield_amount));
+    Ok(recipe_nutrition)
+}
+
+/// Compute the nutrition facts of a recipe in total and per serving.
+///
+/// # Errors
+///
+/// - when the recipe does not exist
+#[tauri::command]
+pub async fn recipe_nutrition(recipe_id: i64) -> Result<RecipeNutrition, CommandError> {
+    let db = database::connect().await;
+    let recipe_nutrition = compute(db, recipe_id).await?;
+    Ok(recipe_nutrition)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::migrator::tests::get_memory_database_migrated;
+
+    fn assert_nutrients_eq(actual: Nutrients, expected: Nutrients) {
+        let difference = [
+            actual.energy - expected.energy,
+            actual.protein - expected.protein,
+            actual.fat - expected.fat,
+            actual.carbohydrates - expected.carbohydrates,
+            actual.fibre - expected.fibre,
+            actual.salt - expected.salt,
+        ];
+        assert!(
+            difference.iter().all(|value| value.abs() < 1e-9),
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_compute() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`, `yield_amount`, `yield_unit`) VALUES (1, 'Pfannkuchen', 2, 'Portionen'), (2, 'Wasser', NULL, NULL); \
+            INSERT INTO `recipe_step` (`id`, `order`, `description`, `recipe_id`) VALUES \
+            (1, 1, 'Teig rühren', 1), (2, 2, 'Braten', 1); \
+            INSERT INTO `ingredient` (`id`, `name`, `density`) VALUES \
+            (1, 'Mehl', NULL), (2, 'Milch', NULL), (3, 'Eier', NULL), (4, 'Knoblauch', NULL), (5, 'Pfeffer', NULL), (6, 'Trüffelöl', NULL), (7, 'Chorizo', NULL); \
+            INSERT INTO `ingredient_nutrition` (`id`, `energy`, `protein`, `fat`, `carbohydrates`, `fibre`, `salt`, `ingredient_id`) VALUES \
+            (1, 300, 10, 1, 70, 3, 0, 1), \
+            (2, 60, 3, 3, 5, 0, 0.1, 2), \
+            (3, 150, 13, 10, 1, 0, 0.3, 3), \
+            (4, 150, 6, 0.5, 33, 2, 0, 4), \
+            (5, 250, 10, 3, 40, 25, 0, 5), \
+            (6, 900, 0, 100, 0, 0, 0, 6); \
+            INSERT INTO `recipe_step_ingredient` (`id`, `order`, `quantity`, `unit`, `recipe_step_id`, `ingredient_id`) VALUES \
+            (1, 1, 0.5, 'kg', 1, 1), \
+            (2, 2, 100, 'ml', 1, 2), \
+            (3, 3, 3, NULL, 1, 3), \
+            (4, 4, 2, 'Zehen', 1, 4), \
+            (5, 5, NULL, NULL, 1, 5), \
+            (6, 1, 2, 'EL', 2, 6), \
+            (7, 2, 100, 'g', 2, 7);",
+        )
+        .await
+        .unwrap();
+        let recipe_nutrition = compute(&db, 1).await.unwrap();
+        assert_nutrients_eq(
+            recipe_nutrition.total,
+            Nutrients {
+                energy: 1561.8,
+                protein: 53.09,
+                fat: 8.09,
+                carbohydrates: 355.15,
+                fibre: 15.0,
+                salt: 0.103,
+            },
+        );
+        assert_nutrients_eq(
+            recipe_nutrition.per_serving.unwrap(),
+            Nutrients {
+                energy: 780.9,
+                protein: 26.545,
+                fat: 4.045,
+                carbohydrates: 177.575,
+                fibre: 7.5,
+                salt: 0.0515,
+            },
+        );
+        assert_eq!(
+            recipe_nutrition.unresolved_ingredients,
+            vec![
+                UnresolvedIngredient {
+                    recipe_step_ingredient_id: 3,
+                    ingredient_id: 3,
+                    reason: UnresolvedReason::UnitUnknown,
+                },
+                UnresolvedIngredient {
+                    recipe_step_ingredient_id: 4,
+                    ingredient_id: 4,
+                    reason: UnresolvedReason::UnitUnknown,
+                },
+                UnresolvedIngredient {
+                    recipe_step_ingredient_id: 5,
+                    ingredient_id: 5,
+                    reason: UnresolvedReason::QuantityUnknown,
+                },
+                UnresolvedIngredient {
+                    recipe_step_ingredient_id: 6,
+                    ingredient_id: 6,
+                    reason: UnresolvedReason::DensityUnknown,
+                },
+                UnresolvedIngredient {
+                    recipe_step_ingredient_id: 7,
+                    ingredient_id: 7,
+                    reason: UnresolvedReason::NutritionUnknown,
+                },
+            ]
+        );
+        let recipe_nutrition = compute(&db, 2).await.unwrap();
+        assert_eq!(
+            recipe_nutrition,
+            RecipeNutrition {
+                recipe_id: 2,
+                total: Nutrients::default(),
+                per_serving: None,
+                unresolved_ingredients: vec![],
+            }
+        );
+        assert!(matches!(compute(&db, 3).await, Err(NotFound)));
+    }
+}
@@ -0,0 +1,724 @@
+//! This module implements the [`tauri::command`]s for refreshing an imported recipe from its source.
+//!
+//! Refreshing first shows a diff between the stored recipe and the external recipe at its source URL.
+//! The user can then either keep the local edits or accept the external recipe, which replaces the stored content and details.
+
+use std::{collections::HashSet, time::SystemTime};
+
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
+    IntoActiveModel, QueryFilter, QueryOrder, TransactionTrait, TryIntoModel,
+};
+use serde::Serialize;
+
+use crate::{
+    calendar::format_date_time,
+    command::{
+        error::{CommandError, CommandError::NotFound},
+        external_recipe::{
+            download_files, emit_content_created, find_tag, insert_content, insert_details,
+            normalize_content, set_details, ExternalRecipeImport,
+        },
+        ingredient_draft::select_ingredient_names,
+    },
+    database,
+    entity::{
+        file, ingredient, recipe, recipe_declared_nutrition, recipe_file, recipe_ingredient_draft,
+        recipe_step, recipe_step_file, recipe_step_ingredient, recipe_step_ingredient_draft,
+        recipe_tag,
+    },
+    event::channel::{
+        ENTITY_ACTION_DELETED_RECIPE_DECLARED_NUTRITION, ENTITY_ACTION_DELETED_RECIPE_FILE,
+        ENTITY_ACTION_DELETED_RECIPE_INGREDIENT_DRAFT, ENTITY_ACTION_DELETED_RECIPE_STEP,
+        ENTITY_ACTION_UPDATED_RECIPE,
+    },
+    external_recipe::{
+        details::{ExternalRecipeDetails, ExternalRecipeNutrition},
+        ExternalRecipe, ExternalRecipeIngredient, ExternalRecipeStep,
+    },
+    file_storage,
+    window::get_window,
+};
+
+/// This enum represents an entry of a diff between a stored and an external list.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "change", content = "value", rename_all = "camelCase")]
+pub enum DiffEntry<T> {
+    /// the value is stored and external
+    Kept(T),
+    /// the value is only external
+    Added(T),
+    /// the value is only stored
+    Removed(T),
+}
+
+/// This enum represents a detail of a recipe, which is set from the [`ExternalRecipeDetails`] of an external recipe.
+///
+/// The times are in seconds.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "detail", content = "value", rename_all = "camelCase")]
+pub enum RecipeDetail {
+    Description(String),
+    Author(String),
+    PrepTime(i64),
+    CookTime(i64),
+    TotalTime(i64),
+    Yield { amount: f64, unit: Option<String> },
+    Tag(String),
+    Nutrition(ExternalRecipeNutrition),
+}
+
+/// This struct represents the diff between a stored recipe and the external recipe at its source URL.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeRefreshDiff {
+    pub recipe_id: i64,
+    /// whether any entry is not [`DiffEntry::Kept`]
+    pub changed: bool,
+    pub name: Vec<DiffEntry<String>>,
+    pub ingredients: Vec<DiffEntry<String>>,
+    pub files: Vec<DiffEntry<String>>,
+    pub steps: Vec<DiffEntry<ExternalRecipeStep>>,
+    pub details: Vec<DiffEntry<RecipeDetail>>,
+}
+
+impl RecipeRefreshDiff {
+    fn new(
+        recipe_id: i64,
+        stored: &ExternalRecipe,
+        external: &ExternalRecipe,
+        stored_details: &[RecipeDetail],
+        external_details: &[RecipeDetail],
+    ) -> Self {
+        let name = diff(&[stored.name.clone()], &[external.name.clone()]);
+        let ingredients = diff(&stored.ingredients, &external.ingredients);
+        let files = diff(&stored.files, &external.files);
+        let steps = diff(&stored.steps, &external.steps);
+        let details = diff(stored_details, external_details);
+        let changed = !(is_kept(&name)
+            && is_kept(&ingredients)
+            && is_kept(&files)
+            && is_kept(&steps)
+            && is_kept(&details));
+        Self {
+            recipe_id,
+            changed,
+            name,
+            ingredients,
+            files,
+            steps,
+            details,
+        }
+    }
+}
+
+fn is_kept<T>(entries: &[DiffEntry<T>]) -> bool {
+    entries
+        .iter()
+        .all(|entry| matches!(entry, DiffEntry::Kept(_)))
+}
+
+/// Diff two lists via their longest common subsequence.
+fn diff<T>(stored: &[T], external: &[T]) -> Vec<DiffEntry<T>>
+where
+    T: Clone + PartialEq,
+{
+    // lengths[i][j] is the length of the longest common subsequence of stored[i..] and external[j..]
+    let mut lengths = vec![vec![0; external.len() + 1]; stored.len() + 1];
+    for i in (0..stored.len()).rev() {
+        for j in (0..external.len()).rev() {
+            lengths[i][j] = if stored[i] == external[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+    let mut entries = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < stored.len() && j < external.len() {
+        if stored[i] == external[j] {
+            entries.push(DiffEntry::Kept(stored[i].clone()));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            entries.push(DiffEntry::Removed(stored[i].clone()));
+            i += 1;
+        } else {
+            entries.push(DiffEntry::Added(external[j].clone()));
+            j += 1;
+        }
+    }
+    entries.extend(stored[i..].iter().cloned().map(DiffEntry::Removed));
+    entries.extend(external[j..].iter().cloned().map(DiffEntry::Added));
+    entries
+}
+
+/// Select the stored content of a recipe in the form it was imported in.
+///
+/// Files are represented by their names, which are their URLs when imported.
+/// Recipe step ingredients are represented by the names of their ingredients.
+async fn select_stored<C>(db: &C, recipe: &recipe::Model) -> Result<ExternalRecipe, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let ingredients = recipe_ingredient_draft::Entity::find()
+        .filter(recipe_ingredient_draft::Column::RecipeId.eq(recipe.id))
+        .order_by_asc(recipe_ingredient_draft::Column::Order)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|recipe_ingredient_draft| recipe_ingredient_draft.text)
+        .collect();
+    let files = file::Entity::find()
+        .inner_join(recipe_file::Entity)
+        .filter(recipe_file::Column::RecipeId.eq(recipe.id))
+        .order_by_asc(recipe_file::Column::Order)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|file| file.name)
+        .collect();
+    let recipe_steps = recipe_step::Entity::find()
+        .filter(recipe_step::Column::RecipeId.eq(recipe.id))
+        .order_by_asc(recipe_step::Column::Order)
+        .all(db)
+        .await?;
+    let mut steps = vec![];
+    for recipe_step in recipe_steps {
+        let ingredients = recipe_step_ingredient_draft::Entity::find()
+            .filter(recipe_step_ingredient_draft::Column::RecipeStepId.eq(recipe_step.id))
+            .order_by_asc(recipe_step_ingredient_draft::Column::Order)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|recipe_step_ingredient_draft| recipe_step_ingredient_draft.text)
+            .collect();
+        let files = file::Entity::find()
+            .inner_join(recipe_step_file::Entity)
+            .filter(recipe_step_file::Column::RecipeStepId.eq(recipe_step.id))
+            .order_by_asc(recipe_step_file::Column::Order)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|file| file.name)
+            .collect();
+        let parsed_ingredients = recipe_step_ingredient::Entity::find()
+            .find_also_related(ingredient::Entity)
+            .filter(recipe_step_ingredient::Column::RecipeStepId.eq(recipe_step.id))
+            .order_by_asc(recipe_step_ingredient::Column::Order)
+            .all(db)
+            .await?
+            .into_iter()
+            .filter_map(|(recipe_step_ingredient, ingredient_option)| {
+                Some(ExternalRecipeIngredient {
+                    quantity: recipe_step_ingredient.quantity,
+                    unit: recipe_step_ingredient.unit,
+                    name: ingredient_option?.name,
+                    quality: recipe_step_ingredient.quality,
+                })
+            })
+            .collect();
+        steps.push(ExternalRecipeStep {
+            ingredients,
+            description: recipe_step.description,
+            files,
+            parsed_ingredients,
+        });
+    }
+    Ok(ExternalRecipe {
+        name: recipe.name.clone(),
+        ingredients,
+        files,
+        steps,
+        ..Default::default()
+    })
+}
+
+/// Get the external recipe in the form its content would be stored in, see [`normalize_content`].
+///
+/// Nothing is downloaded for the diff, so the files are compared by their URLs,
+/// and files which can not be downloaded are only skipped when the external recipe is applied.
+async fn normalize_external<C>(db: &C, external: &ExternalRecipe) -> Result<ExternalRecipe, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let ingredient_names = select_ingredient_names(db).await?;
+    Ok(normalize_content(external, &ingredient_names, |_| true))
+}
+
+/// Get the details of a recipe with its tags and its declared nutrition.
+fn recipe_details(
+    recipe: &recipe::Model,
+    tag_names: Vec<String>,
+    nutrition: Option<ExternalRecipeNutrition>,
+) -> Vec<RecipeDetail> {
+    let mut details = vec![];
+    details.extend(recipe.description.clone().map(RecipeDetail::Description));
+    details.extend(recipe.author.clone().map(RecipeDetail::Author));
+    details.extend(recipe.prep_time.map(RecipeDetail::PrepTime));
+    details.extend(recipe.cook_time.map(RecipeDetail::CookTime));
+    details.extend(recipe.total_time.map(RecipeDetail::TotalTime));
+    details.extend(recipe.yield_amount.map(|amount| RecipeDetail::Yield {
+        amount,
+        unit: recipe.yield_unit.clone(),
+    }));
+    details.extend(tag_names.into_iter().map(RecipeDetail::Tag));
+    details.extend(nutrition.map(RecipeDetail::Nutrition));
+    details
+}
+
+/// Select the stored details of a recipe and get the details of an external recipe in the form they would be stored in,
+/// see [`set_details`] and [`insert_details`].
+///
+/// Only the tags of the external recipe are compared, because refreshing keeps all other tags of the recipe.
+async fn select_details<C>(
+    db: &C,
+    recipe: &recipe::Model,
+    external_details: &ExternalRecipeDetails,
+) -> Result<(Vec<RecipeDetail>, Vec<RecipeDetail>), DbErr>
+where
+    C: ConnectionTrait,
+{
+    let tag_ids: HashSet<i64> = recipe_tag::Entity::find()
+        .filter(recipe_tag::Column::RecipeId.eq(recipe.id))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|recipe_tag| recipe_tag.tag_id)
+        .collect();
+    let mut stored_tag_names = vec![];
+    let mut external_tag_names = vec![];
+    for tag_name in external_details.tag_names() {
+        match find_tag(db, tag_name).await? {
+            Some(tag) => {
+                if tag_ids.contains(&tag.id) {
+                    stored_tag_names.push(tag.name.clone());
+                }
+                external_tag_names.push(tag.name);
+            }
+            None => external_tag_names.push(tag_name.to_string()),
+        }
+    }
+    let stored_nutrition = recipe_declared_nutrition::Entity::find()
+        .filter(recipe_declared_nutrition::Column::RecipeId.eq(recipe.id))
+        .one(db)
+        .await?
+        .map(|declared_nutrition| ExternalRecipeNutrition {
+            energy: declared_nutrition.energy,
+            protein: declared_nutrition.protein,
+            fat: declared_nutrition.fat,
+            carbohydrates: declared_nutrition.carbohydrates,
+            fibre: declared_nutrition.fibre,
+            salt: declared_nutrition.salt,
+        });
+    let mut external_recipe = recipe.clone().into_active_model();
+    set_details(&mut external_recipe, external_details);
+    Ok((
+        recipe_details(recipe, stored_tag_names, stored_nutrition),
+        recipe_details(
+            &external_recipe.try_into_model()?,
+            external_tag_names,
+            external_details.nutrition.clone(),
+        ),
+    ))
+}
+
+/// This struct contains the ids of the content deleted by [`delete_content`].
+#[derive(Debug, PartialEq)]
+struct DeletedContent {
+    recipe_ingredient_draft_ids: Vec<i64>,
+    recipe_file_ids: Vec<i64>,
+    recipe_step_ids: Vec<i64>,
+    /// the paths of the deleted orphaned files, which are still in the [`crate::file_storage`]
+    orphaned_file_paths: Vec<String>,
+}
+
+impl DeletedContent {
+    /// Delete the orphaned files from the [`crate::file_storage`], once their deletion is committed.
+    async fn delete_orphaned_files(&self) {
+        for path in &self.orphaned_file_paths {
+            if let Err(err) = file_storage::delete(path).await {
+                log::warn!("Could not delete orphaned file from storage: {}", err);
+            }
+        }
+    }
+}
+
+/// Delete the ingredient drafts, files and steps of a recipe.
+///
+/// The steps are deleted including their ingredients, so local edits are lost.
+/// File entities which are not referenced anymore are deleted,
+/// but their files are kept in the [`crate::file_storage`] until the deletion is committed, see [`DeletedContent::delete_orphaned_files`].
+async fn delete_content<C>(db: &C, recipe_id: i64) -> Result<DeletedContent, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let deleted_content = DeletedContent {
+        recipe_ingredient_draft_ids: recipe_ingredient_draft::Entity::find()
+            .filter(recipe_ingredient_draft::Column::RecipeId.eq(recipe_id))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|recipe_ingredient_draft| recipe_ingredient_draft.id)
+            .collect(),
+        recipe_file_ids: recipe_file::Entity::find()
+            .filter(recipe_file::Column::RecipeId.eq(recipe_id))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|recipe_file| recipe_file.id)
+            .collect(),
+        recipe_step_ids: recipe_step::Entity::find()
+            .filter(recipe_step::Column::RecipeId.eq(recipe_id))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|recipe_step| recipe_step.id)
+            .collect(),
+        orphaned_file_paths: vec![],
+    };
+    recipe_ingredient_draft::Entity::delete_many()
+        .filter(recipe_ingredient_draft::Column::RecipeId.eq(recipe_id))
+        .exec(db)
+        .await?;
+    recipe_file::Entity::delete_many()
+        .filter(recipe_file::Column::RecipeId.eq(recipe_id))
+        .exec(db)
+        .await?;
+    recipe_step::Entity::delete_many()
+        .filter(recipe_step::Column::RecipeId.eq(recipe_id))
+        .exec(db)
+        .await?;
+    let orphaned_files = file::find_orphans(db).await?;
+    // deleting many does not run the hook of the file entity, which would delete the stored files right away
+    file::Entity::delete_many()
+        .filter(file::Column::Id.is_in(orphaned_files.iter().map(|file| file.id)))
+        .exec(db)
+        .await?;
+    Ok(DeletedContent {
+        orphaned_file_paths: orphaned_files.into_iter().map(|file| file.path).collect(),
+        ..deleted_content
+    })
+}
+
+/// Read a recipe and get the external recipe from its source URL.
+///
+/// The source is fetched again instead of taking it from the cache, so that the recipe is really refreshed.
+async fn get_external(
+    recipe_id: i64,
+) -> Result<(recipe::Model, String, ExternalRecipe), CommandError> {
+    let db = database::connect().await;
+    let recipe = recipe::Entity::find_by_id(recipe_id)
+        .one(db)
+        .await?
+        .ok_or(NotFound)?;
+    let Some(source_url) = recipe.source_url.clone() else {
+        return Err(CommandError::RecipeWithoutSource);
+    };
+    let (getter_name, external_recipe) =
+        crate::external_recipe::get_fresh_with_getter_name(source_url).await?;
+    Ok((recipe, getter_name, external_recipe))
+}
+
+/// Diff a recipe with the external recipe from its source URL.
+///
+/// The external recipe is compared in the form it would be stored in, so that an unchanged source shows no changes.
+#[tauri::command]
+pub async fn recipe_refresh_diff(recipe_id: i64) -> Result<RecipeRefreshDiff, CommandError> {
+    let (recipe, _, external_recipe) = get_external(recipe_id).await?;
+    let db = database::connect().await;
+    let stored_recipe = select_stored(db, &recipe).await?;
+    let external_recipe = normalize_external(db, &external_recipe).await?;
+    let (stored_details, external_details) =
+        select_details(db, &recipe, &external_recipe.details).await?;
+    Ok(RecipeRefreshDiff::new(
+        recipe_id,
+        &stored_recipe,
+        &external_recipe,
+        &stored_details,
+        &external_details,
+    ))
+}
+
+/// Replace the content and details of a recipe with the external recipe from its source URL.
+///
+/// The files are downloaded first, then the content is replaced in one transaction.
+/// Orphaned files are deleted from the [`crate::file_storage`] only after the transaction is committed.
+/// Tags of the recipe are kept, only the tags of the external recipe are added.
+#[tauri::command]
+pub async fn recipe_refresh_apply(recipe_id: i64) -> Result<ExternalRecipeImport, CommandError> {
+    let (recipe, getter_name, external_recipe) = get_external(recipe_id).await?;
+    let downloaded_files = download_files(&external_recipe, None, None).await;
+    let db = database::connect_writing().await;
+    let replace_result = async {
+        let txn = db.begin().await?;
+        let deleted_content = delete_content(&txn, recipe_id).await?;
+        let mut recipe_active_model = recipe::ActiveModel {
+            id: ActiveValue::Unchanged(recipe.id),
+            name: ActiveValue::Set(external_recipe.name.clone()),
+            source_getter: ActiveValue::Set(Some(getter_name.clone())),
+            imported_at: ActiveValue::Set(Some(format_date_time(SystemTime::now()))),
+            ..Default::default()
+        };
+        set_details(&mut recipe_active_model, &external_recipe.details);
+        recipe_active_model.update(&txn).await?;
+        insert_content(&txn, recipe_id, &external_recipe, &downloaded_files).await?;
+        let deleted_declared_nutrition_id =
+            insert_details(&txn, recipe_id, &external_recipe.details).await?;
+        txn.commit().await?;
+        Ok::<_, DbErr>((deleted_content, deleted_declared_nutrition_id))
+    }
+    .await;
+    let (deleted_content, deleted_declared_nutrition_id) = match replace_result {
+        Ok(deleted) => deleted,
+        Err(err) => {
+            downloaded_files.discard().await;
+            return Err(err.into());
+        }
+    };
+    deleted_content.delete_orphaned_files().await;
+    let window = get_window();
+    for id in deleted_content.recipe_ingredient_draft_ids {
+        window.emit(ENTITY_ACTION_DELETED_RECIPE_INGREDIENT_DRAFT, id)?;
+    }
+    for id in deleted_content.recipe_file_ids {
+        window.emit(ENTITY_ACTION_DELETED_RECIPE_FILE, id)?;
+    }
+    for id in deleted_content.recipe_step_ids {
+        window.emit(ENTITY_ACTION_DELETED_RECIPE_STEP, id)?;
+    }
+    if let Some(id) = deleted_declared_nutrition_id {
+        window.emit(ENTITY_ACTION_DELETED_RECIPE_DECLARED_NUTRITION, id)?;
+    }
+    window.emit(ENTITY_ACTION_UPDATED_RECIPE, recipe_id)?;
+    emit_content_created()?;
+    Ok(ExternalRecipeImport {
+        recipe_id,
+        failed_files: downloaded_files.failed_files,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sea_orm::PaginatorTrait;
+
+    use super::*;
+    use crate::migrator::tests::get_memory_database_migrated;
+
+    fn step(description: &str) -> ExternalRecipeStep {
+        ExternalRecipeStep {
+            description: description.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(
+            diff(&["Mehl", "Milch", "Eier"], &["Mehl", "Eier", "Salz"]),
+            vec![
+                DiffEntry::Kept("Mehl"),
+                DiffEntry::Removed("Milch"),
+                DiffEntry::Kept("Eier"),
+                DiffEntry::Added("Salz"),
+            ]
+        );
+        assert_eq!(diff(&["Mehl"], &[]), vec![DiffEntry::Removed("Mehl")]);
+        assert!(diff::<&str>(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn test_recipe_refresh_diff_new() {
+        let stored = ExternalRecipe {
+            name: String::from("Pfannkuchen"),
+            ingredients: vec![String::from("Mehl")],
+            files: vec![],
+            steps: vec![step("Teig rühren"), step("Braten")],
+            ..Default::default()
+        };
+        let stored_details = vec![RecipeDetail::Tag(String::from("Dessert"))];
+        let diff = RecipeRefreshDiff::new(1, &stored, &stored, &stored_details, &stored_details);
+        assert!(!diff.changed);
+        let external = ExternalRecipe {
+            steps: vec![step("Teig rühren"), step("Ruhen lassen"), step("Braten")],
+            ..stored.clone()
+        };
+        let diff = RecipeRefreshDiff::new(1, &stored, &external, &stored_details, &stored_details);
+        assert!(diff.changed);
+        assert_eq!(
+            diff.steps,
+            vec![
+                DiffEntry::Kept(step("Teig rühren")),
+                DiffEntry::Added(step("Ruhen lassen")),
+                DiffEntry::Kept(step("Braten")),
+            ]
+        );
+        let external_details = vec![RecipeDetail::PrepTime(600)];
+        let diff = RecipeRefreshDiff::new(1, &stored, &stored, &stored_details, &external_details);
+        assert!(diff.changed);
+        assert_eq!(
+            diff.details,
+            vec![
+                DiffEntry::Removed(RecipeDetail::Tag(String::from("Dessert"))),
+                DiffEntry::Added(RecipeDetail::PrepTime(600)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_stored() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`, `source_url`) VALUES (1, 'Pfannkuchen', 'https://example.com/pfannkuchen'); \
+            INSERT INTO `recipe_ingredient_draft` (`id`, `order`, `text`, `recipe_id`) VALUES (1, 2, '2 Eier', 1), (2, 1, '200 g Mehl', 1); \
+            INSERT INTO `file` (`id`, `name`, `mime`, `path`) VALUES \
+            (1, 'https://example.com/pfannkuchen.jpg', 'image/jpeg', '/pfannkuchen.jpg'), (2, 'https://example.com/teig.jpg', 'image/jpeg', '/teig.jpg'); \
+            INSERT INTO `recipe_file` (`id`, `order`, `recipe_id`, `file_id`) VALUES (1, 1, 1, 1); \
+            INSERT INTO `recipe_step` (`id`, `order`, `description`, `recipe_id`) VALUES (1, 2, 'Braten', 1), (2, 1, 'Teig rühren', 1); \
+            INSERT INTO `recipe_step_ingredient_draft` (`id`, `order`, `text`, `recipe_step_id`) VALUES (1, 1, '200 g Mehl', 2); \
+            INSERT INTO `ingredient` (`id`, `name`) VALUES (1, 'Butter'), (2, 'Salz'); \
+            INSERT INTO `recipe_step_ingredient` (`id`, `order`, `quantity`, `unit`, `quality`, `recipe_step_id`, `ingredient_id`) VALUES \
+            (1, 2, NULL, NULL, NULL, 1, 2), (2, 1, 1, 'EL', 'zerlassen', 1, 1); \
+            INSERT INTO `recipe_step_file` (`id`, `order`, `recipe_step_id`, `file_id`) VALUES (1, 1, 2, 2);",
+        )
+        .await
+        .unwrap();
+        let recipe = recipe::Entity::find_by_id(1)
+            .one(&db)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            select_stored(&db, &recipe).await.unwrap(),
+            ExternalRecipe {
+                name: String::from("Pfannkuchen"),
+                ingredients: vec![String::from("200 g Mehl"), String::from("2 Eier")],
+                files: vec![String::from("https://example.com/pfannkuchen.jpg")],
+                steps: vec![
+                    ExternalRecipeStep {
+                        ingredients: vec![String::from("200 g Mehl")],
+                        description: String::from("Teig rühren"),
+                        files: vec![String::from("https://example.com/teig.jpg")],
+                        parsed_ingredients: vec![],
+                    },
+                    ExternalRecipeStep {
+                        parsed_ingredients: vec![
+                            ExternalRecipeIngredient {
+                                quantity: Some(1.0),
+                                unit: Some(String::from("EL")),
+                                name: String::from("Butter"),
+                                quality: Some(String::from("zerlassen")),
+                            },
+                            ExternalRecipeIngredient {
+                                name: String::from("Salz"),
+                                ..Default::default()
+                            },
+                        ],
+                        ..step("Braten")
+                    },
+                ],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_details() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`, `yield_amount`, `yield_unit`, `author`, `prep_time`) VALUES (1, 'Pfannkuchen', 4, 'Portionen', 'Oma', 600); \
+            INSERT INTO `tag` (`id`, `name`) VALUES (1, 'Dessert'), (2, 'schnell'), (3, 'Französisch'); \
+            INSERT INTO `recipe_tag` (`recipe_id`, `tag_id`) VALUES (1, 2), (1, 3); \
+            INSERT INTO `recipe_declared_nutrition` (`id`, `energy`, `recipe_id`) VALUES (1, 240, 1);",
+        )
+        .await
+        .unwrap();
+        let recipe = recipe::Entity::find_by_id(1)
+            .one(&db)
+            .await
+            .unwrap()
+            .unwrap();
+        let external_details = ExternalRecipeDetails {
+            author: Some(String::from("Oma")),
+            prep_time: Some(600),
+            recipe_yield: Some(String::from("Für 4 Portionen")),
+            categories: vec![String::from("Dessert")],
+            keywords: vec![String::from("Schnell"), String::from("Vegan")],
+            ..Default::default()
+        };
+        let (stored_details, external_details) = select_details(&db, &recipe, &external_details)
+            .await
+            .unwrap();
+        let yield_detail = RecipeDetail::Yield {
+            amount: 4.0,
+            unit: Some(String::from("Portionen")),
+        };
+        assert_eq!(
+            stored_details,
+            vec![
+                RecipeDetail::Author(String::from("Oma")),
+                RecipeDetail::PrepTime(600),
+                yield_detail.clone(),
+                RecipeDetail::Tag(String::from("schnell")),
+                RecipeDetail::Nutrition(ExternalRecipeNutrition {
+                    energy: Some(240.0),
+                    ..Default::default()
+                }),
+            ]
+        );
+        assert_eq!(
+            external_details,
+            vec![
+                RecipeDetail::Author(String::from("Oma")),
+                RecipeDetail::PrepTime(600),
+                yield_detail,
+                RecipeDetail::Tag(String::from("Dessert")),
+                RecipeDetail::Tag(String::from("schnell")),
+                RecipeDetail::Tag(String::from("Vegan")),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_content() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`) VALUES (1, 'Pfannkuchen'), (2, 'Stollen'); \
+            INSERT INTO `recipe_ingredient_draft` (`id`, `order`, `text`, `recipe_id`) VALUES (1, 1, '200 g Mehl', 1), (2, 1, '1 kg Mehl', 2); \
+            INSERT INTO `recipe_step` (`id`, `order`, `description`, `recipe_id`) VALUES (1, 1, 'Braten', 1), (2, 1, 'Backen', 2); \
+            INSERT INTO `recipe_step_ingredient_draft` (`id`, `order`, `text`, `recipe_step_id`) VALUES (1, 1, '200 g Mehl', 1), (2, 1, '1 kg Mehl', 2); \
+            INSERT INTO `file` (`id`, `name`, `mime`, `path`) VALUES \
+            (1, 'https://example.com/pfannkuchen.jpg', 'image/jpeg', '/pfannkuchen.jpg'), (2, 'https://example.com/stollen.jpg', 'image/jpeg', '/stollen.jpg'); \
+            INSERT INTO `recipe_file` (`id`, `order`, `recipe_id`, `file_id`) VALUES (1, 1, 1, 1), (2, 1, 2, 2);",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            delete_content(&db, 1).await.unwrap(),
+            DeletedContent {
+                recipe_ingredient_draft_ids: vec![1],
+                recipe_file_ids: vec![1],
+                recipe_step_ids: vec![1],
+                orphaned_file_paths: vec![String::from("/pfannkuchen.jpg")],
+            }
+        );
+        assert_eq!(file::Entity::find().count(&db).await.unwrap(), 1);
+        assert_eq!(
+            recipe_ingredient_draft::Entity::find()
+                .count(&db)
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(recipe_step::Entity::find().count(&db).await.unwrap(), 1);
+        assert_eq!(
+            recipe_step_ingredient_draft::Entity::find()
+                .count(&db)
+                .await
+                .unwrap(),
+            1
+        );
+    }
+}
@@ -0,0 +1,266 @@
+//! This module implements the [`tauri::command`] for scaling a recipe to another yield.
+//!
+//! The quantities of the recipe step ingredients are multiplied by the ratio of the target yield to the recipe yield.
+
+use anyhow::anyhow;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    command::{
+        error::{CommandError, CommandError::NotFound},
+        unit_list::UnitNames,
+    },
+    database,
+    entity::{recipe, recipe_step, recipe_step_ingredient, unit_name::unit::Unit},
+    unit_conversion,
+    unit_conversion::round_kitchen_friendly,
+};
+
+/// This struct represents the options of a recipe scaling.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct RecipeScaleOptions {
+    /// whether to convert quantities with a known unit into the best fitting metric unit
+    pub normalize_units: bool,
+    /// whether to round quantities with [`round_kitchen_friendly`]
+    pub round: bool,
+}
+
+/// This struct represents a recipe step ingredient with a scaled quantity.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScaledRecipeStepIngredient {
+    pub id: i64,
+    pub recipe_step_id: i64,
+    pub ingredient_id: i64,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+}
+
+/// This struct represents a recipe scaled to another yield.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScaledRecipe {
+    pub recipe_id: i64,
+    pub yield_amount: f64,
+    pub yield_unit: Option<String>,
+    pub factor: f64,
+    pub recipe_step_ingredients: Vec<ScaledRecipeStepIngredient>,
+}
+
+/// Scale a quantity and optionally normalize its unit and round it.
+///
+/// When normalizing, the quantity is rounded in g or ml before converting it into kg or l.
+fn scale_quantity(
+    quantity: f64,
+    unit: Option<String>,
+    factor: f64,
+    options: &RecipeScaleOptions,
+    unit_names: &UnitNames,
+) -> (f64, Option<String>) {
+    let quantity = quantity * factor;
+    let known_unit = unit.as_deref().and_then(|name| unit_names.unit(name));
+    if let (true, Some(known_unit)) = (options.normalize_units, known_unit) {
+        let (mut metric_quantity, metric_unit) =
+            unit_conversion::Unit::from(known_unit).to_metric(quantity);
+        if options.round {
+            metric_quantity = round_kitchen_friendly(metric_quantity);
+        }
+        let (normalized_quantity, normalized_unit) = metric_unit.normalize(metric_quantity);
+        if let Some(name) = unit_names.name(&Unit::from(normalized_unit)) {
+            return (normalized_quantity, Some(name.to_string()));
+        }
+    }
+    if options.round {
+        return (round_kitchen_friendly(quantity), unit);
+    }
+    (quantity, unit)
+}
+
+/// Scale the recipe step ingredients of a recipe to a target yield.
+///
+/// The recipe step ingredients are ordered by their recipe step.
+pub async fn scale<C>(
+    db: &C,
+    recipe_id: i64,
+    yield_amount: f64,
+    options: &RecipeScaleOptions,
+) -> Result<ScaledRecipe, CommandError>
+where
+    C: ConnectionTrait,
+{
+    if yield_amount.is_nan() || yield_amount <= 0.0 {
+        return Err(anyhow!("The target yield must be positive.").into());
+    }
+    let recipe = recipe::Entity::find_by_id(recipe_id)
+        .one(db)
+        .await?
+        .ok_or(NotFound)?;
+    let recipe_yield_amount = recipe
+        .yield_amount
+        .filter(|recipe_yield_amount| *recipe_yield_amount > 0.0)
+        .ok_or(CommandError::RecipeWithoutYield)?;
+    let factor = yield_amount / recipe_yield_amount;
+    let unit_names = UnitNames::select(db).await?;
+    let recipe_step_ingredients = recipe_step_ingredient::Entity::find()
+        .inner_join(recipe_step::Entity)
+        .filter(recipe_step::Column::RecipeId.eq(recipe_id))
+        .order_by_asc(recipe_step::Column::Order)
+        .order_by_asc(recipe_step_ingredient::Column::Order)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| {
+            let (quantity, unit) = match model.quantity {
+                Some(quantity) => {
+                    let (quantity, unit) =
+                        scale_quantity(quantity, model.unit, factor, options, &unit_names);
+                    (Some(quantity), unit)
+                }
+                None => (None, model.unit),
+            };
+            ScaledRecipeStepIngredient {
+                id: model.id,
+                recipe_step_id: model.recipe_step_id,
+                ingredient_id: model.ingredient_id,
+                quantity,
+                unit,
+            }
+        })
+        .collect();
+    Ok(ScaledRecipe {
+        recipe_id,
+        yield_amount,
+        yield_unit: recipe.yield_unit,
+        factor,
+        recipe_step_ingredients,
+    })
+}
+
+/// Scale a recipe to a target yield in the unit of the recipe yield.
+///
+/// The recipe itself is not changed.
+///
+/// # Errors
+///
+/// - when the recipe does not exist
+/// - when the recipe has no yield
+/// - when the target yield is not positive
+#[tauri::command]
+pub async fn recipe_scale(
+    recipe_id: i64,
+    yield_amount: f64,
+    options: Option<RecipeScaleOptions>,
+) -> Result<ScaledRecipe, CommandError> {
+    let db = database::connect().await;
+    let scaled_recipe = scale(db, recipe_id, yield_amount, &options.unwrap_or_default()).await?;
+    Ok(scaled_recipe)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::migrator::tests::get_memory_database_migrated;
+
+    #[tokio::test]
+    async fn test_scale() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`, `yield_amount`, `yield_unit`) VALUES (1, 'Pizza', 2, 'servings'); \
+            INSERT INTO `recipe_step` (`id`, `order`, `description`, `recipe_id`) VALUES (1, 2, 'Belegen', 1); \
+            INSERT INTO `recipe_step` (`id`, `order`, `description`, `recipe_id`) VALUES (2, 1, 'Teig kneten', 1); \
+            INSERT INTO `ingredient` (`id`, `name`) VALUES (1, 'Mehl'), (2, 'Wasser'), (3, 'Salz'), (4, 'Tomaten'); \
+            INSERT INTO `recipe_step_ingredient` (`id`, `order`, `quantity`, `unit`, `recipe_step_id`, `ingredient_id`) VALUES \
+            (1, 1, 400, 'g', 2, 1), \
+            (2, 2, 1, 'cup', 2, 2), \
+            (3, 3, NULL, NULL, 2, 3), \
+            (4, 1, 1.3, 'Dose', 1, 4);",
+        )
+        .await
+        .unwrap();
+        let scaled_recipe = scale(&db, 1, 5.0, &RecipeScaleOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            scaled_recipe,
+            ScaledRecipe {
+                recipe_id: 1,
+                yield_amount: 5.0,
+                yield_unit: Some(String::from("servings")),
+                factor: 2.5,
+                recipe_step_ingredients: vec![
+                    ScaledRecipeStepIngredient {
+                        id: 1,
+                        recipe_step_id: 2,
+                        ingredient_id: 1,
+                        quantity: Some(1000.0),
+                        unit: Some(String::from("g")),
+                    },
+                    ScaledRecipeStepIngredient {
+                        id: 2,
+                        recipe_step_id: 2,
+                        ingredient_id: 2,
+                        quantity: Some(2.5),
+                        unit: Some(String::from("cup")),
+                    },
+                    ScaledRecipeStepIngredient {
+                        id: 3,
+                        recipe_step_id: 2,
+                        ingredient_id: 3,
+                        quantity: None,
+                        unit: None,
+                    },
+                    ScaledRecipeStepIngredient {
+                        id: 4,
+                        recipe_step_id: 1,
+                        ingredient_id: 4,
+                        quantity: Some(3.25),
+                        unit: Some(String::from("Dose")),
+                    },
+                ],
+            }
+        );
+        let scaled_recipe = scale(
+            &db,
+            1,
+            5.0,
+            &RecipeScaleOptions {
+                normalize_units: true,
+                round: true,
+            },
+        )
+        .await
+        .unwrap();
+        let quantities: Vec<(Option<f64>, Option<String>)> = scaled_recipe
+            .recipe_step_ingredients
+            .into_iter()
+            .map(|recipe_step_ingredient| {
+                (recipe_step_ingredient.quantity, recipe_step_ingredient.unit)
+            })
+            .collect();
+        assert_eq!(
+            quantities,
+            vec![
+                (Some(1.0), Some(String::from("kg"))),
+                (Some(590.0), Some(String::from("ml"))),
+                (None, None),
+                (Some(3.5), Some(String::from("Dose"))),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scale_without_yield() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared("INSERT INTO `recipe` (`id`, `name`) VALUES (1, 'Pizza');")
+            .await
+            .unwrap();
+        let result = scale(&db, 1, 4.0, &RecipeScaleOptions::default()).await;
+        assert!(matches!(result, Err(CommandError::RecipeWithoutYield)));
+        let result = scale(&db, 2, 4.0, &RecipeScaleOptions::default()).await;
+        assert!(matches!(result, Err(NotFound)));
+    }
+}
@@ -0,0 +1,89 @@
+//! This module implements the [`tauri::command`] for rendering the description of a recipe step.
+//!
+//! The temperatures in the description are found and rendered by [`crate::step_text_analyzer`].
+
+use sea_orm::{ConnectionTrait, EntityTrait};
+
+use crate::{
+    command::error::{CommandError, CommandError::NotFound},
+    database,
+    entity::{recipe_step, unit_name::unit::Unit as EntityUnit},
+    step_text_analyzer::render_temperatures,
+    unit_conversion::{ConversionError, Unit},
+};
+
+/// Render the description of a recipe step with all temperatures in the given temperature unit.
+///
+/// # Errors
+///
+/// - when there is any problem with the database
+/// - when the recipe step does not exist
+/// - when the unit is not a temperature unit
+async fn render<C>(
+    db: &C,
+    recipe_step_id: i64,
+    temperature_unit: EntityUnit,
+) -> Result<String, CommandError>
+where
+    C: ConnectionTrait,
+{
+    let Unit::Temperature(temperature_unit) = Unit::from(temperature_unit) else {
+        return Err(ConversionError::IncompatibleUnits.into());
+    };
+    let recipe_step = recipe_step::Entity::find_by_id(recipe_step_id)
+        .one(db)
+        .await?
+        .ok_or(NotFound)?;
+    Ok(render_temperatures(
+        &recipe_step.description,
+        &temperature_unit,
+    ))
+}
+
+/// Render the description of a recipe step with all temperatures in the given temperature unit.
+///
+/// See [`render_temperatures`].
+#[tauri::command]
+pub async fn recipe_step_description_render(
+    recipe_step_id: i64,
+    temperature_unit: EntityUnit,
+) -> Result<String, CommandError> {
+    let db = database::connect().await;
+    let description = render(db, recipe_step_id, temperature_unit).await?;
+    Ok(description)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::migrator::tests::get_memory_database_migrated;
+
+    #[tokio::test]
+    async fn test_render() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`) VALUES (1, 'Pizza'); \
+            INSERT INTO `recipe_step` (`id`, `order`, `description`, `recipe_id`) VALUES (1, 1, 'Bei 250 °C Umluft backen.', 1);",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            render(&db, 1, EntityUnit::TemperatureFahrenheit)
+                .await
+                .unwrap(),
+            "Bei 520 °F backen."
+        );
+        assert!(matches!(
+            render(&db, 1, EntityUnit::MassGram).await,
+            Err(CommandError::UnitConversion(
+                ConversionError::IncompatibleUnits
+            ))
+        ));
+        assert!(matches!(
+            render(&db, 2, EntityUnit::TemperatureCelsius).await,
+            Err(NotFound)
+        ));
+    }
+}
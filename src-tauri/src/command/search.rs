@@ -0,0 +1,250 @@
+//! This module implements the [`tauri::command`] for full-text search over recipes.
+//!
+//! The search index is created and kept in sync by the `recipe_search` migration in [`crate::migrator`].
+
+use indexmap::IndexMap;
+use sea_orm::{ConnectionTrait, Statement};
+use serde::Serialize;
+
+use crate::{command::error::CommandError, database};
+
+/// This character marks the start of a highlighted match inside a snippet.
+const HIGHLIGHT_START: char = '\u{2}';
+
+/// This character marks the end of a highlighted match inside a snippet.
+const HIGHLIGHT_END: char = '\u{3}';
+
+/// This struct represents a recipe found by the search.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResult {
+    pub recipe_id: i64,
+    pub snippets: Vec<SearchSnippet>,
+}
+
+/// This struct represents a snippet of matched text.
+///
+/// The source is the table of the matched text, e.g. `recipe_step`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchSnippet {
+    pub source: String,
+    pub source_id: i64,
+    pub parts: Vec<SearchSnippetPart>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchSnippetPart {
+    pub text: String,
+    pub highlighted: bool,
+}
+
+/// Split a snippet into its highlighted and not highlighted parts.
+fn parse_snippet(snippet: &str) -> Vec<SearchSnippetPart> {
+    let mut parts = vec![];
+    let mut highlighted = false;
+    for text in snippet.split([HIGHLIGHT_START, HIGHLIGHT_END]) {
+        if !text.is_empty() {
+            parts.push(SearchSnippetPart {
+                text: text.to_string(),
+                highlighted,
+            });
+        }
+        highlighted = !highlighted;
+    }
+    parts
+}
+
+/// Get the alternative spellings of a search term.
+///
+/// German umlauts and "ß" are often transliterated, e.g. "Kaese" for "Käse", so both spellings are searched.
+/// The search index removes diacritics, so "Käse" is indexed as "kase", but keeps "ß".
+fn term_variants(term: &str) -> Vec<String> {
+    let lowercase = term.to_lowercase();
+    let transliterated = lowercase
+        .replace('ä', "ae")
+        .replace('ö', "oe")
+        .replace('ü', "ue")
+        .replace('ß', "ss");
+    let folded = lowercase
+        .replace("ae", "a")
+        .replace("oe", "o")
+        .replace("ue", "u")
+        .replace("ss", "ß");
+    let mut variants = vec![lowercase];
+    for variant in [transliterated, folded] {
+        if !variants.contains(&variant) {
+            variants.push(variant);
+        }
+    }
+    variants
+}
+
+/// Create an [FTS5 query](https://www.sqlite.org/fts5.html#full_text_query_syntax) from user input.
+///
+/// Every term is searched as a prefix, rows matching any term are found.
+///
+/// Returns [`None`] if the input does not contain any term.
+fn fts_query(input: &str) -> Option<String> {
+    let phrases: Vec<String> = input
+        .split(|char: char| !char.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .flat_map(term_variants)
+        .map(|term| format!("\"{term}\"*"))
+        .collect();
+    if phrases.is_empty() {
+        return None;
+    }
+    Some(phrases.join(" OR "))
+}
+
+/// Search recipes with an FTS5 query, see [`search`].
+///
+/// The recipes are ranked and limited in SQL, their snippets are ordered by the rank of their matches.
+async fn search_recipes<C>(
+    db: &C,
+    fts_query: String,
+    limit: Option<usize>,
+) -> Result<Vec<SearchResult>, CommandError>
+where
+    C: ConnectionTrait,
+{
+    // a negative limit means no limit in SQLite
+    let limit = limit.map_or(-1, |limit| i64::try_from(limit).unwrap_or(i64::MAX));
+    let query_results = db
+        .query_all(Statement::from_sql_and_values(
+            db.get_database_backend(),
+            format!(
+                "WITH `recipe_match` AS (\
+                SELECT `recipe_id`, `source`, `source_id`, \
+                snippet(`recipe_search`, 3, '{HIGHLIGHT_START}', '{HIGHLIGHT_END}', '…', 16) AS `snippet`, \
+                bm25(`recipe_search`) AS `rank` \
+                FROM `recipe_search` WHERE `recipe_search` MATCH ?\
+                ), `recipe_rank` AS (\
+                SELECT `recipe_id`, SUM(`rank`) AS `rank` FROM `recipe_match` \
+                GROUP BY `recipe_id` ORDER BY `rank`, `recipe_id` LIMIT ?\
+                ) \
+                SELECT `recipe_match`.`recipe_id`, `source`, `source_id`, `snippet` \
+                FROM `recipe_match` JOIN `recipe_rank` ON `recipe_rank`.`recipe_id` = `recipe_match`.`recipe_id` \
+                ORDER BY `recipe_rank`.`rank`, `recipe_match`.`recipe_id`, `recipe_match`.`rank`;"
+            ),
+            [fts_query.into(), limit.into()],
+        ))
+        .await?;
+    let mut results: IndexMap<i64, SearchResult> = IndexMap::new();
+    for query_result in query_results {
+        let (recipe_id, source, source_id, snippet): (i64, String, i64, String) =
+            query_result.try_get_many_by_index()?;
+        let result = results.entry(recipe_id).or_insert(SearchResult {
+            recipe_id,
+            snippets: vec![],
+        });
+        result.snippets.push(SearchSnippet {
+            source,
+            source_id,
+            parts: parse_snippet(&snippet),
+        });
+    }
+    Ok(results.into_values().collect())
+}
+
+/// Search recipes by their name, step descriptions, ingredients, and ingredient drafts.
+///
+/// The recipes are ranked by the sum of the [BM25](https://www.sqlite.org/fts5.html#the_bm25_function) rank of their matches,
+/// bm25 returns lower values for better matches.
+#[tauri::command]
+pub async fn search(
+    query: String,
+    limit: Option<usize>,
+) -> Result<Vec<SearchResult>, CommandError> {
+    let Some(fts_query) = fts_query(&query) else {
+        return Ok(vec![]);
+    };
+    let db = database::connect().await;
+    let results = search_recipes(db, fts_query, limit).await?;
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::migrator::tests::get_memory_database_migrated;
+
+    #[test]
+    fn test_fts_query() {
+        assert_eq!(
+            fts_query("Käse-Kuchen \"mit\" Soße"),
+            Some(String::from(
+                "\"käse\"* OR \"kaese\"* OR \"kuchen\"* OR \"mit\"* OR \"soße\"* OR \"sosse\"*"
+            ))
+        );
+        assert_eq!(
+            fts_query("Kaese Strasse"),
+            Some(String::from(
+                "\"kaese\"* OR \"kase\"* OR \"strasse\"* OR \"straße\"*"
+            ))
+        );
+        assert_eq!(fts_query(" *\"- "), None);
+    }
+
+    #[tokio::test]
+    async fn test_search_recipes() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`) VALUES (1, 'Käsekuchen'), (2, 'Kaesespaetzle'), (3, 'Strassenbrot'), (4, 'Salat'); \
+            INSERT INTO `recipe_step` (`id`, `order`, `description`, `recipe_id`) VALUES \
+            (1, 1, 'Käse reiben und Käse schmelzen', 2), (2, 1, 'Brot wie auf der Straße backen', 3);",
+        )
+        .await
+        .unwrap();
+        let recipe_ids = |results: Vec<SearchResult>| -> Vec<i64> {
+            results.into_iter().map(|result| result.recipe_id).collect()
+        };
+        let results = search_recipes(&db, fts_query("Käse").unwrap(), None)
+            .await
+            .unwrap();
+        assert_eq!(results[0].snippets.len(), 2);
+        assert_eq!(recipe_ids(results), vec![2, 1]);
+        let results = search_recipes(&db, fts_query("Käse").unwrap(), Some(1))
+            .await
+            .unwrap();
+        assert_eq!(recipe_ids(results), vec![2]);
+        let results = search_recipes(&db, fts_query("Straße").unwrap(), None)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].snippets.len(), 2);
+        let results = search_recipes(&db, fts_query("Strasse").unwrap(), None)
+            .await
+            .unwrap();
+        assert_eq!(recipe_ids(results), vec![3]);
+    }
+
+    #[test]
+    fn test_parse_snippet() {
+        assert_eq!(
+            parse_snippet("…den \u{2}Käse\u{3} reiben und \u{2}Kässpätzle\u{3}"),
+            vec![
+                SearchSnippetPart {
+                    text: String::from("…den "),
+                    highlighted: false,
+                },
+                SearchSnippetPart {
+                    text: String::from("Käse"),
+                    highlighted: true,
+                },
+                SearchSnippetPart {
+                    text: String::from(" reiben und "),
+                    highlighted: false,
+                },
+                SearchSnippetPart {
+                    text: String::from("Kässpätzle"),
+                    highlighted: true,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,396 @@
+//! This module implements the [`tauri::command`] for adding recipes to a shopping list.
+//!
+//! The quantities of the same ingredient are merged into one shopping list item if their units are compatible.
+
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    command::{
+        error::{CommandError, CommandError::NotFound},
+        preference::{select_preference, UNIT_SYSTEM},
+        recipe_scale::{scale, RecipeScaleOptions},
+        unit_list::UnitNames,
+    },
+    database,
+    entity::{
+        recipe, recipe_step, recipe_step_ingredient, shopping_list, shopping_list_item,
+        unit_name::unit::Unit,
+    },
+    event::channel::{
+        ENTITY_ACTION_CREATED_SHOPPING_LIST_ITEM, ENTITY_ACTION_UPDATED_SHOPPING_LIST_ITEM,
+    },
+    unit_conversion,
+    unit_conversion::UnitSystem,
+    window::get_window,
+};
+
+/// This struct represents a recipe to add to a shopping list.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShoppingListRecipe {
+    pub recipe_id: i64,
+    /// the yield to scale the recipe to, the recipe is added unscaled if not given
+    pub yield_amount: Option<f64>,
+}
+
+/// This struct represents the shopping list items changed by adding recipes.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShoppingListAddition {
+    pub created_shopping_list_item_ids: Vec<i64>,
+    pub updated_shopping_list_item_ids: Vec<i64>,
+}
+
+/// This struct represents the quantity of an ingredient on a shopping list while adding recipes.
+#[derive(Debug)]
+struct IngredientQuantity {
+    /// the id of the shopping list item, [`None`] if it still needs to be created
+    id: Option<i64>,
+    ingredient_id: i64,
+    quantity: Option<f64>,
+    unit: Option<String>,
+    changed: bool,
+}
+
+impl IngredientQuantity {
+    /// Add a quantity of the same ingredient if the units are compatible.
+    ///
+    /// Quantities with known units of the same dimension are summed and converted into the best fitting unit of the unit system, e.g. 500 g + 1 kg = 1.5 kg.
+    /// Quantities with the same unknown unit are summed as they are.
+    /// An ingredient without quantity and unit, e.g. "salt to taste", is absorbed by any other quantity.
+    ///
+    /// Returns whether the quantity was added.
+    fn add(
+        &mut self,
+        quantity: Option<f64>,
+        unit: Option<&str>,
+        unit_system: &UnitSystem,
+        unit_names: &UnitNames,
+    ) -> bool {
+        if quantity.is_none() && unit.is_none() {
+            return true;
+        }
+        if self.quantity.is_none() && self.unit.is_none() {
+            self.set(quantity, unit.map(String::from));
+            return true;
+        }
+        if let Some((sum, sum_unit)) = sum_known(
+            self.quantity,
+            self.unit.as_deref(),
+            quantity,
+            unit,
+            unit_system,
+            unit_names,
+        ) {
+            self.set(Some(sum), Some(sum_unit));
+            return true;
+        }
+        if self.unit.as_deref() == unit {
+            let sum = match (self.quantity, quantity) {
+                (Some(a), Some(b)) => Some(a + b),
+                (a, b) => a.or(b),
+            };
+            self.set(sum, self.unit.clone());
+            return true;
+        }
+        false
+    }
+
+    fn set(&mut self, quantity: Option<f64>, unit: Option<String>) {
+        self.changed |= quantity != self.quantity || unit != self.unit;
+        self.quantity = quantity;
+        self.unit = unit;
+    }
+}
+
+/// Sum two quantities with known units of the same dimension in the best fitting unit of a unit system.
+///
+/// A missing quantity counts as zero.
+/// Temperatures are never summed.
+fn sum_known(
+    a: Option<f64>,
+    a_unit: Option<&str>,
+    b: Option<f64>,
+    b_unit: Option<&str>,
+    unit_system: &UnitSystem,
+    unit_names: &UnitNames,
+) -> Option<(f64, String)> {
+    let a_unit = unit_conversion::Unit::from(unit_names.unit(a_unit?)?);
+    let b_unit = unit_conversion::Unit::from(unit_names.unit(b_unit?)?);
+    let (a, a_unit) = a_unit.to_metric(a.unwrap_or_default());
+    let (b, b_unit) = b_unit.to_metric(b.unwrap_or_default());
+    match (&a_unit, &b_unit) {
+        (unit_conversion::Unit::Mass(_), unit_conversion::Unit::Mass(_))
+        | (unit_conversion::Unit::Volume(_), unit_conversion::Unit::Volume(_)) => {
+            let (sum, sum_unit) = a_unit.to_unit_system(a + b, unit_system);
+            let name = unit_names.name(&Unit::from(sum_unit))?;
+            Some((sum, name.to_string()))
+        }
+        _ => None,
+    }
+}
+
+/// Select the quantities of the recipe step ingredients of a recipe, optionally scaled to a yield.
+///
+/// Returns the ingredient id, quantity, and unit of each recipe step ingredient, ordered by their recipe step.
+async fn select_quantities<C>(
+    db: &C,
+    recipe: &ShoppingListRecipe,
+) -> Result<Vec<(i64, Option<f64>, Option<String>)>, CommandError>
+where
+    C: ConnectionTrait,
+{
+    if let Some(yield_amount) = recipe.yield_amount {
+        let scaled_recipe = scale(
+            db,
+            recipe.recipe_id,
+            yield_amount,
+            &RecipeScaleOptions::default(),
+        )
+        .await?;
+        return Ok(scaled_recipe
+            .recipe_step_ingredients
+            .into_iter()
+            .map(|scaled| (scaled.ingredient_id, scaled.quantity, scaled.unit))
+            .collect());
+    }
+    recipe::Entity::find_by_id(recipe.recipe_id)
+        .one(db)
+        .await?
+        .ok_or(NotFound)?;
+    let models = recipe_step_ingredient::Entity::find()
+        .inner_join(recipe_step::Entity)
+        .filter(recipe_step::Column::RecipeId.eq(recipe.recipe_id))
+        .order_by_asc(recipe_step::Column::Order)
+        .order_by_asc(recipe_step_ingredient::Column::Order)
+        .all(db)
+        .await?;
+    Ok(models
+        .into_iter()
+        .map(|model| (model.ingredient_id, model.quantity, model.unit))
+        .collect())
+}
+
+/// Add the ingredients of recipes to a shopping list.
+///
+/// The quantities are [merged](IngredientQuantity::add) with each other and with the unchecked shopping list items of the same ingredient.
+/// Checked shopping list items are already bought, so they are never changed.
+/// The new shopping list items are appended in the order of the recipes.
+pub async fn add_recipes<C>(
+    db: &C,
+    shopping_list_id: i64,
+    recipes: &[ShoppingListRecipe],
+) -> Result<ShoppingListAddition, CommandError>
+where
+    C: ConnectionTrait,
+{
+    shopping_list::Entity::find_by_id(shopping_list_id)
+        .one(db)
+        .await?
+        .ok_or(NotFound)?;
+    let unit_system: UnitSystem = select_preference(db, UNIT_SYSTEM)
+        .await?
+        .unwrap_or_default();
+    let unit_names = UnitNames::select(db).await?;
+    let mut ingredient_quantities: Vec<IngredientQuantity> = shopping_list_item::Entity::find()
+        .filter(shopping_list_item::Column::ShoppingListId.eq(shopping_list_id))
+        .filter(shopping_list_item::Column::Checked.eq(false))
+        .filter(shopping_list_item::Column::IngredientId.is_not_null())
+        .order_by_asc(shopping_list_item::Column::Order)
+        .all(db)
+        .await?
+        .into_iter()
+        .filter_map(|model| {
+            Some(IngredientQuantity {
+                id: Some(model.id),
+                ingredient_id: model.ingredient_id?,
+                quantity: model.quantity,
+                unit: model.unit,
+                changed: false,
+            })
+        })
+        .collect();
+    for recipe in recipes {
+        for (ingredient_id, quantity, unit) in select_quantities(db, recipe).await? {
+            let added = ingredient_quantities
+                .iter_mut()
+                .filter(|ingredient_quantity| ingredient_quantity.ingredient_id == ingredient_id)
+                .any(|ingredient_quantity| {
+                    ingredient_quantity.add(quantity, unit.as_deref(), &unit_system, &unit_names)
+                });
+            if !added {
+                ingredient_quantities.push(IngredientQuantity {
+                    id: None,
+                    ingredient_id,
+                    quantity,
+                    unit,
+                    changed: true,
+                });
+            }
+        }
+    }
+    let mut order = shopping_list_item::Entity::find()
+        .select_only()
+        .column_as(shopping_list_item::Column::Order.max(), "order")
+        .filter(shopping_list_item::Column::ShoppingListId.eq(shopping_list_id))
+        .into_tuple::<Option<i64>>()
+        .one(db)
+        .await?
+        .flatten()
+        .unwrap_or_default();
+    let mut addition = ShoppingListAddition {
+        created_shopping_list_item_ids: vec![],
+        updated_shopping_list_item_ids: vec![],
+    };
+    for ingredient_quantity in ingredient_quantities {
+        if !ingredient_quantity.changed {
+            continue;
+        }
+        match ingredient_quantity.id {
+            Some(id) => {
+                shopping_list_item::ActiveModel {
+                    id: ActiveValue::Unchanged(id),
+                    quantity: ActiveValue::Set(ingredient_quantity.quantity),
+                    unit: ActiveValue::Set(ingredient_quantity.unit),
+                    ..Default::default()
+                }
+                .update(db)
+                .await?;
+                addition.updated_shopping_list_item_ids.push(id);
+            }
+            None => {
+                order += 1;
+                let model = shopping_list_item::ActiveModel {
+                    order: ActiveValue::Set(order),
+                    quantity: ActiveValue::Set(ingredient_quantity.quantity),
+                    unit: ActiveValue::Set(ingredient_quantity.unit),
+                    name: ActiveValue::Set(None),
+                    checked: ActiveValue::Set(false),
+                    shopping_list_id: ActiveValue::Set(shopping_list_id),
+                    ingredient_id: ActiveValue::Set(Some(ingredient_quantity.ingredient_id)),
+                    ..Default::default()
+                }
+                .insert(db)
+                .await?;
+                addition.created_shopping_list_item_ids.push(model.id);
+            }
+        }
+    }
+    Ok(addition)
+}
+
+/// Add the ingredients of recipes, optionally scaled, to a shopping list.
+///
+/// The same ingredients are merged and their quantities summed if their units can be converted into each other, e.g. 500 g + 1 kg = 1.5 kg.
+/// All shopping list items are stored in one transaction.
+///
+/// # Errors
+///
+/// - when the shopping list or a recipe does not exist
+/// - when a recipe should be scaled but has no yield
+/// - when a target yield is not positive
+#[tauri::command]
+pub async fn shopping_list_add_recipes(
+    shopping_list_id: i64,
+    recipes: Vec<ShoppingListRecipe>,
+) -> Result<ShoppingListAddition, CommandError> {
+    let db = database::connect_writing().await;
+    let txn = db.begin().await?;
+    let addition = add_recipes(&txn, shopping_list_id, &recipes).await?;
+    txn.commit().await?;
+    let window = get_window();
+    if !addition.created_shopping_list_item_ids.is_empty() {
+        window.emit(ENTITY_ACTION_CREATED_SHOPPING_LIST_ITEM, ())?;
+    }
+    for id in &addition.updated_shopping_list_item_ids {
+        window.emit(ENTITY_ACTION_UPDATED_SHOPPING_LIST_ITEM, id)?;
+    }
+    Ok(addition)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sea_orm::Statement;
+
+    use super::*;
+    use crate::migrator::tests::get_memory_database_migrated;
+
+    #[tokio::test]
+    async fn test_add_recipes() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`, `yield_amount`) VALUES (1, 'Brot', 2), (2, 'Pizza', NULL); \
+            INSERT INTO `recipe_step` (`id`, `order`, `description`, `recipe_id`) VALUES (1, 1, 'Teig kneten', 1), (2, 1, 'Teig kneten', 2), (3, 2, 'Belegen', 2); \
+            INSERT INTO `ingredient` (`id`, `name`) VALUES (1, 'Mehl'), (2, 'Wasser'), (3, 'Zucker'), (4, 'Salz'), (5, 'Tomaten'); \
+            INSERT INTO `recipe_step_ingredient` (`id`, `order`, `quantity`, `unit`, `recipe_step_id`, `ingredient_id`) VALUES \
+            (1, 1, 1, 'kg', 1, 1), \
+            (2, 2, 200, 'ml', 1, 2), \
+            (3, 3, 50, 'g', 1, 3), \
+            (4, 4, NULL, NULL, 1, 4), \
+            (5, 1, 0.5, 'l', 2, 2), \
+            (6, 2, 1, 'TL', 2, 4), \
+            (7, 3, 1, 'Dose', 2, 5), \
+            (8, 1, 200, 'g', 3, 5); \
+            INSERT INTO `shopping_list` (`id`, `name`) VALUES (1, 'Wochenende'); \
+            INSERT INTO `shopping_list_item` (`id`, `order`, `quantity`, `unit`, `name`, `checked`, `shopping_list_id`, `ingredient_id`) VALUES \
+            (1, 1, 500, 'g', NULL, false, 1, 1), \
+            (2, 2, 100, 'g', NULL, true, 1, 3), \
+            (3, 3, NULL, NULL, 'Zahnpasta', false, 1, NULL);",
+        )
+        .await
+        .unwrap();
+        let recipes = vec![
+            ShoppingListRecipe {
+                recipe_id: 1,
+                yield_amount: Some(4.0),
+            },
+            ShoppingListRecipe {
+                recipe_id: 2,
+                yield_amount: None,
+            },
+        ];
+        let addition = add_recipes(&db, 1, &recipes).await.unwrap();
+        assert_eq!(
+            addition,
+            ShoppingListAddition {
+                created_shopping_list_item_ids: vec![4, 5, 6, 7, 8],
+                updated_shopping_list_item_ids: vec![1],
+            }
+        );
+        let shopping_list_items: Vec<(i64, Option<f64>, Option<String>, bool, Option<i64>)> = db
+            .query_all(Statement::from_string(
+                db.get_database_backend(),
+                "SELECT `order`, `quantity`, `unit`, `checked`, `ingredient_id` FROM `shopping_list_item` ORDER BY `id`",
+            ))
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|query_result| query_result.try_get_many_by_index().unwrap())
+            .collect();
+        assert_eq!(
+            shopping_list_items,
+            vec![
+                (1, Some(2.5), Some(String::from("kg")), false, Some(1)),
+                (2, Some(100.0), Some(String::from("g")), true, Some(3)),
+                (3, None, None, false, None),
+                (4, Some(900.0), Some(String::from("ml")), false, Some(2)),
+                (5, Some(100.0), Some(String::from("g")), false, Some(3)),
+                (6, Some(1.0), Some(String::from("TL")), false, Some(4)),
+                (7, Some(1.0), Some(String::from("Dose")), false, Some(5)),
+                (8, Some(200.0), Some(String::from("g")), false, Some(5)),
+            ]
+        );
+        let recipes = vec![ShoppingListRecipe {
+            recipe_id: 2,
+            yield_amount: Some(4.0),
+        }];
+        let result = add_recipes(&db, 1, &recipes).await;
+        assert!(matches!(result, Err(CommandError::RecipeWithoutYield)));
+        assert!(matches!(add_recipes(&db, 2, &[]).await, Err(NotFound)));
+    }
+}
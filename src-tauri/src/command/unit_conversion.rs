@@ -2,11 +2,16 @@
 //!
 //! The decision to which units to convert, happens inside [`ConversionBuilder::build`].
 
+use std::collections::HashMap;
+
+use sea_orm::{ConnectionTrait, DbErr, EntityTrait};
 use serde::Serialize;
 
 use crate::{
-    entity::unit_name::unit::Unit as EntityUnit,
-    unit_conversion::{Convert, MassUnit, Unit, VolumeUnit},
+    command::error::{CommandError, CommandError::NotFound},
+    database,
+    entity::{common_ingredient_density, ingredient, unit_name::unit::Unit as EntityUnit},
+    unit_conversion::{ConversionError, Convert, MassUnit, TemperatureUnit, Unit, VolumeUnit},
 };
 
 /// This struct represents a finished unit conversion.
@@ -30,6 +35,7 @@ pub struct ConvertedValue {
 pub struct ConversionBuilder {
     original_value: f64,
     original_unit: Unit,
+    density: Option<f64>,
 }
 
 impl ConversionBuilder {
@@ -37,12 +43,19 @@ impl ConversionBuilder {
         Self {
             original_value,
             original_unit,
+            density: None,
         }
     }
 
+    /// Set the density in g/ml to include the metric units of the other dimension, i.e. volume for a mass and vice versa.
+    pub fn density(mut self, density: f64) -> Self {
+        self.density = Some(density);
+        self
+    }
+
     /// Build the [conversion](Conversion), including only metric units.
     pub fn build(self) -> Conversion {
-        let values = match self.original_unit {
+        let mut values = match self.original_unit {
             Unit::Mass(mass_unit) => vec![
                 ConvertedValue {
                     value: mass_unit.convert(self.original_value, &MassUnit::Kilogram),
@@ -63,12 +76,152 @@ impl ConversionBuilder {
                     unit: EntityUnit::from(Unit::Volume(VolumeUnit::Millilitre)),
                 },
             ],
+            Unit::Temperature(temperature_unit) => vec![ConvertedValue {
+                value: temperature_unit.convert(self.original_value, &TemperatureUnit::Celsius),
+                unit: EntityUnit::from(Unit::Temperature(TemperatureUnit::Celsius)),
+            }],
         };
+        if let Some(density) = self.density {
+            let other_units = match self.original_unit {
+                Unit::Mass(_) => vec![
+                    Unit::Volume(VolumeUnit::Litre),
+                    Unit::Volume(VolumeUnit::Millilitre),
+                ],
+                Unit::Volume(_) => {
+                    vec![Unit::Mass(MassUnit::Kilogram), Unit::Mass(MassUnit::Gram)]
+                }
+                Unit::Temperature(_) => vec![],
+            };
+            for other_unit in other_units {
+                let value = self
+                    .original_unit
+                    .convert_to(self.original_value, &other_unit, Some(density))
+                    .expect("The density is given.");
+                values.push(ConvertedValue {
+                    value,
+                    unit: EntityUnit::from(other_unit),
+                });
+            }
+        }
         Conversion { values }
     }
 }
 
+/// This struct maps the lowercase names of [common ingredients](common_ingredient_density) to their densities.
+pub struct CommonDensities {
+    densities: HashMap<String, f64>,
+}
+
+impl CommonDensities {
+    pub async fn select<C>(db: &C) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let densities = common_ingredient_density::Entity::find()
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|model| (model.name.to_lowercase(), model.density))
+            .collect();
+        Ok(Self { densities })
+    }
+
+    /// Get the density of an ingredient in g/ml.
+    ///
+    /// If the ingredient has no density, the density of the common ingredient with the same name is used.
+    pub fn density(&self, ingredient: &ingredient::Model) -> Option<f64> {
+        ingredient
+            .density
+            .or_else(|| self.densities.get(&ingredient.name.to_lowercase()).copied())
+    }
+}
+
+/// Get the density of an ingredient in g/ml, see [`CommonDensities::density`].
+///
+/// # Errors
+///
+/// - when there is any problem with the database
+/// - when the ingredient does not exist
+pub async fn select_density<C>(db: &C, ingredient_id: i64) -> Result<Option<f64>, CommandError>
+where
+    C: ConnectionTrait,
+{
+    let ingredient = ingredient::Entity::find_by_id(ingredient_id)
+        .one(db)
+        .await?
+        .ok_or(NotFound)?;
+    if ingredient.density.is_some() {
+        return Ok(ingredient.density);
+    }
+    let common_densities = CommonDensities::select(db).await?;
+    Ok(common_densities.density(&ingredient))
+}
+
+/// Convert a value into metric units.
+///
+/// If an ingredient is given, the value is also converted between mass and volume by the density of the ingredient.
+///
+/// # Errors
+///
+/// - when the ingredient does not exist
+/// - when the ingredient has no known density
 #[tauri::command]
-pub async fn unit_convert(value: f64, unit: EntityUnit) -> Conversion {
-    Conversion::builder(value, Unit::from(unit)).build()
+pub async fn unit_convert(
+    value: f64,
+    unit: EntityUnit,
+    ingredient_id: Option<i64>,
+) -> Result<Conversion, CommandError> {
+    let mut builder = Conversion::builder(value, Unit::from(unit));
+    if let Some(ingredient_id) = ingredient_id {
+        let db = database::connect().await;
+        let density = select_density(db, ingredient_id)
+            .await?
+            .ok_or(ConversionError::DensityUnknown)?;
+        builder = builder.density(density);
+    }
+    Ok(builder.build())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::migrator::tests::get_memory_database_migrated;
+
+    #[test]
+    fn test_build_with_density() {
+        let conversion = Conversion::builder(2.0, Unit::Mass(MassUnit::Kilogram))
+            .density(0.5)
+            .build();
+        let units: Vec<EntityUnit> = conversion
+            .values
+            .iter()
+            .map(|converted_value| converted_value.unit.clone())
+            .collect();
+        assert_eq!(
+            units,
+            vec![
+                EntityUnit::MassKilogram,
+                EntityUnit::MassGram,
+                EntityUnit::VolumeLitre,
+                EntityUnit::VolumeMillilitre,
+            ]
+        );
+        assert!((conversion.values[2].value - 4.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_select_density() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `ingredient` (`id`, `name`, `density`) VALUES (1, 'Mehl', 0.6), (2, 'mehl', NULL), (3, 'Chorizo', NULL);",
+        )
+        .await
+        .unwrap();
+        assert_eq!(select_density(&db, 1).await.unwrap(), Some(0.6));
+        assert_eq!(select_density(&db, 2).await.unwrap(), Some(0.53));
+        assert_eq!(select_density(&db, 3).await.unwrap(), None);
+        assert!(matches!(select_density(&db, 4).await, Err(NotFound)));
+    }
 }
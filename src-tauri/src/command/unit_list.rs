@@ -1,12 +1,14 @@
+use std::collections::HashMap;
+
 use sea_orm::{
     sea_query::{Expr, Query, UnionType},
-    ConnectionTrait, DeriveIden, EntityName, EnumIter, TryGetableMany,
+    ConnectionTrait, DbErr, DeriveIden, EntityName, EntityTrait, EnumIter, TryGetableMany,
 };
 
 use crate::{
     command::error::CommandError,
     database,
-    entity::{recipe_step_ingredient, unit_name},
+    entity::{recipe_step_ingredient, unit_name, unit_name::unit::Unit},
 };
 
 #[derive(EnumIter, DeriveIden)]
@@ -20,6 +22,15 @@ enum ResultColumn {
 #[tauri::command]
 pub async fn unit_list_get() -> Result<Vec<String>, CommandError> {
     let db = database::connect().await;
+    let result = select_unit_names(db).await?;
+    Ok(result)
+}
+
+/// Select the unit names currently in use, see [`unit_list_get`].
+pub async fn select_unit_names<C>(db: &C) -> Result<Vec<String>, DbErr>
+where
+    C: ConnectionTrait,
+{
     let query = Query::select()
         .column(recipe_step_ingredient::Column::Unit)
         .distinct()
@@ -39,3 +50,36 @@ pub async fn unit_list_get() -> Result<Vec<String>, CommandError> {
         .await?;
     Ok(result)
 }
+
+/// This struct maps between unit names and [units](Unit).
+pub struct UnitNames {
+    units: HashMap<String, Unit>,
+}
+
+impl UnitNames {
+    pub async fn select<C>(db: &C) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let units = unit_name::Entity::find()
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|model| (model.name, model.unit))
+            .collect();
+        Ok(Self { units })
+    }
+
+    pub fn unit(&self, name: &str) -> Option<Unit> {
+        self.units.get(name).cloned()
+    }
+
+    /// Get the shortest name of a unit, e.g. "g" instead of "gram".
+    pub fn name(&self, unit: &Unit) -> Option<&str> {
+        self.units
+            .iter()
+            .filter(|(_, name_unit)| *name_unit == unit)
+            .map(|(name, _)| name.as_str())
+            .min_by_key(|name| (name.len(), *name))
+    }
+}
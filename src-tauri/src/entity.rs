@@ -1,12 +1,24 @@
 //! This module contains the [`sea_orm`] entities.
 
+pub mod collection;
+pub mod common_ingredient_density;
 pub mod file;
 pub mod ingredient;
+pub mod ingredient_nutrition;
+pub mod meal_plan_entry;
+pub mod pantry_item;
+pub mod preference;
 pub mod recipe;
+pub mod recipe_collection;
+pub mod recipe_declared_nutrition;
 pub mod recipe_file;
 pub mod recipe_ingredient_draft;
 pub mod recipe_step;
 pub mod recipe_step_file;
 pub mod recipe_step_ingredient;
 pub mod recipe_step_ingredient_draft;
+pub mod recipe_tag;
+pub mod shopping_list;
+pub mod shopping_list_item;
+pub mod tag;
 pub mod unit_name;
@@ -0,0 +1,43 @@
+//! This module implements the collection entity.
+//!
+//! See [`Model`] for more information.
+
+use sea_orm::entity::prelude::*;
+use serde::Serialize;
+
+/// This struct represents a collection.
+///
+/// A collection is an ordered series of [`super::recipe::Model`]s, e.g. _Christmas_, which are collected via [`super::recipe_collection::Model`]s.
+/// A collection is identifiable by its name.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "collection")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub name: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::recipe_collection::Entity")]
+    RecipeCollection,
+}
+
+impl Related<super::recipe_collection::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RecipeCollection.def()
+    }
+}
+
+impl Related<super::recipe::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::recipe_collection::Relation::Recipe.def()
+    }
+
+    fn via() -> Option<RelationDef> {
+        Some(super::recipe_collection::Relation::Collection.def().rev())
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,23 @@
+//! This module implements the common ingredient density entity.
+//!
+//! See [`Model`] for more information.
+
+use sea_orm::entity::prelude::*;
+use serde::Serialize;
+
+/// This struct represents the known density of a common ingredient in g/ml.
+///
+/// It is used for an [`super::ingredient::Model`] with the same name but without a density.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "common_ingredient_density")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub name: String,
+    pub density: f64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -58,20 +58,28 @@ impl ActiveModelBehavior for ActiveModel {
     }
 }
 
-/// Remove orphaned file entities.
+/// Find orphaned file entities.
 ///
 /// An entity is orphaned if no other entity references it.
-pub async fn remove_orphans<C>(db: &C) -> Result<(), DbErr>
+pub async fn find_orphans<C>(db: &C) -> Result<Vec<Model>, DbErr>
 where
     C: ConnectionTrait,
 {
-    let orphaned_files = Entity::find()
+    Entity::find()
         .left_join(super::recipe_step_file::Entity)
         .filter(super::recipe_step_file::Column::Id.is_null())
         .left_join(super::recipe_file::Entity)
         .filter(super::recipe_file::Column::Id.is_null())
         .all(db)
-        .await?;
+        .await
+}
+
+/// Remove orphaned file entities, see [`find_orphans`].
+pub async fn remove_orphans<C>(db: &C) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    let orphaned_files = find_orphans(db).await?;
     for orphaned_file in orphaned_files {
         orphaned_file.delete(db).await?;
     }
@@ -11,19 +11,41 @@ use serde::Serialize;
 ///
 /// An ingredient is used up in a recipe.
 /// An ingredient is identifiable by its name.
-#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize)]
+///
+/// The density in g/ml enables conversions between mass and volume.
+/// See [`super::common_ingredient_density::Model`] for ingredients without a density.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize)]
 #[serde(rename_all = "camelCase")]
 #[sea_orm(table_name = "ingredient")]
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i64,
     pub name: String,
+    pub density: Option<f64>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_one = "super::ingredient_nutrition::Entity")]
+    IngredientNutrition,
+    #[sea_orm(has_many = "super::pantry_item::Entity")]
+    PantryItem,
     #[sea_orm(has_many = "super::recipe_step_ingredient::Entity")]
     RecipeStepIngredient,
+    #[sea_orm(has_many = "super::shopping_list_item::Entity")]
+    ShoppingListItem,
+}
+
+impl Related<super::ingredient_nutrition::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::IngredientNutrition.def()
+    }
+}
+
+impl Related<super::pantry_item::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PantryItem.def()
+    }
 }
 
 impl Related<super::recipe_step_ingredient::Entity> for Entity {
@@ -32,4 +54,10 @@ impl Related<super::recipe_step_ingredient::Entity> for Entity {
     }
 }
 
+impl Related<super::shopping_list_item::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ShoppingListItem.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,46 @@
+//! This module implements the ingredient nutrition entity.
+//!
+//! See [`Model`] for more information.
+
+use sea_orm::entity::prelude::*;
+use serde::Serialize;
+
+/// This struct represents the nutrition facts of an [`super::ingredient::Model`] per 100 g.
+///
+/// The energy is in kcal, all other nutrients are in g.
+/// An ingredient has at most one ingredient nutrition.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "ingredient_nutrition")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub energy: f64,
+    pub protein: f64,
+    pub fat: f64,
+    pub carbohydrates: f64,
+    pub fibre: f64,
+    pub salt: f64,
+    #[sea_orm(unique)]
+    pub ingredient_id: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::ingredient::Entity",
+        from = "Column::IngredientId",
+        to = "super::ingredient::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Ingredient,
+}
+
+impl Related<super::ingredient::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Ingredient.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,49 @@
+//! This module implements the meal plan entry entity.
+//!
+//! See [`Model`] for more information.
+
+pub mod slot;
+
+use sea_orm::entity::prelude::*;
+use serde::Serialize;
+
+use crate::entity::meal_plan_entry::slot::Slot;
+
+/// This struct represents a meal plan entry.
+///
+/// A meal plan entry plans to cook a [`super::recipe::Model`] on a date for a [`Slot`].
+/// The date is an ISO 8601 calendar date like "2026-10-18", so dates are ordered like strings.
+///
+/// The servings override the yield of the recipe, the recipe is cooked as it is if there are none.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "meal_plan_entry")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub date: String,
+    pub slot: Slot,
+    pub slot_name: Option<String>,
+    pub servings: Option<f64>,
+    pub recipe_id: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::recipe::Entity",
+        from = "Column::RecipeId",
+        to = "super::recipe::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Recipe,
+}
+
+impl Related<super::recipe::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Recipe.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
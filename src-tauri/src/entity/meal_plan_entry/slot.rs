@@ -0,0 +1,26 @@
+//! This module implements the [`Slot`] enum for usage in the [meal plan entry entity](super::Model).
+
+use sea_orm::{entity::prelude::*, ActiveValue, IntoActiveValue};
+use serde::{Deserialize, Serialize};
+
+/// This enum represents the meal of a day a recipe is planned for.
+///
+/// A custom slot is named by [`super::Model::slot_name`], e.g. "Snack" or "Brunch".
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(Some(1))")]
+pub enum Slot {
+    #[sea_orm(string_value = "Breakfast")]
+    Breakfast,
+    #[sea_orm(string_value = "Lunch")]
+    Lunch,
+    #[sea_orm(string_value = "Dinner")]
+    Dinner,
+    #[sea_orm(string_value = "Custom")]
+    Custom,
+}
+
+impl IntoActiveValue<Slot> for Slot {
+    fn into_active_value(self) -> ActiveValue<Slot> {
+        ActiveValue::Set(self)
+    }
+}
@@ -0,0 +1,44 @@
+//! This module implements the pantry item entity.
+//!
+//! See [`Model`] for more information.
+
+use sea_orm::entity::prelude::*;
+use serde::Serialize;
+
+/// This struct represents a pantry item.
+///
+/// A pantry item describes the quantity of an [`super::ingredient::Model`] which is in stock.
+/// A pantry item without quantity is in stock in an unknown amount, e.g. _salt_.
+///
+/// The best-before date is an ISO 8601 calendar date like "2026-10-18", so dates are ordered like strings.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "pantry_item")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    pub best_before: Option<String>,
+    pub ingredient_id: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::ingredient::Entity",
+        from = "Column::IngredientId",
+        to = "super::ingredient::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Ingredient,
+}
+
+impl Related<super::ingredient::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Ingredient.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
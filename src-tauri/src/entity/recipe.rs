@@ -9,19 +9,62 @@ use serde::Serialize;
 ///
 /// A recipe is a series of instructions.
 /// A recipe is identifiable by its name.
-#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize)]
+///
+/// The yield is the amount of what the recipe makes, e.g. 4 servings, 12 pieces, or 1 cake pan with 26 cm diameter.
+///
+/// An imported recipe remembers its source URL, the name of the getter which imported it, and when it was imported,
+/// see [`crate::calendar::format_date_time`] for the format of the import time.
+///
+/// The description, author, and the preparation, cooking and total time in seconds are usually imported, but can be edited.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize)]
 #[serde(rename_all = "camelCase")]
 #[sea_orm(table_name = "recipe")]
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i64,
     pub name: String,
+    pub yield_amount: Option<f64>,
+    pub yield_unit: Option<String>,
+    pub source_url: Option<String>,
+    pub source_getter: Option<String>,
+    pub imported_at: Option<String>,
+    pub description: Option<String>,
+    pub author: Option<String>,
+    pub prep_time: Option<i64>,
+    pub cook_time: Option<i64>,
+    pub total_time: Option<i64>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::meal_plan_entry::Entity")]
+    MealPlanEntry,
+    #[sea_orm(has_many = "super::recipe_collection::Entity")]
+    RecipeCollection,
+    #[sea_orm(has_one = "super::recipe_declared_nutrition::Entity")]
+    RecipeDeclaredNutrition,
     #[sea_orm(has_many = "super::recipe_step::Entity")]
     RecipeStep,
+    #[sea_orm(has_many = "super::recipe_tag::Entity")]
+    RecipeTag,
+}
+
+impl Related<super::meal_plan_entry::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MealPlanEntry.def()
+    }
+}
+
+impl Related<super::recipe_collection::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RecipeCollection.def()
+    }
+}
+
+impl Related<super::recipe_declared_nutrition::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RecipeDeclaredNutrition.def()
+    }
 }
 
 impl Related<super::recipe_step::Entity> for Entity {
@@ -30,4 +73,30 @@ impl Related<super::recipe_step::Entity> for Entity {
     }
 }
 
+impl Related<super::recipe_tag::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RecipeTag.def()
+    }
+}
+
+impl Related<super::collection::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::recipe_collection::Relation::Collection.def()
+    }
+
+    fn via() -> Option<RelationDef> {
+        Some(super::recipe_collection::Relation::Recipe.def().rev())
+    }
+}
+
+impl Related<super::tag::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::recipe_tag::Relation::Tag.def()
+    }
+
+    fn via() -> Option<RelationDef> {
+        Some(super::recipe_tag::Relation::Recipe.def().rev())
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,48 @@
+//! This module implements the recipe declared nutrition entity.
+//!
+//! See [`Model`] for more information.
+
+use sea_orm::entity::prelude::*;
+use serde::Serialize;
+
+/// This struct represents the nutrition facts per serving of a [`super::recipe::Model`] as declared by its source.
+///
+/// Unlike the nutrition facts computed from the ingredients, these are imported as they are,
+/// so each nutrient is only known if the source declares it.
+/// The energy is in kcal, all other nutrients are in g.
+/// A recipe has at most one recipe declared nutrition.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "recipe_declared_nutrition")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub energy: Option<f64>,
+    pub protein: Option<f64>,
+    pub fat: Option<f64>,
+    pub carbohydrates: Option<f64>,
+    pub fibre: Option<f64>,
+    pub salt: Option<f64>,
+    #[sea_orm(unique)]
+    pub recipe_id: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::recipe::Entity",
+        from = "Column::RecipeId",
+        to = "super::recipe::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Recipe,
+}
+
+impl Related<super::recipe::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Recipe.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
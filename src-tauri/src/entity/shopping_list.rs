@@ -0,0 +1,33 @@
+//! This module implements the shopping list entity.
+//!
+//! See [`Model`] for more information.
+
+use sea_orm::entity::prelude::*;
+use serde::Serialize;
+
+/// This struct represents a shopping list.
+///
+/// A shopping list is a series of [`super::shopping_list_item::Model`]s to buy.
+/// A shopping list is identifiable by its name.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "shopping_list")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub name: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::shopping_list_item::Entity")]
+    ShoppingListItem,
+}
+
+impl Related<super::shopping_list_item::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ShoppingListItem.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,60 @@
+//! This module implements the shopping list item entity.
+//!
+//! See [`Model`] for more information.
+
+use sea_orm::entity::prelude::*;
+use serde::Serialize;
+
+/// This struct represents a shopping list item.
+///
+/// A shopping list item describes the quantity of an [`super::ingredient::Model`] to buy for a [`super::shopping_list::Model`].
+/// A manual shopping list item has a name instead of an ingredient, e.g. _toothpaste_.
+/// A shopping list item is checked once it is bought.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "shopping_list_item")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub order: i64,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    pub name: Option<String>,
+    pub checked: bool,
+    pub shopping_list_id: i64,
+    pub ingredient_id: Option<i64>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::ingredient::Entity",
+        from = "Column::IngredientId",
+        to = "super::ingredient::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Restrict"
+    )]
+    Ingredient,
+    #[sea_orm(
+        belongs_to = "super::shopping_list::Entity",
+        from = "Column::ShoppingListId",
+        to = "super::shopping_list::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    ShoppingList,
+}
+
+impl Related<super::ingredient::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Ingredient.def()
+    }
+}
+
+impl Related<super::shopping_list::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ShoppingList.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,44 @@
+//! This module implements the tag entity.
+//!
+//! See [`Model`] for more information.
+
+use sea_orm::entity::prelude::*;
+use serde::Serialize;
+
+/// This struct represents a tag.
+///
+/// A tag is a label of [`super::recipe::Model`]s, e.g. _Weeknight_ or _Baking_, which are tagged via [`super::recipe_tag::Model`]s.
+/// A tag is identifiable by its unique name.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(table_name = "tag")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    #[sea_orm(unique)]
+    pub name: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::recipe_tag::Entity")]
+    RecipeTag,
+}
+
+impl Related<super::recipe_tag::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RecipeTag.def()
+    }
+}
+
+impl Related<super::recipe::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::recipe_tag::Relation::Recipe.def()
+    }
+
+    fn via() -> Option<RelationDef> {
+        Some(super::recipe_tag::Relation::Tag.def().rev())
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
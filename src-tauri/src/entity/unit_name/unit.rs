@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     unit_conversion,
-    unit_conversion::{MassUnit, VolumeUnit},
+    unit_conversion::{MassUnit, TemperatureUnit, VolumeUnit},
 };
 
 /// This enum is invertibly related to [`unit_conversion::Unit`], but necessary for usage in the ORM.
@@ -18,12 +18,60 @@ pub enum Unit {
     MassGram,
     #[sea_orm(string_value = "MassPound")]
     MassPound,
+    #[sea_orm(string_value = "MassOunce")]
+    MassOunce,
     #[sea_orm(string_value = "VolumeLitre")]
     VolumeLitre,
+    #[sea_orm(string_value = "VolumeDecilitre")]
+    VolumeDecilitre,
+    #[sea_orm(string_value = "VolumeCentilitre")]
+    VolumeCentilitre,
     #[sea_orm(string_value = "VolumeMillilitre")]
     VolumeMillilitre,
+    #[sea_orm(string_value = "VolumeMetricCup")]
+    VolumeMetricCup,
+    #[sea_orm(string_value = "VolumeMetricTablespoon")]
+    VolumeMetricTablespoon,
+    #[sea_orm(string_value = "VolumeMetricTeaspoon")]
+    VolumeMetricTeaspoon,
     #[sea_orm(string_value = "VolumeUsCup")]
     VolumeUsCup,
+    #[sea_orm(string_value = "VolumeUsTablespoon")]
+    VolumeUsTablespoon,
+    #[sea_orm(string_value = "VolumeUsTeaspoon")]
+    VolumeUsTeaspoon,
+    #[sea_orm(string_value = "VolumeUsFluidOunce")]
+    VolumeUsFluidOunce,
+    #[sea_orm(string_value = "VolumeUsPint")]
+    VolumeUsPint,
+    #[sea_orm(string_value = "VolumeUsQuart")]
+    VolumeUsQuart,
+    #[sea_orm(string_value = "VolumeUsGallon")]
+    VolumeUsGallon,
+    #[sea_orm(string_value = "VolumeImperialCup")]
+    VolumeImperialCup,
+    #[sea_orm(string_value = "VolumeImperialFluidOunce")]
+    VolumeImperialFluidOunce,
+    #[sea_orm(string_value = "VolumeImperialPint")]
+    VolumeImperialPint,
+    #[sea_orm(string_value = "VolumeImperialQuart")]
+    VolumeImperialQuart,
+    #[sea_orm(string_value = "VolumeImperialGallon")]
+    VolumeImperialGallon,
+    #[sea_orm(string_value = "VolumePinch")]
+    VolumePinch,
+    #[sea_orm(string_value = "TemperatureCelsius")]
+    TemperatureCelsius,
+    #[sea_orm(string_value = "TemperatureCelsiusFan")]
+    TemperatureCelsiusFan,
+    #[sea_orm(string_value = "TemperatureFahrenheit")]
+    TemperatureFahrenheit,
+    #[sea_orm(string_value = "TemperatureFahrenheitFan")]
+    TemperatureFahrenheitFan,
+    #[sea_orm(string_value = "TemperatureKelvin")]
+    TemperatureKelvin,
+    #[sea_orm(string_value = "TemperatureGasMark")]
+    TemperatureGasMark,
 }
 
 impl IntoActiveValue<Unit> for Unit {
@@ -39,11 +87,37 @@ impl From<unit_conversion::Unit> for Unit {
                 MassUnit::Kilogram => Self::MassKilogram,
                 MassUnit::Gram => Self::MassGram,
                 MassUnit::Pound => Self::MassPound,
+                MassUnit::Ounce => Self::MassOunce,
             },
             unit_conversion::Unit::Volume(volume_unit) => match volume_unit {
                 VolumeUnit::Litre => Self::VolumeLitre,
+                VolumeUnit::Decilitre => Self::VolumeDecilitre,
+                VolumeUnit::Centilitre => Self::VolumeCentilitre,
                 VolumeUnit::Millilitre => Self::VolumeMillilitre,
+                VolumeUnit::MetricCup => Self::VolumeMetricCup,
+                VolumeUnit::MetricTablespoon => Self::VolumeMetricTablespoon,
+                VolumeUnit::MetricTeaspoon => Self::VolumeMetricTeaspoon,
                 VolumeUnit::UsCup => Self::VolumeUsCup,
+                VolumeUnit::UsTablespoon => Self::VolumeUsTablespoon,
+                VolumeUnit::UsTeaspoon => Self::VolumeUsTeaspoon,
+                VolumeUnit::UsFluidOunce => Self::VolumeUsFluidOunce,
+                VolumeUnit::UsPint => Self::VolumeUsPint,
+                VolumeUnit::UsQuart => Self::VolumeUsQuart,
+                VolumeUnit::UsGallon => Self::VolumeUsGallon,
+                VolumeUnit::ImperialCup => Self::VolumeImperialCup,
+                VolumeUnit::ImperialFluidOunce => Self::VolumeImperialFluidOunce,
+                VolumeUnit::ImperialPint => Self::VolumeImperialPint,
+                VolumeUnit::ImperialQuart => Self::VolumeImperialQuart,
+                VolumeUnit::ImperialGallon => Self::VolumeImperialGallon,
+                VolumeUnit::Pinch => Self::VolumePinch,
+            },
+            unit_conversion::Unit::Temperature(temperature_unit) => match temperature_unit {
+                TemperatureUnit::Celsius => Self::TemperatureCelsius,
+                TemperatureUnit::CelsiusFan => Self::TemperatureCelsiusFan,
+                TemperatureUnit::Fahrenheit => Self::TemperatureFahrenheit,
+                TemperatureUnit::FahrenheitFan => Self::TemperatureFahrenheitFan,
+                TemperatureUnit::Kelvin => Self::TemperatureKelvin,
+                TemperatureUnit::GasMark => Self::TemperatureGasMark,
             },
         }
     }
@@ -55,9 +129,47 @@ impl From<Unit> for unit_conversion::Unit {
             Unit::MassKilogram => unit_conversion::Unit::Mass(MassUnit::Kilogram),
             Unit::MassGram => unit_conversion::Unit::Mass(MassUnit::Gram),
             Unit::MassPound => unit_conversion::Unit::Mass(MassUnit::Pound),
+            Unit::MassOunce => unit_conversion::Unit::Mass(MassUnit::Ounce),
             Unit::VolumeLitre => unit_conversion::Unit::Volume(VolumeUnit::Litre),
+            Unit::VolumeDecilitre => unit_conversion::Unit::Volume(VolumeUnit::Decilitre),
+            Unit::VolumeCentilitre => unit_conversion::Unit::Volume(VolumeUnit::Centilitre),
             Unit::VolumeMillilitre => unit_conversion::Unit::Volume(VolumeUnit::Millilitre),
+            Unit::VolumeMetricCup => unit_conversion::Unit::Volume(VolumeUnit::MetricCup),
+            Unit::VolumeMetricTablespoon => {
+                unit_conversion::Unit::Volume(VolumeUnit::MetricTablespoon)
+            }
+            Unit::VolumeMetricTeaspoon => unit_conversion::Unit::Volume(VolumeUnit::MetricTeaspoon),
             Unit::VolumeUsCup => unit_conversion::Unit::Volume(VolumeUnit::UsCup),
+            Unit::VolumeUsTablespoon => unit_conversion::Unit::Volume(VolumeUnit::UsTablespoon),
+            Unit::VolumeUsTeaspoon => unit_conversion::Unit::Volume(VolumeUnit::UsTeaspoon),
+            Unit::VolumeUsFluidOunce => unit_conversion::Unit::Volume(VolumeUnit::UsFluidOunce),
+            Unit::VolumeUsPint => unit_conversion::Unit::Volume(VolumeUnit::UsPint),
+            Unit::VolumeUsQuart => unit_conversion::Unit::Volume(VolumeUnit::UsQuart),
+            Unit::VolumeUsGallon => unit_conversion::Unit::Volume(VolumeUnit::UsGallon),
+            Unit::VolumeImperialCup => unit_conversion::Unit::Volume(VolumeUnit::ImperialCup),
+            Unit::VolumeImperialFluidOunce => {
+                unit_conversion::Unit::Volume(VolumeUnit::ImperialFluidOunce)
+            }
+            Unit::VolumeImperialPint => unit_conversion::Unit::Volume(VolumeUnit::ImperialPint),
+            Unit::VolumeImperialQuart => unit_conversion::Unit::Volume(VolumeUnit::ImperialQuart),
+            Unit::VolumeImperialGallon => unit_conversion::Unit::Volume(VolumeUnit::ImperialGallon),
+            Unit::VolumePinch => unit_conversion::Unit::Volume(VolumeUnit::Pinch),
+            Unit::TemperatureCelsius => {
+                unit_conversion::Unit::Temperature(TemperatureUnit::Celsius)
+            }
+            Unit::TemperatureCelsiusFan => {
+                unit_conversion::Unit::Temperature(TemperatureUnit::CelsiusFan)
+            }
+            Unit::TemperatureFahrenheit => {
+                unit_conversion::Unit::Temperature(TemperatureUnit::Fahrenheit)
+            }
+            Unit::TemperatureFahrenheitFan => {
+                unit_conversion::Unit::Temperature(TemperatureUnit::FahrenheitFan)
+            }
+            Unit::TemperatureKelvin => unit_conversion::Unit::Temperature(TemperatureUnit::Kelvin),
+            Unit::TemperatureGasMark => {
+                unit_conversion::Unit::Temperature(TemperatureUnit::GasMark)
+            }
         }
     }
 }
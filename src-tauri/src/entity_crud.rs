@@ -1,29 +1,40 @@
 //! This module implements create, read, update, delete, list, and count operations for the entities in [`crate::entity`].  
 
-use std::fmt::Debug;
+use std::{fmt::Debug, iter};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use async_trait::async_trait;
+use percent_encoding::{percent_decode_str, utf8_percent_encode, NON_ALPHANUMERIC};
 use sea_orm::{
     sea_query, sea_query::IntoCondition, ActiveModelBehavior, ActiveModelTrait, ColumnTrait,
-    EntityTrait, FromQueryResult, IntoActiveModel, ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait,
-    QueryFilter, QuerySelect, RelationTrait, Select, TransactionTrait, TryFromU64, TryGetable,
-    TryGetableMany,
+    Condition, EntityTrait, FromQueryResult, IntoActiveModel, JsonValue, ModelTrait,
+    PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+    TransactionTrait, TryFromU64, TryGetable, TryGetableMany, Value,
 };
-use sea_query::{FromValueTuple, IntoValueTuple};
-use serde::{Deserialize, Serialize};
+use sea_query::{FromValueTuple, IntoValueTuple, NullOrdering};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use crate::{database, window::get_window};
 
+pub mod collection;
 pub mod file;
 pub mod ingredient;
+pub mod ingredient_nutrition;
+pub mod meal_plan_entry;
+pub mod pantry_item;
 pub mod recipe;
+pub mod recipe_collection;
+pub mod recipe_declared_nutrition;
 pub mod recipe_file;
 pub mod recipe_ingredient_draft;
 pub mod recipe_step;
 pub mod recipe_step_file;
 pub mod recipe_step_ingredient;
 pub mod recipe_step_ingredient_draft;
+pub mod recipe_tag;
+pub mod shopping_list;
+pub mod shopping_list_item;
+pub mod tag;
 pub mod unit_name;
 
 /// A trait to transform from any type into an [active model](ActiveModelTrait).
@@ -60,7 +71,7 @@ where
 }
 
 /// This enum is used to specify how to order results when listing an entity.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Copy, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum Order {
     Asc,
@@ -82,7 +93,10 @@ impl From<Order> for sea_query::Order {
     }
 }
 
-/// This struct combines conditional filtering and ordering when listing entities.
+/// This struct combines conditional filtering, ordering, and pagination when listing entities.
+///
+/// When [`Self::limit`] is set, the list is paginated. The next page is requested by passing the [`Page::next_cursor`] of the previous page as [`Self::cursor`].
+/// The cursor is only valid for the same condition and ordering it was created with.
 ///
 /// This struct is used in [`EntityCrudTrait`].
 #[derive(Debug, Deserialize)]
@@ -90,14 +104,143 @@ impl From<Order> for sea_query::Order {
 pub struct Filter<Condition, OrderBy> {
     pub condition: Option<Condition>,
     pub order_by: Option<Vec<OrderBy>>,
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
 }
 
-/// Implementors of this trait should add their order-by statements in the [`Self::add`] function.
+/// This struct represents one page of listed entities.
+///
+/// The [`Self::next_cursor`] is [`None`] when there are no more entities to list.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+/// Implementors of this trait map their order-by variants to a column and an [`Order`] in the [`Self::column_order`] function.
 pub trait OrderBy {
     type Entity: EntityTrait;
 
-    /// Add an ordering to the select statement.
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity>;
+    /// Get the column to order by and the direction of the ordering.
+    fn column_order(&self) -> (<Self::Entity as EntityTrait>::Column, Order);
+}
+
+/// This struct represents an opaque keyset cursor.
+///
+/// It holds the values of the ordered columns of the last listed row, the primary key being the last column.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Cursor(Vec<JsonValue>);
+
+impl Cursor {
+    /// Create the cursor from a listed row whose ordered columns are aliased via [`cursor_alias`].
+    fn from_row(row: &JsonValue, column_count: usize) -> Self {
+        Self(
+            (0..column_count)
+                .map(|i| row[cursor_alias(i)].clone())
+                .collect(),
+        )
+    }
+
+    /// Encode the cursor into an opaque string.
+    fn encode(&self) -> Result<String> {
+        let json = serde_json::to_string(&self.0)?;
+        Ok(utf8_percent_encode(&json, NON_ALPHANUMERIC).to_string())
+    }
+
+    /// Decode the cursor from an opaque string created with [`Self::encode`].
+    fn decode(cursor: &str) -> Result<Self> {
+        let json = percent_decode_str(cursor).decode_utf8()?;
+        Ok(Self(serde_json::from_str(&json)?))
+    }
+
+    /// Get the condition selecting all rows after this cursor.
+    ///
+    /// For the columns `a`, `b`, and `c` ordered ascending, this is `a > ? OR (a = ? AND b > ?) OR (a = ? AND b = ? AND c > ?)`.
+    /// NULL values are ordered before all other values, see [`null_ordering`], so they are compared with `IS NULL` and `IS NOT NULL`.
+    ///
+    /// # Errors
+    ///
+    /// - when the cursor was created for a different ordering
+    /// - when a cursor value is not null, a string, number, or boolean
+    fn into_condition<C>(self, column_orders: &[(C, Order)]) -> Result<Condition>
+    where
+        C: ColumnTrait,
+    {
+        if self.0.len() != column_orders.len() {
+            return Err(anyhow!("The cursor does not match the ordering."));
+        }
+        let values = self
+            .0
+            .into_iter()
+            .map(json_to_value)
+            .collect::<Result<Vec<_>>>()?;
+        let mut condition = Condition::any();
+        for (i, (column, order)) in column_orders.iter().enumerate() {
+            let after_condition = match (order, &values[i]) {
+                (Order::Asc, None) => column.is_not_null().into_condition(),
+                (Order::Asc, Some(value)) => column.gt(value.clone()).into_condition(),
+                // no row comes after NULL, which is ordered last
+                (Order::Desc, None) => continue,
+                (Order::Desc, Some(value)) => Condition::any()
+                    .add(column.lt(value.clone()))
+                    .add(column.is_null()),
+            };
+            let mut keyset_condition = Condition::all();
+            for (j, (equal_column, _)) in column_orders[..i].iter().enumerate() {
+                keyset_condition = keyset_condition.add(match &values[j] {
+                    Some(value) => equal_column.eq(value.clone()),
+                    None => equal_column.is_null(),
+                });
+            }
+            condition = condition.add(keyset_condition.add(after_condition));
+        }
+        Ok(condition)
+    }
+}
+
+/// Get where NULL values are ordered, which is before all other values.
+///
+/// Unlike the default of SQLite, this also applies to descending orders, where NULL values come last.
+fn null_ordering(order: Order) -> NullOrdering {
+    match order {
+        Order::Asc => NullOrdering::First,
+        Order::Desc => NullOrdering::Last,
+    }
+}
+
+/// Check the limit of a [`Filter`].
+///
+/// # Errors
+///
+/// - when the limit is zero, because an empty page can't tell whether there are more entities
+fn check_limit(limit: Option<u64>) -> Result<()> {
+    if limit == Some(0) {
+        return Err(anyhow!("The limit must be greater than zero."));
+    }
+    Ok(())
+}
+
+/// Get the alias of the ordered column at the given position when listing.
+fn cursor_alias(i: usize) -> String {
+    format!("cursor_{i}")
+}
+
+/// Convert a JSON cursor value into a database value, which is [`None`] for NULL.
+fn json_to_value(json: JsonValue) -> Result<Option<Value>> {
+    match json {
+        JsonValue::Null => Ok(None),
+        JsonValue::Bool(bool) => Ok(Some(bool.into())),
+        JsonValue::Number(number) => match number.as_i64() {
+            Some(int) => Ok(Some(int.into())),
+            None => number
+                .as_f64()
+                .map(|float| Some(Value::from(float)))
+                .ok_or_else(|| anyhow!("The cursor contains an invalid number.")),
+        },
+        JsonValue::String(string) => Ok(Some(string.into())),
+        _ => Err(anyhow!("The cursor contains an invalid value.")),
+    }
 }
 
 /// This trait implements create, read, update, delete, list, and count operation for an entity.
@@ -146,6 +289,7 @@ pub trait EntityCrudTrait {
         + TryFromU64
         + TryGetable
         + Serialize
+        + DeserializeOwned
         + Clone;
 
     /// the struct with which to create an entity, implementing [`TryIntoActiveModel<Self::ActiveModel>`]
@@ -231,27 +375,61 @@ pub trait EntityCrudTrait {
 
     /// List entities.
     ///
+    /// The entities are always ordered by their primary key last, so that pagination is deterministic.
+    /// NULL values are ordered first when ascending and last when descending.
+    ///
     /// # Errors
     ///
     /// - when there is any problem with the database
+    /// - when the limit is zero
+    /// - when the cursor is invalid
     async fn list(
         filter: Filter<Self::EntityCondition, Self::EntityOrderBy>,
-    ) -> Result<Vec<Self::PrimaryKeyValue>> {
+    ) -> Result<Page<Self::PrimaryKeyValue>> {
+        check_limit(filter.limit)?;
         let db = database::connect().await;
+        let column_orders: Vec<(Self::Column, Order)> = filter
+            .order_by
+            .into_iter()
+            .flatten()
+            .map(|order_by| order_by.column_order())
+            .chain(iter::once((Self::primary_key_colum(), Order::Asc)))
+            .collect();
         let mut select = Self::Entity::find()
             .select_only()
             .column_as(Self::primary_key_colum(), "id");
+        for (i, (column, _)) in column_orders.iter().enumerate() {
+            select = select.column_as(*column, cursor_alias(i));
+        }
         if let Some(condition) = filter.condition {
             select = select.filter(condition);
         }
-        for order_by in filter.order_by.into_iter().flatten() {
-            select = order_by.add(select);
+        if let Some(cursor) = filter.cursor {
+            select = select.filter(Cursor::decode(&cursor)?.into_condition(&column_orders)?);
         }
-        let models = select
-            .into_model::<IdColumn<Self::PrimaryKeyValue>>()
-            .all(db)
-            .await?;
-        Ok(models.into_iter().map(|id_column| id_column.id).collect())
+        for (column, order) in &column_orders {
+            select = select.order_by_with_nulls(*column, (*order).into(), null_ordering(*order));
+        }
+        if let Some(limit) = filter.limit {
+            // select one more row to know whether there is a next page
+            select = select.limit(limit + 1);
+        }
+        let mut rows = select.into_json().all(db).await?;
+        let next_cursor = match filter.limit {
+            Some(limit) if rows.len() as u64 > limit => {
+                rows.truncate(limit as usize);
+                match rows.last() {
+                    Some(row) => Some(Cursor::from_row(row, column_orders.len()).encode()?),
+                    None => None,
+                }
+            }
+            _ => None,
+        };
+        let items = rows
+            .into_iter()
+            .map(|mut row| serde_json::from_value(row["id"].take()))
+            .collect::<Result<_, _>>()?;
+        Ok(Page { items, next_cursor })
     }
 
     /// Count entities.
@@ -290,3 +468,98 @@ pub trait EntityCrudTrait {
     /// Get the tauri event channel for a deleted entity.
     fn entity_action_deleted_channel() -> &'static str;
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+    use sea_orm::{sea_query::SqliteQueryBuilder, QueryTrait};
+
+    use super::*;
+    use crate::entity::recipe;
+
+    /// Get the SQL of the where clause of a condition.
+    fn where_clause(condition: Condition) -> String {
+        let sql = recipe::Entity::find()
+            .filter(condition)
+            .into_query()
+            .to_string(SqliteQueryBuilder);
+        let (_, where_clause) = sql.split_once(" WHERE ").unwrap();
+        where_clause.to_string()
+    }
+
+    #[test]
+    fn test_check_limit() {
+        assert!(check_limit(None).is_ok());
+        assert!(check_limit(Some(1)).is_ok());
+        assert!(check_limit(Some(0)).is_err());
+    }
+
+    #[test]
+    fn test_cursor_encode_decode() {
+        let cursor = Cursor(vec![JsonValue::from("Ä \"name\""), JsonValue::from(42)]);
+        let encoded = cursor.encode().unwrap();
+        assert!(
+            encoded
+                .chars()
+                .all(|char| char.is_ascii_alphanumeric() || char == '%')
+        );
+        assert_eq!(Cursor::decode(&encoded).unwrap(), cursor);
+    }
+
+    #[test]
+    fn test_cursor_into_condition() {
+        let cursor = Cursor(vec![JsonValue::from("name"), JsonValue::from(42)]);
+        let condition = cursor
+            .into_condition(&[
+                (recipe::Column::Name, Order::Desc),
+                (recipe::Column::Id, Order::Asc),
+            ])
+            .unwrap();
+        assert_str_eq!(
+            where_clause(condition),
+            "\"recipe\".\"name\" < 'name' \
+            OR (\"recipe\".\"name\" = 'name' AND \"recipe\".\"id\" > 42)"
+        );
+    }
+
+    #[test]
+    fn test_cursor_into_condition_null() {
+        let cursor = Cursor(vec![JsonValue::Null, JsonValue::from(42)]);
+        let condition = cursor
+            .into_condition(&[
+                (recipe::Column::Author, Order::Asc),
+                (recipe::Column::Id, Order::Asc),
+            ])
+            .unwrap();
+        assert_str_eq!(
+            where_clause(condition),
+            "\"recipe\".\"author\" IS NOT NULL \
+            OR (\"recipe\".\"author\" IS NULL AND \"recipe\".\"id\" > 42)"
+        );
+        let cursor = Cursor(vec![JsonValue::from("Sally"), JsonValue::from(42)]);
+        let condition = cursor
+            .into_condition(&[
+                (recipe::Column::Author, Order::Desc),
+                (recipe::Column::Id, Order::Asc),
+            ])
+            .unwrap();
+        assert_str_eq!(
+            where_clause(condition),
+            "(\"recipe\".\"author\" < 'Sally' OR \"recipe\".\"author\" IS NULL) \
+            OR (\"recipe\".\"author\" = 'Sally' AND \"recipe\".\"id\" > 42)"
+        );
+    }
+
+    #[test]
+    fn test_cursor_into_condition_mismatch() {
+        let cursor = Cursor(vec![JsonValue::from(42)]);
+        assert!(
+            cursor
+                .into_condition(&[
+                    (recipe::Column::Name, Order::Asc),
+                    (recipe::Column::Id, Order::Asc),
+                ])
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,123 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::collection`].
+
+use sea_orm::{
+    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
+    EntityTrait, IntoActiveModel, QueryFilter, QuerySelect, QueryTrait,
+};
+use serde::Deserialize;
+
+use crate::{
+    entity::{
+        collection::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+        recipe_collection,
+    },
+    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
+    event::channel::{
+        ENTITY_ACTION_CREATED_COLLECTION, ENTITY_ACTION_DELETED_COLLECTION,
+        ENTITY_ACTION_UPDATED_COLLECTION,
+    },
+};
+
+#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionCreate {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionUpdate {
+    pub id: i64,
+    pub name: Option<String>,
+}
+
+impl IntoActiveModel<ActiveModel> for CollectionUpdate {
+    fn into_active_model(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            name: match self.name {
+                Some(name) => ActiveValue::Set(name),
+                _ => ActiveValue::NotSet,
+            },
+        }
+    }
+}
+
+pub type CollectionFilter = Filter<CollectionCondition, CollectionOrderBy>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionCondition {
+    pub name: Option<String>,
+    pub name_exact: Option<String>,
+    pub recipe_id: Option<i64>,
+}
+
+impl IntoCondition for CollectionCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all()
+            .add_option(self.name.map(|name| Column::Name.like(format!("%{name}%"))))
+            .add_option(self.name_exact.map(|name| Column::Name.eq(name)))
+            .add_option(self.recipe_id.map(|recipe_id| {
+                Column::Id.in_subquery(
+                    recipe_collection::Entity::find()
+                        .select_only()
+                        .column(recipe_collection::Column::CollectionId)
+                        .filter(recipe_collection::Column::RecipeId.eq(recipe_id))
+                        .into_query(),
+                )
+            }))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CollectionOrderBy {
+    Name(Order),
+}
+
+impl OrderBy for CollectionOrderBy {
+    type Entity = Entity;
+
+    fn column_order(&self) -> (Column, Order) {
+        match self {
+            CollectionOrderBy::Name(order) => (Column::Name, *order),
+        }
+    }
+}
+
+pub struct CollectionCrud {}
+
+impl EntityCrudTrait for CollectionCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = CollectionCreate;
+    type EntityUpdate = CollectionUpdate;
+    type EntityCondition = CollectionCondition;
+    type EntityOrderBy = CollectionOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_COLLECTION
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_COLLECTION
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_COLLECTION
+    }
+}
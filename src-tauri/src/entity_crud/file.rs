@@ -5,11 +5,7 @@ use std::{fs, str::FromStr};
 use anyhow::Result;
 use async_trait::async_trait;
 use mime_guess::mime;
-use reqwest::header;
-use sea_orm::{
-    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, IntoActiveModel, QueryOrder,
-    Select,
-};
+use sea_orm::{sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, IntoActiveModel};
 use serde::Deserialize;
 use tempfile::NamedTempFile;
 use url::Url;
@@ -20,6 +16,7 @@ use crate::{
     event::channel::{
         ENTITY_ACTION_CREATED_FILE, ENTITY_ACTION_DELETED_FILE, ENTITY_ACTION_UPDATED_FILE,
     },
+    fetch::fetch_file,
     file_storage,
 };
 
@@ -52,14 +49,11 @@ impl TryIntoActiveModel<ActiveModel> for FileCreate {
             }
             FileCreateUri::Url(url) => {
                 let url = Url::from_str(&url)?;
-                let response = reqwest::get(url).await?;
+                let response = fetch_file(&url).await?;
                 let mime = response
-                    .headers()
-                    .get(header::CONTENT_TYPE)
-                    .and_then(|content_type| content_type.to_str().ok())
-                    .map(String::from)
+                    .content_type
                     .unwrap_or(mime::APPLICATION_OCTET_STREAM.to_string());
-                let bytes = response.bytes().await?;
+                let bytes = response.body;
                 let named_temp_file = NamedTempFile::new()?;
                 let path = named_temp_file.path().to_string_lossy().to_string();
                 named_temp_file.close()?;
@@ -122,9 +116,9 @@ pub enum FileOrderBy {
 impl OrderBy for FileOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
+    fn column_order(&self) -> (Column, Order) {
         match self {
-            FileOrderBy::Name(order) => select.order_by(Column::Name, order.into()),
+            FileOrderBy::Name(order) => (Column::Name, *order),
         }
     }
 }
@@ -1,8 +1,10 @@
 //! This module implements [`EntityCrudTrait`] for [`crate::entity::ingredient`].
 
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
 use sea_orm::{
-    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
-    EntityTrait, IntoActiveModel, QueryFilter, QueryOrder, QuerySelect, QueryTrait, Select,
+    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, EntityTrait, QueryFilter,
+    QuerySelect, QueryTrait,
 };
 use serde::Deserialize;
 
@@ -11,17 +13,38 @@ use crate::{
         ingredient::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
         recipe_step_ingredient,
     },
-    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
+    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy, TryIntoActiveModel},
     event::channel::{
         ENTITY_ACTION_CREATED_INGREDIENT, ENTITY_ACTION_DELETED_INGREDIENT,
         ENTITY_ACTION_UPDATED_INGREDIENT,
     },
 };
 
-#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+/// Ensure that a density is a finite number greater than 0, so that converting with it gives finite amounts.
+fn validate_density(density: f64) -> Result<f64> {
+    if !density.is_finite() || density <= 0.0 {
+        return Err(anyhow!("The density {density} is no finite number greater than 0."));
+    }
+    Ok(density)
+}
+
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IngredientCreate {
     pub name: String,
+    pub density: Option<f64>,
+}
+
+#[async_trait]
+impl TryIntoActiveModel<ActiveModel> for IngredientCreate {
+    /// Transform [`IngredientCreate`] into an [`ActiveModel`] after validating the density.
+    async fn try_into_active_model(self) -> Result<ActiveModel> {
+        Ok(ActiveModel {
+            id: ActiveValue::NotSet,
+            name: ActiveValue::Set(self.name),
+            density: ActiveValue::Set(self.density.map(validate_density).transpose()?),
+        })
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -29,17 +52,25 @@ pub struct IngredientCreate {
 pub struct IngredientUpdate {
     pub id: i64,
     pub name: Option<String>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub density: Option<Option<f64>>,
 }
 
-impl IntoActiveModel<ActiveModel> for IngredientUpdate {
-    fn into_active_model(self) -> ActiveModel {
-        ActiveModel {
+#[async_trait]
+impl TryIntoActiveModel<ActiveModel> for IngredientUpdate {
+    /// Transform [`IngredientUpdate`] into an [`ActiveModel`] after validating the density.
+    async fn try_into_active_model(self) -> Result<ActiveModel> {
+        Ok(ActiveModel {
             id: ActiveValue::Unchanged(self.id),
             name: match self.name {
                 Some(name) => ActiveValue::Set(name),
                 _ => ActiveValue::NotSet,
             },
-        }
+            density: match self.density {
+                Some(density) => ActiveValue::Set(density.map(validate_density).transpose()?),
+                _ => ActiveValue::NotSet,
+            },
+        })
     }
 }
 
@@ -79,9 +110,9 @@ pub enum IngredientOrderBy {
 impl OrderBy for IngredientOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
+    fn column_order(&self) -> (Column, Order) {
         match self {
-            IngredientOrderBy::Name(order) => select.order_by(Column::Name, order.into()),
+            IngredientOrderBy::Name(order) => (Column::Name, *order),
         }
     }
 }
@@ -121,3 +152,17 @@ impl EntityCrudTrait for IngredientCrud {
         ENTITY_ACTION_DELETED_INGREDIENT
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_density() {
+        assert_eq!(validate_density(0.92).unwrap(), 0.92);
+        assert!(validate_density(0.0).is_err());
+        assert!(validate_density(-1.0).is_err());
+        assert!(validate_density(f64::INFINITY).is_err());
+        assert!(validate_density(f64::NAN).is_err());
+    }
+}
@@ -0,0 +1,143 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::ingredient_nutrition`].
+
+use sea_orm::{
+    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
+    IntoActiveModel,
+};
+use serde::Deserialize;
+
+use crate::{
+    entity::ingredient_nutrition::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
+    event::channel::{
+        ENTITY_ACTION_CREATED_INGREDIENT_NUTRITION, ENTITY_ACTION_DELETED_INGREDIENT_NUTRITION,
+        ENTITY_ACTION_UPDATED_INGREDIENT_NUTRITION,
+    },
+};
+
+#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+#[serde(rename_all = "camelCase")]
+pub struct IngredientNutritionCreate {
+    pub energy: f64,
+    pub protein: f64,
+    pub fat: f64,
+    pub carbohydrates: f64,
+    pub fibre: f64,
+    pub salt: f64,
+    pub ingredient_id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngredientNutritionUpdate {
+    pub id: i64,
+    pub energy: Option<f64>,
+    pub protein: Option<f64>,
+    pub fat: Option<f64>,
+    pub carbohydrates: Option<f64>,
+    pub fibre: Option<f64>,
+    pub salt: Option<f64>,
+}
+
+impl IntoActiveModel<ActiveModel> for IngredientNutritionUpdate {
+    fn into_active_model(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            energy: match self.energy {
+                Some(energy) => ActiveValue::Set(energy),
+                _ => ActiveValue::NotSet,
+            },
+            protein: match self.protein {
+                Some(protein) => ActiveValue::Set(protein),
+                _ => ActiveValue::NotSet,
+            },
+            fat: match self.fat {
+                Some(fat) => ActiveValue::Set(fat),
+                _ => ActiveValue::NotSet,
+            },
+            carbohydrates: match self.carbohydrates {
+                Some(carbohydrates) => ActiveValue::Set(carbohydrates),
+                _ => ActiveValue::NotSet,
+            },
+            fibre: match self.fibre {
+                Some(fibre) => ActiveValue::Set(fibre),
+                _ => ActiveValue::NotSet,
+            },
+            salt: match self.salt {
+                Some(salt) => ActiveValue::Set(salt),
+                _ => ActiveValue::NotSet,
+            },
+            ingredient_id: ActiveValue::NotSet,
+        }
+    }
+}
+
+pub type IngredientNutritionFilter =
+    Filter<IngredientNutritionCondition, IngredientNutritionOrderBy>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngredientNutritionCondition {
+    pub ingredient_id: Option<i64>,
+}
+
+impl IntoCondition for IngredientNutritionCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all().add_option(
+            self.ingredient_id
+                .map(|ingredient_id| Column::IngredientId.eq(ingredient_id)),
+        )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IngredientNutritionOrderBy {
+    Energy(Order),
+}
+
+impl OrderBy for IngredientNutritionOrderBy {
+    type Entity = Entity;
+
+    fn column_order(&self) -> (Column, Order) {
+        match self {
+            IngredientNutritionOrderBy::Energy(order) => (Column::Energy, *order),
+        }
+    }
+}
+
+pub struct IngredientNutritionCrud {}
+
+impl EntityCrudTrait for IngredientNutritionCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = IngredientNutritionCreate;
+    type EntityUpdate = IngredientNutritionUpdate;
+    type EntityCondition = IngredientNutritionCondition;
+    type EntityOrderBy = IngredientNutritionOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_INGREDIENT_NUTRITION
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_INGREDIENT_NUTRITION
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_INGREDIENT_NUTRITION
+    }
+}
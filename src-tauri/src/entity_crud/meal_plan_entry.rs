@@ -0,0 +1,161 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::meal_plan_entry`].
+
+use anyhow::Result;
+use async_trait::async_trait;
+use sea_orm::{sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, IntoActiveValue};
+use serde::Deserialize;
+
+use crate::{
+    calendar::validate_date,
+    entity::meal_plan_entry::{slot::Slot, ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy, TryIntoActiveModel},
+    event::channel::{
+        ENTITY_ACTION_CREATED_MEAL_PLAN_ENTRY, ENTITY_ACTION_DELETED_MEAL_PLAN_ENTRY,
+        ENTITY_ACTION_UPDATED_MEAL_PLAN_ENTRY,
+    },
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MealPlanEntryCreate {
+    pub date: String,
+    pub slot: Slot,
+    pub slot_name: Option<String>,
+    pub servings: Option<f64>,
+    pub recipe_id: i64,
+}
+
+#[async_trait]
+impl TryIntoActiveModel<ActiveModel> for MealPlanEntryCreate {
+    /// Transform [`MealPlanEntryCreate`] into an [`ActiveModel`] after validating the date.
+    async fn try_into_active_model(self) -> Result<ActiveModel> {
+        Ok(ActiveModel {
+            id: ActiveValue::NotSet,
+            date: ActiveValue::Set(validate_date(self.date)?),
+            slot: self.slot.into_active_value(),
+            slot_name: ActiveValue::Set(self.slot_name),
+            servings: ActiveValue::Set(self.servings),
+            recipe_id: ActiveValue::Set(self.recipe_id),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MealPlanEntryUpdate {
+    pub id: i64,
+    pub date: Option<String>,
+    pub slot: Option<Slot>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub slot_name: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub servings: Option<Option<f64>>,
+    pub recipe_id: Option<i64>,
+}
+
+#[async_trait]
+impl TryIntoActiveModel<ActiveModel> for MealPlanEntryUpdate {
+    /// Transform [`MealPlanEntryUpdate`] into an [`ActiveModel`] after validating the date.
+    async fn try_into_active_model(self) -> Result<ActiveModel> {
+        Ok(ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            date: match self.date {
+                Some(date) => ActiveValue::Set(validate_date(date)?),
+                _ => ActiveValue::NotSet,
+            },
+            slot: match self.slot {
+                Some(slot) => ActiveValue::Set(slot),
+                _ => ActiveValue::NotSet,
+            },
+            slot_name: match self.slot_name {
+                Some(slot_name) => ActiveValue::Set(slot_name),
+                _ => ActiveValue::NotSet,
+            },
+            servings: match self.servings {
+                Some(servings) => ActiveValue::Set(servings),
+                _ => ActiveValue::NotSet,
+            },
+            recipe_id: match self.recipe_id {
+                Some(recipe_id) => ActiveValue::Set(recipe_id),
+                _ => ActiveValue::NotSet,
+            },
+        })
+    }
+}
+
+pub type MealPlanEntryFilter = Filter<MealPlanEntryCondition, MealPlanEntryOrderBy>;
+
+/// The date range is inclusive on both ends.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MealPlanEntryCondition {
+    pub date_from: Option<String>,
+    pub date_to: Option<String>,
+    pub slot: Option<Slot>,
+    pub recipe_id: Option<i64>,
+}
+
+impl IntoCondition for MealPlanEntryCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all()
+            .add_option(self.date_from.map(|date_from| Column::Date.gte(date_from)))
+            .add_option(self.date_to.map(|date_to| Column::Date.lte(date_to)))
+            .add_option(self.slot.map(|slot| Column::Slot.eq(slot)))
+            .add_option(
+                self.recipe_id
+                    .map(|recipe_id| Column::RecipeId.eq(recipe_id)),
+            )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MealPlanEntryOrderBy {
+    Date(Order),
+}
+
+impl OrderBy for MealPlanEntryOrderBy {
+    type Entity = Entity;
+
+    fn column_order(&self) -> (Column, Order) {
+        match self {
+            MealPlanEntryOrderBy::Date(order) => (Column::Date, *order),
+        }
+    }
+}
+
+pub struct MealPlanEntryCrud {}
+
+impl EntityCrudTrait for MealPlanEntryCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = MealPlanEntryCreate;
+    type EntityUpdate = MealPlanEntryUpdate;
+    type EntityCondition = MealPlanEntryCondition;
+    type EntityOrderBy = MealPlanEntryOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_MEAL_PLAN_ENTRY
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_MEAL_PLAN_ENTRY
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_MEAL_PLAN_ENTRY
+    }
+}
@@ -0,0 +1,154 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::pantry_item`].
+
+use anyhow::Result;
+use async_trait::async_trait;
+use sea_orm::{sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition};
+use serde::Deserialize;
+
+use crate::{
+    calendar::validate_date,
+    entity::pantry_item::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy, TryIntoActiveModel},
+    event::channel::{
+        ENTITY_ACTION_CREATED_PANTRY_ITEM, ENTITY_ACTION_DELETED_PANTRY_ITEM,
+        ENTITY_ACTION_UPDATED_PANTRY_ITEM,
+    },
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PantryItemCreate {
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    pub best_before: Option<String>,
+    pub ingredient_id: i64,
+}
+
+#[async_trait]
+impl TryIntoActiveModel<ActiveModel> for PantryItemCreate {
+    /// Transform [`PantryItemCreate`] into an [`ActiveModel`] after validating the best-before date.
+    async fn try_into_active_model(self) -> Result<ActiveModel> {
+        Ok(ActiveModel {
+            id: ActiveValue::NotSet,
+            quantity: ActiveValue::Set(self.quantity),
+            unit: ActiveValue::Set(self.unit),
+            best_before: ActiveValue::Set(self.best_before.map(validate_date).transpose()?),
+            ingredient_id: ActiveValue::Set(self.ingredient_id),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PantryItemUpdate {
+    pub id: i64,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub quantity: Option<Option<f64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub unit: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub best_before: Option<Option<String>>,
+    pub ingredient_id: Option<i64>,
+}
+
+#[async_trait]
+impl TryIntoActiveModel<ActiveModel> for PantryItemUpdate {
+    /// Transform [`PantryItemUpdate`] into an [`ActiveModel`] after validating the best-before date.
+    async fn try_into_active_model(self) -> Result<ActiveModel> {
+        Ok(ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            quantity: match self.quantity {
+                Some(quantity) => ActiveValue::Set(quantity),
+                _ => ActiveValue::NotSet,
+            },
+            unit: match self.unit {
+                Some(unit) => ActiveValue::Set(unit),
+                _ => ActiveValue::NotSet,
+            },
+            best_before: match self.best_before {
+                Some(best_before) => ActiveValue::Set(best_before.map(validate_date).transpose()?),
+                _ => ActiveValue::NotSet,
+            },
+            ingredient_id: match self.ingredient_id {
+                Some(ingredient_id) => ActiveValue::Set(ingredient_id),
+                _ => ActiveValue::NotSet,
+            },
+        })
+    }
+}
+
+pub type PantryItemFilter = Filter<PantryItemCondition, PantryItemOrderBy>;
+
+/// The best-before date is inclusive, so it selects the pantry items which expire until then.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PantryItemCondition {
+    pub ingredient_id: Option<i64>,
+    pub best_before_to: Option<String>,
+}
+
+impl IntoCondition for PantryItemCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all()
+            .add_option(
+                self.ingredient_id
+                    .map(|ingredient_id| Column::IngredientId.eq(ingredient_id)),
+            )
+            .add_option(
+                self.best_before_to
+                    .map(|best_before_to| Column::BestBefore.lte(best_before_to)),
+            )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PantryItemOrderBy {
+    BestBefore(Order),
+}
+
+impl OrderBy for PantryItemOrderBy {
+    type Entity = Entity;
+
+    fn column_order(&self) -> (Column, Order) {
+        match self {
+            PantryItemOrderBy::BestBefore(order) => (Column::BestBefore, *order),
+        }
+    }
+}
+
+pub struct PantryItemCrud {}
+
+impl EntityCrudTrait for PantryItemCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = PantryItemCreate;
+    type EntityUpdate = PantryItemUpdate;
+    type EntityCondition = PantryItemCondition;
+    type EntityOrderBy = PantryItemOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_PANTRY_ITEM
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_PANTRY_ITEM
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_PANTRY_ITEM
+    }
+}
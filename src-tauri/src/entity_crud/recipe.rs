@@ -1,13 +1,17 @@
 //! This module implements [`EntityCrudTrait`] for [`crate::entity::recipe`].
 
 use sea_orm::{
-    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
-    IntoActiveModel, QueryOrder, Select,
+    sea_query::{IntoCondition, SelectStatement},
+    ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel, EntityTrait, IntoActiveModel,
+    QueryFilter, QuerySelect, QueryTrait,
 };
 use serde::Deserialize;
 
 use crate::{
-    entity::recipe::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+    entity::{
+        recipe::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+        recipe_collection, recipe_tag,
+    },
     entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
     event::channel::{
         ENTITY_ACTION_CREATED_RECIPE, ENTITY_ACTION_DELETED_RECIPE, ENTITY_ACTION_UPDATED_RECIPE,
@@ -18,6 +22,16 @@ use crate::{
 #[serde(rename_all = "camelCase")]
 pub struct RecipeCreate {
     pub name: String,
+    pub yield_amount: Option<f64>,
+    pub yield_unit: Option<String>,
+    pub source_url: Option<String>,
+    pub source_getter: Option<String>,
+    pub imported_at: Option<String>,
+    pub description: Option<String>,
+    pub author: Option<String>,
+    pub prep_time: Option<i64>,
+    pub cook_time: Option<i64>,
+    pub total_time: Option<i64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -25,6 +39,20 @@ pub struct RecipeCreate {
 pub struct RecipeUpdate {
     pub id: i64,
     pub name: Option<String>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub yield_amount: Option<Option<f64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub yield_unit: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub description: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub author: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub prep_time: Option<Option<i64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub cook_time: Option<Option<i64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub total_time: Option<Option<i64>>,
 }
 
 impl IntoActiveModel<ActiveModel> for RecipeUpdate {
@@ -35,6 +63,37 @@ impl IntoActiveModel<ActiveModel> for RecipeUpdate {
                 Some(name) => ActiveValue::Set(name),
                 _ => ActiveValue::NotSet,
             },
+            yield_amount: match self.yield_amount {
+                Some(yield_amount) => ActiveValue::Set(yield_amount),
+                _ => ActiveValue::NotSet,
+            },
+            yield_unit: match self.yield_unit {
+                Some(yield_unit) => ActiveValue::Set(yield_unit),
+                _ => ActiveValue::NotSet,
+            },
+            source_url: ActiveValue::NotSet,
+            source_getter: ActiveValue::NotSet,
+            imported_at: ActiveValue::NotSet,
+            description: match self.description {
+                Some(description) => ActiveValue::Set(description),
+                _ => ActiveValue::NotSet,
+            },
+            author: match self.author {
+                Some(author) => ActiveValue::Set(author),
+                _ => ActiveValue::NotSet,
+            },
+            prep_time: match self.prep_time {
+                Some(prep_time) => ActiveValue::Set(prep_time),
+                _ => ActiveValue::NotSet,
+            },
+            cook_time: match self.cook_time {
+                Some(cook_time) => ActiveValue::Set(cook_time),
+                _ => ActiveValue::NotSet,
+            },
+            total_time: match self.total_time {
+                Some(total_time) => ActiveValue::Set(total_time),
+                _ => ActiveValue::NotSet,
+            },
         }
     }
 }
@@ -45,14 +104,60 @@ pub type RecipeFilter = Filter<RecipeCondition, RecipeOrderBy>;
 #[serde(rename_all = "camelCase")]
 pub struct RecipeCondition {
     pub name: Option<String>,
+    /// the recipes must be tagged with any of these tags, so an empty list matches no recipe
+    pub any_tag_ids: Option<Vec<i64>>,
+    /// the recipes must be tagged with all of these tags, so an empty list matches all recipes
+    pub all_tag_ids: Option<Vec<i64>>,
+    pub collection_id: Option<i64>,
+    pub source_url: Option<String>,
 }
 
 impl IntoCondition for RecipeCondition {
     fn into_condition(self) -> Condition {
-        Condition::all().add_option(self.name.map(|name| Column::Name.like(format!("%{name}%"))))
+        Condition::all()
+            .add_option(self.name.map(|name| Column::Name.like(format!("%{name}%"))))
+            .add_option(self.any_tag_ids.map(|tag_ids| {
+                Column::Id.in_subquery(select_tagged_recipe_ids(
+                    recipe_tag::Column::TagId.is_in(tag_ids),
+                ))
+            }))
+            .add_option(self.all_tag_ids.map(|tag_ids| {
+                tag_ids
+                    .into_iter()
+                    .fold(Condition::all(), |condition, tag_id| {
+                        condition.add(Column::Id.in_subquery(select_tagged_recipe_ids(
+                            recipe_tag::Column::TagId.eq(tag_id),
+                        )))
+                    })
+            }))
+            .add_option(self.collection_id.map(|collection_id| {
+                Column::Id.in_subquery(
+                    recipe_collection::Entity::find()
+                        .select_only()
+                        .column(recipe_collection::Column::RecipeId)
+                        .filter(recipe_collection::Column::CollectionId.eq(collection_id))
+                        .into_query(),
+                )
+            }))
+            .add_option(
+                self.source_url
+                    .map(|source_url| Column::SourceUrl.eq(source_url)),
+            )
     }
 }
 
+/// Select the ids of the recipes with a recipe tag matching the condition.
+fn select_tagged_recipe_ids<F>(condition: F) -> SelectStatement
+where
+    F: IntoCondition,
+{
+    recipe_tag::Entity::find()
+        .select_only()
+        .column(recipe_tag::Column::RecipeId)
+        .filter(condition)
+        .into_query()
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum RecipeOrderBy {
@@ -62,9 +167,9 @@ pub enum RecipeOrderBy {
 impl OrderBy for RecipeOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
+    fn column_order(&self) -> (Column, Order) {
         match self {
-            RecipeOrderBy::Name(order) => select.order_by(Column::Name, order.into()),
+            RecipeOrderBy::Name(order) => (Column::Name, *order),
         }
     }
 }
@@ -104,3 +209,70 @@ impl EntityCrudTrait for RecipeCrud {
         ENTITY_ACTION_DELETED_RECIPE
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sea_orm::{ConnectionTrait, QueryOrder};
+
+    use super::*;
+    use crate::migrator::tests::get_memory_database_migrated;
+
+    #[tokio::test]
+    async fn test_recipe_condition() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`) VALUES (1, 'Pfannkuchen'), (2, 'Plätzchen'), (3, 'Stollen'), (4, 'Nudeln'); \
+            INSERT INTO `tag` (`id`, `name`) VALUES (1, 'Weeknight'), (2, 'Baking'), (3, 'Christmas'); \
+            INSERT INTO `recipe_tag` (`id`, `recipe_id`, `tag_id`) VALUES (1, 1, 1), (2, 2, 2), (3, 2, 3), (4, 3, 2), (5, 4, 1); \
+            INSERT INTO `collection` (`id`, `name`) VALUES (1, 'Weihnachten'); \
+            INSERT INTO `recipe_collection` (`id`, `order`, `recipe_id`, `collection_id`) VALUES (1, 1, 3, 1), (2, 2, 2, 1);",
+        )
+        .await
+        .unwrap();
+        let select_ids = |condition: RecipeCondition| {
+            let db = &db;
+            async move {
+                Entity::find()
+                    .filter(condition)
+                    .order_by_asc(Column::Id)
+                    .all(db)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|model| model.id)
+                    .collect::<Vec<i64>>()
+            }
+        };
+        let condition = |any_tag_ids: Option<Vec<i64>>,
+                         all_tag_ids: Option<Vec<i64>>,
+                         collection_id: Option<i64>| RecipeCondition {
+            name: None,
+            any_tag_ids,
+            all_tag_ids,
+            collection_id,
+            source_url: None,
+        };
+        assert_eq!(
+            select_ids(condition(Some(vec![1, 3]), None, None)).await,
+            vec![1, 2, 4]
+        );
+        assert_eq!(
+            select_ids(condition(None, Some(vec![2, 3]), None)).await,
+            vec![2]
+        );
+        assert_eq!(select_ids(condition(None, None, Some(1))).await, vec![2, 3]);
+        assert_eq!(
+            select_ids(condition(Some(vec![]), None, None)).await,
+            Vec::<i64>::new()
+        );
+        assert_eq!(
+            select_ids(condition(None, Some(vec![]), None)).await,
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(
+            select_ids(condition(Some(vec![1]), Some(vec![2]), Some(1))).await,
+            Vec::<i64>::new()
+        );
+    }
+}
@@ -0,0 +1,120 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::recipe_collection`].
+
+use sea_orm::{
+    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
+    IntoActiveModel,
+};
+use serde::Deserialize;
+
+use crate::{
+    entity::recipe_collection::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
+    event::channel::{
+        ENTITY_ACTION_CREATED_RECIPE_COLLECTION, ENTITY_ACTION_DELETED_RECIPE_COLLECTION,
+        ENTITY_ACTION_UPDATED_RECIPE_COLLECTION,
+    },
+};
+
+#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeCollectionCreate {
+    pub order: i64,
+    pub recipe_id: i64,
+    pub collection_id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeCollectionUpdate {
+    pub id: i64,
+    pub order: Option<i64>,
+}
+
+impl IntoActiveModel<ActiveModel> for RecipeCollectionUpdate {
+    fn into_active_model(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            order: match self.order {
+                Some(order) => ActiveValue::Set(order),
+                _ => ActiveValue::NotSet,
+            },
+            recipe_id: ActiveValue::NotSet,
+            collection_id: ActiveValue::NotSet,
+        }
+    }
+}
+
+pub type RecipeCollectionFilter = Filter<RecipeCollectionCondition, RecipeCollectionOrderBy>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeCollectionCondition {
+    pub recipe_id: Option<i64>,
+    pub collection_id: Option<i64>,
+}
+
+impl IntoCondition for RecipeCollectionCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all()
+            .add_option(
+                self.recipe_id
+                    .map(|recipe_id| Column::RecipeId.eq(recipe_id)),
+            )
+            .add_option(
+                self.collection_id
+                    .map(|collection_id| Column::CollectionId.eq(collection_id)),
+            )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RecipeCollectionOrderBy {
+    Order(Order),
+}
+
+impl OrderBy for RecipeCollectionOrderBy {
+    type Entity = Entity;
+
+    fn column_order(&self) -> (Column, Order) {
+        match self {
+            RecipeCollectionOrderBy::Order(order) => (Column::Order, *order),
+        }
+    }
+}
+
+pub struct RecipeCollectionCrud {}
+
+impl EntityCrudTrait for RecipeCollectionCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = RecipeCollectionCreate;
+    type EntityUpdate = RecipeCollectionUpdate;
+    type EntityCondition = RecipeCollectionCondition;
+    type EntityOrderBy = RecipeCollectionOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_RECIPE_COLLECTION
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_RECIPE_COLLECTION
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_RECIPE_COLLECTION
+    }
+}
@@ -0,0 +1,150 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::recipe_declared_nutrition`].
+
+use sea_orm::{
+    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
+    IntoActiveModel,
+};
+use serde::Deserialize;
+
+use crate::{
+    entity::recipe_declared_nutrition::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
+    event::channel::{
+        ENTITY_ACTION_CREATED_RECIPE_DECLARED_NUTRITION,
+        ENTITY_ACTION_DELETED_RECIPE_DECLARED_NUTRITION,
+        ENTITY_ACTION_UPDATED_RECIPE_DECLARED_NUTRITION,
+    },
+};
+
+#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeDeclaredNutritionCreate {
+    pub energy: Option<f64>,
+    pub protein: Option<f64>,
+    pub fat: Option<f64>,
+    pub carbohydrates: Option<f64>,
+    pub fibre: Option<f64>,
+    pub salt: Option<f64>,
+    pub recipe_id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeDeclaredNutritionUpdate {
+    pub id: i64,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub energy: Option<Option<f64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub protein: Option<Option<f64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub fat: Option<Option<f64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub carbohydrates: Option<Option<f64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub fibre: Option<Option<f64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub salt: Option<Option<f64>>,
+}
+
+impl IntoActiveModel<ActiveModel> for RecipeDeclaredNutritionUpdate {
+    fn into_active_model(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            energy: match self.energy {
+                Some(energy) => ActiveValue::Set(energy),
+                _ => ActiveValue::NotSet,
+            },
+            protein: match self.protein {
+                Some(protein) => ActiveValue::Set(protein),
+                _ => ActiveValue::NotSet,
+            },
+            fat: match self.fat {
+                Some(fat) => ActiveValue::Set(fat),
+                _ => ActiveValue::NotSet,
+            },
+            carbohydrates: match self.carbohydrates {
+                Some(carbohydrates) => ActiveValue::Set(carbohydrates),
+                _ => ActiveValue::NotSet,
+            },
+            fibre: match self.fibre {
+                Some(fibre) => ActiveValue::Set(fibre),
+                _ => ActiveValue::NotSet,
+            },
+            salt: match self.salt {
+                Some(salt) => ActiveValue::Set(salt),
+                _ => ActiveValue::NotSet,
+            },
+            recipe_id: ActiveValue::NotSet,
+        }
+    }
+}
+
+pub type RecipeDeclaredNutritionFilter =
+    Filter<RecipeDeclaredNutritionCondition, RecipeDeclaredNutritionOrderBy>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeDeclaredNutritionCondition {
+    pub recipe_id: Option<i64>,
+}
+
+impl IntoCondition for RecipeDeclaredNutritionCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all().add_option(
+            self.recipe_id
+                .map(|recipe_id| Column::RecipeId.eq(recipe_id)),
+        )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RecipeDeclaredNutritionOrderBy {
+    Energy(Order),
+}
+
+impl OrderBy for RecipeDeclaredNutritionOrderBy {
+    type Entity = Entity;
+
+    fn column_order(&self) -> (Column, Order) {
+        match self {
+            RecipeDeclaredNutritionOrderBy::Energy(order) => (Column::Energy, *order),
+        }
+    }
+}
+
+pub struct RecipeDeclaredNutritionCrud {}
+
+impl EntityCrudTrait for RecipeDeclaredNutritionCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = RecipeDeclaredNutritionCreate;
+    type EntityUpdate = RecipeDeclaredNutritionUpdate;
+    type EntityCondition = RecipeDeclaredNutritionCondition;
+    type EntityOrderBy = RecipeDeclaredNutritionOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_RECIPE_DECLARED_NUTRITION
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_RECIPE_DECLARED_NUTRITION
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_RECIPE_DECLARED_NUTRITION
+    }
+}
@@ -2,7 +2,7 @@
 
 use sea_orm::{
     sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
-    IntoActiveModel, QueryOrder, Select,
+    IntoActiveModel,
 };
 use serde::Deserialize;
 
@@ -76,11 +76,9 @@ pub enum RecipeIngredientDraftOrderBy {
 impl OrderBy for RecipeIngredientDraftOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
+    fn column_order(&self) -> (Column, Order) {
         match self {
-            RecipeIngredientDraftOrderBy::Order(order) => {
-                select.order_by(Column::Order, order.into())
-            }
+            RecipeIngredientDraftOrderBy::Order(order) => (Column::Order, *order),
         }
     }
 }
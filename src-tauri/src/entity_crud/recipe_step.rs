@@ -2,7 +2,7 @@
 
 use sea_orm::{
     sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
-    IntoActiveModel, QueryOrder, Select,
+    IntoActiveModel,
 };
 use serde::Deserialize;
 
@@ -74,9 +74,9 @@ pub enum RecipeStepOrderBy {
 impl OrderBy for RecipeStepOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
+    fn column_order(&self) -> (Column, Order) {
         match self {
-            RecipeStepOrderBy::Order(order) => select.order_by(Column::Order, order.into()),
+            RecipeStepOrderBy::Order(order) => (Column::Order, *order),
         }
     }
 }
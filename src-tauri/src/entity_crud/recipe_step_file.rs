@@ -3,7 +3,7 @@
 use async_trait::async_trait;
 use sea_orm::{
     sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
-    IntoActiveModel, QueryOrder, Select,
+    IntoActiveModel,
 };
 use serde::Deserialize;
 
@@ -71,9 +71,9 @@ pub enum RecipeStepFileOrderBy {
 impl OrderBy for RecipeStepFileOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
+    fn column_order(&self) -> (Column, Order) {
         match self {
-            RecipeStepFileOrderBy::Order(order) => select.order_by(Column::Order, order.into()),
+            RecipeStepFileOrderBy::Order(order) => (Column::Order, *order),
         }
     }
 }
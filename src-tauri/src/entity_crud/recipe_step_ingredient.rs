@@ -2,7 +2,7 @@
 
 use sea_orm::{
     sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
-    IntoActiveModel, QueryOrder, Select,
+    IntoActiveModel,
 };
 use serde::Deserialize;
 
@@ -102,11 +102,9 @@ pub enum RecipeStepIngredientOrderBy {
 impl OrderBy for RecipeStepIngredientOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
+    fn column_order(&self) -> (Column, Order) {
         match self {
-            RecipeStepIngredientOrderBy::Order(order) => {
-                select.order_by(Column::Order, order.into())
-            }
+            RecipeStepIngredientOrderBy::Order(order) => (Column::Order, *order),
         }
     }
 }
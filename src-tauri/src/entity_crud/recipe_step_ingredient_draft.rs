@@ -2,7 +2,7 @@
 
 use sea_orm::{
     sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
-    IntoActiveModel, QueryOrder, Select,
+    IntoActiveModel,
 };
 use serde::Deserialize;
 
@@ -78,11 +78,9 @@ pub enum RecipeStepIngredientDraftOrderBy {
 impl OrderBy for RecipeStepIngredientDraftOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
+    fn column_order(&self) -> (Column, Order) {
         match self {
-            RecipeStepIngredientDraftOrderBy::Order(order) => {
-                select.order_by(Column::Order, order.into())
-            }
+            RecipeStepIngredientDraftOrderBy::Order(order) => (Column::Order, *order),
         }
     }
 }
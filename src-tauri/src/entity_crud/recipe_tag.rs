@@ -0,0 +1,115 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::recipe_tag`].
+
+use sea_orm::{
+    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
+    IntoActiveModel,
+};
+use serde::Deserialize;
+
+use crate::{
+    entity::recipe_tag::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
+    event::channel::{
+        ENTITY_ACTION_CREATED_RECIPE_TAG, ENTITY_ACTION_DELETED_RECIPE_TAG,
+        ENTITY_ACTION_UPDATED_RECIPE_TAG,
+    },
+};
+
+#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeTagCreate {
+    pub recipe_id: i64,
+    pub tag_id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeTagUpdate {
+    pub id: i64,
+    pub tag_id: Option<i64>,
+}
+
+impl IntoActiveModel<ActiveModel> for RecipeTagUpdate {
+    fn into_active_model(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            recipe_id: ActiveValue::NotSet,
+            tag_id: match self.tag_id {
+                Some(tag_id) => ActiveValue::Set(tag_id),
+                _ => ActiveValue::NotSet,
+            },
+        }
+    }
+}
+
+pub type RecipeTagFilter = Filter<RecipeTagCondition, RecipeTagOrderBy>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeTagCondition {
+    pub recipe_id: Option<i64>,
+    pub tag_id: Option<i64>,
+}
+
+impl IntoCondition for RecipeTagCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all()
+            .add_option(
+                self.recipe_id
+                    .map(|recipe_id| Column::RecipeId.eq(recipe_id)),
+            )
+            .add_option(self.tag_id.map(|tag_id| Column::TagId.eq(tag_id)))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RecipeTagOrderBy {
+    TagId(Order),
+}
+
+impl OrderBy for RecipeTagOrderBy {
+    type Entity = Entity;
+
+    fn column_order(&self) -> (Column, Order) {
+        match self {
+            RecipeTagOrderBy::TagId(order) => (Column::TagId, *order),
+        }
+    }
+}
+
+pub struct RecipeTagCrud {}
+
+impl EntityCrudTrait for RecipeTagCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = RecipeTagCreate;
+    type EntityUpdate = RecipeTagUpdate;
+    type EntityCondition = RecipeTagCondition;
+    type EntityOrderBy = RecipeTagOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_RECIPE_TAG
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_RECIPE_TAG
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_RECIPE_TAG
+    }
+}
@@ -0,0 +1,107 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::shopping_list`].
+
+use sea_orm::{
+    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
+    IntoActiveModel,
+};
+use serde::Deserialize;
+
+use crate::{
+    entity::shopping_list::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
+    event::channel::{
+        ENTITY_ACTION_CREATED_SHOPPING_LIST, ENTITY_ACTION_DELETED_SHOPPING_LIST,
+        ENTITY_ACTION_UPDATED_SHOPPING_LIST,
+    },
+};
+
+#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+#[serde(rename_all = "camelCase")]
+pub struct ShoppingListCreate {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShoppingListUpdate {
+    pub id: i64,
+    pub name: Option<String>,
+}
+
+impl IntoActiveModel<ActiveModel> for ShoppingListUpdate {
+    fn into_active_model(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            name: match self.name {
+                Some(name) => ActiveValue::Set(name),
+                _ => ActiveValue::NotSet,
+            },
+        }
+    }
+}
+
+pub type ShoppingListFilter = Filter<ShoppingListCondition, ShoppingListOrderBy>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShoppingListCondition {
+    pub name: Option<String>,
+}
+
+impl IntoCondition for ShoppingListCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all().add_option(self.name.map(|name| Column::Name.like(format!("%{name}%"))))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ShoppingListOrderBy {
+    Name(Order),
+}
+
+impl OrderBy for ShoppingListOrderBy {
+    type Entity = Entity;
+
+    fn column_order(&self) -> (Column, Order) {
+        match self {
+            ShoppingListOrderBy::Name(order) => (Column::Name, *order),
+        }
+    }
+}
+
+pub struct ShoppingListCrud {}
+
+impl EntityCrudTrait for ShoppingListCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = ShoppingListCreate;
+    type EntityUpdate = ShoppingListUpdate;
+    type EntityCondition = ShoppingListCondition;
+    type EntityOrderBy = ShoppingListOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_SHOPPING_LIST
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_SHOPPING_LIST
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_SHOPPING_LIST
+    }
+}
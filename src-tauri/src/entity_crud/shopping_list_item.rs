@@ -0,0 +1,155 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::shopping_list_item`].
+
+use sea_orm::{
+    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
+    IntoActiveModel,
+};
+use serde::Deserialize;
+
+use crate::{
+    entity::shopping_list_item::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
+    event::channel::{
+        ENTITY_ACTION_CREATED_SHOPPING_LIST_ITEM, ENTITY_ACTION_DELETED_SHOPPING_LIST_ITEM,
+        ENTITY_ACTION_UPDATED_SHOPPING_LIST_ITEM,
+    },
+};
+
+#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+#[serde(rename_all = "camelCase")]
+pub struct ShoppingListItemCreate {
+    pub order: i64,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    pub name: Option<String>,
+    #[serde(default)]
+    pub checked: bool,
+    pub shopping_list_id: i64,
+    pub ingredient_id: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShoppingListItemUpdate {
+    pub id: i64,
+    pub order: Option<i64>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub quantity: Option<Option<f64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub unit: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub name: Option<Option<String>>,
+    pub checked: Option<bool>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub ingredient_id: Option<Option<i64>>,
+}
+
+impl IntoActiveModel<ActiveModel> for ShoppingListItemUpdate {
+    fn into_active_model(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            order: match self.order {
+                Some(order) => ActiveValue::Set(order),
+                _ => ActiveValue::NotSet,
+            },
+            quantity: match self.quantity {
+                Some(quantity) => ActiveValue::Set(quantity),
+                _ => ActiveValue::NotSet,
+            },
+            unit: match self.unit {
+                Some(unit) => ActiveValue::Set(unit),
+                _ => ActiveValue::NotSet,
+            },
+            name: match self.name {
+                Some(name) => ActiveValue::Set(name),
+                _ => ActiveValue::NotSet,
+            },
+            checked: match self.checked {
+                Some(checked) => ActiveValue::Set(checked),
+                _ => ActiveValue::NotSet,
+            },
+            shopping_list_id: ActiveValue::NotSet,
+            ingredient_id: match self.ingredient_id {
+                Some(ingredient_id) => ActiveValue::Set(ingredient_id),
+                _ => ActiveValue::NotSet,
+            },
+        }
+    }
+}
+
+pub type ShoppingListItemFilter = Filter<ShoppingListItemCondition, ShoppingListItemOrderBy>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShoppingListItemCondition {
+    pub shopping_list_id: Option<i64>,
+    pub ingredient_id: Option<i64>,
+    pub checked: Option<bool>,
+}
+
+impl IntoCondition for ShoppingListItemCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all()
+            .add_option(
+                self.shopping_list_id
+                    .map(|shopping_list_id| Column::ShoppingListId.eq(shopping_list_id)),
+            )
+            .add_option(
+                self.ingredient_id
+                    .map(|ingredient_id| Column::IngredientId.eq(ingredient_id)),
+            )
+            .add_option(self.checked.map(|checked| Column::Checked.eq(checked)))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ShoppingListItemOrderBy {
+    Order(Order),
+}
+
+impl OrderBy for ShoppingListItemOrderBy {
+    type Entity = Entity;
+
+    fn column_order(&self) -> (Column, Order) {
+        match self {
+            ShoppingListItemOrderBy::Order(order) => (Column::Order, *order),
+        }
+    }
+}
+
+pub struct ShoppingListItemCrud {}
+
+impl EntityCrudTrait for ShoppingListItemCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = ShoppingListItemCreate;
+    type EntityUpdate = ShoppingListItemUpdate;
+    type EntityCondition = ShoppingListItemCondition;
+    type EntityOrderBy = ShoppingListItemOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_SHOPPING_LIST_ITEM
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_SHOPPING_LIST_ITEM
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_SHOPPING_LIST_ITEM
+    }
+}
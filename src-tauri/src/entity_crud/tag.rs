@@ -0,0 +1,122 @@
+//! This module implements [`EntityCrudTrait`] for [`crate::entity::tag`].
+
+use sea_orm::{
+    sea_query::IntoCondition, ActiveValue, ColumnTrait, Condition, DeriveIntoActiveModel,
+    EntityTrait, IntoActiveModel, QueryFilter, QuerySelect, QueryTrait,
+};
+use serde::Deserialize;
+
+use crate::{
+    entity::{
+        recipe_tag,
+        tag::{ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
+    },
+    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
+    event::channel::{
+        ENTITY_ACTION_CREATED_TAG, ENTITY_ACTION_DELETED_TAG, ENTITY_ACTION_UPDATED_TAG,
+    },
+};
+
+#[derive(Debug, Deserialize, DeriveIntoActiveModel)]
+#[serde(rename_all = "camelCase")]
+pub struct TagCreate {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagUpdate {
+    pub id: i64,
+    pub name: Option<String>,
+}
+
+impl IntoActiveModel<ActiveModel> for TagUpdate {
+    fn into_active_model(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(self.id),
+            name: match self.name {
+                Some(name) => ActiveValue::Set(name),
+                _ => ActiveValue::NotSet,
+            },
+        }
+    }
+}
+
+pub type TagFilter = Filter<TagCondition, TagOrderBy>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagCondition {
+    pub name: Option<String>,
+    pub name_exact: Option<String>,
+    pub recipe_id: Option<i64>,
+}
+
+impl IntoCondition for TagCondition {
+    fn into_condition(self) -> Condition {
+        Condition::all()
+            .add_option(self.name.map(|name| Column::Name.like(format!("%{name}%"))))
+            .add_option(self.name_exact.map(|name| Column::Name.eq(name)))
+            .add_option(self.recipe_id.map(|recipe_id| {
+                Column::Id.in_subquery(
+                    recipe_tag::Entity::find()
+                        .select_only()
+                        .column(recipe_tag::Column::TagId)
+                        .filter(recipe_tag::Column::RecipeId.eq(recipe_id))
+                        .into_query(),
+                )
+            }))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TagOrderBy {
+    Name(Order),
+}
+
+impl OrderBy for TagOrderBy {
+    type Entity = Entity;
+
+    fn column_order(&self) -> (Column, Order) {
+        match self {
+            TagOrderBy::Name(order) => (Column::Name, *order),
+        }
+    }
+}
+
+pub struct TagCrud {}
+
+impl EntityCrudTrait for TagCrud {
+    type Entity = Entity;
+    type Model = Model;
+    type ActiveModel = ActiveModel;
+    type Column = Column;
+    type Relation = Relation;
+    type PrimaryKey = PrimaryKey;
+    type PrimaryKeyValue = i64;
+    type EntityCreate = TagCreate;
+    type EntityUpdate = TagUpdate;
+    type EntityCondition = TagCondition;
+    type EntityOrderBy = TagOrderBy;
+
+    fn primary_key_value(model: &Model) -> i64 {
+        model.id
+    }
+
+    fn primary_key_colum() -> Column {
+        Column::Id
+    }
+
+    fn entity_action_created_channel() -> &'static str {
+        ENTITY_ACTION_CREATED_TAG
+    }
+
+    fn entity_action_updated_channel() -> &'static str {
+        ENTITY_ACTION_UPDATED_TAG
+    }
+
+    fn entity_action_deleted_channel() -> &'static str {
+        ENTITY_ACTION_DELETED_TAG
+    }
+}
@@ -1,13 +1,11 @@
 //! This module implements [`EntityCrudTrait`] for [`crate::entity::unit_name`].
 
-use sea_orm::{
-    sea_query::IntoCondition, ActiveValue, Condition, IntoActiveModel, IntoActiveValue, Select,
-};
+use sea_orm::{sea_query::IntoCondition, ActiveValue, Condition, IntoActiveModel, IntoActiveValue};
 use serde::Deserialize;
 
 use crate::{
     entity::unit_name::{unit::Unit, ActiveModel, Column, Entity, Model, PrimaryKey, Relation},
-    entity_crud::{EntityCrudTrait, Filter, OrderBy},
+    entity_crud::{EntityCrudTrait, Filter, Order, OrderBy},
     event::channel::{
         ENTITY_ACTION_CREATED_UNIT_NAME, ENTITY_ACTION_DELETED_UNIT_NAME,
         ENTITY_ACTION_UPDATED_UNIT_NAME,
@@ -68,8 +66,8 @@ pub enum UnitNameOrderBy {}
 impl OrderBy for UnitNameOrderBy {
     type Entity = Entity;
 
-    fn add(self, select: Select<Self::Entity>) -> Select<Self::Entity> {
-        select
+    fn column_order(&self) -> (Column, Order) {
+        match *self {}
     }
 }
 
@@ -1,5 +1,9 @@
 //! This module contains all tauri event channels.
 
+pub const ENTITY_ACTION_CREATED_COLLECTION: &str = "ENTITY_ACTION_CREATED_COLLECTION";
+pub const ENTITY_ACTION_UPDATED_COLLECTION: &str = "ENTITY_ACTION_UPDATED_COLLECTION";
+pub const ENTITY_ACTION_DELETED_COLLECTION: &str = "ENTITY_ACTION_DELETED_COLLECTION";
+
 pub const ENTITY_ACTION_CREATED_FILE: &str = "ENTITY_ACTION_CREATED_FILE";
 pub const ENTITY_ACTION_UPDATED_FILE: &str = "ENTITY_ACTION_UPDATED_FILE";
 pub const ENTITY_ACTION_DELETED_FILE: &str = "ENTITY_ACTION_DELETED_FILE";
@@ -8,10 +12,36 @@ pub const ENTITY_ACTION_CREATED_INGREDIENT: &str = "ENTITY_ACTION_CREATED_INGRED
 pub const ENTITY_ACTION_UPDATED_INGREDIENT: &str = "ENTITY_ACTION_UPDATED_INGREDIENT";
 pub const ENTITY_ACTION_DELETED_INGREDIENT: &str = "ENTITY_ACTION_DELETED_INGREDIENT";
 
+pub const ENTITY_ACTION_CREATED_INGREDIENT_NUTRITION: &str =
+    "ENTITY_ACTION_CREATED_INGREDIENT_NUTRITION";
+pub const ENTITY_ACTION_UPDATED_INGREDIENT_NUTRITION: &str =
+    "ENTITY_ACTION_UPDATED_INGREDIENT_NUTRITION";
+pub const ENTITY_ACTION_DELETED_INGREDIENT_NUTRITION: &str =
+    "ENTITY_ACTION_DELETED_INGREDIENT_NUTRITION";
+
+pub const ENTITY_ACTION_CREATED_MEAL_PLAN_ENTRY: &str = "ENTITY_ACTION_CREATED_MEAL_PLAN_ENTRY";
+pub const ENTITY_ACTION_UPDATED_MEAL_PLAN_ENTRY: &str = "ENTITY_ACTION_UPDATED_MEAL_PLAN_ENTRY";
+pub const ENTITY_ACTION_DELETED_MEAL_PLAN_ENTRY: &str = "ENTITY_ACTION_DELETED_MEAL_PLAN_ENTRY";
+
+pub const ENTITY_ACTION_CREATED_PANTRY_ITEM: &str = "ENTITY_ACTION_CREATED_PANTRY_ITEM";
+pub const ENTITY_ACTION_UPDATED_PANTRY_ITEM: &str = "ENTITY_ACTION_UPDATED_PANTRY_ITEM";
+pub const ENTITY_ACTION_DELETED_PANTRY_ITEM: &str = "ENTITY_ACTION_DELETED_PANTRY_ITEM";
+
 pub const ENTITY_ACTION_CREATED_RECIPE: &str = "ENTITY_ACTION_CREATED_RECIPE";
 pub const ENTITY_ACTION_UPDATED_RECIPE: &str = "ENTITY_ACTION_UPDATED_RECIPE";
 pub const ENTITY_ACTION_DELETED_RECIPE: &str = "ENTITY_ACTION_DELETED_RECIPE";
 
+pub const ENTITY_ACTION_CREATED_RECIPE_COLLECTION: &str = "ENTITY_ACTION_CREATED_RECIPE_COLLECTION";
+pub const ENTITY_ACTION_UPDATED_RECIPE_COLLECTION: &str = "ENTITY_ACTION_UPDATED_RECIPE_COLLECTION";
+pub const ENTITY_ACTION_DELETED_RECIPE_COLLECTION: &str = "ENTITY_ACTION_DELETED_RECIPE_COLLECTION";
+
+pub const ENTITY_ACTION_CREATED_RECIPE_DECLARED_NUTRITION: &str =
+    "ENTITY_ACTION_CREATED_RECIPE_DECLARED_NUTRITION";
+pub const ENTITY_ACTION_UPDATED_RECIPE_DECLARED_NUTRITION: &str =
+    "ENTITY_ACTION_UPDATED_RECIPE_DECLARED_NUTRITION";
+pub const ENTITY_ACTION_DELETED_RECIPE_DECLARED_NUTRITION: &str =
+    "ENTITY_ACTION_DELETED_RECIPE_DECLARED_NUTRITION";
+
 pub const ENTITY_ACTION_CREATED_RECIPE_FILE: &str = "ENTITY_ACTION_CREATED_RECIPE_FILE";
 pub const ENTITY_ACTION_UPDATED_RECIPE_FILE: &str = "ENTITY_ACTION_UPDATED_RECIPE_FILE";
 pub const ENTITY_ACTION_DELETED_RECIPE_FILE: &str = "ENTITY_ACTION_DELETED_RECIPE_FILE";
@@ -45,9 +75,34 @@ pub const ENTITY_ACTION_CREATED_RECIPE_STEP: &str = "ENTITY_ACTION_CREATED_RECIP
 pub const ENTITY_ACTION_UPDATED_RECIPE_STEP: &str = "ENTITY_ACTION_UPDATED_RECIPE_STEP";
 pub const ENTITY_ACTION_DELETED_RECIPE_STEP: &str = "ENTITY_ACTION_DELETED_RECIPE_STEP";
 
+pub const ENTITY_ACTION_CREATED_RECIPE_TAG: &str = "ENTITY_ACTION_CREATED_RECIPE_TAG";
+pub const ENTITY_ACTION_UPDATED_RECIPE_TAG: &str = "ENTITY_ACTION_UPDATED_RECIPE_TAG";
+pub const ENTITY_ACTION_DELETED_RECIPE_TAG: &str = "ENTITY_ACTION_DELETED_RECIPE_TAG";
+
+pub const ENTITY_ACTION_CREATED_SHOPPING_LIST: &str = "ENTITY_ACTION_CREATED_SHOPPING_LIST";
+pub const ENTITY_ACTION_UPDATED_SHOPPING_LIST: &str = "ENTITY_ACTION_UPDATED_SHOPPING_LIST";
+pub const ENTITY_ACTION_DELETED_SHOPPING_LIST: &str = "ENTITY_ACTION_DELETED_SHOPPING_LIST";
+
+pub const ENTITY_ACTION_CREATED_SHOPPING_LIST_ITEM: &str =
+    "ENTITY_ACTION_CREATED_SHOPPING_LIST_ITEM";
+pub const ENTITY_ACTION_UPDATED_SHOPPING_LIST_ITEM: &str =
+    "ENTITY_ACTION_UPDATED_SHOPPING_LIST_ITEM";
+pub const ENTITY_ACTION_DELETED_SHOPPING_LIST_ITEM: &str =
+    "ENTITY_ACTION_DELETED_SHOPPING_LIST_ITEM";
+
+pub const ENTITY_ACTION_CREATED_TAG: &str = "ENTITY_ACTION_CREATED_TAG";
+pub const ENTITY_ACTION_UPDATED_TAG: &str = "ENTITY_ACTION_UPDATED_TAG";
+pub const ENTITY_ACTION_DELETED_TAG: &str = "ENTITY_ACTION_DELETED_TAG";
+
 pub const ENTITY_ACTION_CREATED_UNIT_NAME: &str = "ENTITY_ACTION_CREATED_UNIT_NAME";
 pub const ENTITY_ACTION_UPDATED_UNIT_NAME: &str = "ENTITY_ACTION_UPDATED_UNIT_NAME";
 pub const ENTITY_ACTION_DELETED_UNIT_NAME: &str = "ENTITY_ACTION_DELETED_UNIT_NAME";
 
+pub const EXTERNAL_RECIPE_IMPORT_PROGRESS: &str = "EXTERNAL_RECIPE_IMPORT_PROGRESS";
+pub const EXTERNAL_RECIPE_IMPORT_FINISHED: &str = "EXTERNAL_RECIPE_IMPORT_FINISHED";
+
+pub const PREFERENCE_UPDATED_UNIT_SYSTEM: &str = "PREFERENCE_UPDATED_UNIT_SYSTEM";
+pub const PREFERENCE_UPDATED_FETCH_SETTINGS: &str = "PREFERENCE_UPDATED_FETCH_SETTINGS";
+
 pub const SCRAPER_DOM_DROP: &str = "SCRAPER_DOM_DROP";
 pub const SCRAPER_ELEMENT_DROP: &str = "SCRAPER_ELEMENT_DROP";
@@ -1,9 +1,13 @@
 //! This module handles getting data from external recipes in the world wide web.
 
-use std::{str::FromStr, sync::OnceLock, time::Duration};
+use std::{
+    collections::HashSet,
+    future::Future,
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
 
 use rdf_types::{Id, Object};
-use reqwest::Client;
 use schema_org_constants::{
     HOW_TO_SECTION_IRI_HTTP, HOW_TO_SECTION_IRI_HTTPS, HOW_TO_STEP_IRI_HTTP, HOW_TO_STEP_IRI_HTTPS,
 };
@@ -12,35 +16,93 @@ use schema_org_traits::{
     GetItemListElementProperty, GetNameProperty, GetRecipeIngredientProperty,
     GetRecipeInstructionsProperty, GetTextProperty, GetVideoProperty,
 };
+use serde::Serialize;
 use url::Url;
 
-use crate::external_recipe::{
-    error::ExternalRecipeError, generic::generic_external_recipe_getters,
-    specific::specific_external_recipe_getters,
+use crate::{
+    external_recipe::{
+        details::ExternalRecipeDetails, error::ExternalRecipeError,
+        generic::generic_external_recipe_getters, saved_page::SavedPage,
+        specific::specific_external_recipe_getters,
+    },
+    fetch::{fetch_page, fetch_page_fresh},
+    scraper::{Dom, ScraperBackend, ScraperError},
 };
 
+pub mod details;
 pub mod error;
 mod generic;
+pub mod saved_page;
 mod specific;
 
-static CLIENT_ONCE_LOCK: OnceLock<Client> = OnceLock::new();
+tokio::task_local! {
+    /// This is called whenever a page was fetched by [`get_dom`], see [`get_with_getter_name_observed`].
+    static ON_FETCHED: Box<dyn Fn() + Send + Sync>;
+
+    /// The backend [`get_dom`] creates DOMs with, see [`get_with_scraper_fallback`].
+    static SCRAPER_BACKEND: ScraperBackend;
+
+    /// The page [`get_dom`] takes instead of fetching it, see [`get_saved_with_getter_name_observed`].
+    static SAVED_PAGE: Arc<SavedPage>;
+
+    /// The pages [`get_dom`] already fetched without the cache, see [`get_fresh_with_getter_name`].
+    static FRESH_URLS: Mutex<HashSet<Url>>;
+}
+
+/// Fetch a page via [`fetch_page`] and create its DOM.
+///
+/// A saved page is not fetched again.
+/// While getting fresh, each page is fetched once via [`fetch_page_fresh`] and then taken from the cache.
+async fn get_dom(url: Url) -> Result<Dom, ExternalRecipeError> {
+    let saved_html_option = SAVED_PAGE
+        .try_with(|saved_page| saved_page.html_of(&url).map(String::from))
+        .ok()
+        .flatten();
+    let text = match saved_html_option {
+        Some(saved_html) => saved_html,
+        None => {
+            let fresh = FRESH_URLS
+                .try_with(|fresh_urls| fresh_urls.lock().unwrap().insert(url.clone()))
+                .unwrap_or(false);
+            let response = if fresh {
+                fetch_page_fresh(&url).await?
+            } else {
+                fetch_page(&url).await?
+            };
+            response.text()
+        }
+    };
+    ON_FETCHED.try_with(|on_fetched| on_fetched()).ok();
+    let scraper_backend = SCRAPER_BACKEND
+        .try_with(|backend| *backend)
+        .unwrap_or_default();
+    Ok(Dom::create(text, scraper_backend).await?)
+}
 
-fn client() -> &'static Client {
-    CLIENT_ONCE_LOCK.get_or_init(|| {
-        reqwest::ClientBuilder::new()
-            .timeout(Duration::from_secs(10))
-            .build()
-            .unwrap()
-    })
+/// Call a getter with the native scraper backend and call it again with the webview scraper backend
+/// if the native one does not support one of its selectors.
+async fn get_with_scraper_fallback<T, F, Fut>(get: F) -> Result<T, ExternalRecipeError>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, ExternalRecipeError>>,
+{
+    match SCRAPER_BACKEND.scope(ScraperBackend::Native, get()).await {
+        Err(ExternalRecipeError::Anyhow(error)) if error.is::<ScraperError>() => {
+            log::warn!("Falling back to the webview scraper backend: {error}");
+            SCRAPER_BACKEND.scope(ScraperBackend::Webview, get()).await
+        }
+        result => result,
+    }
 }
 
-#[derive(Debug, Clone, Default)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ExternalRecipe {
     pub name: String,
     pub ingredients: Vec<String>,
     pub files: Vec<String>,
     pub steps: Vec<ExternalRecipeStep>,
+    pub details: ExternalRecipeDetails,
 }
 
 impl ExternalRecipe {
@@ -114,22 +176,51 @@ impl ExternalRecipe {
                 },
             )
             .collect();
+        let details =
+            ExternalRecipeDetails::from_schema_org_recipe_json_ld(json_ld_store, recipe_id);
 
         Self {
             name,
             ingredients,
             files,
             steps,
+            details,
         }
     }
 }
 
-#[derive(Debug, Clone, Default)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ExternalRecipeStep {
     pub ingredients: Vec<String>,
     pub description: String,
     pub files: Vec<String>,
+    /// the ingredients whose quantity, unit and name are already separated by the source
+    pub parsed_ingredients: Vec<ExternalRecipeIngredient>,
+}
+
+/// This struct represents an ingredient whose quantity, unit and name are already separated by the source.
+///
+/// It is imported as recipe step ingredient instead of as draft, so its fields match the ones of a recipe step ingredient.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalRecipeIngredient {
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    pub name: String,
+    pub quality: Option<String>,
+}
+
+impl ExternalRecipeIngredient {
+    /// Get the ingredient as draft text, e.g. "200 g Mehl (Typ 550)".
+    pub fn text(&self) -> String {
+        let mut parts = vec![];
+        parts.extend(self.quantity.map(|quantity| quantity.to_string()));
+        parts.extend(self.unit.clone());
+        parts.push(self.name.clone());
+        parts.extend(self.quality.as_ref().map(|quality| format!("({quality})")));
+        parts.join(" ")
+    }
 }
 
 impl ExternalRecipeStep {
@@ -195,8 +286,58 @@ fn get_file_urls(json_ld_store: &JsonLdStore, id: &Id) -> Vec<String> {
 
 /// Get an external recipe from a URL.
 pub async fn get(url_string: String) -> Result<ExternalRecipe, ExternalRecipeError> {
+    let (_, external_recipe) = get_with_getter_name(url_string).await?;
+    Ok(external_recipe)
+}
+
+/// Get an external recipe like [`get_with_getter_name`] and call `on_fetched` whenever a page was fetched and is parsed next.
+pub async fn get_with_getter_name_observed<F>(
+    url_string: String,
+    on_fetched: F,
+) -> Result<(String, ExternalRecipe), ExternalRecipeError>
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    ON_FETCHED
+        .scope(Box::new(on_fetched), get_with_getter_name(url_string))
+        .await
+}
+
+/// Get an external recipe from a saved page like [`get_with_getter_name_observed`].
+///
+/// The getters are chosen by the original URL of the page and get its DOM from the saved page instead of fetching it.
+pub async fn get_saved_with_getter_name_observed<F>(
+    saved_page: Arc<SavedPage>,
+    on_fetched: F,
+) -> Result<(String, ExternalRecipe), ExternalRecipeError>
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    let url_string = saved_page.url.to_string();
+    SAVED_PAGE
+        .scope(
+            saved_page,
+            get_with_getter_name_observed(url_string, on_fetched),
+        )
+        .await
+}
+
+/// Get an external recipe like [`get_with_getter_name`], but fetch its pages instead of taking them from the cache.
+pub async fn get_fresh_with_getter_name(
+    url_string: String,
+) -> Result<(String, ExternalRecipe), ExternalRecipeError> {
+    FRESH_URLS
+        .scope(Mutex::new(HashSet::new()), get_with_getter_name(url_string))
+        .await
+}
+
+/// Get an external recipe from a URL together with the name of the getter which got it.
+pub async fn get_with_getter_name(
+    url_string: String,
+) -> Result<(String, ExternalRecipe), ExternalRecipeError> {
     let url = Url::from_str(&url_string).map_err(anyhow::Error::from)?;
     let external_recipe_getter_option = specific_external_recipe_getters()
+        .await
         .into_iter()
         .find(|external_recipe_getter| external_recipe_getter.can_get(&url));
     let external_recipe_option = match external_recipe_getter_option {
@@ -207,8 +348,10 @@ pub async fn get(url_string: String) -> Result<ExternalRecipe, ExternalRecipeErr
             loop {
                 match getter_option {
                     Some(getter) => {
-                        if let Some(external_recipe) = getter.get(url.clone()).await? {
-                            break Some(external_recipe);
+                        if let Some(external_recipe) =
+                            get_with_scraper_fallback(|| getter.get(url.clone())).await?
+                        {
+                            break Some((getter.name().to_string(), external_recipe));
                         }
                     }
                     None => {
@@ -218,12 +361,15 @@ pub async fn get(url_string: String) -> Result<ExternalRecipe, ExternalRecipeErr
                 getter_option = getter_iterator.next();
             }
         }
-        Some(getter) => Some(getter.get(url).await?),
+        Some(getter) => Some((
+            getter.name().to_string(),
+            get_with_scraper_fallback(|| getter.get(url.clone())).await?,
+        )),
     };
-    let Some(external_recipe) = external_recipe_option else {
+    let Some(named_external_recipe) = external_recipe_option else {
         return Err(ExternalRecipeError::UrlNotSupported(url_string));
     };
-    Ok(external_recipe)
+    Ok(named_external_recipe)
 }
 
 #[cfg(test)]
@@ -241,6 +387,10 @@ mod tests {
     where
         T: SpecificExternalRecipeGetterTrait,
     {
+        fn name(&self) -> &str {
+            SpecificExternalRecipeGetterTrait::name(self)
+        }
+
         async fn get(&self, url: Url) -> Result<Option<ExternalRecipe>, ExternalRecipeError> {
             Ok(Some(self.get(url).await?))
         }
@@ -252,10 +402,22 @@ mod tests {
         pub external_recipe: ExternalRecipe,
     }
 
+    /// Assert the content of the external recipes got from the URLs.
+    ///
+    /// The pages are served from their recordings, see [`crate::fetch::recording`].
+    /// The details are ignored, because they change more often on the live pages.
     pub async fn assert_expected_gets(expected_gets: Vec<ExpectedGet>) {
+        // the fetch settings and cache need the app
+        crate::tests::run();
         for expected_get in expected_gets {
             let actual = get(expected_get.url).await.unwrap();
-            assert_eq!(actual, expected_get.external_recipe);
+            assert_eq!(
+                ExternalRecipe {
+                    details: ExternalRecipeDetails::default(),
+                    ..actual
+                },
+                expected_get.external_recipe
+            );
         }
     }
 }
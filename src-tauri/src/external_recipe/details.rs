@@ -0,0 +1,323 @@
+//! This module implements the details of an [`super::ExternalRecipe`] besides its content,
+//! like times, yield, keywords, author, description and nutrition.
+//!
+//! See [`ExternalRecipeDetails`] for more information.
+
+use rdf_types::{Id, Object};
+use schema_org_traits::{
+    json_ld_0_15::JsonLdStore, GetAuthorProperty, GetCaloriesProperty,
+    GetCarbohydrateContentProperty, GetCookTimeProperty, GetDescriptionProperty,
+    GetFatContentProperty, GetFiberContentProperty, GetKeywordsProperty, GetNameProperty,
+    GetNutritionProperty, GetPrepTimeProperty, GetProteinContentProperty, GetRecipeCategoryProperty,
+    GetRecipeCuisineProperty, GetRecipeYieldProperty, GetSodiumContentProperty,
+    GetTotalTimeProperty,
+};
+use serde::Serialize;
+
+use crate::ingredient_parser::normalize_whitespace;
+
+/// This is the factor to compute salt from sodium.
+const SALT_PER_SODIUM: f64 = 2.5;
+
+/// This is the energy of one kcal in kJ.
+const KILOJOULES_PER_KILOCALORIE: f64 = 4.184;
+
+/// This struct represents the details of an external recipe.
+///
+/// The times are in seconds.
+/// The yield is the text of the source, e.g. "4 servings", see [`crate::ingredient_parser::parse_yield`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalRecipeDetails {
+    pub description: Option<String>,
+    pub author: Option<String>,
+    pub prep_time: Option<i64>,
+    pub cook_time: Option<i64>,
+    pub total_time: Option<i64>,
+    pub recipe_yield: Option<String>,
+    pub categories: Vec<String>,
+    pub cuisines: Vec<String>,
+    pub keywords: Vec<String>,
+    pub nutrition: Option<ExternalRecipeNutrition>,
+}
+
+impl ExternalRecipeDetails {
+    /// Create the [`ExternalRecipeDetails`] of a given [`schema_org_constants::RECIPE_IRI_HTTP`] id inside a [`JsonLdStore`].
+    pub fn from_schema_org_recipe_json_ld(json_ld_store: &JsonLdStore, recipe_id: &Id) -> Self {
+        let author = json_ld_store
+            .get_author_property(recipe_id)
+            .into_iter()
+            .filter_map(|object| match object {
+                Object::Id(id) => get_first_literal(json_ld_store.get_name_property(id)),
+                Object::Literal(literal) => normalize_whitespace(literal.as_str()),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let recipe_yield = json_ld_store
+            .get_recipe_yield_property(recipe_id)
+            .into_iter()
+            .filter_map(|object| object.as_literal())
+            .filter_map(|literal| normalize_whitespace(literal.as_str()))
+            // sources often declare the yield twice, e.g. "4" and "4 servings"
+            .max_by_key(|recipe_yield| recipe_yield.len());
+        let nutrition = json_ld_store
+            .get_nutrition_property(recipe_id)
+            .into_iter()
+            .find_map(|object| object.as_id())
+            .map(|id| {
+                ExternalRecipeNutrition::from_schema_org_nutrition_information_json_ld(
+                    json_ld_store,
+                    id,
+                )
+            })
+            .filter(|nutrition| *nutrition != ExternalRecipeNutrition::default());
+        Self {
+            description: get_first_literal(json_ld_store.get_description_property(recipe_id)),
+            author: Some(author).filter(|author| !author.is_empty()),
+            prep_time: get_first_literal(json_ld_store.get_prep_time_property(recipe_id))
+                .and_then(|prep_time| parse_duration(&prep_time)),
+            cook_time: get_first_literal(json_ld_store.get_cook_time_property(recipe_id))
+                .and_then(|cook_time| parse_duration(&cook_time)),
+            total_time: get_first_literal(json_ld_store.get_total_time_property(recipe_id))
+                .and_then(|total_time| parse_duration(&total_time)),
+            recipe_yield,
+            categories: get_list(json_ld_store.get_recipe_category_property(recipe_id)),
+            cuisines: get_list(json_ld_store.get_recipe_cuisine_property(recipe_id)),
+            keywords: get_list(json_ld_store.get_keywords_property(recipe_id)),
+            nutrition,
+        }
+    }
+
+    /// Get the names of the tags of the recipe, which are its categories, cuisines and keywords.
+    ///
+    /// Each name is contained once, ignoring ASCII case like the SQLite function `lower`,
+    /// with which existing tags are matched when importing.
+    pub fn tag_names(&self) -> Vec<&str> {
+        let mut tag_names: Vec<&str> = vec![];
+        for name in self
+            .categories
+            .iter()
+            .chain(&self.cuisines)
+            .chain(&self.keywords)
+        {
+            if !tag_names
+                .iter()
+                .any(|tag_name| tag_name.eq_ignore_ascii_case(name))
+            {
+                tag_names.push(name);
+            }
+        }
+        tag_names
+    }
+}
+
+/// This struct represents the nutrition facts per serving of an external recipe.
+///
+/// The energy is in kcal, all other nutrients are in g.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalRecipeNutrition {
+    pub energy: Option<f64>,
+    pub protein: Option<f64>,
+    pub fat: Option<f64>,
+    pub carbohydrates: Option<f64>,
+    pub fibre: Option<f64>,
+    pub salt: Option<f64>,
+}
+
+impl ExternalRecipeNutrition {
+    /// Create an [`ExternalRecipeNutrition`] from a given [`schema_org_constants::NUTRITION_INFORMATION_IRI_HTTP`] id inside a [`JsonLdStore`].
+    ///
+    /// The salt is computed from the sodium, because schema.org has no salt property.
+    fn from_schema_org_nutrition_information_json_ld(
+        json_ld_store: &JsonLdStore,
+        nutrition_information_id: &Id,
+    ) -> Self {
+        let get_mass =
+            |objects: Vec<&Object>| get_first_literal(objects).and_then(|mass| parse_mass(&mass));
+        Self {
+            energy: get_first_literal(
+                json_ld_store.get_calories_property(nutrition_information_id),
+            )
+            .and_then(|energy| parse_energy(&energy)),
+            protein: get_mass(json_ld_store.get_protein_content_property(nutrition_information_id)),
+            fat: get_mass(json_ld_store.get_fat_content_property(nutrition_information_id)),
+            carbohydrates: get_mass(
+                json_ld_store.get_carbohydrate_content_property(nutrition_information_id),
+            ),
+            fibre: get_mass(json_ld_store.get_fiber_content_property(nutrition_information_id)),
+            salt: get_mass(json_ld_store.get_sodium_content_property(nutrition_information_id))
+                .map(|sodium| sodium * SALT_PER_SODIUM),
+        }
+    }
+}
+
+/// Get the first literal of the objects of a property, which is not blank.
+fn get_first_literal(objects: Vec<&Object>) -> Option<String> {
+    objects
+        .into_iter()
+        .filter_map(|object| object.as_literal())
+        .find_map(|literal| normalize_whitespace(literal.as_str()))
+}
+
+/// Get the entries of the literals of a property, which can each be a comma separated list.
+fn get_list(objects: Vec<&Object>) -> Vec<String> {
+    objects
+        .into_iter()
+        .filter_map(|object| object.as_literal())
+        .flat_map(|literal| split_list(literal.as_str()))
+        .collect()
+}
+
+/// Split a comma separated list like "Kuchen, Backen" into its entries.
+fn split_list(text: &str) -> Vec<String> {
+    text.split(',').filter_map(normalize_whitespace).collect()
+}
+
+/// Parse an [ISO 8601 duration](https://en.wikipedia.org/wiki/ISO_8601#Durations) like "PT1H30M" into seconds.
+///
+/// Years and months are approximated with 365 and 30 days.
+/// Returns [`None`] if the text is no valid duration.
+fn parse_duration(text: &str) -> Option<i64> {
+    let rest = text.trim().strip_prefix('P')?;
+    let (date, time) = match rest.split_once('T') {
+        Some((date, time)) => (date, time),
+        None => (rest, ""),
+    };
+    let date_designators = [
+        ('Y', 365.0 * 86_400.0),
+        ('M', 30.0 * 86_400.0),
+        ('W', 7.0 * 86_400.0),
+        ('D', 86_400.0),
+    ];
+    let time_designators = [('H', 3_600.0), ('M', 60.0), ('S', 1.0)];
+    let mut seconds = 0.0;
+    let mut has_value = false;
+    for (part, designators) in [(date, &date_designators[..]), (time, &time_designators[..])] {
+        let mut rest = part;
+        for (designator, factor) in designators {
+            let Some((number, after_designator)) = rest.split_once(*designator) else {
+                continue;
+            };
+            let value: f64 = number.replace(',', ".").parse().ok()?;
+            seconds += value * factor;
+            has_value = true;
+            rest = after_designator;
+        }
+        if !rest.is_empty() {
+            return None;
+        }
+    }
+    if !has_value {
+        return None;
+    }
+    Some(seconds.round() as i64)
+}
+
+/// Split a leading decimal number like "12", "12.5" or "12,5" from a text and return it with the trimmed, lowercase rest.
+fn split_number(text: &str) -> Option<(f64, String)> {
+    let text = text.trim();
+    let end = text
+        .find(|char: char| !char.is_ascii_digit() && char != '.' && char != ',')
+        .unwrap_or(text.len());
+    let (number, rest) = text.split_at(end);
+    let number = number.replace(',', ".").parse().ok()?;
+    Some((number, rest.trim().to_lowercase()))
+}
+
+/// Parse a mass like "12 g", "12,5g" or "300 mg" into g.
+///
+/// A number without unit is taken as g.
+/// Returns [`None`] if the text has no number or an unknown unit.
+fn parse_mass(text: &str) -> Option<f64> {
+    let (number, unit) = split_number(text)?;
+    let units_per_gram = match unit.as_str() {
+        "" | "g" | "gram" | "grams" | "gramm" => 1.0,
+        "mg" | "milligram" | "milligrams" | "milligramm" => 1_000.0,
+        "µg" | "mcg" | "microgram" | "micrograms" => 1_000_000.0,
+        _ => return None,
+    };
+    Some(number / units_per_gram)
+}
+
+/// Parse an energy like "240 kcal", "240 calories" or "1000 kJ" into kcal.
+///
+/// A number without unit is taken as kcal.
+/// Returns [`None`] if the text has no number or an unknown unit.
+fn parse_energy(text: &str) -> Option<f64> {
+    let (number, unit) = split_number(text)?;
+    match unit.as_str() {
+        "" | "kcal" | "cal" | "calories" | "kalorien" | "kilocalories" => Some(number),
+        "kj" | "kilojoules" => Some(number / KILOJOULES_PER_KILOCALORIE),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("PT1H30M"), Some(5_400));
+        assert_eq!(parse_duration("PT90M"), Some(5_400));
+        assert_eq!(parse_duration("P1DT2H"), Some(93_600));
+        assert_eq!(parse_duration("P0Y0M0DT0H35M0.000S"), Some(2_100));
+        assert_eq!(parse_duration("PT0,5H"), Some(1_800));
+        assert_eq!(parse_duration("P2W"), Some(1_209_600));
+        assert_eq!(parse_duration(" PT20S "), Some(20));
+        assert_eq!(parse_duration("PT"), None);
+        assert_eq!(parse_duration("P"), None);
+        assert_eq!(parse_duration("30 Minuten"), None);
+        assert_eq!(parse_duration("PT30X"), None);
+        assert_eq!(parse_duration("PT30M1H"), None);
+    }
+
+    #[test]
+    fn test_parse_mass() {
+        assert_eq!(parse_mass("12 g"), Some(12.0));
+        assert_eq!(parse_mass("12,5g"), Some(12.5));
+        assert_eq!(parse_mass("300 mg"), Some(0.3));
+        assert_eq!(parse_mass("7"), Some(7.0));
+        assert_eq!(parse_mass("2 cups"), None);
+        assert_eq!(parse_mass("g"), None);
+    }
+
+    #[test]
+    fn test_parse_energy() {
+        assert_eq!(parse_energy("240 kcal"), Some(240.0));
+        assert_eq!(parse_energy("240 Calories"), Some(240.0));
+        assert_eq!(parse_energy("418.4 kJ").map(f64::round), Some(100.0));
+        assert_eq!(parse_energy("240"), Some(240.0));
+        assert_eq!(parse_energy("240 Punkte"), None);
+    }
+
+    #[test]
+    fn test_split_list() {
+        assert_eq!(
+            split_list("Kuchen,  Backen , ,Weihnachten"),
+            vec![
+                String::from("Kuchen"),
+                String::from("Backen"),
+                String::from("Weihnachten")
+            ]
+        );
+        assert_eq!(split_list(" "), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_tag_names() {
+        let details = ExternalRecipeDetails {
+            categories: vec![String::from("Dessert")],
+            cuisines: vec![String::from("Deutsch")],
+            keywords: vec![String::from("dessert"), String::from("Weihnachten")],
+            ..Default::default()
+        };
+        assert_eq!(
+            details.tag_names(),
+            vec!["Dessert", "Deutsch", "Weihnachten"]
+        );
+    }
+}
@@ -1,10 +1,14 @@
 use thiserror::Error;
 
+use crate::fetch::error::FetchError;
+
 #[derive(Debug, Error)]
 pub enum ExternalRecipeError {
     #[error(transparent)]
     Reqwest(#[from] reqwest::Error),
     #[error(transparent)]
+    Fetch(#[from] FetchError),
+    #[error(transparent)]
     Anyhow(#[from] anyhow::Error),
     #[error("The external recipe url \"{0}\" is not supported.")]
     UrlNotSupported(String),
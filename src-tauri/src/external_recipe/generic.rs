@@ -1,17 +1,50 @@
 use async_trait::async_trait;
+use json_ld::{
+    syntax::{Parse, Value},
+    RemoteDocument,
+};
+use schema_org_traits::json_ld_0_15::JsonLdStore;
 use url::Url;
 
-use crate::external_recipe::{error::ExternalRecipeError, ExternalRecipe};
+use crate::external_recipe::{
+    error::ExternalRecipeError, generic::json_ld_loader::FetchLoader, ExternalRecipe,
+};
 
+mod json_ld_loader;
+mod json_ld_scripts;
+mod microdata;
+mod recipe_card;
 mod yoast_schema_graph;
 
 /// Implementors implement the getting itself.
 #[async_trait]
 pub trait GenericExternalRecipeGetterTrait: Send + Sync {
+    /// Get the name of this implementor, which is stored as the getter of imported recipes.
+    fn name(&self) -> &str;
+
     /// Get the external recipe from the URL.
     async fn get(&self, url: Url) -> Result<Option<ExternalRecipe>, ExternalRecipeError>;
 }
 
 pub fn generic_external_recipe_getters() -> Vec<Box<dyn GenericExternalRecipeGetterTrait>> {
-    vec![Box::new(yoast_schema_graph::ExternalRecipeGetter)]
+    vec![
+        Box::new(recipe_card::ExternalRecipeGetter::WP_RECIPE_MAKER),
+        Box::new(recipe_card::ExternalRecipeGetter::TASTY_RECIPES),
+        Box::new(recipe_card::ExternalRecipeGetter::CREATE),
+        Box::new(yoast_schema_graph::ExternalRecipeGetter),
+        Box::new(json_ld_scripts::ExternalRecipeGetter),
+        Box::new(microdata::ExternalRecipeGetter),
+    ]
+}
+
+/// Parse a JSON-LD document and create an [`ExternalRecipe`] from the first recipe in it.
+///
+/// Remote contexts are loaded with a [`FetchLoader`].
+/// Returns [`None`] if the document is no valid JSON or contains no recipe.
+async fn parse_json_ld(json_ld_text: &str) -> Option<ExternalRecipe> {
+    let value = Value::parse_str(json_ld_text, |_| ()).ok()?;
+    let input = RemoteDocument::new(None, Some("application/ld+json".parse().unwrap()), value);
+    let mut loader = FetchLoader;
+    let json_ld_store = JsonLdStore::new(input, &mut loader, None).await;
+    ExternalRecipe::try_from_json_ld(&json_ld_store)
 }
@@ -0,0 +1,106 @@
+//! This module implements a [`Loader`] for the remote contexts of JSON-LD documents.
+//!
+//! The [schema.org](https://schema.org/) context, which nearly all recipes use, is bundled, see [`SCHEMA_ORG_CONTEXT`].
+//! All other contexts are fetched via [`crate::fetch::fetch_page`], like the pages themselves.
+
+use iref::IriBuf;
+use json_ld::{
+    future::{BoxFuture, FutureExt},
+    syntax::{Parse, Value},
+    Loader, LoadingResult, RemoteDocument,
+};
+use rdf_types::IriVocabularyMut;
+use url::Url;
+
+use crate::{external_recipe::error::ExternalRecipeError, fetch::fetch_page};
+
+/// This is the bundled context of schema.org.
+///
+/// It only contains the vocabulary and the aliases of the [official context](https://schema.org/docs/jsonldcontext.jsonld),
+/// the term definitions of the official context only declare the types of values, which the getters do not rely on.
+const SCHEMA_ORG_CONTEXT: &str = include_str!("schema_org_context.jsonld");
+
+pub struct FetchLoader;
+
+/// Check whether a URL references the context of schema.org, e.g. `"@context": "https://schema.org"`.
+fn is_schema_org_context(url: &Url) -> bool {
+    matches!(url.scheme(), "http" | "https")
+        && matches!(url.host_str(), Some("schema.org" | "www.schema.org"))
+        && matches!(
+            url.path(),
+            "/" | "/docs/jsonldcontext.json" | "/docs/jsonldcontext.jsonld"
+        )
+}
+
+async fn load_text(url: &str) -> Result<String, ExternalRecipeError> {
+    let url = Url::parse(url).map_err(|err| ExternalRecipeError::ParseError(err.to_string()))?;
+    if is_schema_org_context(&url) {
+        return Ok(String::from(SCHEMA_ORG_CONTEXT));
+    }
+    Ok(fetch_page(&url).await?.text())
+}
+
+impl Loader<IriBuf, ()> for FetchLoader {
+    type Output = Value<()>;
+    type Error = ExternalRecipeError;
+
+    fn load_with<'a>(
+        &'a mut self,
+        _vocabulary: &'a mut (impl Sync + Send + IriVocabularyMut<Iri = IriBuf>),
+        url: IriBuf,
+    ) -> BoxFuture<'a, LoadingResult<IriBuf, (), Value<()>, ExternalRecipeError>>
+    where
+        IriBuf: 'a,
+    {
+        async move {
+            let text = load_text(url.as_str()).await?;
+            let document = Value::parse_str(&text, |_| ()).map_err(|err| {
+                ExternalRecipeError::ParseError(format!(
+                    "The JSON-LD context \"{}\" is no valid JSON: {err}",
+                    url.as_str()
+                ))
+            })?;
+            Ok(RemoteDocument::new(
+                Some(url),
+                Some("application/ld+json".parse().unwrap()),
+                document,
+            ))
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external_recipe::generic::parse_json_ld;
+
+    #[test]
+    fn test_is_schema_org_context() {
+        assert!(is_schema_org_context(
+            &Url::parse("https://schema.org").unwrap()
+        ));
+        assert!(is_schema_org_context(
+            &Url::parse("http://schema.org/").unwrap()
+        ));
+        assert!(is_schema_org_context(
+            &Url::parse("https://schema.org/docs/jsonldcontext.jsonld").unwrap()
+        ));
+        assert!(!is_schema_org_context(
+            &Url::parse("https://schema.org/Recipe").unwrap()
+        ));
+        assert!(!is_schema_org_context(
+            &Url::parse("https://example.com/").unwrap()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_parse_json_ld() {
+        let external_recipe = parse_json_ld(
+            r#"{"@context": "https://schema.org", "@type": "Recipe", "name": "Zimtsterne"}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!(external_recipe.name, "Zimtsterne");
+    }
+}
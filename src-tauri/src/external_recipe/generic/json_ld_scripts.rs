@@ -0,0 +1,133 @@
+//! This module implements [`GenericExternalRecipeGetterTrait`] for any page with [JSON-LD](https://json-ld.org/) script elements.
+//!
+//! Pages can contain several JSON-LD script elements, so all of them are combined into one JSON-LD document.
+
+use async_trait::async_trait;
+use json_ld::syntax::{Parse, Value};
+use url::Url;
+
+use crate::{
+    external_recipe::{
+        error::ExternalRecipeError,
+        generic::{parse_json_ld, GenericExternalRecipeGetterTrait},
+        get_dom, ExternalRecipe,
+    },
+    scraper::{Dom, ParentNode},
+};
+
+pub struct ExternalRecipeGetter;
+
+async fn read_json_ld_scripts(dom: &Dom) -> Result<Vec<String>, ExternalRecipeError> {
+    let mut scripts = vec![];
+    for element in dom
+        .select_all("script[type=\"application/ld+json\"]")
+        .await?
+    {
+        scripts.push(element.text_content().await?);
+    }
+    Ok(scripts)
+}
+
+/// Combine JSON-LD scripts into one JSON-LD document, which is an array of all scripts.
+///
+/// Scripts which are no valid JSON are skipped.
+/// Returns [`None`] if no script is valid JSON.
+fn combine_json_ld_scripts(scripts: &[String]) -> Option<String> {
+    let valid_scripts: Vec<&str> = scripts
+        .iter()
+        .map(|script| script.trim())
+        .filter(|script| Value::parse_str(script, |_| ()).is_ok())
+        .collect();
+    if valid_scripts.is_empty() {
+        return None;
+    }
+    Some(format!("[{}]", valid_scripts.join(",")))
+}
+
+/// Read the first recipe of the combined JSON-LD scripts of a DOM.
+///
+/// Returns [`None`] if no script is valid JSON or the scripts contain no recipe.
+pub async fn read_external_recipe(
+    dom: &Dom,
+) -> Result<Option<ExternalRecipe>, ExternalRecipeError> {
+    let scripts = read_json_ld_scripts(dom).await?;
+    let Some(json_ld_text) = combine_json_ld_scripts(&scripts) else {
+        return Ok(None);
+    };
+    Ok(parse_json_ld(&json_ld_text).await)
+}
+
+#[async_trait]
+impl GenericExternalRecipeGetterTrait for ExternalRecipeGetter {
+    fn name(&self) -> &str {
+        "json_ld_scripts"
+    }
+
+    async fn get(&self, url: Url) -> Result<Option<ExternalRecipe>, ExternalRecipeError> {
+        let dom = get_dom(url).await?;
+        read_external_recipe(&dom).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::external_recipe::{
+        tests::{assert_expected_gets, ExpectedGet},
+        ExternalRecipeStep,
+    };
+
+    #[test]
+    fn test_combine_json_ld_scripts() {
+        let scripts = vec![
+            String::from(
+                "\n{\"@context\": \"https://schema.org\", \"@type\": \"WebSite\", \"name\": \"Rezepte\"}\n",
+            ),
+            String::from("{kein JSON"),
+            String::from(
+                "{\"@context\": \"https://schema.org\", \"@graph\": [{\"@type\": \"Recipe\", \"name\": \"Stollen\"}]}",
+            ),
+        ];
+        assert_eq!(
+            combine_json_ld_scripts(&scripts),
+            Some(String::from(
+                "[{\"@context\": \"https://schema.org\", \"@type\": \"WebSite\", \"name\": \"Rezepte\"},\
+                {\"@context\": \"https://schema.org\", \"@graph\": [{\"@type\": \"Recipe\", \"name\": \"Stollen\"}]}]"
+            ))
+        );
+        assert_eq!(combine_json_ld_scripts(&[String::from("{kein JSON")]), None);
+        assert_eq!(combine_json_ld_scripts(&[]), None);
+    }
+
+    #[tokio::test]
+    async fn test_get_hand_written() {
+        assert_expected_gets(vec![ExpectedGet {
+            url: String::from("https://example.com/rezepte/zimtschnecken"),
+            external_recipe: ExternalRecipe {
+                name: String::from("Zimtschnecken"),
+                ingredients: vec![
+                    String::from("500 g Mehl"),
+                    String::from("80 g Zucker"),
+                    String::from("2 EL Zimt"),
+                ],
+                files: vec![String::from("https://example.com/bilder/zimtschnecken.jpg")],
+                steps: vec![
+                    ExternalRecipeStep {
+                        description: String::from("Den Teig kneten."),
+                        ..Default::default()
+                    },
+                    ExternalRecipeStep {
+                        description: String::from(
+                            "Den Teig mit Zimt und Zucker bestreuen, aufrollen und backen.",
+                        ),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            },
+        }])
+        .await;
+    }
+}
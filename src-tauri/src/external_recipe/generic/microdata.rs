@@ -0,0 +1,559 @@
+//! This module implements [`GenericExternalRecipeGetterTrait`] for any page with [microdata](https://html.spec.whatwg.org/multipage/microdata.html)
+//! or [RDFa Lite](https://www.w3.org/TR/rdfa-lite/) markup.
+//!
+//! The items on the page are converted into a JSON-LD document with expanded IRIs,
+//! so they end up in the same RDF model as the JSON-LD of other pages.
+
+use std::collections::VecDeque;
+
+use async_trait::async_trait;
+use serde_json::{json, Map, Value};
+use url::Url;
+
+use crate::{
+    external_recipe::{
+        error::ExternalRecipeError,
+        generic::{parse_json_ld, GenericExternalRecipeGetterTrait},
+        get_dom, ExternalRecipe,
+    },
+    scraper::{Dom, Element, ParentNode},
+};
+
+pub struct ExternalRecipeGetter;
+
+/// The vocabulary relative types and properties are expanded with.
+///
+/// This is the vocabulary the context of schema.org JSON-LD expands to.
+const SCHEMA_ORG_VOCABULARY: &str = "http://schema.org/";
+
+/// The attributes and selectors of a markup syntax.
+struct Syntax {
+    /// Selects items, which are no property of another item.
+    top_level_item_selector: &'static str,
+    /// Selects the properties of the scoped item, which are no items themselves.
+    value_property_selector: &'static str,
+    /// Selects the properties of the scoped item, which are items themselves.
+    item_property_selector: &'static str,
+    type_attribute: &'static str,
+    property_attribute: &'static str,
+    id_attribute: &'static str,
+}
+
+const MICRODATA: Syntax = Syntax {
+    top_level_item_selector: "[itemscope]:not([itemprop])",
+    value_property_selector: ":scope [itemprop]:not([itemscope]):not(:scope [itemscope] [itemprop])",
+    item_property_selector: ":scope [itemprop][itemscope]:not(:scope [itemscope] [itemprop])",
+    type_attribute: "itemtype",
+    property_attribute: "itemprop",
+    id_attribute: "itemid",
+};
+
+const RDFA: Syntax = Syntax {
+    top_level_item_selector: "[typeof]:not([property])",
+    value_property_selector: ":scope [property]:not([typeof]):not(:scope [typeof] [property])",
+    item_property_selector: ":scope [property][typeof]:not(:scope [typeof] [property])",
+    type_attribute: "typeof",
+    property_attribute: "property",
+    id_attribute: "resource",
+};
+
+/// An item with its types and properties, which are expanded IRIs.
+#[derive(Debug, Default, PartialEq)]
+struct Item {
+    id: Option<String>,
+    types: Vec<String>,
+    properties: Vec<(String, PropertyValue)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum PropertyValue {
+    Text(String),
+    Url(String),
+    /// The index of another item.
+    Item(usize),
+}
+
+/// Expand a type or property to an IRI.
+///
+/// Terms with the `schema` prefix and relative terms are expanded with [`SCHEMA_ORG_VOCABULARY`],
+/// other absolute IRIs are kept as they are.
+fn expand_term(term: &str) -> String {
+    if let Some(name) = term
+        .strip_prefix("https://schema.org/")
+        .or_else(|| term.strip_prefix("schema:"))
+    {
+        return format!("{SCHEMA_ORG_VOCABULARY}{name}");
+    }
+    if term.contains(':') {
+        return term.to_string();
+    }
+    format!("{SCHEMA_ORG_VOCABULARY}{term}")
+}
+
+/// Read the value of a property, which is no item.
+///
+/// Like the microdata property value, this prefers the machine readable attributes over the text content.
+async fn read_value(element: &Element, url: &Url) -> Result<PropertyValue, ExternalRecipeError> {
+    for attribute in ["content", "datetime"] {
+        let value = element.get_attribute(attribute).await?;
+        if !value.is_empty() {
+            return Ok(PropertyValue::Text(value));
+        }
+    }
+    for attribute in ["src", "href", "data", "resource"] {
+        let value = element.get_attribute(attribute).await?;
+        if !value.is_empty() {
+            return Ok(match url.join(&value) {
+                Ok(value_url) => PropertyValue::Url(value_url.to_string()),
+                Err(_) => PropertyValue::Text(value),
+            });
+        }
+    }
+    let text_content = element.text_content().await?;
+    Ok(PropertyValue::Text(
+        text_content
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" "),
+    ))
+}
+
+/// Read all items of a syntax from the DOM and append them to `items`.
+///
+/// Nested items are appended as well and referenced by their index.
+async fn read_items(
+    dom: &Dom,
+    syntax: &Syntax,
+    url: &Url,
+    items: &mut Vec<Item>,
+) -> Result<(), ExternalRecipeError> {
+    let mut pending_items = VecDeque::new();
+    for element in dom.select_all(syntax.top_level_item_selector).await? {
+        pending_items.push_back((element, items.len()));
+        items.push(Item::default());
+    }
+    while let Some((element, index)) = pending_items.pop_front() {
+        let id = element.get_attribute(syntax.id_attribute).await?;
+        if !id.is_empty() {
+            items[index].id = Some(url.join(&id).map_or(id, |id_url| id_url.to_string()));
+        }
+        items[index].types = element
+            .get_attribute(syntax.type_attribute)
+            .await?
+            .split_whitespace()
+            .map(expand_term)
+            .collect();
+        for property_element in element.select_all(syntax.value_property_selector).await? {
+            let names = property_element
+                .get_attribute(syntax.property_attribute)
+                .await?;
+            let value = read_value(&property_element, url).await?;
+            for name in names.split_whitespace() {
+                items[index]
+                    .properties
+                    .push((expand_term(name), value.clone()));
+            }
+        }
+        for property_element in element.select_all(syntax.item_property_selector).await? {
+            let names = property_element
+                .get_attribute(syntax.property_attribute)
+                .await?;
+            let nested_index = items.len();
+            items.push(Item::default());
+            for name in names.split_whitespace() {
+                items[index]
+                    .properties
+                    .push((expand_term(name), PropertyValue::Item(nested_index)));
+            }
+            pending_items.push_back((property_element, nested_index));
+        }
+    }
+    Ok(())
+}
+
+/// Convert items into a JSON-LD document with one node object per item.
+///
+/// Items without an id get a blank node identifier.
+fn items_to_json_ld(items: &[Item]) -> String {
+    let node_id = |index: usize| {
+        items[index]
+            .id
+            .clone()
+            .unwrap_or_else(|| format!("_:b{index}"))
+    };
+    let nodes = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let mut node = Map::new();
+            node.insert(String::from("@id"), json!(node_id(index)));
+            if !item.types.is_empty() {
+                node.insert(String::from("@type"), json!(item.types));
+            }
+            for (name, value) in &item.properties {
+                let value = match value {
+                    PropertyValue::Text(text) => json!(text),
+                    PropertyValue::Url(url) => json!({ "@id": url }),
+                    PropertyValue::Item(index) => json!({ "@id": node_id(*index) }),
+                };
+                if let Value::Array(values) = node.entry(name.clone()).or_insert(json!([])) {
+                    values.push(value);
+                }
+            }
+            Value::Object(node)
+        })
+        .collect();
+    Value::Array(nodes).to_string()
+}
+
+#[async_trait]
+impl GenericExternalRecipeGetterTrait for ExternalRecipeGetter {
+    fn name(&self) -> &str {
+        "microdata"
+    }
+
+    async fn get(&self, url: Url) -> Result<Option<ExternalRecipe>, ExternalRecipeError> {
+        let dom = get_dom(url.clone()).await?;
+        let mut items = vec![];
+        read_items(&dom, &MICRODATA, &url, &mut items).await?;
+        read_items(&dom, &RDFA, &url, &mut items).await?;
+        if items.is_empty() {
+            return Ok(None);
+        }
+        Ok(parse_json_ld(&items_to_json_ld(&items)).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        external_recipe::{
+            details::{ExternalRecipeDetails, ExternalRecipeNutrition},
+            tests::{assert_expected_gets, ExpectedGet},
+            ExternalRecipeStep,
+        },
+        scraper::ScraperBackend,
+    };
+
+    fn text(name: &str, text: &str) -> (String, PropertyValue) {
+        (expand_term(name), PropertyValue::Text(text.to_string()))
+    }
+
+    fn item(name: &str, index: usize) -> (String, PropertyValue) {
+        (expand_term(name), PropertyValue::Item(index))
+    }
+
+    #[test]
+    fn test_expand_term() {
+        assert_eq!(expand_term("Recipe"), "http://schema.org/Recipe");
+        assert_eq!(expand_term("schema:name"), "http://schema.org/name");
+        assert_eq!(
+            expand_term("https://schema.org/HowToStep"),
+            "http://schema.org/HowToStep"
+        );
+        assert_eq!(
+            expand_term("http://schema.org/recipeIngredient"),
+            "http://schema.org/recipeIngredient"
+        );
+        assert_eq!(
+            expand_term("https://example.com/vocabulary#rating"),
+            "https://example.com/vocabulary#rating"
+        );
+    }
+
+    #[test]
+    fn test_items_to_json_ld() {
+        let items = vec![
+            Item {
+                id: None,
+                types: vec![expand_term("Recipe")],
+                properties: vec![
+                    text("name", "Stollen"),
+                    (
+                        expand_term("image"),
+                        PropertyValue::Url(String::from("https://example.com/stollen.jpg")),
+                    ),
+                    item("recipeInstructions", 1),
+                ],
+            },
+            Item {
+                id: Some(String::from("https://example.com/stollen#kneten")),
+                types: vec![expand_term("HowToStep")],
+                properties: vec![text("text", "Kneten.")],
+            },
+        ];
+        assert_eq!(
+            serde_json::from_str::<Value>(&items_to_json_ld(&items)).unwrap(),
+            json!([
+                {
+                    "@id": "_:b0",
+                    "@type": ["http://schema.org/Recipe"],
+                    "http://schema.org/name": ["Stollen"],
+                    "http://schema.org/image": [{ "@id": "https://example.com/stollen.jpg" }],
+                    "http://schema.org/recipeInstructions": [
+                        { "@id": "https://example.com/stollen#kneten" }
+                    ]
+                },
+                {
+                    "@id": "https://example.com/stollen#kneten",
+                    "@type": ["http://schema.org/HowToStep"],
+                    "http://schema.org/text": ["Kneten."]
+                }
+            ])
+        );
+    }
+
+    const HOW_TO_STEP_HTML: &str = r#"<div itemscope itemtype="https://schema.org/Recipe">
+  <h1 itemprop="name">Zimtschnecken</h1>
+  <ul>
+    <li itemprop="recipeIngredient">500 g Mehl</li>
+    <li itemprop="recipeIngredient">2 EL Zimt</li>
+  </ul>
+  <ol>
+    <li itemprop="recipeInstructions" itemscope itemtype="https://schema.org/HowToStep">
+      <img itemprop="image" src="/teig.jpg">
+      <p itemprop="text">Teig kneten.</p>
+    </li>
+    <li itemprop="recipeInstructions" itemscope itemtype="https://schema.org/HowToStep">
+      <p itemprop="text">Teig rollen.</p>
+    </li>
+  </ol>
+  <p itemprop="description">Fluffig und saftig.</p>
+  <meta itemprop="prepTime" content="PT30M">
+  <meta itemprop="totalTime" content="PT1H45M">
+  <span itemprop="recipeYield">12 Stück</span>
+  <span itemprop="recipeCategory">Gebäck</span>
+  <meta itemprop="keywords" content="Zimt, Hefeteig">
+  <span itemprop="author" itemscope itemtype="https://schema.org/Person">
+    <span itemprop="name">Oma Erna</span>
+  </span>
+  <div itemprop="nutrition" itemscope itemtype="https://schema.org/NutritionInformation">
+    <span itemprop="calories">240 kcal</span>
+    <span itemprop="fatContent">9 g</span>
+    <span itemprop="sodiumContent">200 mg</span>
+  </div>
+</div>"#;
+
+    /// Items as read from [`HOW_TO_STEP_HTML`].
+    fn how_to_step_items() -> Vec<Item> {
+        vec![
+            Item {
+                id: None,
+                types: vec![expand_term("https://schema.org/Recipe")],
+                properties: vec![
+                    text("name", "Zimtschnecken"),
+                    text("recipeIngredient", "500 g Mehl"),
+                    text("recipeIngredient", "2 EL Zimt"),
+                    text("description", "Fluffig und saftig."),
+                    text("prepTime", "PT30M"),
+                    text("totalTime", "PT1H45M"),
+                    text("recipeYield", "12 Stück"),
+                    text("recipeCategory", "Gebäck"),
+                    text("keywords", "Zimt, Hefeteig"),
+                    item("recipeInstructions", 1),
+                    item("recipeInstructions", 2),
+                    item("author", 3),
+                    item("nutrition", 4),
+                ],
+            },
+            Item {
+                id: None,
+                types: vec![expand_term("https://schema.org/HowToStep")],
+                properties: vec![
+                    (
+                        expand_term("image"),
+                        PropertyValue::Url(String::from("https://example.com/teig.jpg")),
+                    ),
+                    text("text", "Teig kneten."),
+                ],
+            },
+            Item {
+                id: None,
+                types: vec![expand_term("https://schema.org/HowToStep")],
+                properties: vec![text("text", "Teig rollen.")],
+            },
+            Item {
+                id: None,
+                types: vec![expand_term("https://schema.org/Person")],
+                properties: vec![text("name", "Oma Erna")],
+            },
+            Item {
+                id: None,
+                types: vec![expand_term("https://schema.org/NutritionInformation")],
+                properties: vec![
+                    text("calories", "240 kcal"),
+                    text("fatContent", "9 g"),
+                    text("sodiumContent", "200 mg"),
+                ],
+            },
+        ]
+    }
+
+    const HOW_TO_SECTION_HTML: &str = r#"<div vocab="https://schema.org/" typeof="Recipe">
+  <h1 property="name">Zimtschnecken</h1>
+  <section property="recipeInstructions" typeof="HowToSection">
+    <h2 property="name">Teig</h2>
+    <p property="itemListElement" typeof="HowToStep"><span property="text">Teig kneten.</span></p>
+    <p property="itemListElement" typeof="HowToStep"><span property="text">Teig rollen.</span></p>
+  </section>
+  <section property="recipeInstructions" typeof="HowToSection">
+    <h2 property="name">Backen</h2>
+    <p property="itemListElement" typeof="HowToStep"><span property="text">Backen.</span></p>
+  </section>
+</div>"#;
+
+    /// Items as read from [`HOW_TO_SECTION_HTML`].
+    fn how_to_section_items() -> Vec<Item> {
+        let how_to_step = |description: &str| Item {
+            id: None,
+            types: vec![expand_term("HowToStep")],
+            properties: vec![text("text", description)],
+        };
+        vec![
+            Item {
+                id: None,
+                types: vec![expand_term("Recipe")],
+                properties: vec![
+                    text("name", "Zimtschnecken"),
+                    item("recipeInstructions", 1),
+                    item("recipeInstructions", 2),
+                ],
+            },
+            Item {
+                id: None,
+                types: vec![expand_term("HowToSection")],
+                properties: vec![
+                    text("name", "Teig"),
+                    item("itemListElement", 3),
+                    item("itemListElement", 4),
+                ],
+            },
+            Item {
+                id: None,
+                types: vec![expand_term("HowToSection")],
+                properties: vec![text("name", "Backen"), item("itemListElement", 5)],
+            },
+            how_to_step("Teig kneten."),
+            how_to_step("Teig rollen."),
+            how_to_step("Backen."),
+        ]
+    }
+
+    /// Read the items of both syntaxes with the native scraper backend.
+    async fn read_native_items(html: &str) -> Vec<Item> {
+        let dom = Dom::create(String::from(html), ScraperBackend::Native)
+            .await
+            .unwrap();
+        let url = Url::parse("https://example.com/zimtschnecken").unwrap();
+        let mut items = vec![];
+        read_items(&dom, &MICRODATA, &url, &mut items)
+            .await
+            .unwrap();
+        read_items(&dom, &RDFA, &url, &mut items).await.unwrap();
+        items
+    }
+
+    #[tokio::test]
+    async fn test_read_items() {
+        assert_eq!(
+            read_native_items(HOW_TO_STEP_HTML).await,
+            how_to_step_items()
+        );
+        assert_eq!(
+            read_native_items(HOW_TO_SECTION_HTML).await,
+            how_to_section_items()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_parse_how_to_step_items() {
+        assert_eq!(
+            parse_json_ld(&items_to_json_ld(&how_to_step_items())).await,
+            Some(ExternalRecipe {
+                name: String::from("Zimtschnecken"),
+                ingredients: vec![String::from("500 g Mehl"), String::from("2 EL Zimt")],
+                files: vec![],
+                steps: vec![
+                    ExternalRecipeStep {
+                        description: String::from("Teig kneten."),
+                        files: vec![String::from("https://example.com/teig.jpg")],
+                        ..Default::default()
+                    },
+                    ExternalRecipeStep {
+                        description: String::from("Teig rollen."),
+                        ..Default::default()
+                    },
+                ],
+                details: ExternalRecipeDetails {
+                    description: Some(String::from("Fluffig und saftig.")),
+                    author: Some(String::from("Oma Erna")),
+                    prep_time: Some(1_800),
+                    cook_time: None,
+                    total_time: Some(6_300),
+                    recipe_yield: Some(String::from("12 Stück")),
+                    categories: vec![String::from("Gebäck")],
+                    cuisines: vec![],
+                    keywords: vec![String::from("Zimt"), String::from("Hefeteig")],
+                    nutrition: Some(ExternalRecipeNutrition {
+                        energy: Some(240.0),
+                        fat: Some(9.0),
+                        salt: Some(0.5),
+                        ..Default::default()
+                    }),
+                },
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_parse_how_to_section_items() {
+        assert_eq!(
+            parse_json_ld(&items_to_json_ld(&how_to_section_items())).await,
+            Some(ExternalRecipe {
+                name: String::from("Zimtschnecken"),
+                steps: vec![
+                    ExternalRecipeStep {
+                        description: String::from("Teig kneten."),
+                        ..Default::default()
+                    },
+                    ExternalRecipeStep {
+                        description: String::from("Teig rollen."),
+                        ..Default::default()
+                    },
+                    ExternalRecipeStep {
+                        description: String::from("Backen."),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_hand_written() {
+        assert_expected_gets(vec![ExpectedGet {
+            url: String::from("https://example.com/rezepte/stollen"),
+            external_recipe: ExternalRecipe {
+                name: String::from("Stollen"),
+                ingredients: vec![String::from("1 kg Mehl"), String::from("500 g Rosinen")],
+                steps: vec![
+                    ExternalRecipeStep {
+                        description: String::from("Die Rosinen in Rum einweichen."),
+                        files: vec![String::from("https://example.com/bilder/rosinen.jpg")],
+                        ..Default::default()
+                    },
+                    ExternalRecipeStep {
+                        description: String::from("Den Stollen backen."),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            },
+        }])
+        .await;
+    }
+}
@@ -0,0 +1,694 @@
+//! This module implements [`GenericExternalRecipeGetterTrait`] for the recipe cards of WordPress recipe plugins,
+//! which are [WP Recipe Maker](https://bootstrapped.ventures/wp-recipe-maker/), [Tasty Recipes](https://www.wptasty.com/tasty-recipes)
+//! and [Create](https://www.mediavine.com/mediavine-recipe-cards-create/).
+//!
+//! The plugins also render JSON-LD, which is preferred for everything but the ingredients.
+//! The ingredients are read from the recipe card, because the plugins separate their amount, unit and name there,
+//! so that they are imported as recipe step ingredients of the first step without parsing their text.
+
+use async_trait::async_trait;
+use indexmap::IndexSet;
+use url::Url;
+
+use crate::{
+    external_recipe::{
+        error::ExternalRecipeError,
+        generic::{json_ld_scripts, GenericExternalRecipeGetterTrait},
+        get_dom, ExternalRecipe, ExternalRecipeIngredient, ExternalRecipeStep,
+    },
+    ingredient_parser::{normalize_whitespace, parse_quantity, Quantity},
+    scraper::{Element, ParentNode},
+};
+
+/// How a plugin separates the parts of an ingredient.
+enum IngredientParts {
+    /// Each part is a child element.
+    Elements {
+        amount: &'static str,
+        unit: &'static str,
+        name: &'static str,
+        notes: &'static str,
+    },
+    /// A child element has the amount and unit as `data-amount` and `data-unit` attributes, the rest of the text is the name.
+    DataAttributes { amount: &'static str },
+    /// The parts are not separated.
+    Unseparated,
+}
+
+/// The selectors of the stable CSS classes a plugin renders its recipe card with.
+struct Plugin {
+    name: &'static str,
+    /// Selects the recipe card, only the first one of a page is read.
+    card: &'static str,
+    title: &'static str,
+    ingredients: &'static str,
+    ingredient_parts: IngredientParts,
+    instructions: &'static str,
+    images: &'static str,
+}
+
+const WP_RECIPE_MAKER: Plugin = Plugin {
+    name: "wp_recipe_maker",
+    card: ".wprm-recipe",
+    title: ".wprm-recipe-name",
+    ingredients: ".wprm-recipe-ingredient",
+    ingredient_parts: IngredientParts::Elements {
+        amount: ".wprm-recipe-ingredient-amount",
+        unit: ".wprm-recipe-ingredient-unit",
+        name: ".wprm-recipe-ingredient-name",
+        notes: ".wprm-recipe-ingredient-notes",
+    },
+    instructions: ".wprm-recipe-instruction-text",
+    images: ".wprm-recipe-image img",
+};
+
+const TASTY_RECIPES: Plugin = Plugin {
+    name: "tasty_recipes",
+    card: ".tasty-recipes",
+    title: ".tasty-recipes-title",
+    ingredients: ".tasty-recipes-ingredients li",
+    ingredient_parts: IngredientParts::DataAttributes {
+        amount: "[data-amount]",
+    },
+    instructions: ".tasty-recipes-instructions li",
+    images: ".tasty-recipes-image img",
+};
+
+const CREATE: Plugin = Plugin {
+    name: "mediavine_create",
+    card: ".mv-create-card",
+    title: ".mv-create-title",
+    ingredients: ".mv-create-ingredients li",
+    ingredient_parts: IngredientParts::Unseparated,
+    instructions: ".mv-create-instructions li",
+    images: "img.mv-create-image",
+};
+
+pub struct ExternalRecipeGetter(&'static Plugin);
+
+impl ExternalRecipeGetter {
+    pub const WP_RECIPE_MAKER: Self = Self(&WP_RECIPE_MAKER);
+    pub const TASTY_RECIPES: Self = Self(&TASTY_RECIPES);
+    pub const CREATE: Self = Self(&CREATE);
+}
+
+/// Join the parts of an ingredient into its text, e.g. "500 g Putenbrust (Oder Hähnchenbrust)".
+fn join_ingredient_parts(amount: &str, unit: &str, name: &str, notes: &str) -> String {
+    let notes = normalize_whitespace(notes.trim().trim_start_matches('(').trim_end_matches(')'));
+    [amount, unit, name]
+        .into_iter()
+        .filter_map(normalize_whitespace)
+        .chain(notes.map(|notes| format!("({notes})")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Create an [`ExternalRecipeIngredient`] from the separated parts of an ingredient.
+///
+/// Returns [`None`] if the name is blank or the amount is no exact quantity like "Etwas" or "3-4", so that the ingredient is kept as text.
+/// A range is kept as text, because its quantity could not be scaled with the recipe.
+fn parse_ingredient_parts(
+    amount: &str,
+    unit: &str,
+    name: &str,
+    notes: &str,
+) -> Option<ExternalRecipeIngredient> {
+    let name = normalize_whitespace(name)?;
+    let quantity = match normalize_whitespace(amount) {
+        Some(amount) => match parse_quantity(&amount) {
+            Some((Quantity::Exact(quantity), rest)) if rest.trim().is_empty() => Some(quantity),
+            _ => return None,
+        },
+        None => None,
+    };
+    Some(ExternalRecipeIngredient {
+        quantity,
+        unit: normalize_whitespace(unit),
+        name,
+        quality: normalize_whitespace(notes.trim().trim_start_matches('(').trim_end_matches(')')),
+    })
+}
+
+/// Get the text content of the first element matching a selector inside an element.
+async fn select_text(element: &Element, selector: &str) -> Result<String, ExternalRecipeError> {
+    let Some(selected_element) = element.select(selector).await? else {
+        return Ok(String::new());
+    };
+    Ok(selected_element.text_content().await?)
+}
+
+/// Read an ingredient of a recipe card as text and, if the plugin separates its parts, as [`ExternalRecipeIngredient`].
+async fn read_ingredient(
+    element: &Element,
+    ingredient_parts: &IngredientParts,
+) -> Result<(String, Option<ExternalRecipeIngredient>), ExternalRecipeError> {
+    let text_content = element.text_content().await?;
+    match ingredient_parts {
+        IngredientParts::Elements {
+            amount,
+            unit,
+            name,
+            notes,
+        } => {
+            let amount = select_text(element, amount).await?;
+            let unit = select_text(element, unit).await?;
+            let name = select_text(element, name).await?;
+            let notes = select_text(element, notes).await?;
+            if name.trim().is_empty() {
+                return Ok((text_content, None));
+            }
+            Ok((
+                join_ingredient_parts(&amount, &unit, &name, &notes),
+                parse_ingredient_parts(&amount, &unit, &name, &notes),
+            ))
+        }
+        IngredientParts::DataAttributes { amount } => {
+            let Some(amount_element) = element.select(amount).await? else {
+                return Ok((text_content, None));
+            };
+            let amount_text = normalize_whitespace(&amount_element.text_content().await?);
+            let text = normalize_whitespace(&text_content).unwrap_or_default();
+            let name_option = amount_text
+                .as_deref()
+                .and_then(|amount_text| text.strip_prefix(amount_text));
+            let Some(name) = name_option else {
+                return Ok((text_content, None));
+            };
+            let amount = amount_element.get_attribute("data-amount").await?;
+            let unit = amount_element.get_attribute("data-unit").await?;
+            let parsed_ingredient_option = parse_ingredient_parts(&amount, &unit, name, "");
+            Ok((text, parsed_ingredient_option))
+        }
+        IngredientParts::Unseparated => Ok((text_content, None)),
+    }
+}
+
+/// Read the recipe card without its ingredients, which is used if the page has no JSON-LD recipe.
+async fn read_card(
+    card: &Element,
+    plugin: &Plugin,
+    url: &Url,
+) -> Result<ExternalRecipe, ExternalRecipeError> {
+    let mut steps = vec![];
+    for instruction_element in card.select_all(plugin.instructions).await? {
+        let Some(description) = normalize_whitespace(&instruction_element.text_content().await?)
+        else {
+            continue;
+        };
+        steps.push(ExternalRecipeStep {
+            description,
+            ..Default::default()
+        });
+    }
+    let mut files = IndexSet::new();
+    for image_element in card.select_all(plugin.images).await? {
+        let src = image_element.get_attribute("src").await?;
+        if src.trim().is_empty() {
+            continue;
+        }
+        if let Ok(image_url) = url.join(src.trim()) {
+            files.insert(image_url.to_string());
+        }
+    }
+    Ok(ExternalRecipe {
+        name: normalize_whitespace(&select_text(card, plugin.title).await?).unwrap_or_default(),
+        files: files.into_iter().collect(),
+        steps,
+        ..Default::default()
+    })
+}
+
+#[async_trait]
+impl GenericExternalRecipeGetterTrait for ExternalRecipeGetter {
+    fn name(&self) -> &str {
+        self.0.name
+    }
+
+    async fn get(&self, url: Url) -> Result<Option<ExternalRecipe>, ExternalRecipeError> {
+        let plugin = self.0;
+        let dom = get_dom(url.clone()).await?;
+        let Some(card) = dom.select(plugin.card).await? else {
+            return Ok(None);
+        };
+        let mut external_recipe = match json_ld_scripts::read_external_recipe(&dom).await? {
+            Some(external_recipe) => external_recipe,
+            None => read_card(&card, plugin, &url).await?,
+        };
+        let mut ingredients = vec![];
+        // repeated ingredients are moved to the drafts of the step on import, see `normalize_content`
+        let mut parsed_ingredients = vec![];
+        for ingredient_element in card.select_all(plugin.ingredients).await? {
+            let (text, parsed_ingredient_option) =
+                read_ingredient(&ingredient_element, &plugin.ingredient_parts).await?;
+            let Some(text) = normalize_whitespace(&text) else {
+                continue;
+            };
+            match parsed_ingredient_option {
+                Some(parsed_ingredient) if !external_recipe.steps.is_empty() => {
+                    parsed_ingredients.push(parsed_ingredient);
+                }
+                _ => ingredients.push(text),
+            }
+        }
+        external_recipe.ingredients = ingredients;
+        if let Some(first_step) = external_recipe.steps.first_mut() {
+            first_step.parsed_ingredients = parsed_ingredients;
+        }
+        Ok(Some(external_recipe))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::external_recipe::tests::{assert_expected_gets, ExpectedGet};
+
+    #[test]
+    fn test_join_ingredient_parts() {
+        assert_eq!(
+            join_ingredient_parts("500", "g", "Putenbrust", "(Oder Hähnchenbrust)"),
+            "500 g Putenbrust (Oder Hähnchenbrust)"
+        );
+        assert_eq!(join_ingredient_parts(" 1 ", "", "Eigelb", ""), "1 Eigelb");
+    }
+
+    #[test]
+    fn test_parse_ingredient_parts() {
+        assert_eq!(
+            parse_ingredient_parts("4", "EL", "Wasser", "(eiskalt)"),
+            Some(ExternalRecipeIngredient {
+                quantity: Some(4.0),
+                unit: Some(String::from("EL")),
+                name: String::from("Wasser"),
+                quality: Some(String::from("eiskalt")),
+            })
+        );
+        assert_eq!(parse_ingredient_parts("3-4", "EL", "Wasser", ""), None);
+        assert_eq!(
+            parse_ingredient_parts("½", "", "Bund  Petersilie", ""),
+            Some(ExternalRecipeIngredient {
+                quantity: Some(0.5),
+                name: String::from("Bund Petersilie"),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            parse_ingredient_parts("", "", "Öl", ""),
+            Some(ExternalRecipeIngredient {
+                name: String::from("Öl"),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            parse_ingredient_parts("Etwas", "", "Schnittlauch", ""),
+            None
+        );
+        assert_eq!(parse_ingredient_parts("1", "EL", " ", ""), None);
+    }
+
+    fn expected_gets() -> Vec<ExpectedGet> {
+        vec![
+            ExpectedGet {
+                url: String::from("https://instakoch.de/gyros-mit-reis-und-salat/"),
+                external_recipe: ExternalRecipe {
+                    name: "Gyros mit Reis und Salat".to_string(),
+                    ingredients: vec!["Etwas Schnittlauch (Zum Garnieren.)".to_string()],
+                    files: vec![
+                        "https://instakoch.de/wp-content/uploads/2019/01/IMG_20190121_185416_824-1-e1549394421544.jpg".to_string(),
+                        "https://instakoch.de/wp-content/uploads/2019/01/IMG_20190121_185416_824-1-e1549394421544-500x500.jpg".to_string(),
+                        "https://instakoch.de/wp-content/uploads/2019/01/IMG_20190121_185416_824-1-e1549394421544-500x375.jpg".to_string(),
+                        "https://instakoch.de/wp-content/uploads/2019/01/IMG_20190121_185416_824-1-e1549394421544-480x270.jpg".to_string(),
+                    ],
+                    steps: vec![
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "Das Fleisch in dünne Streifen schneiden.".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![
+                                ExternalRecipeIngredient {
+                                    quantity: Some(500.0),
+                                    unit: Some("g".to_string()),
+                                    name: "Putenbrust".to_string(),
+                                    quality: Some("Oder Hähnchenbrust".to_string()),
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: Some(1.0),
+                                    unit: Some("EL".to_string()),
+                                    name: "Gyrosgewürz".to_string(),
+                                    quality: None,
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: None,
+                                    unit: None,
+                                    name: "Salz und Pfeffer".to_string(),
+                                    quality: Some("nach Geschmack".to_string()),
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: Some(1.0),
+                                    unit: Some("TL".to_string()),
+                                    name: "Zitronensaft".to_string(),
+                                    quality: None,
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: Some(1.0),
+                                    unit: Some("TL".to_string()),
+                                    name: "Zucker".to_string(),
+                                    quality: None,
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: Some(4.0),
+                                    unit: Some("EL".to_string()),
+                                    name: "Öl".to_string(),
+                                    quality: None,
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: Some(1.0),
+                                    unit: Some("Becher".to_string()),
+                                    name: "Schmand".to_string(),
+                                    quality: Some("200 Gramm".to_string()),
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: Some(100.0),
+                                    unit: Some("ml".to_string()),
+                                    name: "Rinderbrühe".to_string(),
+                                    quality: None,
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: Some(200.0),
+                                    unit: Some("g".to_string()),
+                                    name: "Reis".to_string(),
+                                    quality: None,
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: Some(1.0),
+                                    unit: Some("TL".to_string()),
+                                    name: "Salz".to_string(),
+                                    quality: None,
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: Some(2.0),
+                                    unit: Some("EL".to_string()),
+                                    name: "Weißwein Essig".to_string(),
+                                    quality: None,
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: Some(6.0),
+                                    unit: Some("EL".to_string()),
+                                    name: "Öl".to_string(),
+                                    quality: None,
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: Some(1.0),
+                                    unit: Some("TL".to_string()),
+                                    name: "Senf".to_string(),
+                                    quality: None,
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: Some(2.0),
+                                    unit: Some("TL".to_string()),
+                                    name: "Honig".to_string(),
+                                    quality: None,
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: Some(1.0),
+                                    unit: Some("TL".to_string()),
+                                    name: "Salz".to_string(),
+                                    quality: None,
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: Some(0.5),
+                                    unit: Some("TL".to_string()),
+                                    name: "Schwarzer Pfeffer".to_string(),
+                                    quality: None,
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: Some(0.5),
+                                    unit: Some("Kopf".to_string()),
+                                    name: "Eisberg Salat".to_string(),
+                                    quality: None,
+                                },
+                            ],
+                        },
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "In einer Schüssel das Öl mit dem Gyros Gewürz, Salz und Pfeffer, sowie 1 TL Zucker und 1 TL Zitronensaft zu einer Marinade verrühren.".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![],
+                        },
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "Das Fleisch hinzugeben, alles gut vermischen und abgedeckt mindestens 30 Minuten lang im Kühlschrank marinieren.".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![],
+                        },
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "Tipp: Du kannst auch bereits mariniertes Gyros verwenden, dann entfällt dieser Schritt".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![],
+                        },
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "Zunächst den Reis kochen. Ich koche Reis gerne im Reiskocher. Das geht schnell, macht keinen Dreck und gelingt immer, ohne anzubrennen. Einfach 1 Teil Reis mit 1,5 Teilen Wasser und einer Prise Salz in den Reiskocher geben und das Reis-Programm starten.".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![],
+                        },
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "Währenddessen das Fleisch in einer vorgeheizten Pfanne ohne zusätzliches Öl scharf anbraten.".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![],
+                        },
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "Wenn es rundum schön braun ist, die Hitze reduzieren und den Schmand zusammen mit der Rinderbrühe zugeben.".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![],
+                        },
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "So lange auf geringer Hitze köcheln, bis eine sämige Konsistenz erreicht ist. Mit Salz und Pfeffer nochmal abschmecken.".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![],
+                        },
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "2 El Weißwein Essig mit 6 El Speiseöl vermischen. Den Senf, sowie Honig, Zitronensaft, Salz und Pfeffer hinzufügen und alles mit einem Schneebesen verrühren.".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![],
+                        },
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "Nochmals mit Salz und Pfeffer abschmecken.".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![],
+                        },
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "Den Salat klein schneiden, waschen und trocken schleudern.".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![],
+                        },
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "Kurz vor dem Anrichten mit dem Salat-Dressing gut vermischen.".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![],
+                        },
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "Tipp: Wenn du möchtest, kannst du auch noch Zwiebelringe, Gurkenscheiben und Tomaten mit in den Salat geben.".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![],
+                        },
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "Gyros auf dem Reis anrichten, den Salat dazugeben. Mit Schnittlauch garnieren und servieren.".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![],
+                        },
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "Guten Appetit!".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![],
+                        },
+                    ],
+                    ..Default::default()
+                },
+            },
+            ExpectedGet {
+                url: "https://www.madamecuisine.de/jaegertorte-mit-preiselbeeren/".to_string(),
+                external_recipe: ExternalRecipe {
+                    name: "Jägertorte mit Preiselbeeren".to_string(),
+                    ingredients: vec![
+                        "100 g weiche Butter".to_string(),
+                        "100 g Zucker".to_string(),
+                        "3  Eier".to_string(),
+                        "125 g gemahlene Haselnüsse oder Mandeln".to_string(),
+                        "2 TL Backpulver".to_string(),
+                        "2 EL dunkles Kakaopulver".to_string(),
+                        "250 ml Sahne".to_string(),
+                        "1 Päckchen Sahnesteif".to_string(),
+                        "1 Glas Preiselbeeren (220 g)".to_string(),
+                    ],
+                    files: vec![
+                        "https://www.madamecuisine.de/wp-content/uploads/2021/05/jaegertorte-featured.jpg".to_string(),
+                        "https://www.madamecuisine.de/wp-content/uploads/2021/05/jaegertorte-featured-500x500.jpg".to_string(),
+                        "https://www.madamecuisine.de/wp-content/uploads/2021/05/jaegertorte-featured-500x375.jpg".to_string(),
+                        "https://www.madamecuisine.de/wp-content/uploads/2021/05/jaegertorte-featured-480x270.jpg".to_string(),
+                    ],
+                    steps: vec![
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "Die Eier trennen, das Eiweiß sehr steif schlagen. Die Eigelbe mit der Butter und dem Zucker schaumig rühren.".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![],
+                        },
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "Gemahlene Haselnüsse oder Mandeln mit dem Backpulver und dem Kakao vermengen und unter die Eigelb-Butter-Mischung rühren. Steif geschlagenes Eiweiß mit dem Schneebesen vorsichtig unterheben.".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![],
+                        },
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "Teig in eine gefettete Springform füllen und im vorgeheizten Ofen bei 180 Grad Ober-/Unterhitze etwa 35 Minuten backen. Anschließend gut auskühlen lassen.".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![],
+                        },
+                        ExternalRecipeStep {
+                            ingredients: vec![],
+                            description: "Die Sahne mit dem Sahnesteif schlagen bis sie fest ist. Preiselbeeren unterrühren und die Masse auf dem Tortenboden verteilen.".to_string(),
+                            files: vec![],
+                            parsed_ingredients: vec![],
+                        },
+                    ],
+                    ..Default::default()
+                },
+            },
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_get() {
+        crate::tests::run();
+        assert_expected_gets(expected_gets()).await;
+    }
+
+    #[tokio::test]
+    async fn test_get_hand_written() {
+        assert_expected_gets(vec![
+            ExpectedGet {
+                url: String::from("https://example.com/rezepte/vanillekipferl"),
+                external_recipe: ExternalRecipe {
+                    name: String::from("Vanillekipferl"),
+                    ingredients: vec![
+                        String::from("2-3 Eigelb"),
+                        String::from("Etwas Puderzucker (zum Bestäuben)"),
+                    ],
+                    files: vec![String::from(
+                        "https://example.com/bilder/vanillekipferl.jpg",
+                    )],
+                    steps: vec![
+                        ExternalRecipeStep {
+                            description: String::from("Alle Zutaten zu einem Teig verkneten."),
+                            parsed_ingredients: vec![
+                                ExternalRecipeIngredient {
+                                    quantity: Some(250.0),
+                                    unit: Some(String::from("g")),
+                                    name: String::from("Mehl"),
+                                    quality: Some(String::from("Typ 405")),
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: Some(1.5),
+                                    unit: Some(String::from("Päckchen")),
+                                    name: String::from("Vanillezucker"),
+                                    quality: None,
+                                },
+                                ExternalRecipeIngredient {
+                                    name: String::from("Salz"),
+                                    ..Default::default()
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: Some(1.0),
+                                    unit: Some(String::from("Prise")),
+                                    name: String::from("salz"),
+                                    quality: None,
+                                },
+                            ],
+                            ..Default::default()
+                        },
+                        ExternalRecipeStep {
+                            description: String::from(
+                                "Kipferl formen und bei 180 °C 12 Minuten backen.",
+                            ),
+                            ..Default::default()
+                        },
+                    ],
+                    ..Default::default()
+                },
+            },
+            ExpectedGet {
+                url: String::from("https://example.com/rezepte/kokosmakronen"),
+                external_recipe: ExternalRecipe {
+                    name: String::from("Kokosmakronen"),
+                    ingredients: vec![String::from("eine Handvoll Schokostreusel")],
+                    files: vec![String::from("https://example.com/bilder/kokosmakronen.jpg")],
+                    steps: vec![
+                        ExternalRecipeStep {
+                            description: String::from("Das Eiweiß steif schlagen."),
+                            parsed_ingredients: vec![
+                                ExternalRecipeIngredient {
+                                    quantity: Some(3.0),
+                                    unit: None,
+                                    name: String::from("Eiweiß"),
+                                    quality: None,
+                                },
+                                ExternalRecipeIngredient {
+                                    quantity: Some(200.0),
+                                    unit: Some(String::from("g")),
+                                    name: String::from("Kokosraspeln"),
+                                    quality: None,
+                                },
+                            ],
+                            ..Default::default()
+                        },
+                        ExternalRecipeStep {
+                            description: String::from(
+                                "Die Kokosraspeln unterheben und Makronen aufs Blech setzen.",
+                            ),
+                            ..Default::default()
+                        },
+                    ],
+                    ..Default::default()
+                },
+            },
+            ExpectedGet {
+                url: String::from("https://example.com/rezepte/bratapfel"),
+                external_recipe: ExternalRecipe {
+                    name: String::from("Bratapfel"),
+                    ingredients: vec![
+                        String::from("4 Äpfel"),
+                        String::from("50 g gehackte Mandeln"),
+                    ],
+                    files: vec![String::from("https://example.com/bilder/bratapfel.jpg")],
+                    steps: vec![
+                        ExternalRecipeStep {
+                            description: String::from("Die Äpfel entkernen und füllen."),
+                            ..Default::default()
+                        },
+                        ExternalRecipeStep {
+                            description: String::from("30 Minuten backen."),
+                            ..Default::default()
+                        },
+                    ],
+                    ..Default::default()
+                },
+            },
+        ])
+        .await;
+    }
+}
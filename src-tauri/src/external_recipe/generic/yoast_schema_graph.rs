@@ -1,17 +1,13 @@
 //! This module implements [`GenericExternalRecipeGetterTrait`] for a [Yoast schema graph](https://developer.yoast.com/features/schema/).
 
 use async_trait::async_trait;
-use json_ld::{
-    syntax::{Parse, Value},
-    RemoteDocument,
-};
-use schema_org_traits::json_ld_0_15::JsonLdStore;
 use url::Url;
 
 use crate::{
     external_recipe::{
-        client, error::ExternalRecipeError, generic::GenericExternalRecipeGetterTrait,
-        ExternalRecipe,
+        error::ExternalRecipeError,
+        generic::{parse_json_ld, GenericExternalRecipeGetterTrait},
+        get_dom, ExternalRecipe,
     },
     scraper::{Dom, ParentNode},
 };
@@ -26,28 +22,19 @@ async fn read_yoast(dom: &Dom) -> Result<Option<String>, ExternalRecipeError> {
     Ok(Some(yoast_schema_graph_element.text_content().await?))
 }
 
-async fn parse_yoast(schema_graph_text: String) -> Option<ExternalRecipe> {
-    let input = RemoteDocument::new(
-        None,
-        Some("application/ld+json".parse().unwrap()),
-        Value::parse_str(&schema_graph_text, |_| ()).unwrap(),
-    );
-    let mut loader = json_ld::ReqwestLoader::new_with_metadata_map(|_, _, _| ());
-    let json_ld_store = JsonLdStore::new(input, &mut loader, None).await;
-    ExternalRecipe::try_from_json_ld(&json_ld_store)
-}
-
 #[async_trait]
 impl GenericExternalRecipeGetterTrait for ExternalRecipeGetter {
+    fn name(&self) -> &str {
+        "yoast_schema_graph"
+    }
+
     async fn get(&self, url: Url) -> Result<Option<ExternalRecipe>, ExternalRecipeError> {
-        let response = client().get(url).send().await?;
-        let text = response.text().await?;
-        let dom = Dom::create(text).await?;
+        let dom = get_dom(url).await?;
         let yoast_schema_graph_option = read_yoast(&dom).await?;
         let Some(yoast_schema_graph) = yoast_schema_graph_option else {
             return Ok(None);
         };
-        let external_recipe_option = parse_yoast(yoast_schema_graph).await;
+        let external_recipe_option = parse_json_ld(&yoast_schema_graph).await;
         let Some(external_recipe) = external_recipe_option else {
             return Ok(None);
         };
@@ -57,12 +44,16 @@ impl GenericExternalRecipeGetterTrait for ExternalRecipeGetter {
 
 #[cfg(test)]
 mod tests {
+    use pretty_assertions::assert_eq;
+
     use super::*;
     use crate::external_recipe::{
+        details::ExternalRecipeDetails,
         tests::{assert_expected_gets, ExpectedGet},
         ExternalRecipeStep,
     };
 
+    /// These pages also have a recipe card, which is preferred by [`crate::external_recipe::get`], so the getter is asked directly.
     fn expected_gets() -> Vec<ExpectedGet> {
         vec![
             ExpectedGet {
@@ -100,78 +91,94 @@ mod tests {
                             ingredients: vec![],
                             description: "Das Fleisch in dünne Streifen schneiden.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "In einer Schüssel das Öl mit dem Gyros Gewürz, Salz und Pfeffer, sowie 1 TL Zucker und 1 TL Zitronensaft zu einer Marinade verrühren.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Das Fleisch hinzugeben, alles gut vermischen und abgedeckt mindestens 30 Minuten lang im Kühlschrank marinieren.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Tipp: Du kannst auch bereits mariniertes Gyros verwenden, dann entfällt dieser Schritt".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Zunächst den Reis kochen. Ich koche Reis gerne im Reiskocher. Das geht schnell, macht keinen Dreck und gelingt immer, ohne anzubrennen. Einfach 1 Teil Reis mit 1,5 Teilen Wasser und einer Prise Salz in den Reiskocher geben und das Reis-Programm starten.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Währenddessen das Fleisch in einer vorgeheizten Pfanne ohne zusätzliches Öl scharf anbraten.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Wenn es rundum schön braun ist, die Hitze reduzieren und den Schmand zusammen mit der Rinderbrühe zugeben.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "So lange auf geringer Hitze köcheln, bis eine sämige Konsistenz erreicht ist. Mit Salz und Pfeffer nochmal abschmecken.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "2 El Weißwein Essig mit 6 El Speiseöl vermischen. Den Senf, sowie Honig, Zitronensaft, Salz und Pfeffer hinzufügen und alles mit einem Schneebesen verrühren.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Nochmals mit Salz und Pfeffer abschmecken.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Den Salat klein schneiden, waschen und trocken schleudern.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Kurz vor dem Anrichten mit dem Salat-Dressing gut vermischen.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Tipp: Wenn du möchtest, kannst du auch noch Zwiebelringe, Gurkenscheiben und Tomaten mit in den Salat geben.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Gyros auf dem Reis anrichten, den Salat dazugeben. Mit Schnittlauch garnieren und servieren.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Guten Appetit!".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                     ],
+                    ..Default::default()
                 },
             },
             ExpectedGet {
@@ -200,23 +207,28 @@ mod tests {
                             ingredients: vec![],
                             description: "Die Eier trennen, das Eiweiß sehr steif schlagen. Die Eigelbe mit der Butter und dem Zucker schaumig rühren.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Gemahlene Haselnüsse oder Mandeln mit dem Backpulver und dem Kakao vermengen und unter die Eigelb-Butter-Mischung rühren. Steif geschlagenes Eiweiß mit dem Schneebesen vorsichtig unterheben.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Teig in eine gefettete Springform füllen und im vorgeheizten Ofen bei 180 Grad Ober-/Unterhitze etwa 35 Minuten backen. Anschließend gut auskühlen lassen.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Die Sahne mit dem Sahnesteif schlagen bis sie fest ist. Preiselbeeren unterrühren und die Masse auf dem Tortenboden verteilen.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                     ],
+                    ..Default::default()
                 },
             },
         ]
@@ -225,6 +237,42 @@ mod tests {
     #[tokio::test]
     async fn test_get() {
         crate::tests::run();
-        assert_expected_gets(expected_gets()).await;
+        for expected_get in expected_gets() {
+            let url = Url::parse(&expected_get.url).unwrap();
+            let actual = ExternalRecipeGetter.get(url).await.unwrap().unwrap();
+            assert_eq!(
+                ExternalRecipe {
+                    details: ExternalRecipeDetails::default(),
+                    ..actual
+                },
+                expected_get.external_recipe
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_hand_written() {
+        assert_expected_gets(vec![ExpectedGet {
+            url: String::from("https://example.com/rezepte/lebkuchen"),
+            external_recipe: ExternalRecipe {
+                name: String::from("Lebkuchen"),
+                ingredients: vec![
+                    String::from("250 g Honig"),
+                    String::from("2 TL Lebkuchengewürz"),
+                ],
+                steps: vec![
+                    ExternalRecipeStep {
+                        description: String::from("Den Honig erwärmen."),
+                        ..Default::default()
+                    },
+                    ExternalRecipeStep {
+                        description: String::from("Die Gewürze unterrühren."),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            },
+        }])
+        .await;
     }
 }
@@ -0,0 +1,518 @@
+//! This module reads pages saved by a browser, so that external recipes can be imported from them without fetching.
+//!
+//! Plain HTML files, MHTML archives and Safari web archives are supported.
+//! Only the archives contain the resources embedded into the page, e.g. its images.
+
+use std::{collections::HashMap, ffi::OsStr, path::Path, sync::OnceLock};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use regex::Regex;
+use serde::Deserialize;
+use url::Url;
+
+use crate::{
+    external_recipe::error::ExternalRecipeError,
+    scraper::{Dom, ParentNode, ScraperBackend},
+};
+
+static SAVED_FROM_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// This struct represents a resource embedded into an archive, e.g. an image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavedResource {
+    pub mime: String,
+    pub content: Vec<u8>,
+}
+
+/// This struct represents a page saved by a browser.
+#[derive(Debug)]
+pub struct SavedPage {
+    /// the original URL of the page, which routes it to the external recipe getters
+    pub url: Url,
+    pub html: String,
+    /// the embedded resources by their URL
+    resources: HashMap<Url, SavedResource>,
+}
+
+impl SavedPage {
+    /// Read a saved page from a file, its format is determined by the file extension.
+    ///
+    /// The original URL of the page is its canonical URL or the URL the file was saved from.
+    ///
+    /// # Errors
+    ///
+    /// - [`ExternalRecipeError::ParseError`] when the file is no supported archive or the original URL can not be found
+    pub async fn read(path: &Path) -> Result<Self, ExternalRecipeError> {
+        let bytes = tokio::fs::read(path).await.map_err(anyhow::Error::from)?;
+        let extension = path
+            .extension()
+            .and_then(OsStr::to_str)
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let archive = match extension.as_str() {
+            "html" | "htm" | "xhtml" => read_html(&bytes),
+            "mhtml" | "mht" => read_mhtml(&String::from_utf8_lossy(&bytes))?,
+            "webarchive" => read_webarchive(&bytes)?,
+            _ => {
+                return Err(ExternalRecipeError::ParseError(format!(
+                    "Saved pages with the extension \"{extension}\" are not supported."
+                )));
+            }
+        };
+        Self::from_archive(archive).await
+    }
+
+    async fn from_archive(archive: Archive) -> Result<Self, ExternalRecipeError> {
+        let location = archive
+            .location
+            .and_then(|location| Url::parse(&location).ok());
+        let url = find_canonical_url(&archive.html, location.as_ref())
+            .await?
+            .or(location)
+            .filter(|url| matches!(url.scheme(), "http" | "https"))
+            .ok_or_else(|| {
+                ExternalRecipeError::ParseError(String::from(
+                    "The saved page does not contain its original URL.",
+                ))
+            })?;
+        let resources = archive
+            .resources
+            .into_iter()
+            .filter_map(|(location, resource)| Some((Url::parse(&location).ok()?, resource)))
+            .collect();
+        Ok(SavedPage {
+            url,
+            html: archive.html,
+            resources,
+        })
+    }
+
+    /// Get the HTML of this page if `url` is its original URL.
+    pub fn html_of(&self, url: &Url) -> Option<&str> {
+        (without_fragment(url) == without_fragment(&self.url)).then_some(self.html.as_str())
+    }
+
+    /// Get an embedded resource by its URL.
+    pub fn resource(&self, url: &str) -> Option<&SavedResource> {
+        self.resources.get(&Url::parse(url).ok()?)
+    }
+}
+
+fn without_fragment(url: &Url) -> Url {
+    let mut url = url.clone();
+    url.set_fragment(None);
+    url
+}
+
+/// This struct contains the content of an archive before the original URL is resolved.
+#[derive(Debug, Default)]
+struct Archive {
+    /// the URL the archive was saved from
+    location: Option<String>,
+    html: String,
+    /// the embedded resources by their URL
+    resources: Vec<(String, SavedResource)>,
+}
+
+/// Find the URL of `<link rel="canonical">`, which can be relative to the location of the page.
+async fn find_canonical_url(
+    html: &str,
+    location: Option<&Url>,
+) -> Result<Option<Url>, ExternalRecipeError> {
+    let dom = Dom::create(html.to_string(), ScraperBackend::Native).await?;
+    let Some(link_element) = dom.select("link[rel~=\"canonical\"][href]").await? else {
+        return Ok(None);
+    };
+    let href = link_element.get_attribute("href").await?;
+    Ok(match location {
+        Some(location) => location.join(&href).ok(),
+        None => Url::parse(&href).ok(),
+    })
+}
+
+/// Read a plain HTML file.
+///
+/// The location is taken from the "saved from" comment browsers add to saved pages.
+fn read_html(bytes: &[u8]) -> Archive {
+    let html = String::from_utf8_lossy(bytes).into_owned();
+    let location = SAVED_FROM_REGEX
+        .get_or_init(|| Regex::new(r"<!-- saved from url=\(\d+\)(\S+?) ?-->").unwrap())
+        .captures(&html)
+        .map(|captures| captures[1].to_string());
+    Archive {
+        location,
+        html,
+        ..Default::default()
+    }
+}
+
+/// Split a MIME entity into its headers with lowercase names and its body.
+///
+/// Folded header lines are unfolded.
+fn split_mime_entity(entity: &str) -> (HashMap<String, String>, &str) {
+    let (header_text, body) = match (entity.find("\r\n\r\n"), entity.find("\n\n")) {
+        (Some(crlf_index), Some(lf_index)) if lf_index < crlf_index => {
+            (&entity[..lf_index], &entity[lf_index + 2..])
+        }
+        (Some(crlf_index), _) => (&entity[..crlf_index], &entity[crlf_index + 4..]),
+        (None, Some(lf_index)) => (&entity[..lf_index], &entity[lf_index + 2..]),
+        (None, None) => (entity, ""),
+    };
+    let mut headers: Vec<(String, String)> = vec![];
+    for line in header_text.lines() {
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, value)) = headers.last_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+            continue;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_ascii_lowercase(), value.trim().to_string()));
+        }
+    }
+    (headers.into_iter().collect(), body)
+}
+
+/// Get the value of a header without its parameters in lowercase.
+fn header_value(header: &str) -> String {
+    header
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
+
+/// Get a parameter of a header like the boundary of `multipart/related; boundary="abc"`.
+fn header_parameter(header: &str, name: &str) -> Option<String> {
+    header.split(';').skip(1).find_map(|parameter| {
+        let (parameter_name, value) = parameter.split_once('=')?;
+        parameter_name
+            .trim()
+            .eq_ignore_ascii_case(name)
+            .then(|| value.trim().trim_matches('"').to_string())
+    })
+}
+
+fn decode_quoted_printable(text: &str) -> Vec<u8> {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'=' {
+            if bytes[i + 1..].starts_with(b"\r\n") {
+                i += 3;
+                continue;
+            }
+            if bytes[i + 1..].starts_with(b"\n") {
+                i += 2;
+                continue;
+            }
+            if let Some(byte) = text
+                .get(i + 1..i + 3)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    decoded
+}
+
+fn decode_body(
+    body: &str,
+    content_transfer_encoding: &str,
+) -> Result<Vec<u8>, ExternalRecipeError> {
+    Ok(match content_transfer_encoding {
+        "base64" => {
+            let base64: String = body.split_ascii_whitespace().collect();
+            STANDARD.decode(base64).map_err(|err| {
+                ExternalRecipeError::ParseError(format!("Invalid base64 in MHTML archive: {err}"))
+            })?
+        }
+        "quoted-printable" => decode_quoted_printable(body),
+        _ => body.as_bytes().to_vec(),
+    })
+}
+
+/// Read an MHTML archive as saved by Chromium based browsers.
+///
+/// The first HTML part is the page, the other parts with a location are its resources.
+fn read_mhtml(text: &str) -> Result<Archive, ExternalRecipeError> {
+    let (headers, body) = split_mime_entity(text);
+    let boundary = headers
+        .get("content-type")
+        .and_then(|content_type| header_parameter(content_type, "boundary"))
+        .ok_or_else(|| {
+            ExternalRecipeError::ParseError(String::from("The MHTML archive has no boundary."))
+        })?;
+    let mut archive = Archive {
+        location: headers.get("snapshot-content-location").cloned(),
+        ..Default::default()
+    };
+    let mut html_option = None;
+    for part in body.split(&format!("--{boundary}")).skip(1) {
+        if part.starts_with("--") {
+            break;
+        }
+        // the line breaks around the boundary belong to it
+        let part = part
+            .strip_prefix("\r\n")
+            .or_else(|| part.strip_prefix('\n'))
+            .unwrap_or(part);
+        let (part_headers, part_body) = split_mime_entity(part);
+        let part_body = part_body
+            .strip_suffix("\r\n")
+            .or_else(|| part_body.strip_suffix('\n'))
+            .unwrap_or(part_body);
+        let content_transfer_encoding = part_headers
+            .get("content-transfer-encoding")
+            .map(|header| header_value(header))
+            .unwrap_or_default();
+        let content = decode_body(part_body, &content_transfer_encoding)?;
+        let mime = part_headers
+            .get("content-type")
+            .map(|header| header_value(header))
+            .unwrap_or_else(|| String::from("application/octet-stream"));
+        let location = part_headers.get("content-location").cloned();
+        if html_option.is_none() && mime == "text/html" {
+            html_option = Some(String::from_utf8_lossy(&content).into_owned());
+            archive.location = archive.location.or(location);
+            continue;
+        }
+        if let Some(location) = location {
+            archive
+                .resources
+                .push((location, SavedResource { mime, content }));
+        }
+    }
+    archive.html = html_option.ok_or_else(|| {
+        ExternalRecipeError::ParseError(String::from("The MHTML archive contains no HTML."))
+    })?;
+    Ok(archive)
+}
+
+#[derive(Debug, Deserialize)]
+struct WebArchive {
+    #[serde(rename = "WebMainResource")]
+    main_resource: WebResource,
+    #[serde(rename = "WebSubresources", default)]
+    subresources: Vec<WebResource>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WebResource {
+    #[serde(rename = "WebResourceURL")]
+    url: String,
+    #[serde(rename = "WebResourceMIMEType")]
+    mime: Option<String>,
+    #[serde(rename = "WebResourceData")]
+    data: plist::Data,
+}
+
+/// Read a web archive as saved by Safari, which is a property list of the main resource and its subresources.
+fn read_webarchive(bytes: &[u8]) -> Result<Archive, ExternalRecipeError> {
+    let web_archive: WebArchive = plist::from_bytes(bytes).map_err(|err| {
+        ExternalRecipeError::ParseError(format!("The web archive can not be read: {err}"))
+    })?;
+    Ok(Archive {
+        location: Some(web_archive.main_resource.url),
+        html: String::from_utf8_lossy(&Vec::from(web_archive.main_resource.data)).into_owned(),
+        resources: web_archive
+            .subresources
+            .into_iter()
+            .map(|resource| {
+                (
+                    resource.url,
+                    SavedResource {
+                        mime: resource
+                            .mime
+                            .unwrap_or_else(|| String::from("application/octet-stream")),
+                        content: resource.data.into(),
+                    },
+                )
+            })
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const MHTML: &str = "From: <Saved by Blink>\r\n\
+        Snapshot-Content-Location: https://example.com/zimtschnecken?utm_source=feed\r\n\
+        Subject: Zimtschnecken\r\n\
+        MIME-Version: 1.0\r\n\
+        Content-Type: multipart/related;\r\n\
+        \ttype=\"text/html\";\r\n\
+        \tboundary=\"----MultipartBoundary--abc----\"\r\n\
+        \r\n\
+        \r\n\
+        ------MultipartBoundary--abc----\r\n\
+        Content-Type: text/html\r\n\
+        Content-ID: <frame-1@mhtml.blink>\r\n\
+        Content-Transfer-Encoding: quoted-printable\r\n\
+        Content-Location: https://example.com/zimtschnecken?utm_source=feed\r\n\
+        \r\n\
+        <html><head><link rel=3D\"canonical\" href=3D\"/zimtschnecken\"></head><body>Geb=\r\n\
+        =C3=A4ck</body></html>\r\n\
+        ------MultipartBoundary--abc----\r\n\
+        Content-Type: image/jpeg\r\n\
+        Content-Transfer-Encoding: base64\r\n\
+        Content-Location: https://example.com/teig.jpg\r\n\
+        \r\n\
+        /9j/\r\n\
+        4AA=\r\n\
+        ------MultipartBoundary--abc------\r\n";
+
+    #[test]
+    fn test_decode_quoted_printable() {
+        assert_eq!(
+            decode_quoted_printable("a=3Db=\r\nc=C3=A4=\nd =XY"),
+            "a=bcäd =XY".as_bytes()
+        );
+    }
+
+    #[test]
+    fn test_header_parameter() {
+        let header = "multipart/related; type=\"text/html\"; Boundary=\"abc\"";
+        assert_eq!(header_value(header), "multipart/related");
+        assert_eq!(
+            header_parameter(header, "boundary"),
+            Some(String::from("abc"))
+        );
+        assert_eq!(header_parameter(header, "charset"), None);
+    }
+
+    #[test]
+    fn test_read_mhtml() {
+        let archive = read_mhtml(MHTML).unwrap();
+        assert_eq!(
+            archive.location,
+            Some(String::from(
+                "https://example.com/zimtschnecken?utm_source=feed"
+            ))
+        );
+        assert_eq!(
+            archive.html,
+            "<html><head><link rel=\"canonical\" href=\"/zimtschnecken\"></head><body>Gebäck</body></html>"
+        );
+        assert_eq!(
+            archive.resources,
+            vec![(
+                String::from("https://example.com/teig.jpg"),
+                SavedResource {
+                    mime: String::from("image/jpeg"),
+                    content: vec![0xff, 0xd8, 0xff, 0xe0, 0x00],
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn test_read_html() {
+        let archive = read_html(
+            "<!DOCTYPE html>\n<!-- saved from url=(0034)https://example.com/zimtschnecken -->\n<html></html>"
+                .as_bytes(),
+        );
+        assert_eq!(
+            archive.location,
+            Some(String::from("https://example.com/zimtschnecken"))
+        );
+    }
+
+    #[test]
+    fn test_read_webarchive() {
+        let plist = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+  <key>WebMainResource</key>
+  <dict>
+    <key>WebResourceData</key>
+    <data>PGh0bWw+PC9odG1sPg==</data>
+    <key>WebResourceMIMEType</key>
+    <string>text/html</string>
+    <key>WebResourceURL</key>
+    <string>https://example.com/zimtschnecken</string>
+  </dict>
+  <key>WebSubresources</key>
+  <array>
+    <dict>
+      <key>WebResourceData</key>
+      <data>/9j/4AA=</data>
+      <key>WebResourceMIMEType</key>
+      <string>image/jpeg</string>
+      <key>WebResourceURL</key>
+      <string>https://example.com/teig.jpg</string>
+    </dict>
+  </array>
+</dict>
+</plist>"#;
+        let archive = read_webarchive(plist.as_bytes()).unwrap();
+        assert_eq!(
+            archive.location,
+            Some(String::from("https://example.com/zimtschnecken"))
+        );
+        assert_eq!(archive.html, "<html></html>");
+        assert_eq!(
+            archive.resources,
+            vec![(
+                String::from("https://example.com/teig.jpg"),
+                SavedResource {
+                    mime: String::from("image/jpeg"),
+                    content: vec![0xff, 0xd8, 0xff, 0xe0, 0x00],
+                }
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_from_archive() {
+        let saved_page = SavedPage::from_archive(read_mhtml(MHTML).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(saved_page.url.as_str(), "https://example.com/zimtschnecken");
+        assert!(
+            saved_page
+                .html_of(&Url::parse("https://example.com/zimtschnecken#rezept").unwrap())
+                .is_some()
+        );
+        assert!(
+            saved_page
+                .html_of(&Url::parse("https://example.com/stollen").unwrap())
+                .is_none()
+        );
+        assert_eq!(
+            saved_page
+                .resource("https://example.com/teig.jpg")
+                .map(|resource| resource.mime.as_str()),
+            Some("image/jpeg")
+        );
+
+        let saved_page = SavedPage::from_archive(Archive {
+            location: Some(String::from("https://example.com/stollen")),
+            html: String::from("<html></html>"),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        assert_eq!(saved_page.url.as_str(), "https://example.com/stollen");
+
+        let result = SavedPage::from_archive(Archive {
+            location: Some(String::from("file:///home/stollen.html")),
+            html: String::from("<html></html>"),
+            ..Default::default()
+        })
+        .await;
+        assert!(matches!(result, Err(ExternalRecipeError::ParseError(_))));
+    }
+}
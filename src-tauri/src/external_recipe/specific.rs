@@ -7,10 +7,14 @@ use crate::external_recipe::{error::ExternalRecipeError, ExternalRecipe};
 mod knusperstuebchen;
 mod pinterest;
 mod sallys_welt;
+pub mod scraping_rule;
 
 /// Implementors define which external recipes they can get and implement the getting itself.
 #[async_trait]
 pub trait SpecificExternalRecipeGetterTrait: Send + Sync {
+    /// Get the name of this implementor, which is stored as the getter of imported recipes.
+    fn name(&self) -> &str;
+
     /// Check whether this implementor can get an external recipe from a specific URL.
     fn can_get(&self, url: &Url) -> bool {
         let Some(prepared_url) = UrlMatch::prepare_url(url) else {
@@ -28,21 +32,28 @@ pub trait SpecificExternalRecipeGetterTrait: Send + Sync {
     async fn get(&self, url: Url) -> Result<ExternalRecipe, ExternalRecipeError>;
 
     /// Get the [`Vec`] of [`UrlMatch`]es of this implementor.
-    fn url_matches(&self) -> Vec<UrlMatch<'static>>;
+    fn url_matches(&self) -> Vec<UrlMatch<'_>>;
 }
 
-pub fn specific_external_recipe_getters() -> Vec<Box<dyn SpecificExternalRecipeGetterTrait>> {
-    vec![
-        Box::new(pinterest::ExternalRecipeGetter),
-        Box::new(sallys_welt::ExternalRecipeGetter),
-        Box::new(knusperstuebchen::ExternalRecipeGetter),
-    ]
+/// Get all specific getters.
+///
+/// The getters of the user-defined [`scraping_rule`]s come first, so that a rule can replace a built-in getter which broke because its site changed.
+pub async fn specific_external_recipe_getters() -> Vec<Box<dyn SpecificExternalRecipeGetterTrait>>
+{
+    let mut getters: Vec<Box<dyn SpecificExternalRecipeGetterTrait>> = vec![];
+    for getter in scraping_rule::getters().await {
+        getters.push(Box::new(getter));
+    }
+    getters.push(Box::new(pinterest::ExternalRecipeGetter));
+    getters.push(Box::new(sallys_welt::ExternalRecipeGetter));
+    getters.push(Box::new(knusperstuebchen::ExternalRecipeGetter));
+    getters
 }
 
 /// Represents an external recipe URL matching rule.
 pub struct UrlMatch<'a> {
-    pub schemes: &'a [&'a str],
-    pub domains: &'a [&'a str],
+    pub schemes: Vec<&'a str>,
+    pub domains: Vec<&'a str>,
     pub path_regex: &'a Regex,
 }
 
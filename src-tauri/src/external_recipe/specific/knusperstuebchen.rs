@@ -8,12 +8,12 @@ use url::Url;
 
 use crate::{
     external_recipe::{
-        client,
         error::ExternalRecipeError,
+        get_dom,
         specific::{SpecificExternalRecipeGetterTrait, UrlMatch},
         ExternalRecipe, ExternalRecipeStep,
     },
-    scraper::{Dom, ParentNode},
+    scraper::ParentNode,
 };
 
 static PATH_REGEX: OnceLock<Regex> = OnceLock::new();
@@ -22,11 +22,13 @@ pub struct ExternalRecipeGetter;
 
 #[async_trait]
 impl SpecificExternalRecipeGetterTrait for ExternalRecipeGetter {
+    fn name(&self) -> &str {
+        "knusperstuebchen"
+    }
+
     /// The recipes on Knusperstuebchen can be only a pdf or a pdf and structured html.
     async fn get(&self, url: Url) -> Result<ExternalRecipe, ExternalRecipeError> {
-        let response = client().get(url).send().await?;
-        let text = response.text().await?;
-        let dom = Dom::create(text).await?;
+        let dom = get_dom(url).await?;
         let name_element = dom.select("h1").await?.unwrap();
         let recipe_element_option = dom.select(".easyrecipe").await?;
         let pdf_anchor_element = dom.select("a[href$=\".pdf\"]").await?.unwrap();
@@ -37,6 +39,7 @@ impl SpecificExternalRecipeGetterTrait for ExternalRecipeGetter {
                     ingredients: vec![],
                     description: String::from(""),
                     files: vec![pdf_anchor_element.get_attribute("href").await?],
+                    parsed_ingredients: vec![],
                 }],
                 ..Default::default()
             }),
@@ -69,6 +72,7 @@ impl SpecificExternalRecipeGetterTrait for ExternalRecipeGetter {
                             img_element.get_attribute("src").await?,
                             pdf_anchor_element.get_attribute("href").await?,
                         ],
+                        parsed_ingredients: vec![],
                     }],
                     ..Default::default()
                 })
@@ -78,8 +82,8 @@ impl SpecificExternalRecipeGetterTrait for ExternalRecipeGetter {
 
     fn url_matches(&self) -> Vec<UrlMatch<'static>> {
         vec![UrlMatch {
-            schemes: &["http", "https"],
-            domains: &["knusperstuebchen.net"],
+            schemes: vec!["http", "https"],
+            domains: vec!["knusperstuebchen.net"],
             path_regex: PATH_REGEX.get_or_init(|| Regex::new(r"^/\d{4}/\d{2}/\d{2}/.*$").unwrap()),
         }]
     }
@@ -153,6 +157,7 @@ mod tests {
                                 "https://knusperstuebchen.net/wp-content/uploads/2013/11/Knusper-Pizza-Rezept.pdf",
                             ),
                         ],
+                        parsed_ingredients: vec![],
                     }],
                     ..Default::default()
                 },
@@ -201,6 +206,7 @@ mod tests {
                                 "https://knusperstuebchen.net/wp-content/uploads/2023/08/Perlcouscous-Salat-mit-Ofenlachs.pdf",
                             ),
                         ],
+                        parsed_ingredients: vec![],
                     }],
                     ..Default::default()
                 },
@@ -213,4 +219,29 @@ mod tests {
         crate::tests::run();
         assert_expected_gets(expected_gets()).await;
     }
+
+    #[tokio::test]
+    async fn test_get_hand_written() {
+        assert_expected_gets(vec![ExpectedGet {
+            url: String::from("https://knusperstuebchen.net/2020/12/04/spekulatius/"),
+            external_recipe: ExternalRecipe {
+                name: String::from("Spekulatius"),
+                steps: vec![ExternalRecipeStep {
+                    ingredients: vec![String::from("250 g Mehl"), String::from("1 TL Zimt")],
+                    description: String::from("So wird's gemacht\nDen Teig kneten."),
+                    files: vec![
+                        String::from(
+                            "https://knusperstuebchen.net/wp-content/uploads/2020/12/Spekulatius.jpg",
+                        ),
+                        String::from(
+                            "https://knusperstuebchen.net/wp-content/uploads/2020/12/Spekulatius.pdf",
+                        ),
+                    ],
+                    parsed_ingredients: vec![],
+                }],
+                ..Default::default()
+            },
+        }])
+        .await;
+    }
 }
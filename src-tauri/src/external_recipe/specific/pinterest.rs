@@ -4,7 +4,6 @@ use std::sync::OnceLock;
 
 use async_trait::async_trait;
 use regex::Regex;
-use scraper::Dom;
 use url::Url;
 
 static PIN_IT_PATH_REGEX: OnceLock<Regex> = OnceLock::new();
@@ -13,8 +12,8 @@ static PINTEREST_PATH_REGEX: OnceLock<Regex> = OnceLock::new();
 
 use crate::{
     external_recipe::{
-        client,
         error::ExternalRecipeError,
+        get_dom,
         specific::{
             pinterest::relay_response::{
                 PinterestRelay, PinterestRelayPinQueryData, PinterestRelayResponse,
@@ -23,7 +22,6 @@ use crate::{
         },
         ExternalRecipe, ExternalRecipeStep,
     },
-    scraper,
     scraper::ParentNode,
 };
 
@@ -32,8 +30,8 @@ mod relay_response;
 /// The [`UrlMatch`] for Pinterest's short URLs.
 fn pin_it_uri_match() -> UrlMatch<'static> {
     UrlMatch {
-        schemes: &["http", "https"],
-        domains: &["pin.it"],
+        schemes: vec!["http", "https"],
+        domains: vec!["pin.it"],
         path_regex: PIN_IT_PATH_REGEX.get_or_init(|| Regex::new(r".*").unwrap()),
     }
 }
@@ -41,8 +39,8 @@ fn pin_it_uri_match() -> UrlMatch<'static> {
 /// The [`UrlMatch`] for Pinterest's standard URLs.
 fn pinterest_uri_match() -> UrlMatch<'static> {
     UrlMatch {
-        schemes: &["http", "https"],
-        domains: &[
+        schemes: vec!["http", "https"],
+        domains: vec![
             "pinterest.at",
             "pinterest.ca",
             "pinterest.ch",
@@ -75,11 +73,13 @@ pub struct ExternalRecipeGetter;
 
 #[async_trait]
 impl SpecificExternalRecipeGetterTrait for ExternalRecipeGetter {
+    fn name(&self) -> &str {
+        "pinterest"
+    }
+
     /// For `pin.it` URLs, we first need to find the canonical URL before trying to parse the HTML for the recipe data.
     async fn get(&self, url: Url) -> Result<ExternalRecipe, ExternalRecipeError> {
-        let response = client().get(url).send().await?;
-        let text = response.text().await?;
-        let dom = Dom::create(text).await?;
+        let dom = get_dom(url).await?;
         let elements = dom
             .select_all("script[data-relay-response=\"true\"][type=\"application/json\"]")
             .await?;
@@ -130,6 +130,7 @@ impl SpecificExternalRecipeGetterTrait for ExternalRecipeGetter {
                         .flat_map(|page| page.blocks)
                         .map(|block| block.video_data.video_list.video.url)
                         .collect(),
+                    parsed_ingredients: vec![],
                 }],
                 ..Default::default()
             }),
@@ -206,6 +207,7 @@ mod tests {
                                 "https://knusperstuebchen.net/wp-content/uploads/2019/03/Blumenkohl-Curry-mit-Reis-Cauliflower-Curry-with-Rice-Rezept.pdf",
                             ),
                         ],
+                        parsed_ingredients: vec![],
                     }],
                     ..Default::default()
                 },
@@ -247,6 +249,7 @@ mod tests {
                             files: vec![
                                 "https://instakoch.de/wp-content/uploads/2019/03/00100dPORTRAIT_00100_BURST20190307134048578_COVER-01-01.jpeg".to_string(),
                             ],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
@@ -254,56 +257,67 @@ mod tests {
                             files: vec![
                                 "https://instakoch.de/wp-content/uploads/2019/03/IMG_20190307_130210.jpg".to_string(),
                             ],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Jetzt den Backofen auf 200 Grad Ober- und Unterhitze vorheizen.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Die Tarteform leicht einfetten.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Die Zwiebeln und den Lauch klein schneiden.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "In einer Pfanne, mit wenig Öl, den Schinken kurz anbraten.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Die Zwiebeln und den Lauch hinzufügen und anschwitzen.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "In einer Schüssel, die Eier mit Sahne und Crème fraîche miteinander vermischen.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Mit Salz, schwarzem Pfeffer und Muskatnuss abschmecken.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Die gehackte Petersilie hinzugeben und alles gut miteinander vermischen.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Den Teig auf bemehlter Fläche ausrollen, bis er 3 cm größer ist als die Tarteform.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Den Teig in die Tarteform geben, festdrücken und den Rand abschneiden.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
@@ -311,38 +325,46 @@ mod tests {
                             files: vec![
                                 "https://instakoch.de/wp-content/uploads/2019/03/00100dPORTRAIT_00100_BURST20190307144836120_COVER-01.jpeg".to_string(),
                             ],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Dann den Reibekäse und die Hälfte der Sahne-Ei-Mischung hinzugeben.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Den Rest der Zwiebel-Lauch-Mischung hinzufügen.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Etwas Reibekäse und den Rest der Sahne-Ei-Mischung hinzufügen und den übrigen Reibekäse obendrauf verteilen".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Dann etwa 45 Minuten lang im Backofen auf mittlerem Einschub backen, oder bis der Rand goldbraun ist.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Herausnehmen und 5 Minuten lang ruhen lassen. Dann aus der Form nehmen und portionsweise auf Tellern servieren.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Guten Appetit!".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                     ],
+                    ..Default::default()
                 },
             },
             ExpectedGet {
@@ -382,83 +404,100 @@ mod tests {
                             ingredients: vec![],
                             description: "Knoblauch schälen und fein hacken.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "In einem Topf das Olivenöl erhitzen und den Knoblauch zusammen mit den Chiliflocken bei milder Hitze ca. 3-4 Min. andünsten.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Tomaten aus der Dose und Weißwein hinzufügen und bei mittlerer Hitze offen etwa 15 Min. kochen lassen.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Thymian und Oregano waschen und trocken schütteln, die Blättchen abzupfen, fein hacken und zur Sauce geben. Mit Salz und Pfeffer würzen und für weitere 5 Min. köcheln.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Anschließend die Sauce in ein hohes Gefäß füllen und mit dem Pürierstab schön cremig pürieren.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Zwiebel und Karotte schälen und fein hacken. Paprika und Zucchini waschen, putzen und in feine Würfel schneiden. Den Spinat waschen und gut abtropfen lassen.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "In einer großen Pfanne das Olivenöl erhitzen und das Gemüse – bis auf den Spinat – bei mittlerer Hitze etwa 5 Min. andünsten. Spinat hinzufügen und kräftig mit Salz und Pfeffer würzen.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Basilikum waschen und trocken schütteln, die Blättchen abzupfen und fein hacken.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Ricotta und Basilikum unter das Gemüse rühren und alles noch einmal kräftig mit Salz und Pfeffer abschmecken.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Den Backofen auf 200 Grad Ober-/Unterhitze vorheizen.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Ein Drittel der Tomatensauce auf dem Boden der Form glatt streichen. Drei Lasagneblätter darauf legen. Die Hälfte der Gemüsemischung auf die Nudeln geben und ebenfalls glatt streichen.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Es folgt das zweite Drittel der Tomatensauce, sowie ein Drittel des geriebenen Mozzarellas.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Darauf drei Lasagneblätter, die zweite Hälfte der Gemüsemischung, sowie ein weiteres Drittel Mozzarella.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Noch einmal 3 Lasagneblätter und die restliche Tomatensauce.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Den übrigen Mozzarella mit 50 g geriebenem Parmesan vermischen und den Käse über der Lasagne verteilen.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                         ExternalRecipeStep {
                             ingredients: vec![],
                             description: "Die Lasagne mit Alufolie abdecken und in der Mitte des vorgeheizten Ofens für 20 Min. backen. Anschließend die Folie entfernen und die Lasagne für weitere 15-20 Min. backen, bis sie schön goldbraun ist und die Nudeln gar sind. Im ausgeschalteten Ofen etwa 5 Min. sitzen lassen und dann servieren.".to_string(),
                             files: vec![],
+                            parsed_ingredients: vec![],
                         },
                     ],
+                    ..Default::default()
                 },
             },
         ]
@@ -469,4 +508,43 @@ mod tests {
         crate::tests::run();
         assert_expected_gets(expected_gets()).await;
     }
+
+    #[tokio::test]
+    async fn test_get_hand_written() {
+        assert_expected_gets(vec![
+            ExpectedGet {
+                url: String::from("https://www.pinterest.de/pin/1234/"),
+                external_recipe: ExternalRecipe {
+                    name: String::from("Zimtschnecken"),
+                    steps: vec![ExternalRecipeStep {
+                        description: String::from(
+                            "Zutaten:\n- 500 g Mehl\n- 2 EL Zimt\nZubereitung:\n1. Den Teig kneten",
+                        ),
+                        files: vec![String::from(
+                            "https://v1.pinimg.com/videos/zimtschnecken.mp4",
+                        )],
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+            },
+            ExpectedGet {
+                // pin to https://sallys-blog.de/rezepte/zimtschnecken
+                url: String::from("https://pin.it/Zimtschnecken"),
+                external_recipe: ExternalRecipe {
+                    name: String::from("Zimtschnecken"),
+                    steps: vec![ExternalRecipeStep {
+                        ingredients: vec![String::from("500 g Mehl"), String::from("2 EL Zimt")],
+                        description: String::from("TeigKnete den Teig."),
+                        files: vec![String::from(
+                            "https://sallys-blog.de/_next/image?url=zimtschnecken.jpg&w=3840&q=75",
+                        )],
+                        parsed_ingredients: vec![],
+                    }],
+                    ..Default::default()
+                },
+            },
+        ])
+        .await;
+    }
 }
@@ -9,12 +9,12 @@ use url::Url;
 
 use crate::{
     external_recipe::{
-        client,
         error::ExternalRecipeError,
+        get_dom,
         specific::{SpecificExternalRecipeGetterTrait, UrlMatch},
         ExternalRecipe, ExternalRecipeStep,
     },
-    scraper::{Dom, ParentNode},
+    scraper::ParentNode,
 };
 
 static PATH_REGEX: OnceLock<Regex> = OnceLock::new();
@@ -26,10 +26,12 @@ pub struct ExternalRecipeGetter;
 
 #[async_trait]
 impl SpecificExternalRecipeGetterTrait for ExternalRecipeGetter {
+    fn name(&self) -> &str {
+        "sallys_welt"
+    }
+
     async fn get(&self, url: Url) -> Result<ExternalRecipe, ExternalRecipeError> {
-        let response = client().get(url.clone()).send().await?;
-        let text = response.text().await?;
-        let dom = Dom::create(text).await?;
+        let dom = get_dom(url.clone()).await?;
         let mut steps = vec![];
         for step_element in dom.select_all(".recipe").await? {
             let mut ingredients = vec![];
@@ -67,6 +69,7 @@ impl SpecificExternalRecipeGetterTrait for ExternalRecipeGetter {
                 ingredients,
                 description: step_element.text_content().await?,
                 files: files.into_iter().collect(),
+                parsed_ingredients: vec![],
             });
         }
         Ok(ExternalRecipe {
@@ -78,8 +81,8 @@ impl SpecificExternalRecipeGetterTrait for ExternalRecipeGetter {
 
     fn url_matches(&self) -> Vec<UrlMatch<'static>> {
         vec![UrlMatch {
-            schemes: &["http", "https"],
-            domains: &["sallys-blog.de"],
+            schemes: vec!["http", "https"],
+            domains: vec!["sallys-blog.de"],
             path_regex: PATH_REGEX.get_or_init(|| Regex::new(r"^/rezepte/.*$").unwrap()),
         }]
     }
@@ -131,7 +134,9 @@ mod tests {
                 "https://sallys-blog.de/_next/image?url=https%3A%2F%2Fimg2.storyblok.com%2F950x650%2Ff%2F130848%2F799x533%2F9f6ed05c7c%2F1292_19674_ofenkebab_kartoffel_3-jpg.jpg&w=3840&q=75".to_string(),
                 "https://sallys-blog.de/_next/image?url=https%3A%2F%2Fimg2.storyblok.com%2F950x650%2Ff%2F130848%2F799x533%2F2db64f46e1%2F1292_19675_ofenkebab_kartoffel_2-jpg.jpg&w=3840&q=75".to_string(),
                     ],
+                    parsed_ingredients: vec![],
                 }],
+                ..Default::default()
             },
         }]
     }
@@ -141,4 +146,24 @@ mod tests {
         crate::tests::run();
         assert_expected_gets(expected_gets()).await;
     }
+
+    #[tokio::test]
+    async fn test_get_hand_written() {
+        assert_expected_gets(vec![ExpectedGet {
+            url: String::from("https://sallys-blog.de/rezepte/zimtschnecken"),
+            external_recipe: ExternalRecipe {
+                name: String::from("Zimtschnecken"),
+                steps: vec![ExternalRecipeStep {
+                    ingredients: vec![String::from("500 g Mehl"), String::from("2 EL Zimt")],
+                    description: String::from("TeigKnete den Teig."),
+                    files: vec![String::from(
+                        "https://sallys-blog.de/_next/image?url=zimtschnecken.jpg&w=3840&q=75",
+                    )],
+                    parsed_ingredients: vec![],
+                }],
+                ..Default::default()
+            },
+        }])
+        .await;
+    }
 }
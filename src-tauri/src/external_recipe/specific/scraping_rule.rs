@@ -0,0 +1,320 @@
+//! This module implements [`SpecificExternalRecipeGetterTrait`] for user-defined scraping rules.
+//!
+//! Each rule is a TOML file in the directory [`rules_dir`], which defines the URLs it matches and the CSS selectors of the recipe parts:
+//!
+//! ```toml
+//! name = "zimtblog"
+//!
+//! [[url_matches]]
+//! # the schemes default to http and https
+//! schemes = ["https"]
+//! domains = ["zimtblog.example"]
+//! path_regex = "^/rezepte/.+$"
+//!
+//! [selectors]
+//! name = "h1.recipe-title"
+//! # the ingredients, steps and images are optional
+//! ingredients = ".ingredients li"
+//! steps = ".instructions li"
+//! images = ".recipe img"
+//! # the attribute containing the image URL, which defaults to src
+//! image_attribute = "data-src"
+//! ```
+//!
+//! The rules are read whenever an external recipe is got, so that new or changed rules apply without restarting the app.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use indexmap::IndexSet;
+use regex::Regex;
+use serde::Deserialize;
+use tokio::fs;
+use url::Url;
+
+use crate::{
+    external_recipe::{
+        error::ExternalRecipeError,
+        get_dom,
+        specific::{SpecificExternalRecipeGetterTrait, UrlMatch},
+        ExternalRecipe, ExternalRecipeStep,
+    },
+    path::app_data_dir,
+    scraper::ParentNode,
+};
+
+fn default_schemes() -> Vec<String> {
+    vec![String::from("http"), String::from("https")]
+}
+
+fn default_image_attribute() -> String {
+    String::from("src")
+}
+
+/// This struct represents a rule file.
+#[derive(Debug, Clone, Deserialize)]
+struct Rule {
+    name: String,
+    url_matches: Vec<RuleUrlMatch>,
+    selectors: RuleSelectors,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct RuleUrlMatch {
+    #[serde(default = "default_schemes")]
+    schemes: Vec<String>,
+    domains: Vec<String>,
+    path_regex: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct RuleSelectors {
+    name: String,
+    ingredients: Option<String>,
+    steps: Option<String>,
+    images: Option<String>,
+    #[serde(default = "default_image_attribute")]
+    image_attribute: String,
+}
+
+/// This struct represents a [`RuleUrlMatch`] prepared for creating [`UrlMatch`]es.
+struct PreparedRuleUrlMatch {
+    schemes: Vec<String>,
+    domains: Vec<String>,
+    path_regex: Regex,
+}
+
+pub struct ExternalRecipeGetter {
+    name: String,
+    url_matches: Vec<PreparedRuleUrlMatch>,
+    selectors: RuleSelectors,
+}
+
+impl ExternalRecipeGetter {
+    /// Parse a rule file.
+    ///
+    /// The getter is named after the rule, prefixed with `rule:` so that it never collides with a built-in getter.
+    fn parse(toml: &str) -> Result<Self> {
+        let rule: Rule = toml::from_str(toml)?;
+        let mut url_matches = vec![];
+        for url_match in rule.url_matches {
+            url_matches.push(PreparedRuleUrlMatch {
+                schemes: url_match.schemes,
+                domains: url_match.domains,
+                path_regex: Regex::new(&url_match.path_regex)?,
+            });
+        }
+        Ok(Self {
+            name: format!("rule:{}", rule.name),
+            url_matches,
+            selectors: rule.selectors,
+        })
+    }
+}
+
+#[async_trait]
+impl SpecificExternalRecipeGetterTrait for ExternalRecipeGetter {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn get(&self, url: Url) -> Result<ExternalRecipe, ExternalRecipeError> {
+        let dom = get_dom(url.clone()).await?;
+        let Some(name_element) = dom.select(&self.selectors.name).await? else {
+            return Err(ExternalRecipeError::ParseError(format!(
+                "The rule \"{}\" found no name.",
+                self.name
+            )));
+        };
+        let mut ingredients = vec![];
+        if let Some(ingredients_selector) = &self.selectors.ingredients {
+            for ingredient_element in dom.select_all(ingredients_selector).await? {
+                let text_content = ingredient_element.text_content().await?;
+                let trimmed = text_content.trim();
+                if trimmed.is_empty() {
+                    continue;
+                }
+                ingredients.push(String::from(trimmed));
+            }
+        }
+        let mut steps = vec![];
+        if let Some(steps_selector) = &self.selectors.steps {
+            for step_element in dom.select_all(steps_selector).await? {
+                let text_content = step_element.text_content().await?;
+                let trimmed = text_content.trim();
+                if trimmed.is_empty() {
+                    continue;
+                }
+                steps.push(ExternalRecipeStep {
+                    description: String::from(trimmed),
+                    ..Default::default()
+                });
+            }
+        }
+        let mut files = IndexSet::new();
+        if let Some(images_selector) = &self.selectors.images {
+            for image_element in dom.select_all(images_selector).await? {
+                let attribute = image_element
+                    .get_attribute(&self.selectors.image_attribute)
+                    .await?;
+                if attribute.trim().is_empty() {
+                    continue;
+                }
+                match Url::options().base_url(Some(&url)).parse(attribute.trim()) {
+                    Ok(image_url) => {
+                        files.insert(image_url.to_string());
+                    }
+                    Err(err) => {
+                        log::warn!(
+                            "Could not parse image attribute \"{}\" as URL for \"{}\": {}",
+                            &attribute,
+                            &url,
+                            err
+                        );
+                    }
+                }
+            }
+        }
+        Ok(ExternalRecipe {
+            name: String::from(name_element.text_content().await?.trim()),
+            ingredients,
+            files: files.into_iter().collect(),
+            steps,
+            ..Default::default()
+        })
+    }
+
+    fn url_matches(&self) -> Vec<UrlMatch<'_>> {
+        self.url_matches
+            .iter()
+            .map(|url_match| UrlMatch {
+                schemes: url_match.schemes.iter().map(String::as_str).collect(),
+                domains: url_match.domains.iter().map(String::as_str).collect(),
+                path_regex: &url_match.path_regex,
+            })
+            .collect()
+    }
+}
+
+/// Get the directory of the rule files.
+pub fn rules_dir() -> PathBuf {
+    app_data_dir().join("scraping_rules")
+}
+
+/// Read the getters of all rule files in a directory, ordered by their file names.
+///
+/// Files which can not be read or parsed are skipped with a warning, so that one broken rule does not break the others.
+async fn read_getters(dir: &Path) -> Vec<ExternalRecipeGetter> {
+    let Ok(mut read_dir) = fs::read_dir(dir).await else {
+        return vec![];
+    };
+    let mut paths: Vec<PathBuf> = vec![];
+    while let Ok(Some(dir_entry)) = read_dir.next_entry().await {
+        let path = dir_entry.path();
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "toml")
+        {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    let mut getters = vec![];
+    for path in paths {
+        match fs::read_to_string(&path)
+            .await
+            .map_err(anyhow::Error::from)
+            .and_then(|toml| ExternalRecipeGetter::parse(&toml))
+        {
+            Ok(getter) => getters.push(getter),
+            Err(err) => log::warn!("Could not read the scraping rule {}: {err}", path.display()),
+        }
+    }
+    getters
+}
+
+/// Get the getters of all rule files in [`rules_dir`].
+pub async fn getters() -> Vec<ExternalRecipeGetter> {
+    read_getters(&rules_dir()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const RULE: &str = r#"name = "zimtblog"
+
+[[url_matches]]
+domains = ["example.com"]
+path_regex = "^/blog/.+$"
+
+[selectors]
+name = "h1"
+ingredients = ".zutaten li"
+steps = ".zubereitung p"
+images = "article img"
+image_attribute = "data-src"
+"#;
+
+    #[test]
+    fn test_parse() {
+        let getter = ExternalRecipeGetter::parse(RULE).unwrap();
+        assert_eq!(getter.name(), "rule:zimtblog");
+        assert!(getter.can_get(&Url::parse("https://www.example.com/blog/stollen").unwrap()));
+        assert!(!getter.can_get(&Url::parse("https://example.com/rezepte/stollen").unwrap()));
+        assert!(!getter.can_get(&Url::parse("ftp://example.com/blog/stollen").unwrap()));
+        assert!(ExternalRecipeGetter::parse("name = \"ohne Selektoren\"").is_err());
+        let invalid_regex_rule = RULE.replace("^/blog/.+$", "^/blog/(");
+        assert!(ExternalRecipeGetter::parse(&invalid_regex_rule).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_read_getters() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("b.toml"), RULE).unwrap();
+        std::fs::write(
+            dir.path().join("a.toml"),
+            RULE.replace("zimtblog", "adventsblog"),
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("c.toml"), "kein TOML").unwrap();
+        std::fs::write(dir.path().join("d.txt"), RULE).unwrap();
+        let getters = read_getters(dir.path()).await;
+        let names: Vec<&str> = getters.iter().map(|getter| getter.name()).collect();
+        assert_eq!(names, vec!["rule:adventsblog", "rule:zimtblog"]);
+        assert!(read_getters(&dir.path().join("fehlt")).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get() {
+        crate::tests::run();
+        let getter = ExternalRecipeGetter::parse(RULE).unwrap();
+        let url = Url::parse("https://example.com/blog/zimtsterne").unwrap();
+        assert_eq!(
+            getter.get(url).await.unwrap(),
+            ExternalRecipe {
+                name: String::from("Zimtsterne"),
+                ingredients: vec![
+                    String::from("3 Eiweiß"),
+                    String::from("250 g Puderzucker"),
+                    String::from("1 EL Zimt"),
+                ],
+                files: vec![String::from("https://example.com/bilder/zimtsterne.jpg")],
+                steps: vec![
+                    ExternalRecipeStep {
+                        description: String::from("Das Eiweiß steif schlagen."),
+                        ..Default::default()
+                    },
+                    ExternalRecipeStep {
+                        description: String::from("Die Sterne ausstechen und backen."),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }
+        );
+    }
+}
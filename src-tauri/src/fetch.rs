@@ -0,0 +1,396 @@
+//! This module implements fetching from the world wide web for the external recipe getters and file downloads.
+//!
+//! Pages are cached on disk, requests are retried with backoff after transient errors,
+//! the concurrent requests per host are limited and the robots.txt of each host is respected.
+
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, Mutex, OnceLock},
+    time::Duration,
+};
+
+use encoding_rs::{Encoding, UTF_8};
+use mime_guess::mime::{Mime, CHARSET};
+use reqwest::{header, Client, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use url::Url;
+
+use crate::{
+    command::preference::{select_preference, FETCH_SETTINGS},
+    database,
+    fetch::error::FetchError,
+    path::app_data_dir,
+};
+
+mod cache;
+pub mod error;
+#[cfg(test)]
+mod recording;
+mod robots;
+
+/// This is the user agent of all requests.
+const USER_AGENT: &str = concat!(
+    "RecipeBook/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/Toromyx/recipe-book)"
+);
+
+/// This is the name the rules of a robots.txt are looked up with, see [`USER_AGENT`].
+const ROBOTS_USER_AGENT: &str = "RecipeBook";
+
+/// This is the longest time waited before a retry, even if the server asks for a longer one.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+static CLIENT_ONCE_LOCK: OnceLock<Client> = OnceLock::new();
+
+static HOST_SEMAPHORES_ONCE_LOCK: OnceLock<Mutex<HashMap<String, (usize, Arc<Semaphore>)>>> =
+    OnceLock::new();
+
+fn client() -> &'static Client {
+    CLIENT_ONCE_LOCK.get_or_init(|| {
+        reqwest::ClientBuilder::new()
+            .user_agent(USER_AGENT)
+            .connect_timeout(Duration::from_secs(10))
+            .build()
+            .unwrap()
+    })
+}
+
+/// This struct contains the settings of fetching, which are stored as preference.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct FetchSettings {
+    /// the seconds after which a request times out
+    pub timeout: u64,
+    /// how often a request is retried after a transient error
+    pub retries: u32,
+    /// the maximum number of requests sent to the same host at the same time
+    pub max_requests_per_host: usize,
+    /// the seconds a cached page is used instead of fetching it again
+    pub cache_max_age: u64,
+    pub respect_robots_txt: bool,
+}
+
+impl Default for FetchSettings {
+    fn default() -> Self {
+        Self {
+            timeout: 10,
+            retries: 2,
+            max_requests_per_host: 2,
+            cache_max_age: 60 * 60 * 24,
+            respect_robots_txt: true,
+        }
+    }
+}
+
+/// Get the fetch settings, which are the default ones if they can not be read.
+pub async fn settings() -> FetchSettings {
+    let db = database::connect().await;
+    match select_preference(db, FETCH_SETTINGS).await {
+        Ok(settings_option) => settings_option.unwrap_or_default(),
+        Err(err) => {
+            log::warn!("Could not read the fetch settings: {err}");
+            FetchSettings::default()
+        }
+    }
+}
+
+/// This struct represents a successful response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchResponse {
+    /// the URL of the response after redirects
+    pub url: String,
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+}
+
+impl FetchResponse {
+    /// Decode the body with the charset of its content type, which defaults to UTF-8.
+    pub fn text(&self) -> String {
+        let encoding = self
+            .content_type
+            .as_deref()
+            .and_then(|content_type| content_type.parse::<Mime>().ok())
+            .and_then(|mime| Encoding::for_label(mime.get_param(CHARSET)?.as_str().as_bytes()))
+            .unwrap_or(UTF_8);
+        let (text, _, _) = encoding.decode(&self.body);
+        text.into_owned()
+    }
+
+    async fn from_response(response: Response) -> Result<Self, FetchError> {
+        let url = response.url().to_string();
+        #[cfg(test)]
+        let url = recording::recorded_url(&response).unwrap_or(url);
+        let content_type = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .map(String::from);
+        let body = response.bytes().await?.to_vec();
+        Ok(Self {
+            url,
+            content_type,
+            body,
+        })
+    }
+}
+
+/// Fetch a page, e.g. of an external recipe.
+///
+/// The page is taken from the cache if it was fetched recently.
+///
+/// # Errors
+///
+/// - [`FetchError::DisallowedByRobots`] when the robots.txt of the host disallows fetching the page
+/// - [`FetchError::Status`] when the response is no success, even after retrying
+/// - [`FetchError::Reqwest`] when the request fails, even after retrying
+pub async fn fetch_page(url: &Url) -> Result<FetchResponse, FetchError> {
+    fetch_page_allowed(url, false).await
+}
+
+/// Fetch a page like [`fetch_page`], but never take it from the cache, e.g. when refreshing a recipe from its source.
+///
+/// The fetched page still replaces the cached one.
+///
+/// # Errors
+///
+/// - see [`fetch_page`]
+pub async fn fetch_page_fresh(url: &Url) -> Result<FetchResponse, FetchError> {
+    fetch_page_allowed(url, true).await
+}
+
+/// Fetch a page if the robots.txt of its host allows it, see [`fetch_page`] and [`fetch_page_fresh`].
+async fn fetch_page_allowed(url: &Url, fresh: bool) -> Result<FetchResponse, FetchError> {
+    let settings = settings().await;
+    if settings.respect_robots_txt && !robots::is_allowed(url, &settings).await {
+        return Err(FetchError::DisallowedByRobots(url.to_string()));
+    }
+    if fresh {
+        fetch_uncached_caching(url, &settings).await
+    } else {
+        fetch_cached(url, &settings).await
+    }
+}
+
+/// Fetch a file, e.g. an image of an external recipe.
+///
+/// Files are not cached, because they are stored in the [`crate::file_storage`] anyway,
+/// and they are not checked against the robots.txt, because they only belong to an already fetched page.
+///
+/// # Errors
+///
+/// - [`FetchError::Status`] when the response is no success, even after retrying
+/// - [`FetchError::Reqwest`] when the request fails, even after retrying
+pub async fn fetch_file(url: &Url) -> Result<FetchResponse, FetchError> {
+    let settings = settings().await;
+    fetch_uncached(url, &settings).await
+}
+
+fn cache_dir() -> PathBuf {
+    app_data_dir().join("cache")
+}
+
+async fn fetch_cached(url: &Url, settings: &FetchSettings) -> Result<FetchResponse, FetchError> {
+    let max_age = Duration::from_secs(settings.cache_max_age);
+    if let Some(response) = cache::read(&cache_dir(), url, max_age).await {
+        return Ok(response);
+    }
+    fetch_uncached_caching(url, settings).await
+}
+
+/// Send a request via [`fetch_uncached`] and cache its response.
+async fn fetch_uncached_caching(
+    url: &Url,
+    settings: &FetchSettings,
+) -> Result<FetchResponse, FetchError> {
+    let response = fetch_uncached(url, settings).await?;
+    if let Err(err) = cache::write(&cache_dir(), url, &response).await {
+        log::warn!("Could not cache the response of {url}: {err}");
+    }
+    Ok(response)
+}
+
+/// Send a request and retry it after transient errors.
+///
+/// The permit for the host is held while waiting for a retry, so that a busy host is not asked even more.
+/// Tests send the request to the stand-in serving recorded responses instead, see [`recording`].
+async fn fetch_uncached(url: &Url, settings: &FetchSettings) -> Result<FetchResponse, FetchError> {
+    #[cfg(test)]
+    let request_url = recording::stand_in_url(url);
+    #[cfg(not(test))]
+    let request_url = url.clone();
+    let _permit = acquire_host_permit(url, settings.max_requests_per_host).await;
+    let mut attempt = 0;
+    loop {
+        let result = client()
+            .get(request_url.clone())
+            .timeout(Duration::from_secs(settings.timeout))
+            .send()
+            .await;
+        #[cfg(test)]
+        if let Ok(response) = &result {
+            recording::assert_recorded(url, response);
+        }
+        let delay = match result {
+            Ok(response) if response.status().is_success() => {
+                return FetchResponse::from_response(response).await;
+            }
+            Ok(response)
+                if attempt < settings.retries && is_transient_status(response.status()) =>
+            {
+                retry_delay(attempt, retry_after(&response))
+            }
+            Ok(response) => {
+                return Err(FetchError::Status {
+                    url: url.to_string(),
+                    status: response.status(),
+                });
+            }
+            Err(err) if attempt < settings.retries && (err.is_timeout() || err.is_connect()) => {
+                retry_delay(attempt, None)
+            }
+            Err(err) => return Err(err.into()),
+        };
+        log::info!("Retrying {url} in {delay:?}");
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+/// Wait until less than `max_requests` requests are sent to the host of the URL.
+async fn acquire_host_permit(url: &Url, max_requests: usize) -> OwnedSemaphorePermit {
+    let max_requests = max_requests.max(1);
+    let semaphore = {
+        let mut host_semaphores = HOST_SEMAPHORES_ONCE_LOCK
+            .get_or_init(|| Mutex::new(HashMap::new()))
+            .lock()
+            .unwrap();
+        let host = url.host_str().unwrap_or_default().to_string();
+        let (semaphore_max_requests, semaphore) = host_semaphores
+            .entry(host)
+            .or_insert_with(|| (max_requests, Arc::new(Semaphore::new(max_requests))));
+        // the running requests keep their permits of the replaced semaphore
+        if *semaphore_max_requests != max_requests {
+            *semaphore_max_requests = max_requests;
+            *semaphore = Arc::new(Semaphore::new(max_requests));
+        }
+        semaphore.clone()
+    };
+    semaphore
+        .acquire_owned()
+        .await
+        .expect("The host semaphores are never closed.")
+}
+
+fn is_transient_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::REQUEST_TIMEOUT
+            | StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// Get the delay the server asks for in seconds via the `Retry-After` header.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let seconds = response
+        .headers()
+        .get(header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
+/// Get the delay before the retry after the attempt with index `attempt`.
+///
+/// The delay doubles with every attempt, unless the server asks for a specific one.
+fn retry_delay(attempt: u32, retry_after: Option<Duration>) -> Duration {
+    retry_after
+        .unwrap_or_else(|| Duration::from_millis(500) * 2u32.saturating_pow(attempt))
+        .min(MAX_RETRY_DELAY)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_text() {
+        let response = FetchResponse {
+            url: String::from("https://example.com/"),
+            content_type: Some(String::from("text/html; charset=ISO-8859-1")),
+            body: vec![b'G', b'e', b'b', 0xe4, b'c', b'k'],
+        };
+        assert_eq!(response.text(), "Gebäck");
+        let response = FetchResponse {
+            content_type: None,
+            body: "Gebäck".as_bytes().to_vec(),
+            ..response
+        };
+        assert_eq!(response.text(), "Gebäck");
+    }
+
+    #[test]
+    fn test_is_transient_status() {
+        assert!(is_transient_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_transient_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!is_transient_status(StatusCode::NOT_FOUND));
+        assert!(!is_transient_status(StatusCode::FORBIDDEN));
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        assert_eq!(retry_delay(0, None), Duration::from_millis(500));
+        assert_eq!(retry_delay(2, None), Duration::from_secs(2));
+        assert_eq!(retry_delay(20, None), MAX_RETRY_DELAY);
+        assert_eq!(
+            retry_delay(0, Some(Duration::from_secs(5))),
+            Duration::from_secs(5)
+        );
+        assert_eq!(
+            retry_delay(0, Some(Duration::from_secs(3600))),
+            MAX_RETRY_DELAY
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_page() {
+        crate::tests::run();
+        let url = Url::parse("https://example.com/rezepte/zimtschnecken").unwrap();
+        let response = fetch_page(&url).await.unwrap();
+        assert_eq!(response.url, url.as_str());
+        assert_eq!(
+            response.content_type.as_deref(),
+            Some("text/html; charset=utf-8")
+        );
+        assert!(response.text().contains("<h1>Zimtschnecken</h1>"));
+        assert_eq!(fetch_page_fresh(&url).await.unwrap(), response);
+        let url = Url::parse("https://example.com/privat/rezepte").unwrap();
+        assert!(matches!(
+            fetch_page(&url).await,
+            Err(FetchError::DisallowedByRobots(_))
+        ));
+    }
+
+    #[test]
+    fn test_fetch_settings_deserialize() {
+        let settings: FetchSettings = serde_json::from_str("{\"timeout\":30}").unwrap();
+        assert_eq!(
+            settings,
+            FetchSettings {
+                timeout: 30,
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,108 @@
+//! This module caches fetched responses on disk.
+//!
+//! Each response is stored in a JSON file named by the hash of its URL.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+use url::Url;
+use uuid::Uuid;
+
+use crate::fetch::FetchResponse;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheEntry {
+    /// the requested URL, which differs from the URL of the response after redirects
+    request_url: String,
+    /// the seconds since the unix epoch
+    fetched_at: u64,
+    url: String,
+    content_type: Option<String>,
+    /// the base64 encoded body
+    body: String,
+}
+
+fn entry_path(dir: &Path, url: &Url) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    url.as_str().hash(&mut hasher);
+    dir.join(format!("{:016x}.json", hasher.finish()))
+}
+
+/// Read the cached response of a URL if it is not older than `max_age`.
+pub async fn read(dir: &Path, url: &Url, max_age: Duration) -> Option<FetchResponse> {
+    let json = fs::read_to_string(entry_path(dir, url)).await.ok()?;
+    let entry: CacheEntry = serde_json::from_str(&json).ok()?;
+    // the hashes of different URLs can collide
+    if entry.request_url != url.as_str() {
+        return None;
+    }
+    let fetched_at = UNIX_EPOCH + Duration::from_secs(entry.fetched_at);
+    let age = SystemTime::now()
+        .duration_since(fetched_at)
+        .unwrap_or_default();
+    if age > max_age {
+        return None;
+    }
+    Some(FetchResponse {
+        url: entry.url,
+        content_type: entry.content_type,
+        body: STANDARD.decode(entry.body).ok()?,
+    })
+}
+
+/// Write the response of a URL into the cache, replacing an older one.
+///
+/// The entry is written to a temporary file first, so that concurrent reads never see a partial entry.
+pub async fn write(dir: &Path, url: &Url, response: &FetchResponse) -> anyhow::Result<()> {
+    let entry = CacheEntry {
+        request_url: url.to_string(),
+        fetched_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+        url: response.url.clone(),
+        content_type: response.content_type.clone(),
+        body: STANDARD.encode(&response.body),
+    };
+    fs::create_dir_all(dir).await?;
+    let temp_path = dir.join(format!("{}.tmp", Uuid::new_v4()));
+    fs::write(&temp_path, serde_json::to_string(&entry)?).await?;
+    fs::rename(&temp_path, entry_path(dir, url)).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_write_read() {
+        let dir = tempfile::tempdir().unwrap();
+        let url = Url::parse("https://example.com/zimtschnecken").unwrap();
+        let response = FetchResponse {
+            url: String::from("https://www.example.com/zimtschnecken"),
+            content_type: Some(String::from("text/html")),
+            body: "<html></html>".as_bytes().to_vec(),
+        };
+        assert_eq!(read(dir.path(), &url, Duration::from_secs(60)).await, None);
+        write(dir.path(), &url, &response).await.unwrap();
+        assert_eq!(
+            read(dir.path(), &url, Duration::from_secs(60)).await,
+            Some(response)
+        );
+        let other_url = Url::parse("https://example.com/stollen").unwrap();
+        assert_eq!(
+            read(dir.path(), &other_url, Duration::from_secs(60)).await,
+            None
+        );
+        tokio::time::sleep(Duration::from_millis(1_100)).await;
+        assert_eq!(read(dir.path(), &url, Duration::ZERO).await, None);
+    }
+}
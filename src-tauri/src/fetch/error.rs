@@ -0,0 +1,14 @@
+//! This module contains the [`std::error::Error`] for the [`crate::fetch`] module.
+
+use reqwest::StatusCode;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum FetchError {
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+    #[error("Fetching \"{url}\" failed with status {status}.")]
+    Status { url: String, status: StatusCode },
+    #[error("Fetching \"{0}\" is disallowed by the robots.txt of its host.")]
+    DisallowedByRobots(String),
+}
@@ -0,0 +1,348 @@
+//! This module implements a local HTTP stand-in for the world wide web in tests, which serves recorded responses.
+//!
+//! All requests of the tests are sent to the stand-in, see [`stand_in_url`], which looks up the recording of the requested URL in [`recordings_dir`].
+//! Each recording consists of a JSON file with the status and headers and a file with the body, so that recorded pages stay readable.
+//!
+//! The environment variable `RECIPE_BOOK_RECORD` controls when the stand-in fetches the live response and records it:
+//! - `never` (default): Only recorded responses are served, a missing recording fails the test, see [`assert_recorded`].
+//! - `missing`: Missing recordings are recorded.
+//! - `all`: All recordings are refreshed, except hand-written ones, which have no [`Recording::recorded_at`].
+//!
+//! Recording needs access to the world wide web, so it is opt-in and the recordings are committed.
+//!
+//! Hand-written recordings are useful for pages which do not exist on the world wide web, e.g. to test edge cases of a getter.
+
+use std::{
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, Result};
+use reqwest::{header, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use tokio::{
+    fs,
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+};
+use url::Url;
+
+use crate::fetch::USER_AGENT;
+
+/// This is the header the stand-in sends the URL of the recorded response after redirects with.
+const RECORDED_URL_HEADER: &str = "x-recorded-url";
+
+/// This is the header the stand-in marks its answer to a URL without recording with.
+const MISSING_RECORDING_HEADER: &str = "x-missing-recording";
+
+static STAND_IN_ADDR_ONCE_LOCK: OnceLock<SocketAddr> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RecordMode {
+    Never,
+    Missing,
+    All,
+}
+
+fn record_mode() -> RecordMode {
+    match std::env::var("RECIPE_BOOK_RECORD").as_deref() {
+        Ok("missing") => RecordMode::Missing,
+        Ok("all") => RecordMode::All,
+        _ => RecordMode::Never,
+    }
+}
+
+/// This struct represents the recorded response of a URL without its body.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Recording {
+    /// the requested URL, which differs from [`Recording::response_url`] after redirects
+    url: String,
+    status: u16,
+    response_url: String,
+    content_type: Option<String>,
+    /// the seconds since the unix epoch, which is [`None`] for hand-written recordings
+    recorded_at: Option<u64>,
+}
+
+fn recordings_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("recordings")
+}
+
+/// Hash a string with [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function).
+///
+/// Unlike [`std::collections::hash_map::DefaultHasher`], the hash is stable between Rust versions, which the committed file names need.
+fn fnv1a(string: &str) -> u64 {
+    string.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Get the paths of the JSON file and the body file of the recording of a URL.
+///
+/// The recordings are grouped by host and named by their readable path, followed by the hash of the whole URL.
+fn recording_paths(url: &Url) -> (PathBuf, PathBuf) {
+    let path_and_query = match url.query() {
+        Some(query) => format!("{}?{query}", url.path()),
+        None => url.path().to_string(),
+    };
+    let mut slug = String::new();
+    for character in path_and_query.chars() {
+        if character.is_ascii_alphanumeric() {
+            slug.push(character.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug: String = slug.trim_end_matches('-').chars().take(80).collect();
+    let slug = if slug.is_empty() {
+        String::from("index")
+    } else {
+        slug
+    };
+    let dir = recordings_dir().join(url.host_str().unwrap_or("unknown"));
+    let name = format!("{slug}-{:016x}", fnv1a(url.as_str()));
+    (
+        dir.join(format!("{name}.json")),
+        dir.join(format!("{name}.body")),
+    )
+}
+
+async fn read_recording(url: &Url) -> Option<(Recording, Vec<u8>)> {
+    let (json_path, body_path) = recording_paths(url);
+    let json = fs::read_to_string(json_path).await.ok()?;
+    let recording: Recording = serde_json::from_str(&json).ok()?;
+    // the hashes of different URLs can collide
+    if recording.url != url.as_str() {
+        return None;
+    }
+    let body = fs::read(body_path).await.ok()?;
+    Some((recording, body))
+}
+
+/// Fetch the live response of a URL and record it.
+async fn record(url: &Url) -> Result<(Recording, Vec<u8>)> {
+    let response = reqwest::Client::builder()
+        .user_agent(USER_AGENT)
+        .timeout(Duration::from_secs(30))
+        .build()?
+        .get(url.clone())
+        .send()
+        .await?;
+    let recording = Recording {
+        url: url.to_string(),
+        status: response.status().as_u16(),
+        response_url: response.url().to_string(),
+        content_type: response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .map(String::from),
+        recorded_at: Some(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs()),
+    };
+    let body = response.bytes().await?.to_vec();
+    let (json_path, body_path) = recording_paths(url);
+    if let Some(dir) = json_path.parent() {
+        fs::create_dir_all(dir).await?;
+    }
+    fs::write(json_path, serde_json::to_string_pretty(&recording)? + "\n").await?;
+    fs::write(body_path, &body).await?;
+    log::info!("Recorded {url}");
+    Ok((recording, body))
+}
+
+/// Get the recording of a URL, recording it depending on [`record_mode`].
+async fn recording_for(url: &Url) -> Result<Option<(Recording, Vec<u8>)>> {
+    let recording_option = read_recording(url).await;
+    let should_record = match (record_mode(), &recording_option) {
+        (RecordMode::Never, _) => false,
+        (RecordMode::Missing, recording_option) => recording_option.is_none(),
+        (RecordMode::All, Some((recording, _))) => recording.recorded_at.is_some(),
+        (RecordMode::All, None) => true,
+    };
+    if should_record {
+        return Ok(Some(record(url).await?));
+    }
+    Ok(recording_option)
+}
+
+/// Answer one request of the stand-in.
+///
+/// The stand-in only understands `GET` requests for its own URLs, see [`stand_in_url`], and closes the connection after each response.
+async fn respond(mut stream: TcpStream) -> Result<()> {
+    let (read_half, mut write_half) = stream.split();
+    let mut reader = BufReader::new(read_half);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await?;
+    // the headers of the request do not matter
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).await? == 0 || line.trim().is_empty() {
+            break;
+        }
+    }
+    let target = request_line
+        .split_whitespace()
+        .nth(1)
+        .ok_or_else(|| anyhow!("Malformed request line: {request_line}"))?;
+    let url = Url::parse("http://stand-in/")?
+        .join(target)?
+        .query_pairs()
+        .find(|(key, _)| key == "url")
+        .map(|(_, url)| Url::parse(&url))
+        .transpose()?
+        .ok_or_else(|| anyhow!("Missing URL in request target: {target}"))?;
+    let (status, mut headers, body) = match recording_for(&url).await? {
+        Some((recording, body)) => {
+            let mut headers = vec![(RECORDED_URL_HEADER, recording.response_url)];
+            if let Some(content_type) = recording.content_type {
+                headers.push((header::CONTENT_TYPE.as_str(), content_type));
+            }
+            (StatusCode::from_u16(recording.status)?, headers, body)
+        }
+        None => {
+            let message = format!(
+                "There is no recording of {url}, record it by running the tests with RECIPE_BOOK_RECORD=missing."
+            );
+            log::error!("{message}");
+            (
+                StatusCode::NOT_FOUND,
+                vec![(MISSING_RECORDING_HEADER, String::from("1"))],
+                message.into_bytes(),
+            )
+        }
+    };
+    headers.push((header::CONTENT_LENGTH.as_str(), body.len().to_string()));
+    headers.push((header::CONNECTION.as_str(), String::from("close")));
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\n",
+        status.as_u16(),
+        status.canonical_reason().unwrap_or_default()
+    );
+    for (name, value) in headers {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    head.push_str("\r\n");
+    write_half.write_all(head.as_bytes()).await?;
+    write_half.write_all(&body).await?;
+    write_half.shutdown().await?;
+    Ok(())
+}
+
+async fn serve(listener: std::net::TcpListener) {
+    let listener = TcpListener::from_std(listener).expect("The listener is non-blocking.");
+    loop {
+        let Ok((stream, _)) = listener.accept().await else {
+            continue;
+        };
+        tokio::spawn(async move {
+            if let Err(err) = respond(stream).await {
+                log::error!("The stand-in could not respond: {err}");
+            }
+        });
+    }
+}
+
+/// Get the address of the stand-in, starting it if it does not run yet.
+///
+/// The stand-in runs on its own thread, because each test has its own runtime, which stops at the end of the test.
+fn stand_in_addr() -> SocketAddr {
+    *STAND_IN_ADDR_ONCE_LOCK.get_or_init(|| {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.set_nonblocking(true).unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+                .block_on(serve(listener));
+        });
+        addr
+    })
+}
+
+/// Get the URL of the stand-in which serves the recording of a URL.
+pub fn stand_in_url(url: &Url) -> Url {
+    let mut stand_in_url = Url::parse(&format!("http://{}/", stand_in_addr())).unwrap();
+    stand_in_url
+        .query_pairs_mut()
+        .append_pair("url", url.as_str());
+    stand_in_url
+}
+
+/// Get the URL of a response of the stand-in after redirects, which the recording contains.
+pub fn recorded_url(response: &Response) -> Option<String> {
+    response
+        .headers()
+        .get(RECORDED_URL_HEADER)?
+        .to_str()
+        .ok()
+        .map(String::from)
+}
+
+/// Fail the test if the stand-in has no recording of a URL.
+///
+/// A missing robots.txt is fine, because a robots.txt which can not be fetched allows everything.
+pub fn assert_recorded(url: &Url, response: &Response) {
+    if url.path() != "/robots.txt" && response.headers().contains_key(MISSING_RECORDING_HEADER) {
+        panic!(
+            "There is no recording of {url}, record it by running the tests with RECIPE_BOOK_RECORD=missing."
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_recording_paths() {
+        let url = Url::parse("https://example.com/rezepte/zimtschnecken/?portionen=12").unwrap();
+        let (json_path, body_path) = recording_paths(&url);
+        assert_eq!(
+            json_path,
+            recordings_dir().join(format!(
+                "example.com/rezepte-zimtschnecken-portionen-12-{:016x}.json",
+                fnv1a(url.as_str())
+            ))
+        );
+        assert_eq!(body_path, json_path.with_extension("body"));
+        let (json_path, _) = recording_paths(&Url::parse("https://example.com/").unwrap());
+        let file_name = json_path.file_name().unwrap().to_string_lossy();
+        assert!(file_name.starts_with("index-"));
+    }
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(fnv1a(""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a("a"), 0xaf63dc4c8601ec8c);
+    }
+
+    #[tokio::test]
+    async fn test_stand_in() {
+        let url = Url::parse("https://example.com/rezepte/zimtschnecken").unwrap();
+        let response = reqwest::get(stand_in_url(&url)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            recorded_url(&response),
+            Some(String::from("https://example.com/rezepte/zimtschnecken"))
+        );
+        let text = response.text().await.unwrap();
+        assert!(text.contains("<h1>Zimtschnecken</h1>"));
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "There is no recording of https://example.com/rezepte/unbekannt")]
+    async fn test_stand_in_missing_recording() {
+        let url = Url::parse("https://example.com/rezepte/unbekannt").unwrap();
+        let response = reqwest::get(stand_in_url(&url)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_recorded(&url, &response);
+    }
+}
@@ -0,0 +1,159 @@
+//! This module implements checking URLs against the [robots.txt](https://www.rfc-editor.org/rfc/rfc9309) of their host.
+
+use regex::Regex;
+use url::Url;
+
+use crate::fetch::{fetch_cached, FetchSettings, ROBOTS_USER_AGENT};
+
+/// This struct represents an allow or disallow rule of a robots.txt.
+#[derive(Debug)]
+struct Rule {
+    allow: bool,
+    pattern: String,
+}
+
+impl Rule {
+    /// Check whether the rule matches a path, `*` matches any characters and a trailing `$` the end of the path.
+    fn matches(&self, path: &str) -> bool {
+        let (pattern, anchored) = match self.pattern.strip_suffix('$') {
+            Some(pattern) => (pattern, true),
+            None => (self.pattern.as_str(), false),
+        };
+        let regex = format!(
+            "^{}{}",
+            pattern
+                .split('*')
+                .map(regex::escape)
+                .collect::<Vec<_>>()
+                .join(".*"),
+            if anchored { "$" } else { "" }
+        );
+        Regex::new(&regex).is_ok_and(|regex| regex.is_match(path))
+    }
+}
+
+/// This struct represents the rules for a group of user agents.
+#[derive(Debug, Default)]
+struct Group {
+    /// the lowercase user agents
+    user_agents: Vec<String>,
+    rules: Vec<Rule>,
+}
+
+fn parse(robots_txt: &str) -> Vec<Group> {
+    let mut groups: Vec<Group> = vec![];
+    let mut is_reading_user_agents = false;
+    for line in robots_txt.lines() {
+        let line = line.split('#').next().unwrap_or_default();
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim().to_ascii_lowercase().as_str() {
+            "user-agent" => {
+                if !is_reading_user_agents {
+                    groups.push(Group::default());
+                }
+                is_reading_user_agents = true;
+                if let Some(group) = groups.last_mut() {
+                    group.user_agents.push(value.to_ascii_lowercase());
+                }
+            }
+            key @ ("allow" | "disallow") => {
+                is_reading_user_agents = false;
+                // an empty disallow rule allows everything, just like no rule
+                if value.is_empty() {
+                    continue;
+                }
+                if let Some(group) = groups.last_mut() {
+                    group.rules.push(Rule {
+                        allow: key == "allow",
+                        pattern: value.to_string(),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    groups
+}
+
+/// Check whether a robots.txt allows a user agent to fetch a path.
+///
+/// The rules of the groups for the user agent apply, or the ones for all user agents if there are none.
+/// The longest matching rule wins, an allow rule wins over a disallow rule of the same length.
+fn allows(robots_txt: &str, user_agent: &str, path: &str) -> bool {
+    let groups = parse(robots_txt);
+    let user_agent = user_agent.to_ascii_lowercase();
+    let groups_for = |user_agent: &str| -> Vec<&Group> {
+        groups
+            .iter()
+            .filter(|group| group.user_agents.iter().any(|agent| agent == user_agent))
+            .collect()
+    };
+    let mut matching_groups = groups_for(&user_agent);
+    if matching_groups.is_empty() {
+        matching_groups = groups_for("*");
+    }
+    matching_groups
+        .into_iter()
+        .flat_map(|group| &group.rules)
+        .filter(|rule| rule.matches(path))
+        .max_by_key(|rule| (rule.pattern.len(), rule.allow))
+        .map_or(true, |rule| rule.allow)
+}
+
+/// Check whether the robots.txt of the host of a URL allows fetching it.
+///
+/// A robots.txt which can not be fetched allows everything.
+pub async fn is_allowed(url: &Url, settings: &FetchSettings) -> bool {
+    let Ok(robots_url) = url.join("/robots.txt") else {
+        return true;
+    };
+    let Ok(response) = fetch_cached(&robots_url, settings).await else {
+        return true;
+    };
+    let path = match url.query() {
+        Some(query) => format!("{}?{query}", url.path()),
+        None => url.path().to_string(),
+    };
+    allows(&response.text(), ROBOTS_USER_AGENT, &path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROBOTS_TXT: &str = "# robots.txt of a recipe blog
+User-agent: *
+Disallow: /wp-admin/
+Allow: /wp-admin/admin-ajax.php
+Disallow: /*?print=
+Disallow: /*.pdf$
+
+User-agent: GPTBot
+User-agent: RecipeBook
+Disallow: /premium/
+
+Sitemap: https://example.com/sitemap.xml
+";
+
+    #[test]
+    fn test_allows() {
+        assert!(allows(ROBOTS_TXT, "Crawler", "/zimtschnecken/"));
+        assert!(!allows(ROBOTS_TXT, "Crawler", "/wp-admin/options.php"));
+        assert!(allows(ROBOTS_TXT, "Crawler", "/wp-admin/admin-ajax.php"));
+        assert!(!allows(ROBOTS_TXT, "Crawler", "/zimtschnecken/?print=1"));
+        assert!(!allows(ROBOTS_TXT, "Crawler", "/zimtschnecken.pdf"));
+        assert!(allows(ROBOTS_TXT, "Crawler", "/zimtschnecken.pdf.html"));
+        // only the group for the specific user agent applies
+        assert!(allows(ROBOTS_TXT, "RecipeBook", "/wp-admin/options.php"));
+        assert!(!allows(ROBOTS_TXT, "recipebook", "/premium/stollen/"));
+        assert!(allows("", "RecipeBook", "/premium/stollen/"));
+        assert!(allows(
+            "User-agent: *\nDisallow:\n",
+            "RecipeBook",
+            "/premium/stollen/"
+        ));
+    }
+}
@@ -0,0 +1,536 @@
+//! This module implements parsing of ingredient texts like "450g Mehl (Typ 550)".
+//!
+//! See [`parse`] for more information.
+//! Yield texts like "Für 4 Portionen" are parsed with the same quantities, see [`parse_yield`].
+
+/// These unit names are known in addition to the unit names passed to [`parse`].
+///
+/// They can not be converted into other units, that is why they are not part of [`crate::entity::unit_name`].
+pub const COUNTABLE_UNIT_NAMES: [&str; 24] = [
+    "Becher",
+    "Bund",
+    "Dose",
+    "Glas",
+    "handvoll",
+    "Msp.",
+    "Päckchen",
+    "Pck.",
+    "Platte",
+    "Platten",
+    "Scheibe",
+    "Scheiben",
+    "Stange",
+    "Stangen",
+    "Zehe",
+    "Zehen",
+    "Zweig",
+    "Zweige",
+    "can",
+    "cans",
+    "clove",
+    "cloves",
+    "slice",
+    "slices",
+];
+
+/// These are the unicode characters for fractions and their values.
+const VULGAR_FRACTIONS: [(char, f64); 15] = [
+    ('½', 1.0 / 2.0),
+    ('⅓', 1.0 / 3.0),
+    ('⅔', 2.0 / 3.0),
+    ('¼', 1.0 / 4.0),
+    ('¾', 3.0 / 4.0),
+    ('⅕', 1.0 / 5.0),
+    ('⅖', 2.0 / 5.0),
+    ('⅗', 3.0 / 5.0),
+    ('⅘', 4.0 / 5.0),
+    ('⅙', 1.0 / 6.0),
+    ('⅚', 5.0 / 6.0),
+    ('⅛', 1.0 / 8.0),
+    ('⅜', 3.0 / 8.0),
+    ('⅝', 5.0 / 8.0),
+    ('⅞', 7.0 / 8.0),
+];
+
+/// These are the separators between the lower and upper bound of a [`Quantity::Range`].
+const RANGE_SEPARATORS: [&str; 5] = ["-", "–", "bis ", "to ", "or "];
+
+/// This enum represents the quantity of an ingredient.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Quantity {
+    Exact(f64),
+    /// the lower and upper bound, e.g. "1-2"
+    Range(f64, f64),
+}
+
+impl Quantity {
+    /// Get a single value for the quantity, which is the lower bound for a [`Quantity::Range`].
+    pub fn value(&self) -> f64 {
+        match *self {
+            Quantity::Exact(value) => value,
+            Quantity::Range(min, _) => min,
+        }
+    }
+}
+
+/// This struct represents a parsed ingredient text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedIngredient {
+    pub quantity: Option<Quantity>,
+    pub unit: Option<String>,
+    pub name: String,
+    /// the texts in parentheses and after a comma, e.g. "Typ 550"
+    pub notes: Vec<String>,
+}
+
+impl ParsedIngredient {
+    /// Get the quality of the ingredient.
+    ///
+    /// It consists of the notes and the range of a [`Quantity::Range`], because a range can not be stored as a single quantity.
+    pub fn quality(&self) -> Option<String> {
+        let range = match self.quantity {
+            Some(Quantity::Range(min, max)) => Some(format!("{min}-{max}")),
+            _ => None,
+        };
+        let quality = range
+            .into_iter()
+            .chain(self.notes.iter().cloned())
+            .collect::<Vec<_>>()
+            .join(", ");
+        if quality.is_empty() {
+            return None;
+        }
+        Some(quality)
+    }
+}
+
+/// Parse an ingredient text into quantity, unit, name, and notes.
+///
+/// The quantity may be a decimal number with a point or comma, a fraction like "1/2", "1 1/2", or "1½", or a range like "1-2".
+/// A unit is recognized if it is one of the `unit_names` or [`COUNTABLE_UNIT_NAMES`], ignoring case and a trailing period.
+/// A word glued to the quantity like in "1TL" is always recognized as unit.
+///
+/// Returns [`None`] if the text does not contain an ingredient name.
+pub fn parse<S>(text: &str, unit_names: &[S]) -> Option<ParsedIngredient>
+where
+    S: AsRef<str>,
+{
+    let (text, mut notes) = split_off_parentheses(text);
+    let rest = text.as_str();
+    let (quantity, rest) = match parse_quantity(rest) {
+        Some((quantity, rest)) => (Some(quantity), rest),
+        None => (None, rest),
+    };
+    let (unit, rest) = parse_unit(rest, quantity.is_some(), unit_names);
+    let name = match rest.split_once(", ") {
+        Some((name, note)) => {
+            notes.extend(normalize_whitespace(note));
+            name
+        }
+        None => rest,
+    }
+    .trim()
+    .trim_end_matches(',');
+    if !name.chars().any(char::is_alphabetic) {
+        return None;
+    }
+    Some(ParsedIngredient {
+        quantity,
+        unit,
+        name: name.to_string(),
+        notes,
+    })
+}
+
+/// Parse a yield text into the first quantity and the text after it as unit.
+///
+/// Any text before the quantity is skipped, e.g. "Für 4-6 Portionen" is parsed as 4-6 and "Portionen".
+///
+/// Returns [`None`] if the text does not contain a quantity.
+pub fn parse_yield(text: &str) -> Option<(Quantity, Option<String>)> {
+    let (quantity, rest) = text.char_indices().find_map(|(i, char)| {
+        let starts_number = char.is_ascii_digit()
+            || VULGAR_FRACTIONS
+                .iter()
+                .any(|(fraction, _)| *fraction == char);
+        let after_digit = text[..i].ends_with(|char: char| char.is_ascii_digit());
+        if !starts_number || after_digit {
+            return None;
+        }
+        parse_quantity(&text[i..])
+    })?;
+    Some((quantity, normalize_whitespace(rest)))
+}
+
+/// Collapse all whitespace into single spaces.
+///
+/// Returns [`None`] if the text is blank.
+pub fn normalize_whitespace(text: &str) -> Option<String> {
+    let normalized = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if normalized.is_empty() {
+        return None;
+    }
+    Some(normalized)
+}
+
+/// Split the text into the text outside of parentheses and the texts inside of parentheses.
+///
+/// Both are returned with normalized whitespace.
+fn split_off_parentheses(text: &str) -> (String, Vec<String>) {
+    let mut outside = String::new();
+    let mut insides = vec![];
+    let mut inside = String::new();
+    let mut depth = 0;
+    for char in text.chars() {
+        match char {
+            '(' => {
+                if depth > 0 {
+                    inside.push(char);
+                }
+                depth += 1;
+            }
+            ')' if depth > 0 => {
+                depth -= 1;
+                if depth > 0 {
+                    inside.push(char);
+                } else {
+                    insides.extend(normalize_whitespace(&inside));
+                    inside.clear();
+                    outside.push(' ');
+                }
+            }
+            _ if depth > 0 => inside.push(char),
+            _ => outside.push(char),
+        }
+    }
+    // an unclosed parenthesis lasts until the end
+    insides.extend(normalize_whitespace(&inside));
+    (normalize_whitespace(&outside).unwrap_or_default(), insides)
+}
+
+/// Split off the leading ASCII digits.
+fn split_digits(text: &str) -> Option<(&str, &str)> {
+    let end = text
+        .find(|char: char| !char.is_ascii_digit())
+        .unwrap_or(text.len());
+    if end == 0 {
+        return None;
+    }
+    Some(text.split_at(end))
+}
+
+/// Parse a leading unicode fraction like "½".
+fn parse_vulgar_fraction(text: &str) -> Option<(f64, &str)> {
+    let char = text.chars().next()?;
+    let (_, value) = VULGAR_FRACTIONS
+        .iter()
+        .find(|(fraction, _)| *fraction == char)?;
+    Some((*value, &text[char.len_utf8()..]))
+}
+
+/// Parse a leading fraction like "½" or "1/2".
+fn parse_fraction(text: &str) -> Option<(f64, &str)> {
+    if let Some(fraction) = parse_vulgar_fraction(text) {
+        return Some(fraction);
+    }
+    let (numerator, rest) = split_digits(text)?;
+    let rest = rest.strip_prefix(['/', '⁄'])?;
+    let (denominator, rest) = split_digits(rest)?;
+    let numerator: f64 = numerator.parse().ok()?;
+    let denominator: f64 = denominator.parse().ok()?;
+    if denominator == 0.0 {
+        return None;
+    }
+    Some((numerator / denominator, rest))
+}
+
+/// Parse a leading number like "1", "0,5", "1.5", "1/2", "½", "1½", or "1 1/2".
+fn parse_number(text: &str) -> Option<(f64, &str)> {
+    if let Some(fraction) = parse_fraction(text) {
+        return Some(fraction);
+    }
+    let (integer, rest) = split_digits(text)?;
+    if let Some((fractional, rest)) = rest.strip_prefix(['.', ',']).and_then(split_digits) {
+        let decimal = format!("{integer}.{fractional}").parse().ok()?;
+        return Some((decimal, rest));
+    }
+    let integer: f64 = integer.parse().ok()?;
+    // a mixed number like "1½" or "1 1/2"
+    if let Some((fraction, rest)) = parse_vulgar_fraction(rest) {
+        return Some((integer + fraction, rest));
+    }
+    if let Some((fraction, rest)) = rest
+        .strip_prefix(' ')
+        .and_then(parse_fraction)
+        .filter(|(fraction, _)| *fraction < 1.0)
+    {
+        return Some((integer + fraction, rest));
+    }
+    Some((integer, rest))
+}
+
+/// Parse a leading quantity, which is a number or a range of numbers.
+///
+/// Returns the quantity and the rest of the text.
+pub fn parse_quantity(text: &str) -> Option<(Quantity, &str)> {
+    let (min, rest) = parse_number(text)?;
+    let max = RANGE_SEPARATORS.iter().find_map(|separator| {
+        let after_separator = rest.trim_start().strip_prefix(separator)?;
+        parse_number(after_separator.trim_start())
+    });
+    match max {
+        Some((max, rest)) => Some((Quantity::Range(min, max), rest)),
+        None => Some((Quantity::Exact(min), rest)),
+    }
+}
+
+/// Find the known unit name for a word.
+fn resolve_unit<'a, S>(word: &str, unit_names: &'a [S]) -> Option<&'a str>
+where
+    S: AsRef<str>,
+{
+    let word = word.trim_end_matches('.').to_lowercase();
+    unit_names
+        .iter()
+        .map(AsRef::<str>::as_ref)
+        .chain(COUNTABLE_UNIT_NAMES)
+        .find(|unit_name| unit_name.trim_end_matches('.').to_lowercase() == word)
+}
+
+/// Parse a leading unit.
+///
+/// A word glued to the quantity is always a unit, otherwise the first word is only a unit if it is known.
+/// Known units of two words like "fl oz" take precedence over the first word.
+fn parse_unit<'a, S>(
+    text: &'a str,
+    follows_quantity: bool,
+    unit_names: &[S],
+) -> (Option<String>, &'a str)
+where
+    S: AsRef<str>,
+{
+    let is_glued = follows_quantity && text.starts_with(char::is_alphabetic);
+    let text = text.trim_start();
+    let (word, rest) = text.split_once(' ').unwrap_or((text, ""));
+    let (second_word, second_rest) = rest.split_once(' ').unwrap_or((rest, ""));
+    if let Some(unit) = resolve_unit(&format!("{word} {second_word}"), unit_names) {
+        return (Some(unit.to_string()), second_rest);
+    }
+    match resolve_unit(word, unit_names) {
+        Some(unit) => (Some(unit.to_string()), rest),
+        None if is_glued => (Some(word.to_string()), rest),
+        None => (None, text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    /// These are some of the unit names of the migrations.
+    const UNIT_NAMES: [&str; 17] = [
+        "EL",
+        "TL",
+        "cup",
+        "cups",
+        "fl oz",
+        "g",
+        "gram",
+        "kg",
+        "kilogram",
+        "l",
+        "lb",
+        "litre",
+        "millilitre",
+        "ml",
+        "pound",
+        "pounds",
+        "tablespoons",
+    ];
+
+    fn parsed(
+        quantity: Option<Quantity>,
+        unit: Option<&str>,
+        name: &str,
+        notes: &[&str],
+    ) -> Option<ParsedIngredient> {
+        Some(ParsedIngredient {
+            quantity,
+            unit: unit.map(String::from),
+            name: String::from(name),
+            notes: notes.iter().map(|note| String::from(*note)).collect(),
+        })
+    }
+
+    #[test]
+    fn test_parse() {
+        let expected_parses = vec![
+            (
+                "450g Mehl (Typ 550)",
+                parsed(
+                    Some(Quantity::Exact(450.0)),
+                    Some("g"),
+                    "Mehl",
+                    &["Typ 550"],
+                ),
+            ),
+            (
+                "1TL Honig",
+                parsed(Some(Quantity::Exact(1.0)), Some("TL"), "Honig", &[]),
+            ),
+            (
+                "500 g Putenbrust (Oder Hähnchenbrust)",
+                parsed(
+                    Some(Quantity::Exact(500.0)),
+                    Some("g"),
+                    "Putenbrust",
+                    &["Oder Hähnchenbrust"],
+                ),
+            ),
+            (
+                "0,5 TL Paprikapulver  (edelsüß)",
+                parsed(
+                    Some(Quantity::Exact(0.5)),
+                    Some("TL"),
+                    "Paprikapulver",
+                    &["edelsüß"],
+                ),
+            ),
+            (
+                "1,5 TL Salz",
+                parsed(Some(Quantity::Exact(1.5)), Some("TL"), "Salz", &[]),
+            ),
+            (
+                "1/4 TL Chiliflocken",
+                parsed(Some(Quantity::Exact(0.25)), Some("TL"), "Chiliflocken", &[]),
+            ),
+            (
+                "½ TL Salz",
+                parsed(Some(Quantity::Exact(0.5)), Some("TL"), "Salz", &[]),
+            ),
+            (
+                "1½ cups flour",
+                parsed(Some(Quantity::Exact(1.5)), Some("cups"), "flour", &[]),
+            ),
+            (
+                "1 1/2 tablespoons olive oil, divided",
+                parsed(
+                    Some(Quantity::Exact(1.5)),
+                    Some("tablespoons"),
+                    "olive oil",
+                    &["divided"],
+                ),
+            ),
+            (
+                "4 fl oz milk",
+                parsed(Some(Quantity::Exact(4.0)), Some("fl oz"), "milk", &[]),
+            ),
+            (
+                "1-2 TL Salz",
+                parsed(Some(Quantity::Range(1.0, 2.0)), Some("TL"), "Salz", &[]),
+            ),
+            (
+                "3-4 EL Wasser",
+                parsed(Some(Quantity::Range(3.0, 4.0)), Some("EL"), "Wasser", &[]),
+            ),
+            (
+                "2 El Weißwein Essig",
+                parsed(
+                    Some(Quantity::Exact(2.0)),
+                    Some("EL"),
+                    "Weißwein Essig",
+                    &[],
+                ),
+            ),
+            (
+                "1 Msp. Chilipulver",
+                parsed(Some(Quantity::Exact(1.0)), Some("Msp."), "Chilipulver", &[]),
+            ),
+            (
+                "1  Stange Lauch",
+                parsed(Some(Quantity::Exact(1.0)), Some("Stange"), "Lauch", &[]),
+            ),
+            (
+                "1  kleine rote Zwiebel",
+                parsed(Some(Quantity::Exact(1.0)), None, "kleine rote Zwiebel", &[]),
+            ),
+            (
+                "1 Blumenkohl (ca. 750-1000g)",
+                parsed(
+                    Some(Quantity::Exact(1.0)),
+                    None,
+                    "Blumenkohl",
+                    &["ca. 750-1000g"],
+                ),
+            ),
+            (
+                "25g frische Hefe oder 1Pck. Trockenhefe",
+                parsed(
+                    Some(Quantity::Exact(25.0)),
+                    Some("g"),
+                    "frische Hefe oder 1Pck. Trockenhefe",
+                    &[],
+                ),
+            ),
+            (
+                "Etwas  Schnittlauch (Zum Garnieren.)",
+                parsed(None, None, "Etwas Schnittlauch", &["Zum Garnieren."]),
+            ),
+            ("Chorizo", parsed(None, None, "Chorizo", &[])),
+            ("500 g", None),
+            ("(optional)", None),
+            ("", None),
+        ];
+        for (text, expected_parse) in expected_parses {
+            assert_eq!(parse(text, &UNIT_NAMES), expected_parse, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_quality() {
+        let parsed_ingredient = parse("1-2 TL Salz (grob), gemahlen", &UNIT_NAMES).unwrap();
+        assert_eq!(
+            parsed_ingredient.quality(),
+            Some(String::from("1-2, grob, gemahlen"))
+        );
+        assert_eq!(
+            parsed_ingredient.quantity.map(|quantity| quantity.value()),
+            Some(1.0)
+        );
+        let parsed_ingredient = parse("1 Gurke", &UNIT_NAMES).unwrap();
+        assert_eq!(parsed_ingredient.quality(), None);
+    }
+
+    #[test]
+    fn test_parse_yield() {
+        let expected_parses = vec![
+            (
+                "4 servings",
+                Some((Quantity::Exact(4.0), Some(String::from("servings")))),
+            ),
+            (
+                "Für 4-6 Portionen",
+                Some((Quantity::Range(4.0, 6.0), Some(String::from("Portionen")))),
+            ),
+            (
+                "1 Springform (26 cm)",
+                Some((
+                    Quantity::Exact(1.0),
+                    Some(String::from("Springform (26 cm)")),
+                )),
+            ),
+            ("12", Some((Quantity::Exact(12.0), None))),
+            (
+                "½ Blech",
+                Some((Quantity::Exact(0.5), Some(String::from("Blech")))),
+            ),
+            ("einige Stücke", None),
+            ("", None),
+        ];
+        for (text, expected_parse) in expected_parses {
+            assert_eq!(parse_yield(text), expected_parse, "{text}");
+        }
+    }
+}
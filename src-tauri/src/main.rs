@@ -7,6 +7,10 @@ use tauri::Wry;
 
 use crate::command::{
     entity::{
+        collection::{
+            entity_count_collection, entity_create_collection, entity_delete_collection,
+            entity_list_collection, entity_read_collection, entity_update_collection,
+        },
         file::{
             entity_count_file, entity_create_file, entity_delete_file, entity_list_file,
             entity_read_file, entity_update_file,
@@ -15,10 +19,34 @@ use crate::command::{
             entity_count_ingredient, entity_create_ingredient, entity_delete_ingredient,
             entity_list_ingredient, entity_read_ingredient, entity_update_ingredient,
         },
+        ingredient_nutrition::{
+            entity_count_ingredient_nutrition, entity_create_ingredient_nutrition,
+            entity_delete_ingredient_nutrition, entity_list_ingredient_nutrition,
+            entity_read_ingredient_nutrition, entity_update_ingredient_nutrition,
+        },
+        meal_plan_entry::{
+            entity_count_meal_plan_entry, entity_create_meal_plan_entry,
+            entity_delete_meal_plan_entry, entity_list_meal_plan_entry,
+            entity_read_meal_plan_entry, entity_update_meal_plan_entry,
+        },
+        pantry_item::{
+            entity_count_pantry_item, entity_create_pantry_item, entity_delete_pantry_item,
+            entity_list_pantry_item, entity_read_pantry_item, entity_update_pantry_item,
+        },
         recipe::{
             entity_count_recipe, entity_create_recipe, entity_delete_recipe, entity_list_recipe,
             entity_read_recipe, entity_update_recipe,
         },
+        recipe_collection::{
+            entity_count_recipe_collection, entity_create_recipe_collection,
+            entity_delete_recipe_collection, entity_list_recipe_collection,
+            entity_read_recipe_collection, entity_update_recipe_collection,
+        },
+        recipe_declared_nutrition::{
+            entity_count_recipe_declared_nutrition, entity_create_recipe_declared_nutrition,
+            entity_delete_recipe_declared_nutrition, entity_list_recipe_declared_nutrition,
+            entity_read_recipe_declared_nutrition, entity_update_recipe_declared_nutrition,
+        },
         recipe_file::{
             entity_count_recipe_file, entity_create_recipe_file, entity_delete_recipe_file,
             entity_list_recipe_file, entity_read_recipe_file, entity_update_recipe_file,
@@ -47,18 +75,54 @@ use crate::command::{
             entity_delete_recipe_step_ingredient_draft, entity_list_recipe_step_ingredient_draft,
             entity_read_recipe_step_ingredient_draft, entity_update_recipe_step_ingredient_draft,
         },
+        recipe_tag::{
+            entity_count_recipe_tag, entity_create_recipe_tag, entity_delete_recipe_tag,
+            entity_list_recipe_tag, entity_read_recipe_tag, entity_update_recipe_tag,
+        },
+        shopping_list::{
+            entity_count_shopping_list, entity_create_shopping_list, entity_delete_shopping_list,
+            entity_list_shopping_list, entity_read_shopping_list, entity_update_shopping_list,
+        },
+        shopping_list_item::{
+            entity_count_shopping_list_item, entity_create_shopping_list_item,
+            entity_delete_shopping_list_item, entity_list_shopping_list_item,
+            entity_read_shopping_list_item, entity_update_shopping_list_item,
+        },
+        tag::{
+            entity_count_tag, entity_create_tag, entity_delete_tag, entity_list_tag,
+            entity_read_tag, entity_update_tag,
+        },
         unit_name::{
             entity_count_unit_name, entity_create_unit_name, entity_delete_unit_name,
             entity_list_unit_name, entity_read_unit_name, entity_update_unit_name,
         },
     },
-    external_recipe::external_recipe,
+    external_recipe::{
+        external_recipe, external_recipe_import_cancel, external_recipe_import_start,
+        external_recipe_saved, external_recipe_saved_import_start,
+    },
+    ingredient_draft::ingredient_draft_convert,
+    ingredient_nutrition_import::ingredient_nutrition_import,
+    meal_plan::{meal_plan_export_ics, meal_plan_shopping_list_create},
     ocr::ocr,
+    pantry_match::pantry_match,
+    preference::{
+        preference_get_fetch_settings, preference_get_unit_system, preference_set_fetch_settings,
+        preference_set_unit_system,
+    },
+    recipe_normalize::recipe_normalize,
+    recipe_nutrition::recipe_nutrition,
+    recipe_refresh::{recipe_refresh_apply, recipe_refresh_diff},
+    recipe_scale::recipe_scale,
+    recipe_step_description::recipe_step_description_render,
+    search::search,
+    shopping_list_add::shopping_list_add_recipes,
     unit_conversion::unit_convert,
     unit_list::unit_list_get,
 };
 
 mod app_handle;
+mod calendar;
 mod command;
 mod database;
 mod dom_content_loaded;
@@ -66,12 +130,16 @@ mod entity;
 mod entity_crud;
 mod event;
 mod external_recipe;
+mod fetch;
 mod file_storage;
 mod fs;
+mod ingredient_parser;
 mod log;
 mod migrator;
+mod nutrition;
 mod path;
 mod scraper;
+mod step_text_analyzer;
 mod unit_conversion;
 mod window;
 
@@ -91,6 +159,12 @@ fn setup() -> tauri::Builder<Wry> {
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
+            entity_create_collection,
+            entity_read_collection,
+            entity_update_collection,
+            entity_delete_collection,
+            entity_list_collection,
+            entity_count_collection,
             entity_create_file,
             entity_read_file,
             entity_update_file,
@@ -103,12 +177,42 @@ fn setup() -> tauri::Builder<Wry> {
             entity_delete_ingredient,
             entity_list_ingredient,
             entity_count_ingredient,
+            entity_create_ingredient_nutrition,
+            entity_read_ingredient_nutrition,
+            entity_update_ingredient_nutrition,
+            entity_delete_ingredient_nutrition,
+            entity_list_ingredient_nutrition,
+            entity_count_ingredient_nutrition,
+            entity_create_meal_plan_entry,
+            entity_read_meal_plan_entry,
+            entity_update_meal_plan_entry,
+            entity_delete_meal_plan_entry,
+            entity_list_meal_plan_entry,
+            entity_count_meal_plan_entry,
+            entity_create_pantry_item,
+            entity_read_pantry_item,
+            entity_update_pantry_item,
+            entity_delete_pantry_item,
+            entity_list_pantry_item,
+            entity_count_pantry_item,
             entity_create_recipe,
             entity_read_recipe,
             entity_update_recipe,
             entity_delete_recipe,
             entity_list_recipe,
             entity_count_recipe,
+            entity_create_recipe_collection,
+            entity_read_recipe_collection,
+            entity_update_recipe_collection,
+            entity_delete_recipe_collection,
+            entity_list_recipe_collection,
+            entity_count_recipe_collection,
+            entity_create_recipe_declared_nutrition,
+            entity_read_recipe_declared_nutrition,
+            entity_update_recipe_declared_nutrition,
+            entity_delete_recipe_declared_nutrition,
+            entity_list_recipe_declared_nutrition,
+            entity_count_recipe_declared_nutrition,
             entity_create_recipe_file,
             entity_read_recipe_file,
             entity_update_recipe_file,
@@ -145,6 +249,30 @@ fn setup() -> tauri::Builder<Wry> {
             entity_delete_recipe_step,
             entity_list_recipe_step,
             entity_count_recipe_step,
+            entity_create_recipe_tag,
+            entity_read_recipe_tag,
+            entity_update_recipe_tag,
+            entity_delete_recipe_tag,
+            entity_list_recipe_tag,
+            entity_count_recipe_tag,
+            entity_create_shopping_list,
+            entity_read_shopping_list,
+            entity_update_shopping_list,
+            entity_delete_shopping_list,
+            entity_list_shopping_list,
+            entity_count_shopping_list,
+            entity_create_shopping_list_item,
+            entity_read_shopping_list_item,
+            entity_update_shopping_list_item,
+            entity_delete_shopping_list_item,
+            entity_list_shopping_list_item,
+            entity_count_shopping_list_item,
+            entity_create_tag,
+            entity_read_tag,
+            entity_update_tag,
+            entity_delete_tag,
+            entity_list_tag,
+            entity_count_tag,
             entity_create_unit_name,
             entity_read_unit_name,
             entity_update_unit_name,
@@ -152,7 +280,28 @@ fn setup() -> tauri::Builder<Wry> {
             entity_list_unit_name,
             entity_count_unit_name,
             external_recipe,
+            external_recipe_import_cancel,
+            external_recipe_import_start,
+            external_recipe_saved,
+            external_recipe_saved_import_start,
+            ingredient_draft_convert,
+            ingredient_nutrition_import,
+            meal_plan_export_ics,
+            meal_plan_shopping_list_create,
             ocr,
+            pantry_match,
+            preference_get_unit_system,
+            preference_set_unit_system,
+            preference_get_fetch_settings,
+            preference_set_fetch_settings,
+            recipe_normalize,
+            recipe_nutrition,
+            recipe_refresh_apply,
+            recipe_refresh_diff,
+            recipe_scale,
+            recipe_step_description_render,
+            search,
+            shopping_list_add_recipes,
             unit_convert,
             unit_list_get,
         ])
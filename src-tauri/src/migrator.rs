@@ -5,13 +5,39 @@
 use sea_orm_migration::prelude::*;
 
 mod m20230306_214922_1_0_0;
+mod m20261018_090000_recipe_search;
+mod m20261018_100000_recipe_yield;
+mod m20261018_110000_ingredient_density;
+mod m20261018_120000_unit_catalogue;
+mod m20261018_130000_preference;
+mod m20261018_140000_shopping_list;
+mod m20261018_150000_meal_plan_entry;
+mod m20261018_160000_pantry_item;
+mod m20261018_170000_ingredient_nutrition;
+mod m20261018_180000_tag_collection;
+mod m20261018_190000_recipe_source;
+mod m20261018_200000_recipe_details;
 
 /// This struct implements [`MigratorTrait`] to run the migrations via [`<Self as MigrationTrait>::up`].
 pub struct Migrator;
 
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20230306_214922_1_0_0::Migration)]
+        vec![
+            Box::new(m20230306_214922_1_0_0::Migration),
+            Box::new(m20261018_090000_recipe_search::Migration),
+            Box::new(m20261018_100000_recipe_yield::Migration),
+            Box::new(m20261018_110000_ingredient_density::Migration),
+            Box::new(m20261018_120000_unit_catalogue::Migration),
+            Box::new(m20261018_130000_preference::Migration),
+            Box::new(m20261018_140000_shopping_list::Migration),
+            Box::new(m20261018_150000_meal_plan_entry::Migration),
+            Box::new(m20261018_160000_pantry_item::Migration),
+            Box::new(m20261018_170000_ingredient_nutrition::Migration),
+            Box::new(m20261018_180000_tag_collection::Migration),
+            Box::new(m20261018_190000_recipe_source::Migration),
+            Box::new(m20261018_200000_recipe_details::Migration),
+        ]
     }
 }
 
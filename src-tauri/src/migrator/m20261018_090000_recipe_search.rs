@@ -0,0 +1,231 @@
+//! This migration implements the full-text search index over recipe data.
+//!
+//! It creates the [FTS5](https://www.sqlite.org/fts5.html) table `recipe_search` and keeps it in sync with triggers.
+//! See [`crate::command::search`] for querying the index.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// This struct represents a table whose rows are indexed in the search table.
+///
+/// The SQL expressions may contain `{row}`, which is replaced by the row reference, e.g. `NEW` inside a trigger.
+struct SearchSource {
+    table: &'static str,
+    /// the SQL expression for the indexed text of a row
+    text: &'static str,
+    /// the SQL expression for the recipe id of a row
+    recipe_id: &'static str,
+    /// the column whose update changes the indexed text
+    text_column: &'static str,
+}
+
+impl SearchSource {
+    fn text(&self, row: &str) -> String {
+        self.text.replace("{row}", row)
+    }
+
+    fn recipe_id(&self, row: &str) -> String {
+        self.recipe_id.replace("{row}", row)
+    }
+
+    /// Get the SQL statements creating the triggers which keep the search table in sync with this source.
+    fn create_triggers(&self) -> String {
+        let table = self.table;
+        format!(
+            "CREATE TRIGGER \"trg-recipe_search-{table}-insert\" AFTER INSERT ON \"{table}\" BEGIN \
+            INSERT INTO \"recipe_search\" (\"recipe_id\", \"source\", \"source_id\", \"text\") \
+            VALUES ({recipe_id}, '{table}', NEW.\"id\", {text}); \
+            END; \
+            CREATE TRIGGER \"trg-recipe_search-{table}-update\" AFTER UPDATE OF \"{text_column}\" ON \"{table}\" BEGIN \
+            UPDATE \"recipe_search\" SET \"text\" = {text} \
+            WHERE \"source\" = '{table}' AND \"source_id\" = NEW.\"id\"; \
+            END; \
+            CREATE TRIGGER \"trg-recipe_search-{table}-delete\" AFTER DELETE ON \"{table}\" BEGIN \
+            DELETE FROM \"recipe_search\" WHERE \"source\" = '{table}' AND \"source_id\" = OLD.\"id\"; \
+            END;",
+            recipe_id = self.recipe_id("NEW"),
+            text = self.text("NEW"),
+            text_column = self.text_column,
+        )
+    }
+
+    /// Get the SQL statement indexing all existing rows of this source.
+    fn insert_existing(&self) -> String {
+        let table = self.table;
+        let row = format!("\"{table}\"");
+        format!(
+            "INSERT INTO \"recipe_search\" (\"recipe_id\", \"source\", \"source_id\", \"text\") \
+            SELECT {recipe_id}, '{table}', {row}.\"id\", {text} FROM {row};",
+            recipe_id = self.recipe_id(&row),
+            text = self.text(&row),
+        )
+    }
+}
+
+const SEARCH_SOURCES: [SearchSource; 5] = [
+    SearchSource {
+        table: "recipe",
+        text: "{row}.\"name\"",
+        recipe_id: "{row}.\"id\"",
+        text_column: "name",
+    },
+    SearchSource {
+        table: "recipe_step",
+        text: "{row}.\"description\"",
+        recipe_id: "{row}.\"recipe_id\"",
+        text_column: "description",
+    },
+    SearchSource {
+        table: "recipe_step_ingredient",
+        text: "(SELECT \"name\" FROM \"ingredient\" WHERE \"id\" = {row}.\"ingredient_id\")",
+        recipe_id: "(SELECT \"recipe_id\" FROM \"recipe_step\" WHERE \"id\" = {row}.\"recipe_step_id\")",
+        text_column: "ingredient_id",
+    },
+    SearchSource {
+        table: "recipe_ingredient_draft",
+        text: "{row}.\"text\"",
+        recipe_id: "{row}.\"recipe_id\"",
+        text_column: "text",
+    },
+    SearchSource {
+        table: "recipe_step_ingredient_draft",
+        text: "{row}.\"text\"",
+        recipe_id: "(SELECT \"recipe_id\" FROM \"recipe_step\" WHERE \"id\" = {row}.\"recipe_step_id\")",
+        text_column: "text",
+    },
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        // The diacritics are removed so that e.g. "kase" finds "Käse".
+        db.execute_unprepared(
+            "CREATE VIRTUAL TABLE \"recipe_search\" USING fts5(\
+            \"recipe_id\" UNINDEXED, \
+            \"source\" UNINDEXED, \
+            \"source_id\" UNINDEXED, \
+            \"text\", \
+            tokenize = 'unicode61 remove_diacritics 2', \
+            prefix = '2 3'\
+            );",
+        )
+        .await?;
+        for search_source in SEARCH_SOURCES {
+            db.execute_unprepared(&search_source.create_triggers())
+                .await?;
+            db.execute_unprepared(&search_source.insert_existing())
+                .await?;
+        }
+        // An ingredient is indexed via its recipe step ingredients.
+        db.execute_unprepared(
+            "CREATE TRIGGER \"trg-recipe_search-ingredient-update\" AFTER UPDATE OF \"name\" ON \"ingredient\" BEGIN \
+            UPDATE \"recipe_search\" SET \"text\" = NEW.\"name\" \
+            WHERE \"source\" = 'recipe_step_ingredient' \
+            AND \"source_id\" IN (SELECT \"id\" FROM \"recipe_step_ingredient\" WHERE \"ingredient_id\" = NEW.\"id\"); \
+            END;",
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+    use sea_orm::{ConnectionTrait, DatabaseConnection, Statement};
+
+    use crate::{
+        database::tests::get_table_schema, migrator::tests::get_memory_database_migrated,
+    };
+
+    async fn get_search_rows(db: &DatabaseConnection) -> Vec<(i64, String, String)> {
+        db.query_all(Statement::from_string(
+            db.get_database_backend(),
+            "SELECT `recipe_id`, `source`, `text` FROM `recipe_search` ORDER BY `source`, `source_id`",
+        ))
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|query_result| query_result.try_get_many_by_index().unwrap())
+        .collect()
+    }
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database_migrated().await;
+        let table_schema = get_table_schema("recipe_search", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE VIRTUAL TABLE \"recipe_search\" USING fts5(\
+            \"recipe_id\" UNINDEXED, \
+            \"source\" UNINDEXED, \
+            \"source_id\" UNINDEXED, \
+            \"text\", \
+            tokenize = 'unicode61 remove_diacritics 2', \
+            prefix = '2 3'\
+            )"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_triggers() {
+        let db = get_memory_database_migrated().await;
+        db.execute_unprepared(
+            "INSERT INTO `recipe` (`id`, `name`) VALUES (1, 'Käsekuchen'); \
+            INSERT INTO `recipe_step` (`id`, `order`, `description`, `recipe_id`) VALUES (1, 1, 'Backen', 1); \
+            INSERT INTO `ingredient` (`id`, `name`) VALUES (1, 'Quark'); \
+            INSERT INTO `recipe_step_ingredient` (`id`, `order`, `recipe_step_id`, `ingredient_id`) VALUES (1, 1, 1, 1); \
+            INSERT INTO `recipe_ingredient_draft` (`id`, `order`, `text`, `recipe_id`) VALUES (1, 1, '500 g Quark', 1); \
+            INSERT INTO `recipe_step_ingredient_draft` (`id`, `order`, `text`, `recipe_step_id`) VALUES (1, 1, '3 Eier', 1);",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            get_search_rows(&db).await,
+            vec![
+                (1, String::from("recipe"), String::from("Käsekuchen")),
+                (
+                    1,
+                    String::from("recipe_ingredient_draft"),
+                    String::from("500 g Quark")
+                ),
+                (1, String::from("recipe_step"), String::from("Backen")),
+                (
+                    1,
+                    String::from("recipe_step_ingredient"),
+                    String::from("Quark")
+                ),
+                (
+                    1,
+                    String::from("recipe_step_ingredient_draft"),
+                    String::from("3 Eier")
+                ),
+            ]
+        );
+        db.execute_unprepared(
+            "UPDATE `recipe` SET `name` = 'Quarkkuchen' WHERE `id` = 1; \
+            UPDATE `ingredient` SET `name` = 'Magerquark' WHERE `id` = 1; \
+            DELETE FROM `recipe_step` WHERE `id` = 1;",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            get_search_rows(&db).await,
+            vec![
+                (1, String::from("recipe"), String::from("Quarkkuchen")),
+                (
+                    1,
+                    String::from("recipe_ingredient_draft"),
+                    String::from("500 g Quark")
+                ),
+            ]
+        );
+        db.execute_unprepared("DELETE FROM `recipe` WHERE `id` = 1;")
+            .await
+            .unwrap();
+        assert!(get_search_rows(&db).await.is_empty());
+    }
+}
@@ -0,0 +1,65 @@
+//! This migration adds the yield to [`crate::entity::recipe`].
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite only supports one column per alter table statement.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Recipe::Table)
+                    .add_column(ColumnDef::new(Recipe::YieldAmount).double().null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Recipe::Table)
+                    .add_column(ColumnDef::new(Recipe::YieldUnit).string().null())
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum Recipe {
+    Table,
+    YieldAmount,
+    YieldUnit,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+    use sea_orm_migration::MigratorTrait;
+
+    use crate::{
+        database::tests::{get_memory_database, get_table_schema},
+        migrator::Migrator,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        // run all migrations up to this one
+        Migrator::up(&db, Some(3)).await.unwrap();
+        let table_schema = get_table_schema("recipe", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"recipe\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"name\" text NOT NULL , \
+            \"yield_amount\" real NULL, \
+            \"yield_unit\" text NULL\
+            )"
+        );
+    }
+}
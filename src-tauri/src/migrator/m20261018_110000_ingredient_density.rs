@@ -0,0 +1,147 @@
+//! This migration adds the density to [`crate::entity::ingredient`].
+//!
+//! It also creates [`crate::entity::common_ingredient_density`] with the densities of common ingredients.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// These are the densities of common ingredients in g/ml.
+///
+/// Most values are derived from the weight of one US cup.
+const COMMON_INGREDIENT_DENSITIES: [(&str, f64); 34] = [
+    ("Butter", 0.96),
+    ("Haferflocken", 0.38),
+    ("Honig", 1.42),
+    ("Joghurt", 1.03),
+    ("Kakaopulver", 0.42),
+    ("Mehl", 0.53),
+    ("Milch", 1.03),
+    ("Olivenöl", 0.91),
+    ("Puderzucker", 0.51),
+    ("Reis", 0.78),
+    ("Sahne", 1.0),
+    ("Salz", 1.2),
+    ("Speisestärke", 0.54),
+    ("Wasser", 1.0),
+    ("Weizenmehl", 0.53),
+    ("Zucker", 0.85),
+    ("Öl", 0.92),
+    ("all-purpose flour", 0.53),
+    ("brown sugar", 0.93),
+    ("butter", 0.96),
+    ("cocoa powder", 0.42),
+    ("cornstarch", 0.54),
+    ("flour", 0.53),
+    ("heavy cream", 1.0),
+    ("honey", 1.42),
+    ("milk", 1.03),
+    ("oil", 0.92),
+    ("olive oil", 0.91),
+    ("powdered sugar", 0.51),
+    ("rice", 0.78),
+    ("rolled oats", 0.38),
+    ("salt", 1.2),
+    ("sugar", 0.85),
+    ("water", 1.0),
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Ingredient::Table)
+                    .add_column(ColumnDef::new(Ingredient::Density).double().null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommonIngredientDensity::Table)
+                    .col(
+                        ColumnDef::new(CommonIngredientDensity::Name)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(CommonIngredientDensity::Density)
+                            .double()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        let mut insert = Query::insert()
+            .into_table(CommonIngredientDensity::Table)
+            .columns([
+                CommonIngredientDensity::Name,
+                CommonIngredientDensity::Density,
+            ])
+            .to_owned();
+        for (name, density) in COMMON_INGREDIENT_DENSITIES {
+            insert.values_panic([name.into(), density.into()]);
+        }
+        manager.exec_stmt(insert).await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum Ingredient {
+    Table,
+    Density,
+}
+
+#[derive(Iden)]
+pub enum CommonIngredientDensity {
+    Table,
+    Name,
+    Density,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+    use sea_orm::{ConnectionTrait, Statement};
+
+    use crate::{
+        database::tests::get_table_schema, migrator::tests::get_memory_database_migrated,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database_migrated().await;
+        let table_schema = get_table_schema("ingredient", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"ingredient\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"name\" text NOT NULL , \
+            \"density\" real NULL\
+            )"
+        );
+        let table_schema = get_table_schema("common_ingredient_density", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"common_ingredient_density\" ( \
+            \"name\" text NOT NULL PRIMARY KEY, \
+            \"density\" real NOT NULL \
+            )"
+        );
+        let query_result = db
+            .query_one(Statement::from_string(
+                db.get_database_backend(),
+                "SELECT `density` FROM `common_ingredient_density` WHERE `name` = 'flour'",
+            ))
+            .await
+            .unwrap()
+            .unwrap();
+        let density: f64 = query_result.try_get_by_index(0).unwrap();
+        assert_eq!(density, 0.53);
+    }
+}
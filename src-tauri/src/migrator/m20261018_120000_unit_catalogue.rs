@@ -0,0 +1,130 @@
+//! This migration adds the names of the units added to [`crate::entity::unit_name::unit::Unit`].
+//!
+//! The existing unit names stay unchanged, even if a user already added one of the names for another unit.
+
+use sea_orm_migration::{prelude::*, sea_query::OnConflict};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const UNIT_NAMES: [(&str, &str); 57] = [
+    ("Gramm", "MassGram"),
+    ("Kilogramm", "MassKilogram"),
+    ("lbs", "MassPound"),
+    ("oz", "MassOunce"),
+    ("ounce", "MassOunce"),
+    ("ounces", "MassOunce"),
+    ("Liter", "VolumeLitre"),
+    ("liter", "VolumeLitre"),
+    ("Milliliter", "VolumeMillilitre"),
+    ("milliliter", "VolumeMillilitre"),
+    ("dl", "VolumeDecilitre"),
+    ("decilitre", "VolumeDecilitre"),
+    ("deciliter", "VolumeDecilitre"),
+    ("Deziliter", "VolumeDecilitre"),
+    ("cl", "VolumeCentilitre"),
+    ("centilitre", "VolumeCentilitre"),
+    ("centiliter", "VolumeCentilitre"),
+    ("Zentiliter", "VolumeCentilitre"),
+    ("metric cup", "VolumeMetricCup"),
+    ("metric cups", "VolumeMetricCup"),
+    ("EL", "VolumeMetricTablespoon"),
+    ("Esslöffel", "VolumeMetricTablespoon"),
+    ("TL", "VolumeMetricTeaspoon"),
+    ("Teelöffel", "VolumeMetricTeaspoon"),
+    ("tbsp", "VolumeUsTablespoon"),
+    ("tablespoon", "VolumeUsTablespoon"),
+    ("tablespoons", "VolumeUsTablespoon"),
+    ("tsp", "VolumeUsTeaspoon"),
+    ("teaspoon", "VolumeUsTeaspoon"),
+    ("teaspoons", "VolumeUsTeaspoon"),
+    ("fl oz", "VolumeUsFluidOunce"),
+    ("fluid ounce", "VolumeUsFluidOunce"),
+    ("fluid ounces", "VolumeUsFluidOunce"),
+    ("pt", "VolumeUsPint"),
+    ("pint", "VolumeUsPint"),
+    ("pints", "VolumeUsPint"),
+    ("qt", "VolumeUsQuart"),
+    ("quart", "VolumeUsQuart"),
+    ("quarts", "VolumeUsQuart"),
+    ("gal", "VolumeUsGallon"),
+    ("gallon", "VolumeUsGallon"),
+    ("gallons", "VolumeUsGallon"),
+    ("imperial cup", "VolumeImperialCup"),
+    ("imperial cups", "VolumeImperialCup"),
+    ("imperial fl oz", "VolumeImperialFluidOunce"),
+    ("imperial fluid ounce", "VolumeImperialFluidOunce"),
+    ("imperial fluid ounces", "VolumeImperialFluidOunce"),
+    ("imperial pint", "VolumeImperialPint"),
+    ("imperial pints", "VolumeImperialPint"),
+    ("imperial quart", "VolumeImperialQuart"),
+    ("imperial quarts", "VolumeImperialQuart"),
+    ("imperial gallon", "VolumeImperialGallon"),
+    ("imperial gallons", "VolumeImperialGallon"),
+    ("pinch", "VolumePinch"),
+    ("pinches", "VolumePinch"),
+    ("Prise", "VolumePinch"),
+    ("Prisen", "VolumePinch"),
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let mut insert = Query::insert()
+            .into_table(UnitName::Table)
+            .columns([UnitName::Name, UnitName::Unit])
+            .on_conflict(OnConflict::column(UnitName::Name).do_nothing().to_owned())
+            .to_owned();
+        for (name, unit) in UNIT_NAMES {
+            insert.values_panic([name.into(), unit.into()]);
+        }
+        manager.exec_stmt(insert).await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum UnitName {
+    Table,
+    Name,
+    Unit,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use sea_orm::{ConnectionTrait, DatabaseConnection, Statement};
+    use sea_orm_migration::MigratorTrait;
+
+    use crate::{database::tests::get_memory_database, migrator::Migrator};
+
+    async fn get_unit(name: &str, db: &DatabaseConnection) -> String {
+        let query_result = db
+            .query_one(Statement::from_sql_and_values(
+                db.get_database_backend(),
+                "SELECT `unit` FROM `unit_name` WHERE `name` = ?",
+                [name.into()],
+            ))
+            .await
+            .unwrap()
+            .unwrap();
+        query_result.try_get_by_index(0).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        // run all migrations before this one
+        Migrator::up(&db, Some(4)).await.unwrap();
+        db.execute_unprepared(
+            "INSERT INTO `unit_name` (`name`, `unit`) VALUES ('TL', 'VolumeMillilitre');",
+        )
+        .await
+        .unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        assert_eq!(get_unit("TL", &db).await, "VolumeMillilitre");
+        assert_eq!(get_unit("EL", &db).await, "VolumeMetricTablespoon");
+        assert_eq!(get_unit("g", &db).await, "MassGram");
+        assert_eq!(get_unit("fl oz", &db).await, "VolumeUsFluidOunce");
+    }
+}
@@ -0,0 +1,56 @@
+//! This migration creates [`crate::entity::preference`].
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Preference::Table)
+                    .col(
+                        ColumnDef::new(Preference::Key)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Preference::Value).text().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum Preference {
+    Table,
+    Key,
+    Value,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+
+    use crate::{
+        database::tests::get_table_schema, migrator::tests::get_memory_database_migrated,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database_migrated().await;
+        let table_schema = get_table_schema("preference", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"preference\" ( \
+            \"key\" text NOT NULL PRIMARY KEY, \
+            \"value\" text NOT NULL \
+            )"
+        );
+    }
+}
@@ -0,0 +1,186 @@
+//! This migration creates [`crate::entity::shopping_list`] and [`crate::entity::shopping_list_item`].
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ShoppingList::Table)
+                    .col(
+                        ColumnDef::new(ShoppingList::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ShoppingList::Name).string().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(&index_name(&ShoppingList::Table, &ShoppingList::Name))
+                    .table(ShoppingList::Table)
+                    .col(ShoppingList::Name)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_table(
+                Table::create()
+                    .table(ShoppingListItem::Table)
+                    .col(
+                        ColumnDef::new(ShoppingListItem::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ShoppingListItem::Order).integer().not_null())
+                    .col(ColumnDef::new(ShoppingListItem::Quantity).double().null())
+                    .col(ColumnDef::new(ShoppingListItem::Unit).string().null())
+                    .col(ColumnDef::new(ShoppingListItem::Name).string().null())
+                    .col(
+                        ColumnDef::new(ShoppingListItem::Checked)
+                            .boolean()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ShoppingListItem::ShoppingListId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ShoppingListItem::IngredientId)
+                            .integer()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ShoppingListItem::Table, ShoppingListItem::ShoppingListId)
+                            .to(ShoppingList::Table, ShoppingList::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ShoppingListItem::Table, ShoppingListItem::IngredientId)
+                            .to(Ingredient::Table, Ingredient::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .index(
+                        Index::create()
+                            .col(ShoppingListItem::Order)
+                            .col(ShoppingListItem::ShoppingListId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        for col in [
+            ShoppingListItem::Order,
+            ShoppingListItem::ShoppingListId,
+            ShoppingListItem::IngredientId,
+        ] {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(&index_name(&ShoppingListItem::Table, &col))
+                        .table(ShoppingListItem::Table)
+                        .col(col)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum ShoppingList {
+    Table,
+    Id,
+    Name,
+}
+
+#[derive(Iden)]
+pub enum ShoppingListItem {
+    Table,
+    Id,
+    Order,
+    Quantity,
+    Unit,
+    Name,
+    Checked,
+    ShoppingListId,
+    IngredientId,
+}
+
+#[derive(Iden)]
+pub enum Ingredient {
+    Table,
+    Id,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use crate::{
+        database::tests::{get_table_indices, get_table_schema},
+        migrator::tests::get_memory_database_migrated,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database_migrated().await;
+        let table_schema = get_table_schema("shopping_list", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"shopping_list\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"name\" text NOT NULL \
+            )"
+        );
+        let table_schema = get_table_schema("shopping_list_item", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"shopping_list_item\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"order\" integer NOT NULL, \
+            \"quantity\" real NULL, \
+            \"unit\" text NULL, \
+            \"name\" text NULL, \
+            \"checked\" boolean NOT NULL, \
+            \"shopping_list_id\" integer NOT NULL, \
+            \"ingredient_id\" integer NULL, \
+            UNIQUE (\"order\", \"shopping_list_id\"), \
+            FOREIGN KEY (\"shopping_list_id\") REFERENCES \"shopping_list\" (\"id\") ON DELETE CASCADE, \
+            FOREIGN KEY (\"ingredient_id\") REFERENCES \"ingredient\" (\"id\") ON DELETE RESTRICT \
+            )"
+        );
+        let indices = get_table_indices("shopping_list_item", &db).await;
+        assert_eq!(
+            indices,
+            vec![
+                String::from(
+                    "CREATE INDEX \"idx-shopping_list_item-order\" ON \"shopping_list_item\" (\"order\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-shopping_list_item-shopping_list_id\" ON \"shopping_list_item\" (\"shopping_list_id\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-shopping_list_item-ingredient_id\" ON \"shopping_list_item\" (\"ingredient_id\")"
+                ),
+            ]
+        );
+    }
+}
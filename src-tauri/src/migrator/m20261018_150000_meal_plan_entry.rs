@@ -0,0 +1,115 @@
+//! This migration creates [`crate::entity::meal_plan_entry`].
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(MealPlanEntry::Table)
+                    .col(
+                        ColumnDef::new(MealPlanEntry::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(MealPlanEntry::Date).string().not_null())
+                    .col(ColumnDef::new(MealPlanEntry::Slot).string().not_null())
+                    .col(ColumnDef::new(MealPlanEntry::SlotName).string().null())
+                    .col(ColumnDef::new(MealPlanEntry::Servings).double().null())
+                    .col(ColumnDef::new(MealPlanEntry::RecipeId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(MealPlanEntry::Table, MealPlanEntry::RecipeId)
+                            .to(Recipe::Table, Recipe::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(&index_name(&MealPlanEntry::Table, &MealPlanEntry::Date))
+                    .table(MealPlanEntry::Table)
+                    .col(MealPlanEntry::Date)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(&index_name(&MealPlanEntry::Table, &MealPlanEntry::RecipeId))
+                    .table(MealPlanEntry::Table)
+                    .col(MealPlanEntry::RecipeId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum MealPlanEntry {
+    Table,
+    Id,
+    Date,
+    Slot,
+    SlotName,
+    Servings,
+    RecipeId,
+}
+
+#[derive(Iden)]
+pub enum Recipe {
+    Table,
+    Id,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use crate::{
+        database::tests::{get_table_indices, get_table_schema},
+        migrator::tests::get_memory_database_migrated,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database_migrated().await;
+        let table_schema = get_table_schema("meal_plan_entry", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"meal_plan_entry\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"date\" text NOT NULL, \
+            \"slot\" text NOT NULL, \
+            \"slot_name\" text NULL, \
+            \"servings\" real NULL, \
+            \"recipe_id\" integer NOT NULL, \
+            FOREIGN KEY (\"recipe_id\") REFERENCES \"recipe\" (\"id\") ON DELETE CASCADE \
+            )"
+        );
+        let indices = get_table_indices("meal_plan_entry", &db).await;
+        assert_eq!(
+            indices,
+            vec![
+                String::from(
+                    "CREATE INDEX \"idx-meal_plan_entry-date\" ON \"meal_plan_entry\" (\"date\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-meal_plan_entry-recipe_id\" ON \"meal_plan_entry\" (\"recipe_id\")"
+                ),
+            ]
+        );
+    }
+}
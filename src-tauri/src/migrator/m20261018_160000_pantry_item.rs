@@ -0,0 +1,109 @@
+//! This migration creates [`crate::entity::pantry_item`].
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PantryItem::Table)
+                    .col(
+                        ColumnDef::new(PantryItem::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(PantryItem::Quantity).double().null())
+                    .col(ColumnDef::new(PantryItem::Unit).string().null())
+                    .col(ColumnDef::new(PantryItem::BestBefore).string().null())
+                    .col(
+                        ColumnDef::new(PantryItem::IngredientId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PantryItem::Table, PantryItem::IngredientId)
+                            .to(Ingredient::Table, Ingredient::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        for col in [PantryItem::BestBefore, PantryItem::IngredientId] {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(&index_name(&PantryItem::Table, &col))
+                        .table(PantryItem::Table)
+                        .col(col)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum PantryItem {
+    Table,
+    Id,
+    Quantity,
+    Unit,
+    BestBefore,
+    IngredientId,
+}
+
+#[derive(Iden)]
+pub enum Ingredient {
+    Table,
+    Id,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use crate::{
+        database::tests::{get_table_indices, get_table_schema},
+        migrator::tests::get_memory_database_migrated,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database_migrated().await;
+        let table_schema = get_table_schema("pantry_item", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"pantry_item\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"quantity\" real NULL, \
+            \"unit\" text NULL, \
+            \"best_before\" text NULL, \
+            \"ingredient_id\" integer NOT NULL, \
+            FOREIGN KEY (\"ingredient_id\") REFERENCES \"ingredient\" (\"id\") ON DELETE CASCADE \
+            )"
+        );
+        let indices = get_table_indices("pantry_item", &db).await;
+        assert_eq!(
+            indices,
+            vec![
+                String::from(
+                    "CREATE INDEX \"idx-pantry_item-best_before\" ON \"pantry_item\" (\"best_before\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-pantry_item-ingredient_id\" ON \"pantry_item\" (\"ingredient_id\")"
+                ),
+            ]
+        );
+    }
+}
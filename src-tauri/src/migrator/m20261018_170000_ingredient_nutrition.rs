@@ -0,0 +1,138 @@
+//! This migration creates [`crate::entity::ingredient_nutrition`].
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(IngredientNutrition::Table)
+                    .col(
+                        ColumnDef::new(IngredientNutrition::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(IngredientNutrition::Energy)
+                            .double()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(IngredientNutrition::Protein)
+                            .double()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(IngredientNutrition::Fat).double().not_null())
+                    .col(
+                        ColumnDef::new(IngredientNutrition::Carbohydrates)
+                            .double()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(IngredientNutrition::Fibre)
+                            .double()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(IngredientNutrition::Salt)
+                            .double()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(IngredientNutrition::IngredientId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                IngredientNutrition::Table,
+                                IngredientNutrition::IngredientId,
+                            )
+                            .to(Ingredient::Table, Ingredient::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(&index_name(
+                        &IngredientNutrition::Table,
+                        &IngredientNutrition::IngredientId,
+                    ))
+                    .table(IngredientNutrition::Table)
+                    .col(IngredientNutrition::IngredientId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum IngredientNutrition {
+    Table,
+    Id,
+    Energy,
+    Protein,
+    Fat,
+    Carbohydrates,
+    Fibre,
+    Salt,
+    IngredientId,
+}
+
+#[derive(Iden)]
+pub enum Ingredient {
+    Table,
+    Id,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use crate::{
+        database::tests::{get_table_indices, get_table_schema},
+        migrator::tests::get_memory_database_migrated,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database_migrated().await;
+        let table_schema = get_table_schema("ingredient_nutrition", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"ingredient_nutrition\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"energy\" real NOT NULL, \
+            \"protein\" real NOT NULL, \
+            \"fat\" real NOT NULL, \
+            \"carbohydrates\" real NOT NULL, \
+            \"fibre\" real NOT NULL, \
+            \"salt\" real NOT NULL, \
+            \"ingredient_id\" integer NOT NULL, \
+            FOREIGN KEY (\"ingredient_id\") REFERENCES \"ingredient\" (\"id\") ON DELETE CASCADE \
+            )"
+        );
+        let indices = get_table_indices("ingredient_nutrition", &db).await;
+        assert_eq!(
+            indices,
+            vec![String::from(
+                "CREATE UNIQUE INDEX \"idx-ingredient_nutrition-ingredient_id\" ON \"ingredient_nutrition\" (\"ingredient_id\")"
+            )]
+        );
+    }
+}
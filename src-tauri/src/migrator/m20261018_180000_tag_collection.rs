@@ -0,0 +1,301 @@
+//! This migration creates [`crate::entity::tag`], [`crate::entity::recipe_tag`], [`crate::entity::collection`], and [`crate::entity::recipe_collection`].
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Tag::Table)
+                    .col(
+                        ColumnDef::new(Tag::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Tag::Name).string().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(&index_name(&Tag::Table, &Tag::Name))
+                    .table(Tag::Table)
+                    .col(Tag::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_table(
+                Table::create()
+                    .table(RecipeTag::Table)
+                    .col(
+                        ColumnDef::new(RecipeTag::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(RecipeTag::RecipeId).integer().not_null())
+                    .col(ColumnDef::new(RecipeTag::TagId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(RecipeTag::Table, RecipeTag::RecipeId)
+                            .to(Recipe::Table, Recipe::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(RecipeTag::Table, RecipeTag::TagId)
+                            .to(Tag::Table, Tag::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .col(RecipeTag::RecipeId)
+                            .col(RecipeTag::TagId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        for col in [RecipeTag::RecipeId, RecipeTag::TagId] {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(&index_name(&RecipeTag::Table, &col))
+                        .table(RecipeTag::Table)
+                        .col(col)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        manager
+            .create_table(
+                Table::create()
+                    .table(Collection::Table)
+                    .col(
+                        ColumnDef::new(Collection::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Collection::Name).string().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(&index_name(&Collection::Table, &Collection::Name))
+                    .table(Collection::Table)
+                    .col(Collection::Name)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_table(
+                Table::create()
+                    .table(RecipeCollection::Table)
+                    .col(
+                        ColumnDef::new(RecipeCollection::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(RecipeCollection::Order).integer().not_null())
+                    .col(
+                        ColumnDef::new(RecipeCollection::RecipeId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RecipeCollection::CollectionId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(RecipeCollection::Table, RecipeCollection::RecipeId)
+                            .to(Recipe::Table, Recipe::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(RecipeCollection::Table, RecipeCollection::CollectionId)
+                            .to(Collection::Table, Collection::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .col(RecipeCollection::Order)
+                            .col(RecipeCollection::CollectionId)
+                            .unique(),
+                    )
+                    .index(
+                        Index::create()
+                            .col(RecipeCollection::RecipeId)
+                            .col(RecipeCollection::CollectionId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        for col in [
+            RecipeCollection::Order,
+            RecipeCollection::RecipeId,
+            RecipeCollection::CollectionId,
+        ] {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(&index_name(&RecipeCollection::Table, &col))
+                        .table(RecipeCollection::Table)
+                        .col(col)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum Tag {
+    Table,
+    Id,
+    Name,
+}
+
+#[derive(Iden)]
+pub enum RecipeTag {
+    Table,
+    Id,
+    RecipeId,
+    TagId,
+}
+
+#[derive(Iden)]
+pub enum Collection {
+    Table,
+    Id,
+    Name,
+}
+
+#[derive(Iden)]
+pub enum RecipeCollection {
+    Table,
+    Id,
+    Order,
+    RecipeId,
+    CollectionId,
+}
+
+#[derive(Iden)]
+pub enum Recipe {
+    Table,
+    Id,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use crate::{
+        database::tests::{get_table_indices, get_table_schema},
+        migrator::tests::get_memory_database_migrated,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database_migrated().await;
+        let table_schema = get_table_schema("tag", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"tag\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"name\" text NOT NULL \
+            )"
+        );
+        let indices = get_table_indices("tag", &db).await;
+        assert_eq!(
+            indices,
+            vec![String::from(
+                "CREATE UNIQUE INDEX \"idx-tag-name\" ON \"tag\" (\"name\")"
+            )]
+        );
+        let table_schema = get_table_schema("recipe_tag", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"recipe_tag\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"recipe_id\" integer NOT NULL, \
+            \"tag_id\" integer NOT NULL, \
+            UNIQUE (\"recipe_id\", \"tag_id\"), \
+            FOREIGN KEY (\"recipe_id\") REFERENCES \"recipe\" (\"id\") ON DELETE CASCADE, \
+            FOREIGN KEY (\"tag_id\") REFERENCES \"tag\" (\"id\") ON DELETE CASCADE \
+            )"
+        );
+        let indices = get_table_indices("recipe_tag", &db).await;
+        assert_eq!(
+            indices,
+            vec![
+                String::from(
+                    "CREATE INDEX \"idx-recipe_tag-recipe_id\" ON \"recipe_tag\" (\"recipe_id\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-recipe_tag-tag_id\" ON \"recipe_tag\" (\"tag_id\")"
+                ),
+            ]
+        );
+        let table_schema = get_table_schema("collection", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"collection\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"name\" text NOT NULL \
+            )"
+        );
+        let table_schema = get_table_schema("recipe_collection", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"recipe_collection\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"order\" integer NOT NULL, \
+            \"recipe_id\" integer NOT NULL, \
+            \"collection_id\" integer NOT NULL, \
+            UNIQUE (\"order\", \"collection_id\"), \
+            UNIQUE (\"recipe_id\", \"collection_id\"), \
+            FOREIGN KEY (\"recipe_id\") REFERENCES \"recipe\" (\"id\") ON DELETE CASCADE, \
+            FOREIGN KEY (\"collection_id\") REFERENCES \"collection\" (\"id\") ON DELETE CASCADE \
+            )"
+        );
+        let indices = get_table_indices("recipe_collection", &db).await;
+        assert_eq!(
+            indices,
+            vec![
+                String::from(
+                    "CREATE INDEX \"idx-recipe_collection-order\" ON \"recipe_collection\" (\"order\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-recipe_collection-recipe_id\" ON \"recipe_collection\" (\"recipe_id\")"
+                ),
+                String::from(
+                    "CREATE INDEX \"idx-recipe_collection-collection_id\" ON \"recipe_collection\" (\"collection_id\")"
+                ),
+            ]
+        );
+    }
+}
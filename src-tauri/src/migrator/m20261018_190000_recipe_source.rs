@@ -0,0 +1,98 @@
+//! This migration adds the source to [`crate::entity::recipe`].
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite only supports one column per alter table statement.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Recipe::Table)
+                    .add_column(ColumnDef::new(Recipe::SourceUrl).string().null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Recipe::Table)
+                    .add_column(ColumnDef::new(Recipe::SourceGetter).string().null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Recipe::Table)
+                    .add_column(ColumnDef::new(Recipe::ImportedAt).string().null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(&index_name(&Recipe::Table, &Recipe::SourceUrl))
+                    .table(Recipe::Table)
+                    .col(Recipe::SourceUrl)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum Recipe {
+    Table,
+    SourceUrl,
+    SourceGetter,
+    ImportedAt,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+    use sea_orm_migration::MigratorTrait;
+
+    use crate::{
+        database::tests::{get_memory_database, get_table_indices, get_table_schema},
+        migrator::Migrator,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database().await;
+        // run all migrations up to this one
+        Migrator::up(&db, Some(12)).await.unwrap();
+        let table_schema = get_table_schema("recipe", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"recipe\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"name\" text NOT NULL , \
+            \"yield_amount\" real NULL, \
+            \"yield_unit\" text NULL, \
+            \"source_url\" text NULL, \
+            \"source_getter\" text NULL, \
+            \"imported_at\" text NULL\
+            )"
+        );
+        let indices = get_table_indices("recipe", &db).await;
+        assert_eq!(
+            indices,
+            vec![
+                String::from("CREATE INDEX \"idx-recipe-name\" ON \"recipe\" (\"name\")"),
+                String::from(
+                    "CREATE INDEX \"idx-recipe-source_url\" ON \"recipe\" (\"source_url\")"
+                ),
+            ]
+        );
+    }
+}
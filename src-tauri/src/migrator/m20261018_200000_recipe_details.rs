@@ -0,0 +1,202 @@
+//! This migration adds the details of imported recipes to [`crate::entity::recipe`] and creates [`crate::entity::recipe_declared_nutrition`].
+
+use sea_orm_migration::prelude::*;
+
+use crate::migrator::index_name;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite only supports one column per alter table statement.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Recipe::Table)
+                    .add_column(ColumnDef::new(Recipe::Description).text().null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Recipe::Table)
+                    .add_column(ColumnDef::new(Recipe::Author).string().null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Recipe::Table)
+                    .add_column(ColumnDef::new(Recipe::PrepTime).integer().null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Recipe::Table)
+                    .add_column(ColumnDef::new(Recipe::CookTime).integer().null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Recipe::Table)
+                    .add_column(ColumnDef::new(Recipe::TotalTime).integer().null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_table(
+                Table::create()
+                    .table(RecipeDeclaredNutrition::Table)
+                    .col(
+                        ColumnDef::new(RecipeDeclaredNutrition::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(RecipeDeclaredNutrition::Energy)
+                            .double()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(RecipeDeclaredNutrition::Protein)
+                            .double()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(RecipeDeclaredNutrition::Fat).double().null())
+                    .col(
+                        ColumnDef::new(RecipeDeclaredNutrition::Carbohydrates)
+                            .double()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(RecipeDeclaredNutrition::Fibre)
+                            .double()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(RecipeDeclaredNutrition::Salt)
+                            .double()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(RecipeDeclaredNutrition::RecipeId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                RecipeDeclaredNutrition::Table,
+                                RecipeDeclaredNutrition::RecipeId,
+                            )
+                            .to(Recipe::Table, Recipe::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(&index_name(
+                        &RecipeDeclaredNutrition::Table,
+                        &RecipeDeclaredNutrition::RecipeId,
+                    ))
+                    .table(RecipeDeclaredNutrition::Table)
+                    .col(RecipeDeclaredNutrition::RecipeId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum Recipe {
+    Table,
+    Id,
+    Description,
+    Author,
+    PrepTime,
+    CookTime,
+    TotalTime,
+}
+
+#[derive(Iden)]
+pub enum RecipeDeclaredNutrition {
+    Table,
+    Id,
+    Energy,
+    Protein,
+    Fat,
+    Carbohydrates,
+    Fibre,
+    Salt,
+    RecipeId,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use crate::{
+        database::tests::{get_table_indices, get_table_schema},
+        migrator::tests::get_memory_database_migrated,
+    };
+
+    #[tokio::test]
+    async fn test_up() {
+        let db = get_memory_database_migrated().await;
+        let table_schema = get_table_schema("recipe", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"recipe\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"name\" text NOT NULL , \
+            \"yield_amount\" real NULL, \
+            \"yield_unit\" text NULL, \
+            \"source_url\" text NULL, \
+            \"source_getter\" text NULL, \
+            \"imported_at\" text NULL, \
+            \"description\" text NULL, \
+            \"author\" text NULL, \
+            \"prep_time\" integer NULL, \
+            \"cook_time\" integer NULL, \
+            \"total_time\" integer NULL\
+            )"
+        );
+        let table_schema = get_table_schema("recipe_declared_nutrition", &db).await;
+        assert_str_eq!(
+            table_schema,
+            "CREATE TABLE \"recipe_declared_nutrition\" ( \
+            \"id\" integer NOT NULL PRIMARY KEY AUTOINCREMENT, \
+            \"energy\" real NULL, \
+            \"protein\" real NULL, \
+            \"fat\" real NULL, \
+            \"carbohydrates\" real NULL, \
+            \"fibre\" real NULL, \
+            \"salt\" real NULL, \
+            \"recipe_id\" integer NOT NULL, \
+            FOREIGN KEY (\"recipe_id\") REFERENCES \"recipe\" (\"id\") ON DELETE CASCADE \
+            )"
+        );
+        let indices = get_table_indices("recipe_declared_nutrition", &db).await;
+        assert_eq!(
+            indices,
+            vec![String::from(
+                "CREATE UNIQUE INDEX \"idx-recipe_declared_nutrition-recipe_id\" ON \"recipe_declared_nutrition\" (\"recipe_id\")"
+            )]
+        );
+    }
+}
@@ -0,0 +1,217 @@
+//! This module implements nutrition facts and parsing nutrition datasets.
+//!
+//! A nutrition dataset is a CSV file with a header and one row per ingredient name, see [`parse_dataset`].
+
+use std::ops::AddAssign;
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+
+use crate::entity::ingredient_nutrition;
+
+/// This is the bundled nutrition dataset of common ingredients.
+///
+/// The values are rounded averages for orientation, not the exact values of a product.
+pub const BUNDLED_DATASET: &str = include_str!("../data/ingredient_nutrition.csv");
+
+/// These are the columns of a nutrition dataset.
+const COLUMNS: [&str; 7] = [
+    "name",
+    "energy",
+    "protein",
+    "fat",
+    "carbohydrates",
+    "fibre",
+    "salt",
+];
+
+/// This struct represents the nutrients of an amount of food.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Nutrients {
+    /// the energy in kcal
+    pub energy: f64,
+    /// the protein in g
+    pub protein: f64,
+    /// the fat in g
+    pub fat: f64,
+    /// the carbohydrates in g
+    pub carbohydrates: f64,
+    /// the fibre in g
+    pub fibre: f64,
+    /// the salt in g
+    pub salt: f64,
+}
+
+impl Nutrients {
+    /// Multiply all nutrients by a factor.
+    pub fn scale(&self, factor: f64) -> Self {
+        Self {
+            energy: self.energy * factor,
+            protein: self.protein * factor,
+            fat: self.fat * factor,
+            carbohydrates: self.carbohydrates * factor,
+            fibre: self.fibre * factor,
+            salt: self.salt * factor,
+        }
+    }
+}
+
+impl AddAssign for Nutrients {
+    fn add_assign(&mut self, rhs: Self) {
+        self.energy += rhs.energy;
+        self.protein += rhs.protein;
+        self.fat += rhs.fat;
+        self.carbohydrates += rhs.carbohydrates;
+        self.fibre += rhs.fibre;
+        self.salt += rhs.salt;
+    }
+}
+
+impl From<&ingredient_nutrition::Model> for Nutrients {
+    fn from(value: &ingredient_nutrition::Model) -> Self {
+        Self {
+            energy: value.energy,
+            protein: value.protein,
+            fat: value.fat,
+            carbohydrates: value.carbohydrates,
+            fibre: value.fibre,
+            salt: value.salt,
+        }
+    }
+}
+
+/// Parse a nutrition dataset into ingredient names and their nutrients per 100 g.
+///
+/// The header must be "name,energy,protein,fat,carbohydrates,fibre,salt", see [`Nutrients`] for the units.
+/// Fields are separated by commas and can not be quoted, so names must not contain commas.
+/// Empty lines are ignored.
+///
+/// # Errors
+///
+/// - when the header is not as expected
+/// - when a row has the wrong number of fields
+/// - when a value is not a non-negative number
+pub fn parse_dataset(csv: &str) -> Result<Vec<(String, Nutrients)>> {
+    let mut lines = csv
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let header: Vec<&str> = lines
+        .next()
+        .map(|(_, line)| line.split(',').map(str::trim).collect())
+        .unwrap_or_default();
+    if header != COLUMNS {
+        return Err(anyhow!(
+            "The header of the nutrition dataset must be \"{}\".",
+            COLUMNS.join(",")
+        ));
+    }
+    let mut rows = vec![];
+    for (index, line) in lines {
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        if fields.len() != COLUMNS.len() {
+            return Err(anyhow!(
+                "Line {} of the nutrition dataset must have {} fields.",
+                index + 1,
+                COLUMNS.len()
+            ));
+        }
+        let mut values = [0.0; 6];
+        for (value, field) in values.iter_mut().zip(&fields[1..]) {
+            *value = field
+                .parse()
+                .ok()
+                .filter(|value: &f64| value.is_finite() && *value >= 0.0)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "\"{field}\" in line {} of the nutrition dataset is not a non-negative number.",
+                        index + 1
+                    )
+                })?;
+        }
+        let [energy, protein, fat, carbohydrates, fibre, salt] = values;
+        rows.push((
+            fields[0].to_string(),
+            Nutrients {
+                energy,
+                protein,
+                fat,
+                carbohydrates,
+                fibre,
+                salt,
+            },
+        ));
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_dataset() {
+        let rows = parse_dataset(
+            "name,energy,protein,fat,carbohydrates,fibre,salt\n\
+            Mehl,364,10.3,1,76.3,2.7,0.01\n\
+            \n\
+            olive oil, 884, 0, 100, 0, 0, 0\n",
+        )
+        .unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                (
+                    String::from("Mehl"),
+                    Nutrients {
+                        energy: 364.0,
+                        protein: 10.3,
+                        fat: 1.0,
+                        carbohydrates: 76.3,
+                        fibre: 2.7,
+                        salt: 0.01,
+                    }
+                ),
+                (
+                    String::from("olive oil"),
+                    Nutrients {
+                        energy: 884.0,
+                        protein: 0.0,
+                        fat: 100.0,
+                        carbohydrates: 0.0,
+                        fibre: 0.0,
+                        salt: 0.0,
+                    }
+                ),
+            ]
+        );
+        assert!(parse_dataset("name,energy\nMehl,364").is_err());
+        assert!(
+            parse_dataset(
+                "name,energy,protein,fat,carbohydrates,fibre,salt\nMehl,364,10.3,1,76.3,2.7"
+            )
+            .is_err()
+        );
+        assert!(
+            parse_dataset(
+                "name,energy,protein,fat,carbohydrates,fibre,salt\nMehl,viel,10.3,1,76.3,2.7,0.01"
+            )
+            .is_err()
+        );
+        assert!(
+            parse_dataset(
+                "name,energy,protein,fat,carbohydrates,fibre,salt\nMehl,-1,10.3,1,76.3,2.7,0.01"
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_bundled_dataset() {
+        let rows = parse_dataset(BUNDLED_DATASET).unwrap();
+        assert!(rows.iter().any(|(name, _)| name == "Mehl"));
+    }
+}
@@ -1,29 +1,15 @@
-//! This module implements HTML scraping via the webview frontend.
-
-use std::fmt::Debug;
+//! This module implements HTML scraping.
+//!
+//! Documents are parsed in-process by the [`native`] backend by default.
+//! The [`webview`] backend sends every call to the webview frontend instead,
+//! which is slower and needs the app window, but supports every selector the webview does.
 
 use anyhow::Result;
 use async_trait::async_trait;
+use thiserror::Error;
 
-use crate::{
-    event,
-    event::{
-        answer_channel::{
-            SCRAPER_DOM_CREATE_ANSWER, SCRAPER_DOM_SELECT_ALL_ANSWER, SCRAPER_DOM_SELECT_ANSWER,
-            SCRAPER_ELEMENT_GET_ATTRIBUTE_ANSWER, SCRAPER_ELEMENT_INNER_TEXT_ANSWER,
-            SCRAPER_ELEMENT_SELECT_ALL_ANSWER, SCRAPER_ELEMENT_SELECT_ANSWER,
-            SCRAPER_ELEMENT_TEXT_CONTENT_ANSWER,
-        },
-        channel::{SCRAPER_DOM_DROP, SCRAPER_ELEMENT_DROP},
-        question_channel::{
-            SCRAPER_DOM_CREATE_QUESTION, SCRAPER_DOM_SELECT_ALL_QUESTION,
-            SCRAPER_DOM_SELECT_QUESTION, SCRAPER_ELEMENT_GET_ATTRIBUTE_QUESTION,
-            SCRAPER_ELEMENT_INNER_TEXT_QUESTION, SCRAPER_ELEMENT_SELECT_ALL_QUESTION,
-            SCRAPER_ELEMENT_SELECT_QUESTION, SCRAPER_ELEMENT_TEXT_CONTENT_QUESTION,
-        },
-    },
-    window::get_window,
-};
+mod native;
+mod webview;
 
 #[async_trait]
 pub trait ParentNode {
@@ -32,120 +18,97 @@ pub trait ParentNode {
     async fn select_all(&self, selector: &str) -> Result<Vec<Element>>;
 }
 
-/// This struct represents a DOM created in the webview frontend.
-#[derive(Debug)]
-pub struct Dom {
-    pub id: String,
+/// The backend a [`Dom`] is created with, its elements keep that backend.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScraperBackend {
+    #[default]
+    Native,
+    Webview,
 }
 
-impl Drop for Dom {
-    /// Delete the DOM in the webview frontend once it isn't needed anymore.
-    fn drop(&mut self) {
-        get_window().emit(SCRAPER_DOM_DROP, &self.id).ok();
-    }
+#[derive(Debug, Error)]
+pub enum ScraperError {
+    #[error("The selector \"{0}\" is not supported by the native scraper backend.")]
+    UnsupportedSelector(String),
+}
+
+/// This enum represents a DOM created by one of the backends.
+#[derive(Debug)]
+pub enum Dom {
+    Native(native::Dom),
+    Webview(webview::Dom),
 }
 
 impl Dom {
-    /// Create a DOM in the webview frontend.
-    pub async fn create(html: String) -> Result<Self> {
-        let dom_id =
-            event::ask(SCRAPER_DOM_CREATE_QUESTION, SCRAPER_DOM_CREATE_ANSWER, html).await?;
-        Ok(Dom { id: dom_id })
+    /// Create a DOM with the specified backend.
+    pub async fn create(html: String, backend: ScraperBackend) -> Result<Self> {
+        Ok(match backend {
+            ScraperBackend::Native => Dom::Native(native::Dom::create(&html)),
+            ScraperBackend::Webview => Dom::Webview(webview::Dom::create(html).await?),
+        })
     }
 }
 
 #[async_trait]
 impl ParentNode for Dom {
     async fn select(&self, selector: &str) -> Result<Option<Element>> {
-        let element_id_option: Option<String> = event::ask(
-            SCRAPER_DOM_SELECT_QUESTION,
-            SCRAPER_DOM_SELECT_ANSWER,
-            (&self.id, selector),
-        )
-        .await?;
-        Ok(element_id_option.map(|element_id| Element { id: element_id }))
+        match self {
+            Dom::Native(dom) => dom.select(selector).await,
+            Dom::Webview(dom) => dom.select(selector).await,
+        }
     }
 
     async fn select_all(&self, selector: &str) -> Result<Vec<Element>> {
-        let element_ids: Vec<String> = event::ask(
-            SCRAPER_DOM_SELECT_ALL_QUESTION,
-            SCRAPER_DOM_SELECT_ALL_ANSWER,
-            (&self.id, selector),
-        )
-        .await?;
-        Ok(element_ids
-            .into_iter()
-            .map(|element_id| Element { id: element_id })
-            .collect())
+        match self {
+            Dom::Native(dom) => dom.select_all(selector).await,
+            Dom::Webview(dom) => dom.select_all(selector).await,
+        }
     }
 }
 
-/// This struct represents an element created in the webview frontend.
+/// This enum represents an element of a [`Dom`].
 #[derive(Debug)]
-pub struct Element {
-    pub id: String,
-}
-
-impl Drop for Element {
-    /// Delete the element in the webview frontend once it isn't needed anymore.
-    fn drop(&mut self) {
-        get_window().emit(SCRAPER_ELEMENT_DROP, &self.id).ok();
-    }
+pub enum Element {
+    Native(native::Element),
+    Webview(webview::Element),
 }
 
 impl Element {
     pub async fn text_content(&self) -> Result<String> {
-        let text_content = event::ask(
-            SCRAPER_ELEMENT_TEXT_CONTENT_QUESTION,
-            SCRAPER_ELEMENT_TEXT_CONTENT_ANSWER,
-            &self.id,
-        )
-        .await?;
-        Ok(text_content)
+        match self {
+            Element::Native(element) => Ok(element.text_content()),
+            Element::Webview(element) => element.text_content().await,
+        }
     }
+
     pub async fn inner_text(&self) -> Result<String> {
-        let inner_text = event::ask(
-            SCRAPER_ELEMENT_INNER_TEXT_QUESTION,
-            SCRAPER_ELEMENT_INNER_TEXT_ANSWER,
-            &self.id,
-        )
-        .await?;
-        Ok(inner_text)
+        match self {
+            Element::Native(element) => Ok(element.inner_text()),
+            Element::Webview(element) => element.inner_text().await,
+        }
     }
 
     pub async fn get_attribute(&self, qualified_name: &str) -> Result<String> {
-        let attribute = event::ask(
-            SCRAPER_ELEMENT_GET_ATTRIBUTE_QUESTION,
-            SCRAPER_ELEMENT_GET_ATTRIBUTE_ANSWER,
-            (&self.id, qualified_name),
-        )
-        .await?;
-        Ok(attribute)
+        match self {
+            Element::Native(element) => Ok(element.get_attribute(qualified_name)),
+            Element::Webview(element) => element.get_attribute(qualified_name).await,
+        }
     }
 }
 
 #[async_trait]
 impl ParentNode for Element {
     async fn select(&self, selector: &str) -> Result<Option<Element>> {
-        let element_id_option: Option<String> = event::ask(
-            SCRAPER_ELEMENT_SELECT_QUESTION,
-            SCRAPER_ELEMENT_SELECT_ANSWER,
-            (&self.id, selector),
-        )
-        .await?;
-        Ok(element_id_option.map(|element_id| Element { id: element_id }))
+        match self {
+            Element::Native(element) => element.select(selector).await,
+            Element::Webview(element) => element.select(selector).await,
+        }
     }
 
     async fn select_all(&self, selector: &str) -> Result<Vec<Element>> {
-        let element_ids: Vec<String> = event::ask(
-            SCRAPER_ELEMENT_SELECT_ALL_QUESTION,
-            SCRAPER_ELEMENT_SELECT_ALL_ANSWER,
-            (&self.id, selector),
-        )
-        .await?;
-        Ok(element_ids
-            .into_iter()
-            .map(|element_id| Element { id: element_id })
-            .collect())
+        match self {
+            Element::Native(element) => element.select_all(selector).await,
+            Element::Webview(element) => element.select_all(selector).await,
+        }
     }
 }
@@ -0,0 +1,219 @@
+//! This module implements [`ParentNode`] and the element API in-process with [`::scraper`], which parses with html5ever.
+
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use ::scraper::{ElementRef, Html, Selector};
+use anyhow::Result;
+use async_trait::async_trait;
+use ego_tree::NodeId;
+
+use crate::scraper::{Element as ScraperElement, ParentNode, ScraperError};
+
+/// This struct represents a DOM parsed in-process.
+///
+/// The document is shared with all elements selected from it.
+#[derive(Debug)]
+pub struct Dom {
+    html: Arc<Mutex<Html>>,
+}
+
+impl Dom {
+    /// Parse an HTML document.
+    pub fn create(html: &str) -> Self {
+        Dom {
+            html: Arc::new(Mutex::new(Html::parse_document(html))),
+        }
+    }
+}
+
+#[async_trait]
+impl ParentNode for Dom {
+    async fn select(&self, selector: &str) -> Result<Option<ScraperElement>> {
+        let selector = parse_selector(selector)?;
+        let html = lock(&self.html);
+        Ok(html
+            .select(&selector)
+            .next()
+            .map(|element_ref| element(&self.html, element_ref)))
+    }
+
+    async fn select_all(&self, selector: &str) -> Result<Vec<ScraperElement>> {
+        let selector = parse_selector(selector)?;
+        let html = lock(&self.html);
+        Ok(html
+            .select(&selector)
+            .map(|element_ref| element(&self.html, element_ref))
+            .collect())
+    }
+}
+
+/// This struct represents an element of a DOM parsed in-process.
+#[derive(Debug)]
+pub struct Element {
+    html: Arc<Mutex<Html>>,
+    id: NodeId,
+}
+
+impl Element {
+    /// Call `f` with the reference to this element inside the locked document.
+    fn with_element_ref<T>(&self, f: impl FnOnce(ElementRef) -> T) -> T {
+        let html = lock(&self.html);
+        let element_ref = html
+            .tree
+            .get(self.id)
+            .and_then(ElementRef::wrap)
+            .expect("The element is part of its document.");
+        f(element_ref)
+    }
+
+    pub fn text_content(&self) -> String {
+        self.with_element_ref(|element_ref| element_ref.text().collect())
+    }
+
+    /// Get the inner text like the webview does.
+    ///
+    /// Documents in the webview are parsed but never rendered, so their inner text is the same as their text content.
+    pub fn inner_text(&self) -> String {
+        self.text_content()
+    }
+
+    /// Get the value of an attribute or an empty string if the element does not have it.
+    pub fn get_attribute(&self, qualified_name: &str) -> String {
+        self.with_element_ref(|element_ref| {
+            element_ref
+                .value()
+                .attr(&qualified_name.to_ascii_lowercase())
+                .unwrap_or_default()
+                .to_string()
+        })
+    }
+}
+
+#[async_trait]
+impl ParentNode for Element {
+    /// Select the first descendant matching the selector, `:scope` matches this element.
+    async fn select(&self, selector: &str) -> Result<Option<ScraperElement>> {
+        let selector = parse_selector(selector)?;
+        Ok(self.with_element_ref(|element_ref| {
+            element_ref
+                .select(&selector)
+                .next()
+                .map(|element_ref| element(&self.html, element_ref))
+        }))
+    }
+
+    /// Select all descendants matching the selector, `:scope` matches this element.
+    async fn select_all(&self, selector: &str) -> Result<Vec<ScraperElement>> {
+        let selector = parse_selector(selector)?;
+        Ok(self.with_element_ref(|element_ref| {
+            element_ref
+                .select(&selector)
+                .map(|element_ref| element(&self.html, element_ref))
+                .collect()
+        }))
+    }
+}
+
+/// Lock the document.
+///
+/// No code panics while holding the lock, so a poisoned lock still holds an intact document.
+fn lock(html: &Mutex<Html>) -> MutexGuard<'_, Html> {
+    html.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn element(html: &Arc<Mutex<Html>>, element_ref: ElementRef) -> ScraperElement {
+    ScraperElement::Native(Element {
+        html: html.clone(),
+        id: element_ref.id(),
+    })
+}
+
+fn parse_selector(selector: &str) -> Result<Selector> {
+    Ok(Selector::parse(selector)
+        .map_err(|_| ScraperError::UnsupportedSelector(String::from(selector)))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const HTML: &str = r#"<!DOCTYPE html>
+<html>
+  <head><title>Zimtschnecken</title></head>
+  <body>
+    <h1 id="name">Zimtschnecken</h1>
+    <ul class="ingredients">
+      <li>500 g <b>Mehl</b></li>
+      <li data-amount="2">2 EL Zimt</li>
+    </ul>
+    <ol>
+      <li><ol><li>Teig kneten.</li></ol></li>
+    </ol>
+  </body>
+</html>"#;
+
+    async fn text_contents(elements: Vec<ScraperElement>) -> Vec<String> {
+        let mut text_contents = vec![];
+        for element in elements {
+            text_contents.push(element.text_content().await.unwrap());
+        }
+        text_contents
+    }
+
+    #[tokio::test]
+    async fn test_select() {
+        let dom = Dom::create(HTML);
+        let name_element = dom.select("#name").await.unwrap().unwrap();
+        assert_eq!(name_element.text_content().await.unwrap(), "Zimtschnecken");
+        assert!(dom.select("video").await.unwrap().is_none());
+        let list_element = dom.select("ul").await.unwrap().unwrap();
+        let item_element = list_element.select("li:last-child").await.unwrap().unwrap();
+        assert_eq!(item_element.inner_text().await.unwrap(), "2 EL Zimt");
+    }
+
+    #[tokio::test]
+    async fn test_select_all() {
+        let dom = Dom::create(HTML);
+        assert_eq!(
+            text_contents(dom.select_all(".ingredients li").await.unwrap()).await,
+            vec![String::from("500 g Mehl"), String::from("2 EL Zimt")]
+        );
+        let list_element = dom.select("ol").await.unwrap().unwrap();
+        assert_eq!(
+            text_contents(list_element.select_all(":scope > li").await.unwrap()).await,
+            vec![String::from("Teig kneten.")]
+        );
+        assert_eq!(
+            list_element.select_all("ol").await.unwrap().len(),
+            1,
+            "the element itself is not selected"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_attribute() {
+        let dom = Dom::create(HTML);
+        let item_element = dom.select("[data-amount]").await.unwrap().unwrap();
+        assert_eq!(
+            item_element.get_attribute("data-amount").await.unwrap(),
+            "2"
+        );
+        assert_eq!(
+            item_element.get_attribute("DATA-AMOUNT").await.unwrap(),
+            "2"
+        );
+        assert_eq!(item_element.get_attribute("href").await.unwrap(), "");
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_selector() {
+        let dom = Dom::create(HTML);
+        let error = dom.select("li:unknown-pseudo-class").await.unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ScraperError>(),
+            Some(ScraperError::UnsupportedSelector(_))
+        ));
+    }
+}
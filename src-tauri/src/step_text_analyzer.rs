@@ -0,0 +1,260 @@
+//! This module implements analyzing the texts of recipe steps like "Bei 180°C Umluft 20 Minuten backen.".
+//!
+//! See [`find_temperatures`] and [`render_temperatures`] for more information.
+
+use std::{ops::Range, sync::OnceLock};
+
+use regex::{Captures, Regex};
+
+use crate::unit_conversion::{Convert, TemperatureUnit};
+
+/// This regex matches temperatures like "180°C", "175-180 °C Umluft", "350 degrees F", and "200 Grad".
+const TEMPERATURE_PATTERN: &str = r"(?ix)
+    (?P<min>\d+(?:[.,]\d+)?)
+    (?:\s*(?:-|–|bis|to)\s*(?P<max>\d+(?:[.,]\d+)?))?
+    \s*
+    (?:
+        (?P<celsius>[°º]\s*C\b|degrees?\s+(?:celsius|C\b)|grad(?:\s+celsius)?\b)
+        |(?P<fahrenheit>[°º]\s*F\b|degrees?\s+(?:fahrenheit|F\b))
+        |(?P<kelvin>kelvin\b)
+    )
+    (?P<fan>\s*(?:\(\s*)?(?:umluft|heißluft|fan(?:[\s-]forced)?|convection)\b(?:\s*\))?)?
+";
+
+/// This regex matches gas marks like "gas mark 4", "Gas Mark ½", and "Gasstufe 2-3".
+const GAS_MARK_PATTERN: &str = r"(?ix)
+    (?:gas\s+mark|gas\s*stufe)\s*
+    (?P<min>\d+(?:[.,]\d+)?|[¼½])
+    (?:\s*(?:-|–|bis|to)\s*(?P<max>\d+(?:[.,]\d+)?|[¼½]))?
+";
+
+static TEMPERATURE_REGEX: OnceLock<Regex> = OnceLock::new();
+
+static GAS_MARK_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// This struct represents a temperature found in a text.
+#[derive(Debug, PartialEq)]
+pub struct FoundTemperature {
+    /// the byte range of the temperature in the text
+    pub range: Range<usize>,
+    pub min: f64,
+    /// the upper bound if the temperature is a range like "175-180 °C"
+    pub max: Option<f64>,
+    pub unit: TemperatureUnit,
+}
+
+impl FoundTemperature {
+    /// Render the temperature in another unit, rounded to a value which can be set on an oven.
+    pub fn render(&self, to: &TemperatureUnit) -> String {
+        let min = format_value(round_oven_friendly(self.unit.convert(self.min, to), to));
+        let value = match self.max {
+            Some(max) => {
+                let max = format_value(round_oven_friendly(self.unit.convert(max, to), to));
+                format!("{min}–{max}")
+            }
+            None => min,
+        };
+        match to {
+            TemperatureUnit::Celsius => format!("{value} °C"),
+            TemperatureUnit::CelsiusFan => format!("{value} °C (fan)"),
+            TemperatureUnit::Fahrenheit => format!("{value} °F"),
+            TemperatureUnit::FahrenheitFan => format!("{value} °F (fan)"),
+            TemperatureUnit::Kelvin => format!("{value} K"),
+            TemperatureUnit::GasMark => format!("gas mark {value}"),
+        }
+    }
+}
+
+/// Find all temperatures and gas marks in a text, ordered by their position.
+///
+/// A temperature is only recognized with an explicit unit, a bare "180°" is ambiguous between Celsius and Fahrenheit.
+/// An oven setting like "Umluft" or "fan" directly after the temperature marks it as a fan temperature.
+pub fn find_temperatures(text: &str) -> Vec<FoundTemperature> {
+    let mut temperatures: Vec<FoundTemperature> = TEMPERATURE_REGEX
+        .get_or_init(|| Regex::new(TEMPERATURE_PATTERN).unwrap())
+        .captures_iter(text)
+        .map(|captures| {
+            let unit = match (
+                captures.name("celsius").is_some(),
+                captures.name("fahrenheit").is_some(),
+                captures.name("fan").is_some(),
+            ) {
+                (true, _, false) => TemperatureUnit::Celsius,
+                (true, _, true) => TemperatureUnit::CelsiusFan,
+                (_, true, false) => TemperatureUnit::Fahrenheit,
+                (_, true, true) => TemperatureUnit::FahrenheitFan,
+                _ => TemperatureUnit::Kelvin,
+            };
+            found_temperature(&captures, unit)
+        })
+        .chain(
+            GAS_MARK_REGEX
+                .get_or_init(|| Regex::new(GAS_MARK_PATTERN).unwrap())
+                .captures_iter(text)
+                .map(|captures| found_temperature(&captures, TemperatureUnit::GasMark)),
+        )
+        .collect();
+    temperatures.sort_by_key(|temperature| temperature.range.start);
+    temperatures
+}
+
+/// Replace all temperatures in a text with their rendering in another unit.
+///
+/// See [`FoundTemperature::render`].
+pub fn render_temperatures(text: &str, to: &TemperatureUnit) -> String {
+    let mut rendered = String::with_capacity(text.len());
+    let mut end = 0;
+    for temperature in find_temperatures(text) {
+        if temperature.range.start < end {
+            continue;
+        }
+        rendered.push_str(&text[end..temperature.range.start]);
+        rendered.push_str(&temperature.render(to));
+        end = temperature.range.end;
+    }
+    rendered.push_str(&text[end..]);
+    rendered
+}
+
+fn found_temperature(captures: &Captures, unit: TemperatureUnit) -> FoundTemperature {
+    let whole_match = captures.get(0).unwrap();
+    FoundTemperature {
+        range: whole_match.range(),
+        min: parse_value(captures.name("min").unwrap().as_str()),
+        max: captures.name("max").map(|max| parse_value(max.as_str())),
+        unit,
+    }
+}
+
+/// Parse a value matched by one of the regexes, which can be a decimal with a comma or one of the fractions of gas marks.
+fn parse_value(text: &str) -> f64 {
+    match text {
+        "¼" => 0.25,
+        "½" => 0.5,
+        text => text
+            .replace(',', ".")
+            .parse()
+            .expect("The regex only matches valid numbers."),
+    }
+}
+
+/// Round a temperature to a value which can be set on an oven.
+///
+/// Celsius and Fahrenheit are rounded to steps of 5, gas marks to ¼, ½, and whole numbers.
+fn round_oven_friendly(value: f64, unit: &TemperatureUnit) -> f64 {
+    match unit {
+        TemperatureUnit::Kelvin => value.round(),
+        TemperatureUnit::GasMark if value < 0.375 => 0.25,
+        TemperatureUnit::GasMark if value < 0.75 => 0.5,
+        TemperatureUnit::GasMark => value.round(),
+        _ => (value / 5.0).round() * 5.0,
+    }
+}
+
+fn format_value(value: f64) -> String {
+    match value {
+        value if value == 0.25 => String::from("¼"),
+        value if value == 0.5 => String::from("½"),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_find_temperatures() {
+        let expected_temperatures = vec![
+            (
+                "Den Ofen auf 180°C Umluft vorheizen.",
+                vec![FoundTemperature {
+                    range: 13..26,
+                    min: 180.0,
+                    max: None,
+                    unit: TemperatureUnit::CelsiusFan,
+                }],
+            ),
+            (
+                "Bei 175-180 °C Ober-/Unterhitze backen, danach bei 200 Grad (Umluft) bräunen.",
+                vec![
+                    FoundTemperature {
+                        range: 4..15,
+                        min: 175.0,
+                        max: Some(180.0),
+                        unit: TemperatureUnit::Celsius,
+                    },
+                    FoundTemperature {
+                        range: 52..69,
+                        min: 200.0,
+                        max: None,
+                        unit: TemperatureUnit::CelsiusFan,
+                    },
+                ],
+            ),
+            (
+                "Preheat the oven to 350°F (gas mark 4).",
+                vec![
+                    FoundTemperature {
+                        range: 20..26,
+                        min: 350.0,
+                        max: None,
+                        unit: TemperatureUnit::Fahrenheit,
+                    },
+                    FoundTemperature {
+                        range: 28..38,
+                        min: 4.0,
+                        max: None,
+                        unit: TemperatureUnit::GasMark,
+                    },
+                ],
+            ),
+            (
+                "Bake at 425 degrees F convection.",
+                vec![FoundTemperature {
+                    range: 8..32,
+                    min: 425.0,
+                    max: None,
+                    unit: TemperatureUnit::FahrenheitFan,
+                }],
+            ),
+            ("Add 2 eggs and 180 g flour, stir for 5 min.", vec![]),
+            ("Bake at 180° for 20 minutes.", vec![]),
+        ];
+        for (text, expected) in expected_temperatures {
+            assert_eq!(find_temperatures(text), expected, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_render_temperatures() {
+        assert_eq!(
+            render_temperatures(
+                "Den Ofen auf 180°C Umluft vorheizen.",
+                &TemperatureUnit::Celsius
+            ),
+            "Den Ofen auf 200 °C vorheizen."
+        );
+        assert_eq!(
+            render_temperatures(
+                "Preheat the oven to 350°F (gas mark 4).",
+                &TemperatureUnit::Celsius
+            ),
+            "Preheat the oven to 175 °C (175 °C)."
+        );
+        assert_eq!(
+            render_temperatures("Bei 175-180 °C backen.", &TemperatureUnit::Fahrenheit),
+            "Bei 345–355 °F backen."
+        );
+        assert_eq!(
+            render_temperatures("Bei 120 °C trocknen.", &TemperatureUnit::GasMark),
+            "Bei gas mark ½ trocknen."
+        );
+        assert_eq!(
+            render_temperatures("Ohne Temperatur.", &TemperatureUnit::Fahrenheit),
+            "Ohne Temperatur."
+        );
+    }
+}
@@ -1,9 +1,13 @@
 //! This module implements unit conversion for known units.
 
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
 #[derive(Debug)]
 pub enum Unit {
     Mass(MassUnit),
     Volume(VolumeUnit),
+    Temperature(TemperatureUnit),
 }
 
 #[derive(Debug)]
@@ -11,28 +15,242 @@ pub enum MassUnit {
     Kilogram,
     Gram,
     Pound,
+    /// the avoirdupois ounce
+    Ounce,
 }
 
 #[derive(Debug)]
 pub enum VolumeUnit {
     Litre,
+    Decilitre,
+    Centilitre,
     Millilitre,
+    /// 250 ml
+    MetricCup,
+    /// 15 ml, e.g. the German "EL"
+    MetricTablespoon,
+    /// 5 ml, e.g. the German "TL"
+    MetricTeaspoon,
     UsCup,
+    UsTablespoon,
+    UsTeaspoon,
+    UsFluidOunce,
+    UsPint,
+    UsQuart,
+    UsGallon,
+    /// half an imperial pint
+    ImperialCup,
+    ImperialFluidOunce,
+    ImperialPint,
+    ImperialQuart,
+    ImperialGallon,
+    /// a sixteenth of a US teaspoon
+    Pinch,
+}
+
+/// The fan variants represent the setting of a fan oven, which heats like a conventional oven [`FAN_OFFSET`] hotter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TemperatureUnit {
+    Celsius,
+    CelsiusFan,
+    Fahrenheit,
+    FahrenheitFan,
+    Kelvin,
+    /// the gas mark of British gas ovens
+    GasMark,
+}
+
+/// This is how much hotter a conventional oven needs to be to match a fan oven, in kelvin.
+pub const FAN_OFFSET: f64 = 20.0;
+
+/// This enum represents a system of units which values can be converted into, see [`Unit::to_unit_system`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UnitSystem {
+    #[default]
+    Metric,
+    UsCustomary,
+    Imperial,
+}
+
+/// This enum represents an error of a conversion between two [units](Unit).
+#[derive(Debug, Error)]
+pub enum ConversionError {
+    #[error(
+        "The density of the ingredient is unknown, so it can not be converted between mass and volume."
+    )]
+    DensityUnknown,
+    #[error("The units have different dimensions, so they can not be converted into each other.")]
+    IncompatibleUnits,
+}
+
+impl Unit {
+    /// Convert a value into another unit.
+    ///
+    /// Converting between mass and volume requires the density of the ingredient in g/ml.
+    pub fn convert_to(
+        &self,
+        value: f64,
+        to: &Unit,
+        density: Option<f64>,
+    ) -> Result<f64, ConversionError> {
+        match (self, to) {
+            (Unit::Mass(from), Unit::Mass(to)) => Ok(from.convert(value, to)),
+            (Unit::Volume(from), Unit::Volume(to)) => Ok(from.convert(value, to)),
+            (Unit::Volume(from), Unit::Mass(to)) => {
+                let density = density.ok_or(ConversionError::DensityUnknown)?;
+                let grams = from.convert(value, &VolumeUnit::Millilitre) * density;
+                Ok(MassUnit::Gram.convert(grams, to))
+            }
+            (Unit::Mass(from), Unit::Volume(to)) => {
+                let density = density.ok_or(ConversionError::DensityUnknown)?;
+                let millilitres = from.convert(value, &MassUnit::Gram) / density;
+                Ok(VolumeUnit::Millilitre.convert(millilitres, to))
+            }
+            (Unit::Temperature(from), Unit::Temperature(to)) => Ok(from.convert(value, to)),
+            _ => Err(ConversionError::IncompatibleUnits),
+        }
+    }
+
+    /// Convert a value into the smaller metric unit, which is g or ml, or into °C.
+    pub fn to_metric(&self, value: f64) -> (f64, Unit) {
+        match self {
+            Unit::Mass(MassUnit::Gram) => (value, Unit::Mass(MassUnit::Gram)),
+            Unit::Mass(mass_unit) => (
+                mass_unit.convert(value, &MassUnit::Gram),
+                Unit::Mass(MassUnit::Gram),
+            ),
+            Unit::Volume(VolumeUnit::Millilitre) => (value, Unit::Volume(VolumeUnit::Millilitre)),
+            Unit::Volume(volume_unit) => (
+                volume_unit.convert(value, &VolumeUnit::Millilitre),
+                Unit::Volume(VolumeUnit::Millilitre),
+            ),
+            Unit::Temperature(temperature_unit) => (
+                temperature_unit.convert(value, &TemperatureUnit::Celsius),
+                Unit::Temperature(TemperatureUnit::Celsius),
+            ),
+        }
+    }
+
+    /// Convert a value into the metric unit which fits its size best.
+    ///
+    /// Values of at least 1000 g or 1000 ml are converted into kg or l, temperatures are always in °C.
+    pub fn normalize(&self, value: f64) -> (f64, Unit) {
+        let (value, unit) = self.to_metric(value);
+        if value < 1000.0 {
+            return (value, unit);
+        }
+        match unit {
+            Unit::Mass(_) => (value / 1000.0, Unit::Mass(MassUnit::Kilogram)),
+            Unit::Volume(_) => (value / 1000.0, Unit::Volume(VolumeUnit::Litre)),
+            Unit::Temperature(_) => (value, unit),
+        }
+    }
+
+    /// Convert a value into the unit of a [unit system](UnitSystem) which fits its size best.
+    ///
+    /// Metric values are [normalized](Self::normalize).
+    /// US customary and imperial values are converted into the largest unit in which they are still easy to measure, e.g. 3 tsp instead of 1/16 cup.
+    /// Imperial recipes use metric spoons, temperatures of both are in °F.
+    pub fn to_unit_system(&self, value: f64, unit_system: &UnitSystem) -> (f64, Unit) {
+        if let UnitSystem::Metric = unit_system {
+            return self.normalize(value);
+        }
+        match self.to_metric(value) {
+            (grams, Unit::Mass(gram)) => {
+                let (value, mass_unit) = convert_to_fitting(
+                    grams,
+                    &gram,
+                    [(MassUnit::Pound, 1.0), (MassUnit::Ounce, 0.0)],
+                );
+                (value, Unit::Mass(mass_unit))
+            }
+            (millilitres, Unit::Volume(millilitre)) => {
+                let (value, volume_unit) = match unit_system {
+                    UnitSystem::Imperial => convert_to_fitting(
+                        millilitres,
+                        &millilitre,
+                        [
+                            (VolumeUnit::ImperialGallon, 1.0),
+                            (VolumeUnit::ImperialPint, 1.0),
+                            (VolumeUnit::ImperialFluidOunce, 2.0),
+                            (VolumeUnit::MetricTablespoon, 1.0),
+                            (VolumeUnit::MetricTeaspoon, 0.0),
+                        ],
+                    ),
+                    _ => convert_to_fitting(
+                        millilitres,
+                        &millilitre,
+                        [
+                            (VolumeUnit::UsGallon, 1.0),
+                            (VolumeUnit::UsCup, 0.25),
+                            (VolumeUnit::UsTablespoon, 1.0),
+                            (VolumeUnit::UsTeaspoon, 0.0),
+                        ],
+                    ),
+                };
+                (value, Unit::Volume(volume_unit))
+            }
+            (celsius, Unit::Temperature(celsius_unit)) => (
+                celsius_unit.convert(celsius, &TemperatureUnit::Fahrenheit),
+                Unit::Temperature(TemperatureUnit::Fahrenheit),
+            ),
+        }
+    }
+}
+
+/// Convert a value into the first candidate unit in which it is at least the candidate's minimum.
+///
+/// The last candidate is used if the value is too small for all candidates.
+fn convert_to_fitting<U, const N: usize>(
+    value: f64,
+    from: &U,
+    candidates: [(U, f64); N],
+) -> (f64, U)
+where
+    U: Convert,
+{
+    let mut fitting = None;
+    for (unit, minimum) in candidates {
+        let converted = from.convert(value, &unit);
+        let fits = converted >= minimum;
+        fitting = Some((converted, unit));
+        if fits {
+            break;
+        }
+    }
+    fitting.expect("There is at least one candidate.")
 }
 
 /// This trait enables implementors to [convert](Self::convert) values of type [`f64`] between two of them.
 ///
-/// The implementors need to provide their conversion factors with [`Self::factor`].
-/// This conversion factor must always be in relation to the same reference.
+/// The implementors need to provide their conversion factors with [`Self::factor`] and, for units with another zero point, their offsets with [`Self::offset`].
+/// The conversion factor and offset must always be in relation to the same reference.
+/// Units which are not related affinely to the reference need to implement [`Self::to_reference`] and [`Self::from_reference`] themselves.
 pub trait Convert: Sized {
     /// Convert a value from [`Self`] to a target [`Self`].
     fn convert(&self, value: f64, to: &Self) -> f64 {
-        let reference_quantity = value * self.factor();
-        reference_quantity / to.factor()
+        let reference_quantity = self.to_reference(value);
+        to.from_reference(reference_quantity)
+    }
+
+    /// Convert a value of the unit into the reference unit.
+    fn to_reference(&self, value: f64) -> f64 {
+        value * self.factor() + self.offset()
+    }
+
+    /// Convert a value of the reference unit into the unit.
+    fn from_reference(&self, value: f64) -> f64 {
+        (value - self.offset()) / self.factor()
     }
 
     /// Get the factor of the unit to convert the unit to the reference unit.
     fn factor(&self) -> f64;
+
+    /// Get the value of the reference unit at the zero point of the unit.
+    fn offset(&self) -> f64 {
+        0.0
+    }
 }
 
 impl Convert for MassUnit {
@@ -42,26 +260,121 @@ impl Convert for MassUnit {
             MassUnit::Kilogram => 1e0,
             MassUnit::Gram => 1e-3,
             MassUnit::Pound => 4.535924e-1,
+            MassUnit::Ounce => 2.834952e-2,
         }
     }
 }
 
 impl Convert for VolumeUnit {
     /// https://www.nist.gov/pml/special-publication-811/nist-guide-si-appendix-b-conversion-factors/nist-guide-si-appendix-b9#VOLUME
+    ///
+    /// The imperial cup, pint, and quart are derived from the imperial gallon.
+    /// The metric cup, tablespoon, and teaspoon as well as the pinch have no NIST factor.
     fn factor(&self) -> f64 {
         match self {
             VolumeUnit::Litre => 1e0,
+            VolumeUnit::Decilitre => 1e-1,
+            VolumeUnit::Centilitre => 1e-2,
             VolumeUnit::Millilitre => 1e-3,
+            VolumeUnit::MetricCup => 2.5e-1,
+            VolumeUnit::MetricTablespoon => 1.5e-2,
+            VolumeUnit::MetricTeaspoon => 5e-3,
             VolumeUnit::UsCup => 2.365882e-1,
+            VolumeUnit::UsTablespoon => 1.478676e-2,
+            VolumeUnit::UsTeaspoon => 4.928922e-3,
+            VolumeUnit::UsFluidOunce => 2.957353e-2,
+            VolumeUnit::UsPint => 4.731765e-1,
+            VolumeUnit::UsQuart => 9.463529e-1,
+            VolumeUnit::UsGallon => 3.785412e0,
+            VolumeUnit::ImperialCup => 2.84130625e-1,
+            VolumeUnit::ImperialFluidOunce => 2.841306e-2,
+            VolumeUnit::ImperialPint => 5.6826125e-1,
+            VolumeUnit::ImperialQuart => 1.1365225e0,
+            VolumeUnit::ImperialGallon => 4.54609e0,
+            VolumeUnit::Pinch => 4.928922e-3 / 16.0,
+        }
+    }
+}
+
+impl Convert for TemperatureUnit {
+    /// Gas marks below 1 do not follow the steps of 25 °F of the higher gas marks, they are interpolated between 225 °F (gas mark ¼), 250 °F (gas mark ½), and 275 °F (gas mark 1).
+    fn to_reference(&self, value: f64) -> f64 {
+        match self {
+            TemperatureUnit::GasMark if value < 0.5 => {
+                TemperatureUnit::Fahrenheit.to_reference(200.0 + value * 100.0)
+            }
+            TemperatureUnit::GasMark if value < 1.0 => {
+                TemperatureUnit::Fahrenheit.to_reference(225.0 + value * 50.0)
+            }
+            _ => value * self.factor() + self.offset(),
+        }
+    }
+
+    fn from_reference(&self, value: f64) -> f64 {
+        if let TemperatureUnit::GasMark = self {
+            let fahrenheit = TemperatureUnit::Fahrenheit.from_reference(value);
+            if fahrenheit < 250.0 {
+                return (fahrenheit - 200.0) / 100.0;
+            }
+            if fahrenheit < 275.0 {
+                return (fahrenheit - 225.0) / 50.0;
+            }
+        }
+        (value - self.offset()) / self.factor()
+    }
+
+    /// https://www.nist.gov/pml/special-publication-811/nist-guide-si-appendix-b-conversion-factors/nist-guide-si-appendix-b9#TEMPERATURE
+    ///
+    /// The gas marks from 1 upwards are 25 °F apart.
+    fn factor(&self) -> f64 {
+        match self {
+            TemperatureUnit::Celsius | TemperatureUnit::CelsiusFan | TemperatureUnit::Kelvin => 1.0,
+            TemperatureUnit::Fahrenheit | TemperatureUnit::FahrenheitFan => 5.0 / 9.0,
+            TemperatureUnit::GasMark => 25.0 * 5.0 / 9.0,
         }
     }
+
+    /// The reference unit is kelvin, gas mark 0 would be 250 °F.
+    fn offset(&self) -> f64 {
+        let fahrenheit_offset = 273.15 - 32.0 * 5.0 / 9.0;
+        match self {
+            TemperatureUnit::Celsius => 273.15,
+            TemperatureUnit::CelsiusFan => 273.15 + FAN_OFFSET,
+            TemperatureUnit::Fahrenheit => fahrenheit_offset,
+            TemperatureUnit::FahrenheitFan => fahrenheit_offset + FAN_OFFSET,
+            TemperatureUnit::Kelvin => 0.0,
+            TemperatureUnit::GasMark => fahrenheit_offset + 250.0 * 5.0 / 9.0,
+        }
+    }
+}
+
+/// Round a value to an amount which is easy to measure in a kitchen.
+///
+/// The larger the value, the coarser the rounding, e.g. 0.3 becomes 0.25, 7.3 becomes 7.5, and 437 becomes 440.
+/// A positive value is never rounded to zero.
+pub fn round_kitchen_friendly(value: f64) -> f64 {
+    let step = match value {
+        value if value < 1.0 => 0.125,
+        value if value < 10.0 => 0.5,
+        value if value < 100.0 => 1.0,
+        value if value < 1000.0 => 10.0,
+        _ => 50.0,
+    };
+    let rounded = (value / step).round() * step;
+    if rounded == 0.0 && value > 0.0 {
+        return step;
+    }
+    rounded
 }
 
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
 
-    use crate::unit_conversion::{Convert, MassUnit, VolumeUnit};
+    use crate::unit_conversion::{
+        round_kitchen_friendly, ConversionError, Convert, MassUnit, TemperatureUnit, Unit,
+        UnitSystem, VolumeUnit,
+    };
 
     #[test]
     fn test_pound_conversion() {
@@ -75,4 +388,212 @@ mod tests {
             VolumeUnit::UsCup.convert(1.0, &VolumeUnit::Millilitre)
         );
     }
+
+    #[test]
+    fn test_ounce_conversion() {
+        assert!((MassUnit::Ounce.convert(16.0, &MassUnit::Pound) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_spoon_conversion() {
+        assert!(
+            (VolumeUnit::UsTablespoon.convert(1.0, &VolumeUnit::UsTeaspoon) - 3.0).abs() < 1e-5
+        );
+        assert!(
+            (VolumeUnit::MetricTablespoon.convert(1.0, &VolumeUnit::MetricTeaspoon) - 3.0).abs()
+                < 1e-9
+        );
+        assert!((VolumeUnit::UsCup.convert(1.0, &VolumeUnit::UsTablespoon) - 16.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_imperial_conversion() {
+        assert!(
+            (VolumeUnit::ImperialGallon.convert(1.0, &VolumeUnit::ImperialPint) - 8.0).abs() < 1e-9
+        );
+        assert!(
+            (VolumeUnit::ImperialPint.convert(1.0, &VolumeUnit::ImperialFluidOunce) - 20.0).abs()
+                < 1e-4
+        );
+        assert!(
+            (VolumeUnit::UsGallon.convert(1.0, &VolumeUnit::UsFluidOunce) - 128.0).abs() < 1e-3
+        );
+    }
+
+    #[test]
+    fn test_temperature_conversion() {
+        let cases = [
+            (
+                180.0,
+                TemperatureUnit::Celsius,
+                TemperatureUnit::Fahrenheit,
+                356.0,
+            ),
+            (
+                350.0,
+                TemperatureUnit::Fahrenheit,
+                TemperatureUnit::Celsius,
+                176.666667,
+            ),
+            (
+                0.0,
+                TemperatureUnit::Celsius,
+                TemperatureUnit::Kelvin,
+                273.15,
+            ),
+            (
+                160.0,
+                TemperatureUnit::CelsiusFan,
+                TemperatureUnit::Celsius,
+                180.0,
+            ),
+            (
+                200.0,
+                TemperatureUnit::Celsius,
+                TemperatureUnit::CelsiusFan,
+                180.0,
+            ),
+            (
+                320.0,
+                TemperatureUnit::FahrenheitFan,
+                TemperatureUnit::Fahrenheit,
+                356.0,
+            ),
+            (
+                4.0,
+                TemperatureUnit::GasMark,
+                TemperatureUnit::Fahrenheit,
+                350.0,
+            ),
+            (
+                1.0,
+                TemperatureUnit::GasMark,
+                TemperatureUnit::Fahrenheit,
+                275.0,
+            ),
+            (
+                0.5,
+                TemperatureUnit::GasMark,
+                TemperatureUnit::Fahrenheit,
+                250.0,
+            ),
+            (
+                0.25,
+                TemperatureUnit::GasMark,
+                TemperatureUnit::Fahrenheit,
+                225.0,
+            ),
+            (
+                425.0,
+                TemperatureUnit::Fahrenheit,
+                TemperatureUnit::GasMark,
+                7.0,
+            ),
+            (
+                262.5,
+                TemperatureUnit::Fahrenheit,
+                TemperatureUnit::GasMark,
+                0.75,
+            ),
+            (
+                225.0,
+                TemperatureUnit::Fahrenheit,
+                TemperatureUnit::GasMark,
+                0.25,
+            ),
+        ];
+        for (value, from, to, expected) in cases {
+            let converted = from.convert(value, &to);
+            assert!(
+                (converted - expected).abs() < 1e-6,
+                "{value} {from:?} in {to:?} is {converted}, expected {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_convert_to() {
+        let grams = Unit::Volume(VolumeUnit::UsCup)
+            .convert_to(1.0, &Unit::Mass(MassUnit::Gram), Some(0.53))
+            .unwrap();
+        assert!((grams - 125.391746).abs() < 1e-9);
+        let litres = Unit::Mass(MassUnit::Kilogram)
+            .convert_to(1.42, &Unit::Volume(VolumeUnit::Litre), Some(1.42))
+            .unwrap();
+        assert!((litres - 1.0).abs() < 1e-9);
+        let result = Unit::Mass(MassUnit::Gram).convert_to(
+            100.0,
+            &Unit::Volume(VolumeUnit::Millilitre),
+            None,
+        );
+        assert!(matches!(result, Err(ConversionError::DensityUnknown)));
+        let result = Unit::Temperature(TemperatureUnit::Celsius).convert_to(
+            100.0,
+            &Unit::Mass(MassUnit::Gram),
+            Some(1.0),
+        );
+        assert!(matches!(result, Err(ConversionError::IncompatibleUnits)));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let (value, unit) = Unit::Mass(MassUnit::Gram).normalize(1500.0);
+        assert_eq!(1.5, value);
+        assert!(matches!(unit, Unit::Mass(MassUnit::Kilogram)));
+        let (value, unit) = Unit::Mass(MassUnit::Kilogram).normalize(0.25);
+        assert_eq!(250.0, value);
+        assert!(matches!(unit, Unit::Mass(MassUnit::Gram)));
+        let (value, unit) = Unit::Volume(VolumeUnit::UsCup).normalize(1.0);
+        assert_eq!(236.5882, value);
+        assert!(matches!(unit, Unit::Volume(VolumeUnit::Millilitre)));
+    }
+
+    #[test]
+    fn test_to_unit_system() {
+        let (value, unit) = Unit::Mass(MassUnit::Kilogram).to_unit_system(1.5, &UnitSystem::Metric);
+        assert_eq!(1.5, value);
+        assert!(matches!(unit, Unit::Mass(MassUnit::Kilogram)));
+        let (value, unit) =
+            Unit::Mass(MassUnit::Gram).to_unit_system(100.0, &UnitSystem::UsCustomary);
+        assert!((value - 3.527397).abs() < 1e-6);
+        assert!(matches!(unit, Unit::Mass(MassUnit::Ounce)));
+        let (value, unit) =
+            Unit::Mass(MassUnit::Kilogram).to_unit_system(1.0, &UnitSystem::Imperial);
+        assert!((value - 2.204623).abs() < 1e-6);
+        assert!(matches!(unit, Unit::Mass(MassUnit::Pound)));
+        let (value, unit) =
+            Unit::Volume(VolumeUnit::Millilitre).to_unit_system(250.0, &UnitSystem::UsCustomary);
+        assert!((value - 1.056688).abs() < 1e-6);
+        assert!(matches!(unit, Unit::Volume(VolumeUnit::UsCup)));
+        let (value, unit) = Unit::Volume(VolumeUnit::MetricTablespoon)
+            .to_unit_system(2.0, &UnitSystem::UsCustomary);
+        assert!((value - 2.028842).abs() < 1e-6);
+        assert!(matches!(unit, Unit::Volume(VolumeUnit::UsTablespoon)));
+        let (value, unit) =
+            Unit::Volume(VolumeUnit::Millilitre).to_unit_system(10.0, &UnitSystem::Imperial);
+        assert!((value - 2.0).abs() < 1e-9);
+        assert!(matches!(unit, Unit::Volume(VolumeUnit::MetricTeaspoon)));
+        let (value, unit) =
+            Unit::Volume(VolumeUnit::UsCup).to_unit_system(2.0, &UnitSystem::Imperial);
+        assert!((value - 16.653483).abs() < 1e-6);
+        assert!(matches!(unit, Unit::Volume(VolumeUnit::ImperialFluidOunce)));
+        let (value, unit) = Unit::Temperature(TemperatureUnit::Celsius)
+            .to_unit_system(180.0, &UnitSystem::UsCustomary);
+        assert!((value - 356.0).abs() < 1e-6);
+        assert!(matches!(
+            unit,
+            Unit::Temperature(TemperatureUnit::Fahrenheit)
+        ));
+    }
+
+    #[test]
+    fn test_round_kitchen_friendly() {
+        assert_eq!(0.125, round_kitchen_friendly(0.01));
+        assert_eq!(0.25, round_kitchen_friendly(0.3));
+        assert_eq!(7.5, round_kitchen_friendly(7.3));
+        assert_eq!(37.0, round_kitchen_friendly(36.6));
+        assert_eq!(440.0, round_kitchen_friendly(437.0));
+        assert_eq!(1250.0, round_kitchen_friendly(1234.0));
+        assert_eq!(0.0, round_kitchen_friendly(0.0));
+    }
 }
@@ -1,5 +1,11 @@
+import type { CollectionInterface } from "../../types/entity/collection-interface.ts";
 import type { FileInterface } from "../../types/entity/file-interface.ts";
 import type { IngredientInterface } from "../../types/entity/ingredient-interface.ts";
+import type { IngredientNutritionInterface } from "../../types/entity/ingredient-nutrition-interface.ts";
+import type { MealPlanEntryInterface } from "../../types/entity/meal-plan-entry-interface.ts";
+import type { PantryItemInterface } from "../../types/entity/pantry-item-interface.ts";
+import type { RecipeCollectionInterface } from "../../types/entity/recipe-collection-interface.ts";
+import type { RecipeDeclaredNutritionInterface } from "../../types/entity/recipe-declared-nutrition-interface.ts";
 import type { RecipeFileInterface } from "../../types/entity/recipe-file-interface.ts";
 import type { RecipeIngredientDraftInterface } from "../../types/entity/recipe-ingredient-draft-interface.ts";
 import type { RecipeInterface } from "../../types/entity/recipe-interface.ts";
@@ -7,82 +13,162 @@ import type { RecipeStepFileInterface } from "../../types/entity/recipe-step-fil
 import type { RecipeStepIngredientDraftInterface } from "../../types/entity/recipe-step-ingredient-draft-interface.ts";
 import type { RecipeStepIngredientInterface } from "../../types/entity/recipe-step-ingredient-interface.ts";
 import type { RecipeStepInterface } from "../../types/entity/recipe-step-interface.ts";
+import type { RecipeTagInterface } from "../../types/entity/recipe-tag-interface.ts";
+import type { ShoppingListInterface } from "../../types/entity/shopping-list-interface.ts";
+import type { ShoppingListItemInterface } from "../../types/entity/shopping-list-item-interface.ts";
+import type { TagInterface } from "../../types/entity/tag-interface.ts";
 import type { UnitNameInterface } from "../../types/entity/unit-name-interface.ts";
+import type { ExternalRecipeImport } from "../../types/external-recipe.ts";
+import type { Page } from "../../types/filter-interface.ts";
 import type { UnitConversion } from "../../types/unit-conversion.ts";
 import type { Command } from "./command.ts";
 
 type CommandAnswerMap = {
+  [Command.ENTITY_CREATE_COLLECTION]: number;
+  [Command.ENTITY_READ_COLLECTION]: CollectionInterface;
+  [Command.ENTITY_UPDATE_COLLECTION]: void;
+  [Command.ENTITY_DELETE_COLLECTION]: void;
+  [Command.ENTITY_LIST_COLLECTION]: Page<number>;
+  [Command.ENTITY_COUNT_COLLECTION]: number;
+
   [Command.ENTITY_CREATE_FILE]: number;
   [Command.ENTITY_READ_FILE]: FileInterface;
   [Command.ENTITY_UPDATE_FILE]: void;
   [Command.ENTITY_DELETE_FILE]: void;
-  [Command.ENTITY_LIST_FILE]: number[];
+  [Command.ENTITY_LIST_FILE]: Page<number>;
   [Command.ENTITY_COUNT_FILE]: number;
 
   [Command.ENTITY_CREATE_INGREDIENT]: number;
   [Command.ENTITY_READ_INGREDIENT]: IngredientInterface;
   [Command.ENTITY_UPDATE_INGREDIENT]: void;
   [Command.ENTITY_DELETE_INGREDIENT]: void;
-  [Command.ENTITY_LIST_INGREDIENT]: number[];
+  [Command.ENTITY_LIST_INGREDIENT]: Page<number>;
   [Command.ENTITY_COUNT_INGREDIENT]: number;
 
+  [Command.ENTITY_CREATE_INGREDIENT_NUTRITION]: number;
+  [Command.ENTITY_READ_INGREDIENT_NUTRITION]: IngredientNutritionInterface;
+  [Command.ENTITY_UPDATE_INGREDIENT_NUTRITION]: void;
+  [Command.ENTITY_DELETE_INGREDIENT_NUTRITION]: void;
+  [Command.ENTITY_LIST_INGREDIENT_NUTRITION]: Page<number>;
+  [Command.ENTITY_COUNT_INGREDIENT_NUTRITION]: number;
+
+  [Command.ENTITY_CREATE_MEAL_PLAN_ENTRY]: number;
+  [Command.ENTITY_READ_MEAL_PLAN_ENTRY]: MealPlanEntryInterface;
+  [Command.ENTITY_UPDATE_MEAL_PLAN_ENTRY]: void;
+  [Command.ENTITY_DELETE_MEAL_PLAN_ENTRY]: void;
+  [Command.ENTITY_LIST_MEAL_PLAN_ENTRY]: Page<number>;
+  [Command.ENTITY_COUNT_MEAL_PLAN_ENTRY]: number;
+
+  [Command.ENTITY_CREATE_PANTRY_ITEM]: number;
+  [Command.ENTITY_READ_PANTRY_ITEM]: PantryItemInterface;
+  [Command.ENTITY_UPDATE_PANTRY_ITEM]: void;
+  [Command.ENTITY_DELETE_PANTRY_ITEM]: void;
+  [Command.ENTITY_LIST_PANTRY_ITEM]: Page<number>;
+  [Command.ENTITY_COUNT_PANTRY_ITEM]: number;
+
   [Command.ENTITY_CREATE_RECIPE]: number;
   [Command.ENTITY_READ_RECIPE]: RecipeInterface;
   [Command.ENTITY_UPDATE_RECIPE]: void;
   [Command.ENTITY_DELETE_RECIPE]: void;
-  [Command.ENTITY_LIST_RECIPE]: number[];
+  [Command.ENTITY_LIST_RECIPE]: Page<number>;
   [Command.ENTITY_COUNT_RECIPE]: number;
 
+  [Command.ENTITY_CREATE_RECIPE_COLLECTION]: number;
+  [Command.ENTITY_READ_RECIPE_COLLECTION]: RecipeCollectionInterface;
+  [Command.ENTITY_UPDATE_RECIPE_COLLECTION]: void;
+  [Command.ENTITY_DELETE_RECIPE_COLLECTION]: void;
+  [Command.ENTITY_LIST_RECIPE_COLLECTION]: Page<number>;
+  [Command.ENTITY_COUNT_RECIPE_COLLECTION]: number;
+
+  [Command.ENTITY_CREATE_RECIPE_DECLARED_NUTRITION]: number;
+  [Command.ENTITY_READ_RECIPE_DECLARED_NUTRITION]: RecipeDeclaredNutritionInterface;
+  [Command.ENTITY_UPDATE_RECIPE_DECLARED_NUTRITION]: void;
+  [Command.ENTITY_DELETE_RECIPE_DECLARED_NUTRITION]: void;
+  [Command.ENTITY_LIST_RECIPE_DECLARED_NUTRITION]: Page<number>;
+  [Command.ENTITY_COUNT_RECIPE_DECLARED_NUTRITION]: number;
+
   [Command.ENTITY_CREATE_RECIPE_FILE]: number;
   [Command.ENTITY_READ_RECIPE_FILE]: RecipeFileInterface;
   [Command.ENTITY_UPDATE_RECIPE_FILE]: void;
   [Command.ENTITY_DELETE_RECIPE_FILE]: void;
-  [Command.ENTITY_LIST_RECIPE_FILE]: number[];
+  [Command.ENTITY_LIST_RECIPE_FILE]: Page<number>;
   [Command.ENTITY_COUNT_RECIPE_FILE]: number;
 
   [Command.ENTITY_CREATE_RECIPE_INGREDIENT_DRAFT]: number;
   [Command.ENTITY_READ_RECIPE_INGREDIENT_DRAFT]: RecipeIngredientDraftInterface;
   [Command.ENTITY_UPDATE_RECIPE_INGREDIENT_DRAFT]: void;
   [Command.ENTITY_DELETE_RECIPE_INGREDIENT_DRAFT]: void;
-  [Command.ENTITY_LIST_RECIPE_INGREDIENT_DRAFT]: number[];
+  [Command.ENTITY_LIST_RECIPE_INGREDIENT_DRAFT]: Page<number>;
   [Command.ENTITY_COUNT_RECIPE_INGREDIENT_DRAFT]: number;
 
   [Command.ENTITY_CREATE_RECIPE_STEP_FILE]: number;
   [Command.ENTITY_READ_RECIPE_STEP_FILE]: RecipeStepFileInterface;
   [Command.ENTITY_UPDATE_RECIPE_STEP_FILE]: void;
   [Command.ENTITY_DELETE_RECIPE_STEP_FILE]: void;
-  [Command.ENTITY_LIST_RECIPE_STEP_FILE]: number[];
+  [Command.ENTITY_LIST_RECIPE_STEP_FILE]: Page<number>;
   [Command.ENTITY_COUNT_RECIPE_STEP_FILE]: number;
 
   [Command.ENTITY_CREATE_RECIPE_STEP_INGREDIENT]: number;
   [Command.ENTITY_READ_RECIPE_STEP_INGREDIENT]: RecipeStepIngredientInterface;
   [Command.ENTITY_UPDATE_RECIPE_STEP_INGREDIENT]: void;
   [Command.ENTITY_DELETE_RECIPE_STEP_INGREDIENT]: void;
-  [Command.ENTITY_LIST_RECIPE_STEP_INGREDIENT]: number[];
+  [Command.ENTITY_LIST_RECIPE_STEP_INGREDIENT]: Page<number>;
   [Command.ENTITY_COUNT_RECIPE_STEP_INGREDIENT]: number;
 
   [Command.ENTITY_CREATE_RECIPE_STEP_INGREDIENT_DRAFT]: number;
   [Command.ENTITY_READ_RECIPE_STEP_INGREDIENT_DRAFT]: RecipeStepIngredientDraftInterface;
   [Command.ENTITY_UPDATE_RECIPE_STEP_INGREDIENT_DRAFT]: void;
   [Command.ENTITY_DELETE_RECIPE_STEP_INGREDIENT_DRAFT]: void;
-  [Command.ENTITY_LIST_RECIPE_STEP_INGREDIENT_DRAFT]: number[];
+  [Command.ENTITY_LIST_RECIPE_STEP_INGREDIENT_DRAFT]: Page<number>;
   [Command.ENTITY_COUNT_RECIPE_STEP_INGREDIENT_DRAFT]: number;
 
   [Command.ENTITY_CREATE_RECIPE_STEP]: number;
   [Command.ENTITY_READ_RECIPE_STEP]: RecipeStepInterface;
   [Command.ENTITY_UPDATE_RECIPE_STEP]: void;
   [Command.ENTITY_DELETE_RECIPE_STEP]: void;
-  [Command.ENTITY_LIST_RECIPE_STEP]: number[];
+  [Command.ENTITY_LIST_RECIPE_STEP]: Page<number>;
   [Command.ENTITY_COUNT_RECIPE_STEP]: number;
 
+  [Command.ENTITY_CREATE_RECIPE_TAG]: number;
+  [Command.ENTITY_READ_RECIPE_TAG]: RecipeTagInterface;
+  [Command.ENTITY_UPDATE_RECIPE_TAG]: void;
+  [Command.ENTITY_DELETE_RECIPE_TAG]: void;
+  [Command.ENTITY_LIST_RECIPE_TAG]: Page<number>;
+  [Command.ENTITY_COUNT_RECIPE_TAG]: number;
+
+  [Command.ENTITY_CREATE_SHOPPING_LIST]: number;
+  [Command.ENTITY_READ_SHOPPING_LIST]: ShoppingListInterface;
+  [Command.ENTITY_UPDATE_SHOPPING_LIST]: void;
+  [Command.ENTITY_DELETE_SHOPPING_LIST]: void;
+  [Command.ENTITY_LIST_SHOPPING_LIST]: Page<number>;
+  [Command.ENTITY_COUNT_SHOPPING_LIST]: number;
+
+  [Command.ENTITY_CREATE_SHOPPING_LIST_ITEM]: number;
+  [Command.ENTITY_READ_SHOPPING_LIST_ITEM]: ShoppingListItemInterface;
+  [Command.ENTITY_UPDATE_SHOPPING_LIST_ITEM]: void;
+  [Command.ENTITY_DELETE_SHOPPING_LIST_ITEM]: void;
+  [Command.ENTITY_LIST_SHOPPING_LIST_ITEM]: Page<number>;
+  [Command.ENTITY_COUNT_SHOPPING_LIST_ITEM]: number;
+
+  [Command.ENTITY_CREATE_TAG]: number;
+  [Command.ENTITY_READ_TAG]: TagInterface;
+  [Command.ENTITY_UPDATE_TAG]: void;
+  [Command.ENTITY_DELETE_TAG]: void;
+  [Command.ENTITY_LIST_TAG]: Page<number>;
+  [Command.ENTITY_COUNT_TAG]: number;
+
   [Command.ENTITY_CREATE_UNIT_NAME]: string;
   [Command.ENTITY_READ_UNIT_NAME]: UnitNameInterface;
   [Command.ENTITY_UPDATE_UNIT_NAME]: void;
   [Command.ENTITY_DELETE_UNIT_NAME]: void;
-  [Command.ENTITY_LIST_UNIT_NAME]: string[];
+  [Command.ENTITY_LIST_UNIT_NAME]: Page<string>;
   [Command.ENTITY_COUNT_UNIT_NAME]: number;
 
-  [Command.EXTERNAL_RECIPE]: number;
+  [Command.EXTERNAL_RECIPE]: ExternalRecipeImport;
+  [Command.EXTERNAL_RECIPE_IMPORT_START]: string;
+  [Command.EXTERNAL_RECIPE_IMPORT_CANCEL]: void;
+  [Command.EXTERNAL_RECIPE_SAVED]: ExternalRecipeImport;
+  [Command.EXTERNAL_RECIPE_SAVED_IMPORT_START]: string;
 
   [Command.OCR]: string;
 
@@ -6,4 +6,5 @@ export type CommandError =
   | { Tauri: string }
   | { Anyhow: string }
   | { ExternalRecipeUrlNotSupported: string }
+  | { ExternalRecipeDuplicate: number }
   | { NotFound: string };
@@ -1,3 +1,7 @@
+import type {
+  CollectionCreateInterface,
+  CollectionUpdateInterface,
+} from "../../types/entity/collection-interface.ts";
 import type {
   FileCreateInterface,
   FileUpdateInterface,
@@ -6,6 +10,26 @@ import type {
   IngredientCreateInterface,
   IngredientUpdateInterface,
 } from "../../types/entity/ingredient-interface.ts";
+import type {
+  IngredientNutritionCreateInterface,
+  IngredientNutritionUpdateInterface,
+} from "../../types/entity/ingredient-nutrition-interface.ts";
+import type {
+  MealPlanEntryCreateInterface,
+  MealPlanEntryUpdateInterface,
+} from "../../types/entity/meal-plan-entry-interface.ts";
+import type {
+  PantryItemCreateInterface,
+  PantryItemUpdateInterface,
+} from "../../types/entity/pantry-item-interface.ts";
+import type {
+  RecipeCollectionCreateInterface,
+  RecipeCollectionUpdateInterface,
+} from "../../types/entity/recipe-collection-interface.ts";
+import type {
+  RecipeDeclaredNutritionCreateInterface,
+  RecipeDeclaredNutritionUpdateInterface,
+} from "../../types/entity/recipe-declared-nutrition-interface.ts";
 import type {
   RecipeFileCreateInterface,
   RecipeFileUpdateInterface,
@@ -34,11 +58,31 @@ import type {
   RecipeStepCreateInterface,
   RecipeStepUpdateInterface,
 } from "../../types/entity/recipe-step-interface.ts";
+import type {
+  RecipeTagCreateInterface,
+  RecipeTagUpdateInterface,
+} from "../../types/entity/recipe-tag-interface.ts";
+import type {
+  ShoppingListCreateInterface,
+  ShoppingListUpdateInterface,
+} from "../../types/entity/shopping-list-interface.ts";
+import type {
+  ShoppingListItemCreateInterface,
+  ShoppingListItemUpdateInterface,
+} from "../../types/entity/shopping-list-item-interface.ts";
+import type {
+  TagCreateInterface,
+  TagUpdateInterface,
+} from "../../types/entity/tag-interface.ts";
 import type {
   Unit,
   UnitNameCreateInterface,
   UnitNameUpdateInterface,
 } from "../../types/entity/unit-name-interface.ts";
+import type {
+  CollectionCondition,
+  CollectionFilter,
+} from "../../types/filter/collection-filter.ts";
 import type {
   FileCondition,
   FileFilter,
@@ -47,6 +91,26 @@ import type {
   IngredientCondition,
   IngredientFilter,
 } from "../../types/filter/ingredient-filter.ts";
+import type {
+  IngredientNutritionCondition,
+  IngredientNutritionFilter,
+} from "../../types/filter/ingredient-nutrition-filter.ts";
+import type {
+  MealPlanEntryCondition,
+  MealPlanEntryFilter,
+} from "../../types/filter/meal-plan-entry-filter.ts";
+import type {
+  PantryItemCondition,
+  PantryItemFilter,
+} from "../../types/filter/pantry-item-filter.ts";
+import type {
+  RecipeCollectionCondition,
+  RecipeCollectionFilter,
+} from "../../types/filter/recipe-collection-filter.ts";
+import type {
+  RecipeDeclaredNutritionCondition,
+  RecipeDeclaredNutritionFilter,
+} from "../../types/filter/recipe-declared-nutrition-filter.ts";
 import type {
   RecipeFileCondition,
   RecipeFileFilter,
@@ -75,6 +139,19 @@ import type {
   RecipeStepIngredientCondition,
   RecipeStepIngredientFilter,
 } from "../../types/filter/recipe-step-ingredient-filter.ts";
+import type {
+  RecipeTagCondition,
+  RecipeTagFilter,
+} from "../../types/filter/recipe-tag-filter.ts";
+import type {
+  ShoppingListCondition,
+  ShoppingListFilter,
+} from "../../types/filter/shopping-list-filter.ts";
+import type {
+  ShoppingListItemCondition,
+  ShoppingListItemFilter,
+} from "../../types/filter/shopping-list-item-filter.ts";
+import type { TagCondition, TagFilter } from "../../types/filter/tag-filter.ts";
 import type {
   UnitNameCondition,
   UnitNameFilter,
@@ -82,6 +159,13 @@ import type {
 import type { Command } from "./command.ts";
 
 type CommandParameterMap = {
+  [Command.ENTITY_CREATE_COLLECTION]: { create: CollectionCreateInterface };
+  [Command.ENTITY_READ_COLLECTION]: { id: number };
+  [Command.ENTITY_UPDATE_COLLECTION]: { update: CollectionUpdateInterface };
+  [Command.ENTITY_DELETE_COLLECTION]: { id: number };
+  [Command.ENTITY_LIST_COLLECTION]: { filter: CollectionFilter };
+  [Command.ENTITY_COUNT_COLLECTION]: { condition?: CollectionCondition };
+
   [Command.ENTITY_CREATE_FILE]: { create: FileCreateInterface };
   [Command.ENTITY_READ_FILE]: { id: number };
   [Command.ENTITY_UPDATE_FILE]: { update: FileUpdateInterface };
@@ -96,6 +180,41 @@ type CommandParameterMap = {
   [Command.ENTITY_LIST_INGREDIENT]: { filter: IngredientFilter };
   [Command.ENTITY_COUNT_INGREDIENT]: { condition?: IngredientCondition };
 
+  [Command.ENTITY_CREATE_INGREDIENT_NUTRITION]: {
+    create: IngredientNutritionCreateInterface;
+  };
+  [Command.ENTITY_READ_INGREDIENT_NUTRITION]: { id: number };
+  [Command.ENTITY_UPDATE_INGREDIENT_NUTRITION]: {
+    update: IngredientNutritionUpdateInterface;
+  };
+  [Command.ENTITY_DELETE_INGREDIENT_NUTRITION]: { id: number };
+  [Command.ENTITY_LIST_INGREDIENT_NUTRITION]: {
+    filter: IngredientNutritionFilter;
+  };
+  [Command.ENTITY_COUNT_INGREDIENT_NUTRITION]: {
+    condition?: IngredientNutritionCondition;
+  };
+
+  [Command.ENTITY_CREATE_MEAL_PLAN_ENTRY]: {
+    create: MealPlanEntryCreateInterface;
+  };
+  [Command.ENTITY_READ_MEAL_PLAN_ENTRY]: { id: number };
+  [Command.ENTITY_UPDATE_MEAL_PLAN_ENTRY]: {
+    update: MealPlanEntryUpdateInterface;
+  };
+  [Command.ENTITY_DELETE_MEAL_PLAN_ENTRY]: { id: number };
+  [Command.ENTITY_LIST_MEAL_PLAN_ENTRY]: { filter: MealPlanEntryFilter };
+  [Command.ENTITY_COUNT_MEAL_PLAN_ENTRY]: {
+    condition?: MealPlanEntryCondition;
+  };
+
+  [Command.ENTITY_CREATE_PANTRY_ITEM]: { create: PantryItemCreateInterface };
+  [Command.ENTITY_READ_PANTRY_ITEM]: { id: number };
+  [Command.ENTITY_UPDATE_PANTRY_ITEM]: { update: PantryItemUpdateInterface };
+  [Command.ENTITY_DELETE_PANTRY_ITEM]: { id: number };
+  [Command.ENTITY_LIST_PANTRY_ITEM]: { filter: PantryItemFilter };
+  [Command.ENTITY_COUNT_PANTRY_ITEM]: { condition?: PantryItemCondition };
+
   [Command.ENTITY_CREATE_RECIPE]: { create: RecipeCreateInterface };
   [Command.ENTITY_READ_RECIPE]: { id: number };
   [Command.ENTITY_UPDATE_RECIPE]: { update: RecipeUpdateInterface };
@@ -103,6 +222,34 @@ type CommandParameterMap = {
   [Command.ENTITY_LIST_RECIPE]: { filter: RecipeFilter };
   [Command.ENTITY_COUNT_RECIPE]: { condition?: RecipeCondition };
 
+  [Command.ENTITY_CREATE_RECIPE_COLLECTION]: {
+    create: RecipeCollectionCreateInterface;
+  };
+  [Command.ENTITY_READ_RECIPE_COLLECTION]: { id: number };
+  [Command.ENTITY_UPDATE_RECIPE_COLLECTION]: {
+    update: RecipeCollectionUpdateInterface;
+  };
+  [Command.ENTITY_DELETE_RECIPE_COLLECTION]: { id: number };
+  [Command.ENTITY_LIST_RECIPE_COLLECTION]: { filter: RecipeCollectionFilter };
+  [Command.ENTITY_COUNT_RECIPE_COLLECTION]: {
+    condition?: RecipeCollectionCondition;
+  };
+
+  [Command.ENTITY_CREATE_RECIPE_DECLARED_NUTRITION]: {
+    create: RecipeDeclaredNutritionCreateInterface;
+  };
+  [Command.ENTITY_READ_RECIPE_DECLARED_NUTRITION]: { id: number };
+  [Command.ENTITY_UPDATE_RECIPE_DECLARED_NUTRITION]: {
+    update: RecipeDeclaredNutritionUpdateInterface;
+  };
+  [Command.ENTITY_DELETE_RECIPE_DECLARED_NUTRITION]: { id: number };
+  [Command.ENTITY_LIST_RECIPE_DECLARED_NUTRITION]: {
+    filter: RecipeDeclaredNutritionFilter;
+  };
+  [Command.ENTITY_COUNT_RECIPE_DECLARED_NUTRITION]: {
+    condition?: RecipeDeclaredNutritionCondition;
+  };
+
   [Command.ENTITY_CREATE_RECIPE_FILE]: {
     create: RecipeFileCreateInterface;
   };
@@ -185,6 +332,46 @@ type CommandParameterMap = {
   [Command.ENTITY_LIST_RECIPE_STEP]: { filter: RecipeStepFilter };
   [Command.ENTITY_COUNT_RECIPE_STEP]: { condition?: RecipeStepCondition };
 
+  [Command.ENTITY_CREATE_RECIPE_TAG]: { create: RecipeTagCreateInterface };
+  [Command.ENTITY_READ_RECIPE_TAG]: { id: number };
+  [Command.ENTITY_UPDATE_RECIPE_TAG]: { update: RecipeTagUpdateInterface };
+  [Command.ENTITY_DELETE_RECIPE_TAG]: { id: number };
+  [Command.ENTITY_LIST_RECIPE_TAG]: { filter: RecipeTagFilter };
+  [Command.ENTITY_COUNT_RECIPE_TAG]: { condition?: RecipeTagCondition };
+
+  [Command.ENTITY_CREATE_SHOPPING_LIST]: {
+    create: ShoppingListCreateInterface;
+  };
+  [Command.ENTITY_READ_SHOPPING_LIST]: { id: number };
+  [Command.ENTITY_UPDATE_SHOPPING_LIST]: {
+    update: ShoppingListUpdateInterface;
+  };
+  [Command.ENTITY_DELETE_SHOPPING_LIST]: { id: number };
+  [Command.ENTITY_LIST_SHOPPING_LIST]: { filter: ShoppingListFilter };
+  [Command.ENTITY_COUNT_SHOPPING_LIST]: { condition?: ShoppingListCondition };
+
+  [Command.ENTITY_CREATE_SHOPPING_LIST_ITEM]: {
+    create: ShoppingListItemCreateInterface;
+  };
+  [Command.ENTITY_READ_SHOPPING_LIST_ITEM]: { id: number };
+  [Command.ENTITY_UPDATE_SHOPPING_LIST_ITEM]: {
+    update: ShoppingListItemUpdateInterface;
+  };
+  [Command.ENTITY_DELETE_SHOPPING_LIST_ITEM]: { id: number };
+  [Command.ENTITY_LIST_SHOPPING_LIST_ITEM]: {
+    filter: ShoppingListItemFilter;
+  };
+  [Command.ENTITY_COUNT_SHOPPING_LIST_ITEM]: {
+    condition?: ShoppingListItemCondition;
+  };
+
+  [Command.ENTITY_CREATE_TAG]: { create: TagCreateInterface };
+  [Command.ENTITY_READ_TAG]: { id: number };
+  [Command.ENTITY_UPDATE_TAG]: { update: TagUpdateInterface };
+  [Command.ENTITY_DELETE_TAG]: { id: number };
+  [Command.ENTITY_LIST_TAG]: { filter: TagFilter };
+  [Command.ENTITY_COUNT_TAG]: { condition?: TagCondition };
+
   [Command.ENTITY_CREATE_UNIT_NAME]: { create: UnitNameCreateInterface };
   [Command.ENTITY_READ_UNIT_NAME]: { id: string };
   [Command.ENTITY_UPDATE_UNIT_NAME]: { update: UnitNameUpdateInterface };
@@ -192,11 +379,25 @@ type CommandParameterMap = {
   [Command.ENTITY_LIST_UNIT_NAME]: { filter: UnitNameFilter };
   [Command.ENTITY_COUNT_UNIT_NAME]: { condition?: UnitNameCondition };
 
-  [Command.EXTERNAL_RECIPE]: { url: string };
+  [Command.EXTERNAL_RECIPE]: { url: string; allowDuplicate?: boolean };
+  [Command.EXTERNAL_RECIPE_IMPORT_START]: {
+    url: string;
+    allowDuplicate?: boolean;
+  };
+  [Command.EXTERNAL_RECIPE_IMPORT_CANCEL]: { importId: string };
+  [Command.EXTERNAL_RECIPE_SAVED]: { path: string; allowDuplicate?: boolean };
+  [Command.EXTERNAL_RECIPE_SAVED_IMPORT_START]: {
+    path: string;
+    allowDuplicate?: boolean;
+  };
 
   [Command.OCR]: { fileId: number };
 
-  [Command.UNIT_CONVERT]: { value: number; unit: Unit };
+  [Command.UNIT_CONVERT]: {
+    value: number;
+    unit: Unit;
+    ingredientId?: number;
+  };
 
   [Command.UNIT_LIST_GET]: undefined;
 };
@@ -1,4 +1,11 @@
 export const enum Command {
+  ENTITY_CREATE_COLLECTION = "entity_create_collection",
+  ENTITY_READ_COLLECTION = "entity_read_collection",
+  ENTITY_UPDATE_COLLECTION = "entity_update_collection",
+  ENTITY_DELETE_COLLECTION = "entity_delete_collection",
+  ENTITY_LIST_COLLECTION = "entity_list_collection",
+  ENTITY_COUNT_COLLECTION = "entity_count_collection",
+
   ENTITY_CREATE_FILE = "entity_create_file",
   ENTITY_READ_FILE = "entity_read_file",
   ENTITY_UPDATE_FILE = "entity_update_file",
@@ -13,6 +20,27 @@ export const enum Command {
   ENTITY_LIST_INGREDIENT = "entity_list_ingredient",
   ENTITY_COUNT_INGREDIENT = "entity_count_ingredient",
 
+  ENTITY_CREATE_INGREDIENT_NUTRITION = "entity_create_ingredient_nutrition",
+  ENTITY_READ_INGREDIENT_NUTRITION = "entity_read_ingredient_nutrition",
+  ENTITY_UPDATE_INGREDIENT_NUTRITION = "entity_update_ingredient_nutrition",
+  ENTITY_DELETE_INGREDIENT_NUTRITION = "entity_delete_ingredient_nutrition",
+  ENTITY_LIST_INGREDIENT_NUTRITION = "entity_list_ingredient_nutrition",
+  ENTITY_COUNT_INGREDIENT_NUTRITION = "entity_count_ingredient_nutrition",
+
+  ENTITY_CREATE_MEAL_PLAN_ENTRY = "entity_create_meal_plan_entry",
+  ENTITY_READ_MEAL_PLAN_ENTRY = "entity_read_meal_plan_entry",
+  ENTITY_UPDATE_MEAL_PLAN_ENTRY = "entity_update_meal_plan_entry",
+  ENTITY_DELETE_MEAL_PLAN_ENTRY = "entity_delete_meal_plan_entry",
+  ENTITY_LIST_MEAL_PLAN_ENTRY = "entity_list_meal_plan_entry",
+  ENTITY_COUNT_MEAL_PLAN_ENTRY = "entity_count_meal_plan_entry",
+
+  ENTITY_CREATE_PANTRY_ITEM = "entity_create_pantry_item",
+  ENTITY_READ_PANTRY_ITEM = "entity_read_pantry_item",
+  ENTITY_UPDATE_PANTRY_ITEM = "entity_update_pantry_item",
+  ENTITY_DELETE_PANTRY_ITEM = "entity_delete_pantry_item",
+  ENTITY_LIST_PANTRY_ITEM = "entity_list_pantry_item",
+  ENTITY_COUNT_PANTRY_ITEM = "entity_count_pantry_item",
+
   ENTITY_CREATE_RECIPE = "entity_create_recipe",
   ENTITY_READ_RECIPE = "entity_read_recipe",
   ENTITY_UPDATE_RECIPE = "entity_update_recipe",
@@ -20,6 +48,20 @@ export const enum Command {
   ENTITY_LIST_RECIPE = "entity_list_recipe",
   ENTITY_COUNT_RECIPE = "entity_count_recipe",
 
+  ENTITY_CREATE_RECIPE_COLLECTION = "entity_create_recipe_collection",
+  ENTITY_READ_RECIPE_COLLECTION = "entity_read_recipe_collection",
+  ENTITY_UPDATE_RECIPE_COLLECTION = "entity_update_recipe_collection",
+  ENTITY_DELETE_RECIPE_COLLECTION = "entity_delete_recipe_collection",
+  ENTITY_LIST_RECIPE_COLLECTION = "entity_list_recipe_collection",
+  ENTITY_COUNT_RECIPE_COLLECTION = "entity_count_recipe_collection",
+
+  ENTITY_CREATE_RECIPE_DECLARED_NUTRITION = "entity_create_recipe_declared_nutrition",
+  ENTITY_READ_RECIPE_DECLARED_NUTRITION = "entity_read_recipe_declared_nutrition",
+  ENTITY_UPDATE_RECIPE_DECLARED_NUTRITION = "entity_update_recipe_declared_nutrition",
+  ENTITY_DELETE_RECIPE_DECLARED_NUTRITION = "entity_delete_recipe_declared_nutrition",
+  ENTITY_LIST_RECIPE_DECLARED_NUTRITION = "entity_list_recipe_declared_nutrition",
+  ENTITY_COUNT_RECIPE_DECLARED_NUTRITION = "entity_count_recipe_declared_nutrition",
+
   ENTITY_CREATE_RECIPE_FILE = "entity_create_recipe_file",
   ENTITY_READ_RECIPE_FILE = "entity_read_recipe_file",
   ENTITY_UPDATE_RECIPE_FILE = "entity_update_recipe_file",
@@ -62,6 +104,34 @@ export const enum Command {
   ENTITY_LIST_RECIPE_STEP = "entity_list_recipe_step",
   ENTITY_COUNT_RECIPE_STEP = "entity_count_recipe_step",
 
+  ENTITY_CREATE_RECIPE_TAG = "entity_create_recipe_tag",
+  ENTITY_READ_RECIPE_TAG = "entity_read_recipe_tag",
+  ENTITY_UPDATE_RECIPE_TAG = "entity_update_recipe_tag",
+  ENTITY_DELETE_RECIPE_TAG = "entity_delete_recipe_tag",
+  ENTITY_LIST_RECIPE_TAG = "entity_list_recipe_tag",
+  ENTITY_COUNT_RECIPE_TAG = "entity_count_recipe_tag",
+
+  ENTITY_CREATE_SHOPPING_LIST = "entity_create_shopping_list",
+  ENTITY_READ_SHOPPING_LIST = "entity_read_shopping_list",
+  ENTITY_UPDATE_SHOPPING_LIST = "entity_update_shopping_list",
+  ENTITY_DELETE_SHOPPING_LIST = "entity_delete_shopping_list",
+  ENTITY_LIST_SHOPPING_LIST = "entity_list_shopping_list",
+  ENTITY_COUNT_SHOPPING_LIST = "entity_count_shopping_list",
+
+  ENTITY_CREATE_SHOPPING_LIST_ITEM = "entity_create_shopping_list_item",
+  ENTITY_READ_SHOPPING_LIST_ITEM = "entity_read_shopping_list_item",
+  ENTITY_UPDATE_SHOPPING_LIST_ITEM = "entity_update_shopping_list_item",
+  ENTITY_DELETE_SHOPPING_LIST_ITEM = "entity_delete_shopping_list_item",
+  ENTITY_LIST_SHOPPING_LIST_ITEM = "entity_list_shopping_list_item",
+  ENTITY_COUNT_SHOPPING_LIST_ITEM = "entity_count_shopping_list_item",
+
+  ENTITY_CREATE_TAG = "entity_create_tag",
+  ENTITY_READ_TAG = "entity_read_tag",
+  ENTITY_UPDATE_TAG = "entity_update_tag",
+  ENTITY_DELETE_TAG = "entity_delete_tag",
+  ENTITY_LIST_TAG = "entity_list_tag",
+  ENTITY_COUNT_TAG = "entity_count_tag",
+
   ENTITY_CREATE_UNIT_NAME = "entity_create_unit_name",
   ENTITY_READ_UNIT_NAME = "entity_read_unit_name",
   ENTITY_UPDATE_UNIT_NAME = "entity_update_unit_name",
@@ -70,6 +140,10 @@ export const enum Command {
   ENTITY_COUNT_UNIT_NAME = "entity_count_unit_name",
 
   EXTERNAL_RECIPE = "external_recipe",
+  EXTERNAL_RECIPE_IMPORT_START = "external_recipe_import_start",
+  EXTERNAL_RECIPE_IMPORT_CANCEL = "external_recipe_import_cancel",
+  EXTERNAL_RECIPE_SAVED = "external_recipe_saved",
+  EXTERNAL_RECIPE_SAVED_IMPORT_START = "external_recipe_saved_import_start",
 
   OCR = "ocr",
 
@@ -1,3 +1,8 @@
+import type {
+  CollectionCreateInterface,
+  CollectionInterface,
+  CollectionUpdateInterface,
+} from "../../types/entity/collection-interface.ts";
 import type {
   FileCreateInterface,
   FileInterface,
@@ -8,6 +13,31 @@ import type {
   IngredientInterface,
   IngredientUpdateInterface,
 } from "../../types/entity/ingredient-interface.ts";
+import type {
+  IngredientNutritionCreateInterface,
+  IngredientNutritionInterface,
+  IngredientNutritionUpdateInterface,
+} from "../../types/entity/ingredient-nutrition-interface.ts";
+import type {
+  MealPlanEntryCreateInterface,
+  MealPlanEntryInterface,
+  MealPlanEntryUpdateInterface,
+} from "../../types/entity/meal-plan-entry-interface.ts";
+import type {
+  PantryItemCreateInterface,
+  PantryItemInterface,
+  PantryItemUpdateInterface,
+} from "../../types/entity/pantry-item-interface.ts";
+import type {
+  RecipeCollectionCreateInterface,
+  RecipeCollectionInterface,
+  RecipeCollectionUpdateInterface,
+} from "../../types/entity/recipe-collection-interface.ts";
+import type {
+  RecipeDeclaredNutritionCreateInterface,
+  RecipeDeclaredNutritionInterface,
+  RecipeDeclaredNutritionUpdateInterface,
+} from "../../types/entity/recipe-declared-nutrition-interface.ts";
 import type {
   RecipeFileCreateInterface,
   RecipeFileInterface,
@@ -43,11 +73,36 @@ import type {
   RecipeStepInterface,
   RecipeStepUpdateInterface,
 } from "../../types/entity/recipe-step-interface.ts";
+import type {
+  RecipeTagCreateInterface,
+  RecipeTagInterface,
+  RecipeTagUpdateInterface,
+} from "../../types/entity/recipe-tag-interface.ts";
+import type {
+  ShoppingListCreateInterface,
+  ShoppingListInterface,
+  ShoppingListUpdateInterface,
+} from "../../types/entity/shopping-list-interface.ts";
+import type {
+  ShoppingListItemCreateInterface,
+  ShoppingListItemInterface,
+  ShoppingListItemUpdateInterface,
+} from "../../types/entity/shopping-list-item-interface.ts";
+import type {
+  TagCreateInterface,
+  TagInterface,
+  TagUpdateInterface,
+} from "../../types/entity/tag-interface.ts";
 import type {
   UnitNameCreateInterface,
   UnitNameInterface,
   UnitNameUpdateInterface,
 } from "../../types/entity/unit-name-interface.ts";
+import type { Page } from "../../types/filter-interface.ts";
+import type {
+  CollectionCondition,
+  CollectionFilter,
+} from "../../types/filter/collection-filter.ts";
 import type {
   FileCondition,
   FileFilter,
@@ -56,6 +111,26 @@ import type {
   IngredientCondition,
   IngredientFilter,
 } from "../../types/filter/ingredient-filter.ts";
+import type {
+  IngredientNutritionCondition,
+  IngredientNutritionFilter,
+} from "../../types/filter/ingredient-nutrition-filter.ts";
+import type {
+  MealPlanEntryCondition,
+  MealPlanEntryFilter,
+} from "../../types/filter/meal-plan-entry-filter.ts";
+import type {
+  PantryItemCondition,
+  PantryItemFilter,
+} from "../../types/filter/pantry-item-filter.ts";
+import type {
+  RecipeCollectionCondition,
+  RecipeCollectionFilter,
+} from "../../types/filter/recipe-collection-filter.ts";
+import type {
+  RecipeDeclaredNutritionCondition,
+  RecipeDeclaredNutritionFilter,
+} from "../../types/filter/recipe-declared-nutrition-filter.ts";
 import type {
   RecipeFileCondition,
   RecipeFileFilter,
@@ -84,6 +159,19 @@ import type {
   RecipeStepIngredientCondition,
   RecipeStepIngredientFilter,
 } from "../../types/filter/recipe-step-ingredient-filter.ts";
+import type {
+  RecipeTagCondition,
+  RecipeTagFilter,
+} from "../../types/filter/recipe-tag-filter.ts";
+import type {
+  ShoppingListCondition,
+  ShoppingListFilter,
+} from "../../types/filter/shopping-list-filter.ts";
+import type {
+  ShoppingListItemCondition,
+  ShoppingListItemFilter,
+} from "../../types/filter/shopping-list-item-filter.ts";
+import type { TagCondition, TagFilter } from "../../types/filter/tag-filter.ts";
 import type {
   UnitNameCondition,
   UnitNameFilter,
@@ -95,39 +183,69 @@ import type { CommandParameter } from "./command-parameter.ts";
 import { Command } from "./command.ts";
 
 type CommandEntityRead =
+  | Command.ENTITY_READ_COLLECTION
   | Command.ENTITY_READ_FILE
   | Command.ENTITY_READ_INGREDIENT
+  | Command.ENTITY_READ_INGREDIENT_NUTRITION
+  | Command.ENTITY_READ_MEAL_PLAN_ENTRY
+  | Command.ENTITY_READ_PANTRY_ITEM
   | Command.ENTITY_READ_RECIPE
+  | Command.ENTITY_READ_RECIPE_COLLECTION
+  | Command.ENTITY_READ_RECIPE_DECLARED_NUTRITION
   | Command.ENTITY_READ_RECIPE_FILE
   | Command.ENTITY_READ_RECIPE_INGREDIENT_DRAFT
   | Command.ENTITY_READ_RECIPE_STEP_FILE
   | Command.ENTITY_READ_RECIPE_STEP_INGREDIENT
   | Command.ENTITY_READ_RECIPE_STEP_INGREDIENT_DRAFT
   | Command.ENTITY_READ_RECIPE_STEP
+  | Command.ENTITY_READ_RECIPE_TAG
+  | Command.ENTITY_READ_SHOPPING_LIST
+  | Command.ENTITY_READ_SHOPPING_LIST_ITEM
+  | Command.ENTITY_READ_TAG
   | Command.ENTITY_READ_UNIT_NAME;
 
 type CommandEntityList =
+  | Command.ENTITY_LIST_COLLECTION
   | Command.ENTITY_LIST_FILE
   | Command.ENTITY_LIST_INGREDIENT
+  | Command.ENTITY_LIST_INGREDIENT_NUTRITION
+  | Command.ENTITY_LIST_MEAL_PLAN_ENTRY
+  | Command.ENTITY_LIST_PANTRY_ITEM
   | Command.ENTITY_LIST_RECIPE
+  | Command.ENTITY_LIST_RECIPE_COLLECTION
+  | Command.ENTITY_LIST_RECIPE_DECLARED_NUTRITION
   | Command.ENTITY_LIST_RECIPE_FILE
   | Command.ENTITY_LIST_RECIPE_INGREDIENT_DRAFT
   | Command.ENTITY_LIST_RECIPE_STEP_FILE
   | Command.ENTITY_LIST_RECIPE_STEP_INGREDIENT
   | Command.ENTITY_LIST_RECIPE_STEP_INGREDIENT_DRAFT
   | Command.ENTITY_LIST_RECIPE_STEP
+  | Command.ENTITY_LIST_RECIPE_TAG
+  | Command.ENTITY_LIST_SHOPPING_LIST
+  | Command.ENTITY_LIST_SHOPPING_LIST_ITEM
+  | Command.ENTITY_LIST_TAG
   | Command.ENTITY_LIST_UNIT_NAME;
 
 type CommandEntityCount =
+  | Command.ENTITY_COUNT_COLLECTION
   | Command.ENTITY_COUNT_FILE
   | Command.ENTITY_COUNT_INGREDIENT
+  | Command.ENTITY_COUNT_INGREDIENT_NUTRITION
+  | Command.ENTITY_COUNT_MEAL_PLAN_ENTRY
+  | Command.ENTITY_COUNT_PANTRY_ITEM
   | Command.ENTITY_COUNT_RECIPE
+  | Command.ENTITY_COUNT_RECIPE_COLLECTION
+  | Command.ENTITY_COUNT_RECIPE_DECLARED_NUTRITION
   | Command.ENTITY_COUNT_RECIPE_FILE
   | Command.ENTITY_COUNT_RECIPE_INGREDIENT_DRAFT
   | Command.ENTITY_COUNT_RECIPE_STEP_FILE
   | Command.ENTITY_COUNT_RECIPE_STEP_INGREDIENT
   | Command.ENTITY_COUNT_RECIPE_STEP_INGREDIENT_DRAFT
   | Command.ENTITY_COUNT_RECIPE_STEP
+  | Command.ENTITY_COUNT_RECIPE_TAG
+  | Command.ENTITY_COUNT_SHOPPING_LIST
+  | Command.ENTITY_COUNT_SHOPPING_LIST_ITEM
+  | Command.ENTITY_COUNT_TAG
   | Command.ENTITY_COUNT_UNIT_NAME;
 
 const entityReadPromiseCollector: {
@@ -135,15 +253,25 @@ const entityReadPromiseCollector: {
     [id in CommandParameter<T>["id"]]: Promise<CommandAnswer<T>>;
   };
 } = {
+  [Command.ENTITY_READ_COLLECTION]: {},
   [Command.ENTITY_READ_FILE]: {},
   [Command.ENTITY_READ_INGREDIENT]: {},
+  [Command.ENTITY_READ_INGREDIENT_NUTRITION]: {},
+  [Command.ENTITY_READ_MEAL_PLAN_ENTRY]: {},
+  [Command.ENTITY_READ_PANTRY_ITEM]: {},
   [Command.ENTITY_READ_RECIPE]: {},
+  [Command.ENTITY_READ_RECIPE_COLLECTION]: {},
+  [Command.ENTITY_READ_RECIPE_DECLARED_NUTRITION]: {},
   [Command.ENTITY_READ_RECIPE_FILE]: {},
   [Command.ENTITY_READ_RECIPE_INGREDIENT_DRAFT]: {},
   [Command.ENTITY_READ_RECIPE_STEP_FILE]: {},
   [Command.ENTITY_READ_RECIPE_STEP_INGREDIENT]: {},
   [Command.ENTITY_READ_RECIPE_STEP_INGREDIENT_DRAFT]: {},
   [Command.ENTITY_READ_RECIPE_STEP]: {},
+  [Command.ENTITY_READ_RECIPE_TAG]: {},
+  [Command.ENTITY_READ_SHOPPING_LIST]: {},
+  [Command.ENTITY_READ_SHOPPING_LIST_ITEM]: {},
+  [Command.ENTITY_READ_TAG]: {},
   [Command.ENTITY_READ_UNIT_NAME]: {},
 };
 
@@ -152,15 +280,25 @@ const entityListPromiseCollector: {
     [filterKey: string]: Promise<CommandAnswer<T>>;
   };
 } = {
+  [Command.ENTITY_LIST_COLLECTION]: {},
   [Command.ENTITY_LIST_FILE]: {},
   [Command.ENTITY_LIST_INGREDIENT]: {},
+  [Command.ENTITY_LIST_INGREDIENT_NUTRITION]: {},
+  [Command.ENTITY_LIST_MEAL_PLAN_ENTRY]: {},
+  [Command.ENTITY_LIST_PANTRY_ITEM]: {},
   [Command.ENTITY_LIST_RECIPE]: {},
+  [Command.ENTITY_LIST_RECIPE_COLLECTION]: {},
+  [Command.ENTITY_LIST_RECIPE_DECLARED_NUTRITION]: {},
   [Command.ENTITY_LIST_RECIPE_FILE]: {},
   [Command.ENTITY_LIST_RECIPE_INGREDIENT_DRAFT]: {},
   [Command.ENTITY_LIST_RECIPE_STEP_FILE]: {},
   [Command.ENTITY_LIST_RECIPE_STEP_INGREDIENT]: {},
   [Command.ENTITY_LIST_RECIPE_STEP_INGREDIENT_DRAFT]: {},
   [Command.ENTITY_LIST_RECIPE_STEP]: {},
+  [Command.ENTITY_LIST_RECIPE_TAG]: {},
+  [Command.ENTITY_LIST_SHOPPING_LIST]: {},
+  [Command.ENTITY_LIST_SHOPPING_LIST_ITEM]: {},
+  [Command.ENTITY_LIST_TAG]: {},
   [Command.ENTITY_LIST_UNIT_NAME]: {},
 };
 
@@ -169,15 +307,25 @@ const entityCountPromiseCollector: {
     [conditionKey: string]: Promise<CommandAnswer<T>>;
   };
 } = {
+  [Command.ENTITY_COUNT_COLLECTION]: {},
   [Command.ENTITY_COUNT_FILE]: {},
   [Command.ENTITY_COUNT_INGREDIENT]: {},
+  [Command.ENTITY_COUNT_INGREDIENT_NUTRITION]: {},
+  [Command.ENTITY_COUNT_MEAL_PLAN_ENTRY]: {},
+  [Command.ENTITY_COUNT_PANTRY_ITEM]: {},
   [Command.ENTITY_COUNT_RECIPE]: {},
+  [Command.ENTITY_COUNT_RECIPE_COLLECTION]: {},
+  [Command.ENTITY_COUNT_RECIPE_DECLARED_NUTRITION]: {},
   [Command.ENTITY_COUNT_RECIPE_FILE]: {},
   [Command.ENTITY_COUNT_RECIPE_INGREDIENT_DRAFT]: {},
   [Command.ENTITY_COUNT_RECIPE_STEP_FILE]: {},
   [Command.ENTITY_COUNT_RECIPE_STEP_INGREDIENT]: {},
   [Command.ENTITY_COUNT_RECIPE_STEP_INGREDIENT_DRAFT]: {},
   [Command.ENTITY_COUNT_RECIPE_STEP]: {},
+  [Command.ENTITY_COUNT_RECIPE_TAG]: {},
+  [Command.ENTITY_COUNT_SHOPPING_LIST]: {},
+  [Command.ENTITY_COUNT_SHOPPING_LIST_ITEM]: {},
+  [Command.ENTITY_COUNT_TAG]: {},
   [Command.ENTITY_COUNT_UNIT_NAME]: {},
 };
 
@@ -245,6 +393,38 @@ function countCollected<Command extends CommandEntityCount>(
   return entityCountPromiseCollector[command][conditionKey];
 }
 
+export function createCollection(
+  create: CollectionCreateInterface,
+): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_COLLECTION, { create });
+}
+
+export function readCollection(id: number): Promise<CollectionInterface> {
+  return readCollected(Command.ENTITY_READ_COLLECTION, id);
+}
+
+export function updateCollection(
+  update: CollectionUpdateInterface,
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_COLLECTION, { update });
+}
+
+export function deleteCollection(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_COLLECTION, { id });
+}
+
+export function listCollection(
+  filter: CollectionFilter,
+): Promise<Page<number>> {
+  return listCollected(Command.ENTITY_LIST_COLLECTION, filter);
+}
+
+export function countCollection(
+  condition?: CollectionCondition,
+): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_COLLECTION, condition);
+}
+
 export function createFile(create: FileCreateInterface): Promise<number> {
   return invoke(Command.ENTITY_CREATE_FILE, { create });
 }
@@ -261,7 +441,7 @@ export function deleteFile(id: number): Promise<void> {
   return invoke(Command.ENTITY_DELETE_FILE, { id });
 }
 
-export function listFile(filter: FileFilter): Promise<number[]> {
+export function listFile(filter: FileFilter): Promise<Page<number>> {
   return listCollected(Command.ENTITY_LIST_FILE, filter);
 }
 
@@ -289,7 +469,9 @@ export function deleteIngredient(id: number): Promise<void> {
   return invoke(Command.ENTITY_DELETE_INGREDIENT, { id });
 }
 
-export function listIngredient(filter: IngredientFilter): Promise<number[]> {
+export function listIngredient(
+  filter: IngredientFilter,
+): Promise<Page<number>> {
   return listCollected(Command.ENTITY_LIST_INGREDIENT, filter);
 }
 
@@ -299,6 +481,106 @@ export function countIngredient(
   return countCollected(Command.ENTITY_COUNT_INGREDIENT, condition);
 }
 
+export function createIngredientNutrition(
+  create: IngredientNutritionCreateInterface,
+): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_INGREDIENT_NUTRITION, { create });
+}
+
+export function readIngredientNutrition(
+  id: number,
+): Promise<IngredientNutritionInterface> {
+  return readCollected(Command.ENTITY_READ_INGREDIENT_NUTRITION, id);
+}
+
+export function updateIngredientNutrition(
+  update: IngredientNutritionUpdateInterface,
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_INGREDIENT_NUTRITION, { update });
+}
+
+export function deleteIngredientNutrition(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_INGREDIENT_NUTRITION, { id });
+}
+
+export function listIngredientNutrition(
+  filter: IngredientNutritionFilter,
+): Promise<Page<number>> {
+  return listCollected(Command.ENTITY_LIST_INGREDIENT_NUTRITION, filter);
+}
+
+export function countIngredientNutrition(
+  condition?: IngredientNutritionCondition,
+): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_INGREDIENT_NUTRITION, condition);
+}
+
+export function createMealPlanEntry(
+  create: MealPlanEntryCreateInterface,
+): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_MEAL_PLAN_ENTRY, { create });
+}
+
+export function readMealPlanEntry(
+  id: number,
+): Promise<MealPlanEntryInterface> {
+  return readCollected(Command.ENTITY_READ_MEAL_PLAN_ENTRY, id);
+}
+
+export function updateMealPlanEntry(
+  update: MealPlanEntryUpdateInterface,
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_MEAL_PLAN_ENTRY, { update });
+}
+
+export function deleteMealPlanEntry(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_MEAL_PLAN_ENTRY, { id });
+}
+
+export function listMealPlanEntry(
+  filter: MealPlanEntryFilter,
+): Promise<Page<number>> {
+  return listCollected(Command.ENTITY_LIST_MEAL_PLAN_ENTRY, filter);
+}
+
+export function countMealPlanEntry(
+  condition?: MealPlanEntryCondition,
+): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_MEAL_PLAN_ENTRY, condition);
+}
+
+export function createPantryItem(
+  create: PantryItemCreateInterface,
+): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_PANTRY_ITEM, { create });
+}
+
+export function readPantryItem(id: number): Promise<PantryItemInterface> {
+  return readCollected(Command.ENTITY_READ_PANTRY_ITEM, id);
+}
+
+export function updatePantryItem(
+  update: PantryItemUpdateInterface,
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_PANTRY_ITEM, { update });
+}
+
+export function deletePantryItem(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_PANTRY_ITEM, { id });
+}
+
+export function listPantryItem(
+  filter: PantryItemFilter,
+): Promise<Page<number>> {
+  return listCollected(Command.ENTITY_LIST_PANTRY_ITEM, filter);
+}
+
+export function countPantryItem(
+  condition?: PantryItemCondition,
+): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_PANTRY_ITEM, condition);
+}
+
 export function createRecipe(create: RecipeCreateInterface): Promise<number> {
   return invoke(Command.ENTITY_CREATE_RECIPE, { create });
 }
@@ -315,7 +597,7 @@ export function deleteRecipe(id: number): Promise<void> {
   return invoke(Command.ENTITY_DELETE_RECIPE, { id });
 }
 
-export function listRecipe(filter: RecipeFilter): Promise<number[]> {
+export function listRecipe(filter: RecipeFilter): Promise<Page<number>> {
   return listCollected(Command.ENTITY_LIST_RECIPE, filter);
 }
 
@@ -323,6 +605,80 @@ export function countRecipe(condition?: RecipeCondition): Promise<number> {
   return countCollected(Command.ENTITY_COUNT_RECIPE, condition);
 }
 
+export function createRecipeCollection(
+  create: RecipeCollectionCreateInterface,
+): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_RECIPE_COLLECTION, { create });
+}
+
+export function readRecipeCollection(
+  id: number,
+): Promise<RecipeCollectionInterface> {
+  return readCollected(Command.ENTITY_READ_RECIPE_COLLECTION, id);
+}
+
+export function updateRecipeCollection(
+  update: RecipeCollectionUpdateInterface,
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_RECIPE_COLLECTION, { update });
+}
+
+export function deleteRecipeCollection(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_RECIPE_COLLECTION, { id });
+}
+
+export function listRecipeCollection(
+  filter: RecipeCollectionFilter,
+): Promise<Page<number>> {
+  return listCollected(Command.ENTITY_LIST_RECIPE_COLLECTION, filter);
+}
+
+export function countRecipeCollection(
+  condition?: RecipeCollectionCondition,
+): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_RECIPE_COLLECTION, condition);
+}
+
+export function createRecipeDeclaredNutrition(
+  create: RecipeDeclaredNutritionCreateInterface,
+): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_RECIPE_DECLARED_NUTRITION, { create });
+}
+
+export function readRecipeDeclaredNutrition(
+  id: number,
+): Promise<RecipeDeclaredNutritionInterface> {
+  return readCollected(Command.ENTITY_READ_RECIPE_DECLARED_NUTRITION, id);
+}
+
+export function updateRecipeDeclaredNutrition(
+  update: RecipeDeclaredNutritionUpdateInterface,
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_RECIPE_DECLARED_NUTRITION, { update });
+}
+
+export function deleteRecipeDeclaredNutrition(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_RECIPE_DECLARED_NUTRITION, { id });
+}
+
+export function listRecipeDeclaredNutrition(
+  filter: RecipeDeclaredNutritionFilter,
+): Promise<Page<number>> {
+  return listCollected(
+    Command.ENTITY_LIST_RECIPE_DECLARED_NUTRITION,
+    filter,
+  );
+}
+
+export function countRecipeDeclaredNutrition(
+  condition?: RecipeDeclaredNutritionCondition,
+): Promise<number> {
+  return countCollected(
+    Command.ENTITY_COUNT_RECIPE_DECLARED_NUTRITION,
+    condition,
+  );
+}
+
 export function createRecipeFile(
   create: RecipeFileCreateInterface,
 ): Promise<number> {
@@ -343,7 +699,9 @@ export function deleteRecipeFile(id: number): Promise<void> {
   return invoke(Command.ENTITY_DELETE_RECIPE_FILE, { id });
 }
 
-export function listRecipeFile(filter: RecipeFileFilter): Promise<number[]> {
+export function listRecipeFile(
+  filter: RecipeFileFilter,
+): Promise<Page<number>> {
   return listCollected(Command.ENTITY_LIST_RECIPE_FILE, filter);
 }
 
@@ -377,8 +735,11 @@ export function deleteRecipeIngredientDraft(id: number): Promise<void> {
 
 export function listRecipeIngredientDraft(
   filter: RecipeIngredientDraftFilter,
-): Promise<number[]> {
-  return listCollected(Command.ENTITY_LIST_RECIPE_INGREDIENT_DRAFT, filter);
+): Promise<Page<number>> {
+  return listCollected(
+    Command.ENTITY_LIST_RECIPE_INGREDIENT_DRAFT,
+    filter,
+  );
 }
 
 export function countRecipeIngredientDraft(
@@ -414,7 +775,7 @@ export function deleteRecipeStepFile(id: number): Promise<void> {
 
 export function listRecipeStepFile(
   filter: RecipeStepFileFilter,
-): Promise<number[]> {
+): Promise<Page<number>> {
   return listCollected(Command.ENTITY_LIST_RECIPE_STEP_FILE, filter);
 }
 
@@ -448,7 +809,7 @@ export function deleteRecipeStepIngredient(id: number): Promise<void> {
 
 export function listRecipeStepIngredient(
   filter: RecipeStepIngredientFilter,
-): Promise<number[]> {
+): Promise<Page<number>> {
   return listCollected(Command.ENTITY_LIST_RECIPE_STEP_INGREDIENT, filter);
 }
 
@@ -482,7 +843,7 @@ export function deleteRecipeStepIngredientDraft(id: number): Promise<void> {
 
 export function listRecipeStepIngredientDraft(
   filter: RecipeStepIngredientDraftFilter,
-): Promise<number[]> {
+): Promise<Page<number>> {
   return listCollected(
     Command.ENTITY_LIST_RECIPE_STEP_INGREDIENT_DRAFT,
     filter,
@@ -518,7 +879,9 @@ export function deleteRecipeStep(id: number): Promise<void> {
   return invoke(Command.ENTITY_DELETE_RECIPE_STEP, { id });
 }
 
-export function listRecipeStep(filter: RecipeStepFilter): Promise<number[]> {
+export function listRecipeStep(
+  filter: RecipeStepFilter,
+): Promise<Page<number>> {
   return listCollected(Command.ENTITY_LIST_RECIPE_STEP, filter);
 }
 
@@ -528,6 +891,126 @@ export function countRecipeStep(
   return countCollected(Command.ENTITY_COUNT_RECIPE_STEP, condition);
 }
 
+export function createRecipeTag(
+  create: RecipeTagCreateInterface,
+): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_RECIPE_TAG, { create });
+}
+
+export function readRecipeTag(id: number): Promise<RecipeTagInterface> {
+  return readCollected(Command.ENTITY_READ_RECIPE_TAG, id);
+}
+
+export function updateRecipeTag(
+  update: RecipeTagUpdateInterface,
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_RECIPE_TAG, { update });
+}
+
+export function deleteRecipeTag(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_RECIPE_TAG, { id });
+}
+
+export function listRecipeTag(filter: RecipeTagFilter): Promise<Page<number>> {
+  return listCollected(Command.ENTITY_LIST_RECIPE_TAG, filter);
+}
+
+export function countRecipeTag(
+  condition?: RecipeTagCondition,
+): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_RECIPE_TAG, condition);
+}
+
+export function createShoppingList(
+  create: ShoppingListCreateInterface,
+): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_SHOPPING_LIST, { create });
+}
+
+export function readShoppingList(id: number): Promise<ShoppingListInterface> {
+  return readCollected(Command.ENTITY_READ_SHOPPING_LIST, id);
+}
+
+export function updateShoppingList(
+  update: ShoppingListUpdateInterface,
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_SHOPPING_LIST, { update });
+}
+
+export function deleteShoppingList(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_SHOPPING_LIST, { id });
+}
+
+export function listShoppingList(
+  filter: ShoppingListFilter,
+): Promise<Page<number>> {
+  return listCollected(Command.ENTITY_LIST_SHOPPING_LIST, filter);
+}
+
+export function countShoppingList(
+  condition?: ShoppingListCondition,
+): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_SHOPPING_LIST, condition);
+}
+
+export function createShoppingListItem(
+  create: ShoppingListItemCreateInterface,
+): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_SHOPPING_LIST_ITEM, { create });
+}
+
+export function readShoppingListItem(
+  id: number,
+): Promise<ShoppingListItemInterface> {
+  return readCollected(Command.ENTITY_READ_SHOPPING_LIST_ITEM, id);
+}
+
+export function updateShoppingListItem(
+  update: ShoppingListItemUpdateInterface,
+): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_SHOPPING_LIST_ITEM, { update });
+}
+
+export function deleteShoppingListItem(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_SHOPPING_LIST_ITEM, { id });
+}
+
+export function listShoppingListItem(
+  filter: ShoppingListItemFilter,
+): Promise<Page<number>> {
+  return listCollected(Command.ENTITY_LIST_SHOPPING_LIST_ITEM, filter);
+}
+
+export function countShoppingListItem(
+  condition?: ShoppingListItemCondition,
+): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_SHOPPING_LIST_ITEM, condition);
+}
+
+export function createTag(create: TagCreateInterface): Promise<number> {
+  return invoke(Command.ENTITY_CREATE_TAG, { create });
+}
+
+export function readTag(id: number): Promise<TagInterface> {
+  return readCollected(Command.ENTITY_READ_TAG, id);
+}
+
+export function updateTag(update: TagUpdateInterface): Promise<void> {
+  return invoke(Command.ENTITY_UPDATE_TAG, { update });
+}
+
+export function deleteTag(id: number): Promise<void> {
+  return invoke(Command.ENTITY_DELETE_TAG, { id });
+}
+
+export function listTag(filter: TagFilter): Promise<Page<number>> {
+  return listCollected(Command.ENTITY_LIST_TAG, filter);
+}
+
+export function countTag(condition?: TagCondition): Promise<number> {
+  return countCollected(Command.ENTITY_COUNT_TAG, condition);
+}
+
 export function createUnitName(
   create: UnitNameCreateInterface,
 ): Promise<string> {
@@ -546,7 +1029,7 @@ export function deleteUnitName(id: string): Promise<void> {
   return invoke(Command.ENTITY_DELETE_UNIT_NAME, { id });
 }
 
-export function listUnitName(filter: UnitNameFilter): Promise<string[]> {
+export function listUnitName(filter: UnitNameFilter): Promise<Page<string>> {
   return listCollected(Command.ENTITY_LIST_UNIT_NAME, filter);
 }
 
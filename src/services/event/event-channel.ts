@@ -1,13 +1,31 @@
 export const enum EventChannel {
+  ENTITY_ACTION_CREATED_COLLECTION = "ENTITY_ACTION_CREATED_COLLECTION",
+  ENTITY_ACTION_UPDATED_COLLECTION = "ENTITY_ACTION_UPDATED_COLLECTION",
+  ENTITY_ACTION_DELETED_COLLECTION = "ENTITY_ACTION_DELETED_COLLECTION",
   ENTITY_ACTION_CREATED_FILE = "ENTITY_ACTION_CREATED_FILE",
   ENTITY_ACTION_UPDATED_FILE = "ENTITY_ACTION_UPDATED_FILE",
   ENTITY_ACTION_DELETED_FILE = "ENTITY_ACTION_DELETED_FILE",
   ENTITY_ACTION_CREATED_INGREDIENT = "ENTITY_ACTION_CREATED_INGREDIENT",
   ENTITY_ACTION_UPDATED_INGREDIENT = "ENTITY_ACTION_UPDATED_INGREDIENT",
   ENTITY_ACTION_DELETED_INGREDIENT = "ENTITY_ACTION_DELETED_INGREDIENT",
+  ENTITY_ACTION_CREATED_INGREDIENT_NUTRITION = "ENTITY_ACTION_CREATED_INGREDIENT_NUTRITION",
+  ENTITY_ACTION_UPDATED_INGREDIENT_NUTRITION = "ENTITY_ACTION_UPDATED_INGREDIENT_NUTRITION",
+  ENTITY_ACTION_DELETED_INGREDIENT_NUTRITION = "ENTITY_ACTION_DELETED_INGREDIENT_NUTRITION",
+  ENTITY_ACTION_CREATED_MEAL_PLAN_ENTRY = "ENTITY_ACTION_CREATED_MEAL_PLAN_ENTRY",
+  ENTITY_ACTION_UPDATED_MEAL_PLAN_ENTRY = "ENTITY_ACTION_UPDATED_MEAL_PLAN_ENTRY",
+  ENTITY_ACTION_DELETED_MEAL_PLAN_ENTRY = "ENTITY_ACTION_DELETED_MEAL_PLAN_ENTRY",
+  ENTITY_ACTION_CREATED_PANTRY_ITEM = "ENTITY_ACTION_CREATED_PANTRY_ITEM",
+  ENTITY_ACTION_UPDATED_PANTRY_ITEM = "ENTITY_ACTION_UPDATED_PANTRY_ITEM",
+  ENTITY_ACTION_DELETED_PANTRY_ITEM = "ENTITY_ACTION_DELETED_PANTRY_ITEM",
   ENTITY_ACTION_CREATED_RECIPE = "ENTITY_ACTION_CREATED_RECIPE",
   ENTITY_ACTION_UPDATED_RECIPE = "ENTITY_ACTION_UPDATED_RECIPE",
   ENTITY_ACTION_DELETED_RECIPE = "ENTITY_ACTION_DELETED_RECIPE",
+  ENTITY_ACTION_CREATED_RECIPE_COLLECTION = "ENTITY_ACTION_CREATED_RECIPE_COLLECTION",
+  ENTITY_ACTION_UPDATED_RECIPE_COLLECTION = "ENTITY_ACTION_UPDATED_RECIPE_COLLECTION",
+  ENTITY_ACTION_DELETED_RECIPE_COLLECTION = "ENTITY_ACTION_DELETED_RECIPE_COLLECTION",
+  ENTITY_ACTION_CREATED_RECIPE_DECLARED_NUTRITION = "ENTITY_ACTION_CREATED_RECIPE_DECLARED_NUTRITION",
+  ENTITY_ACTION_UPDATED_RECIPE_DECLARED_NUTRITION = "ENTITY_ACTION_UPDATED_RECIPE_DECLARED_NUTRITION",
+  ENTITY_ACTION_DELETED_RECIPE_DECLARED_NUTRITION = "ENTITY_ACTION_DELETED_RECIPE_DECLARED_NUTRITION",
   ENTITY_ACTION_CREATED_RECIPE_FILE = "ENTITY_ACTION_CREATED_RECIPE_FILE",
   ENTITY_ACTION_UPDATED_RECIPE_FILE = "ENTITY_ACTION_UPDATED_RECIPE_FILE",
   ENTITY_ACTION_DELETED_RECIPE_FILE = "ENTITY_ACTION_DELETED_RECIPE_FILE",
@@ -26,10 +44,28 @@ export const enum EventChannel {
   ENTITY_ACTION_CREATED_RECIPE_STEP = "ENTITY_ACTION_CREATED_RECIPE_STEP",
   ENTITY_ACTION_UPDATED_RECIPE_STEP = "ENTITY_ACTION_UPDATED_RECIPE_STEP",
   ENTITY_ACTION_DELETED_RECIPE_STEP = "ENTITY_ACTION_DELETED_RECIPE_STEP",
+  ENTITY_ACTION_CREATED_RECIPE_TAG = "ENTITY_ACTION_CREATED_RECIPE_TAG",
+  ENTITY_ACTION_UPDATED_RECIPE_TAG = "ENTITY_ACTION_UPDATED_RECIPE_TAG",
+  ENTITY_ACTION_DELETED_RECIPE_TAG = "ENTITY_ACTION_DELETED_RECIPE_TAG",
+  ENTITY_ACTION_CREATED_SHOPPING_LIST = "ENTITY_ACTION_CREATED_SHOPPING_LIST",
+  ENTITY_ACTION_UPDATED_SHOPPING_LIST = "ENTITY_ACTION_UPDATED_SHOPPING_LIST",
+  ENTITY_ACTION_DELETED_SHOPPING_LIST = "ENTITY_ACTION_DELETED_SHOPPING_LIST",
+  ENTITY_ACTION_CREATED_SHOPPING_LIST_ITEM = "ENTITY_ACTION_CREATED_SHOPPING_LIST_ITEM",
+  ENTITY_ACTION_UPDATED_SHOPPING_LIST_ITEM = "ENTITY_ACTION_UPDATED_SHOPPING_LIST_ITEM",
+  ENTITY_ACTION_DELETED_SHOPPING_LIST_ITEM = "ENTITY_ACTION_DELETED_SHOPPING_LIST_ITEM",
+  ENTITY_ACTION_CREATED_TAG = "ENTITY_ACTION_CREATED_TAG",
+  ENTITY_ACTION_UPDATED_TAG = "ENTITY_ACTION_UPDATED_TAG",
+  ENTITY_ACTION_DELETED_TAG = "ENTITY_ACTION_DELETED_TAG",
   ENTITY_ACTION_CREATED_UNIT_NAME = "ENTITY_ACTION_CREATED_UNIT_NAME",
   ENTITY_ACTION_UPDATED_UNIT_NAME = "ENTITY_ACTION_UPDATED_UNIT_NAME",
   ENTITY_ACTION_DELETED_UNIT_NAME = "ENTITY_ACTION_DELETED_UNIT_NAME",
 
+  EXTERNAL_RECIPE_IMPORT_PROGRESS = "EXTERNAL_RECIPE_IMPORT_PROGRESS",
+  EXTERNAL_RECIPE_IMPORT_FINISHED = "EXTERNAL_RECIPE_IMPORT_FINISHED",
+
+  PREFERENCE_UPDATED_UNIT_SYSTEM = "PREFERENCE_UPDATED_UNIT_SYSTEM",
+  PREFERENCE_UPDATED_FETCH_SETTINGS = "PREFERENCE_UPDATED_FETCH_SETTINGS",
+
   SCRAPER_DOM_DROP = "SCRAPER_DOM_DROP",
   SCRAPER_ELEMENT_DROP = "SCRAPER_ELEMENT_DROP",
 }
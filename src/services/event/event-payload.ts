@@ -1,15 +1,40 @@
+import type {
+  ExternalRecipeImportFinished,
+  ExternalRecipeImportProgress,
+} from "../../types/external-recipe.ts";
+import type { FetchSettings } from "../../types/fetch.ts";
+import type { UnitSystem } from "../../types/unit-conversion.ts";
+
 import type { EventChannel } from "./event-channel.ts";
 
 type EventPayloadMap = {
+  [EventChannel.ENTITY_ACTION_CREATED_COLLECTION]: void;
+  [EventChannel.ENTITY_ACTION_UPDATED_COLLECTION]: number;
+  [EventChannel.ENTITY_ACTION_DELETED_COLLECTION]: number;
   [EventChannel.ENTITY_ACTION_CREATED_FILE]: void;
   [EventChannel.ENTITY_ACTION_UPDATED_FILE]: number;
   [EventChannel.ENTITY_ACTION_DELETED_FILE]: number;
   [EventChannel.ENTITY_ACTION_CREATED_INGREDIENT]: void;
   [EventChannel.ENTITY_ACTION_UPDATED_INGREDIENT]: number;
   [EventChannel.ENTITY_ACTION_DELETED_INGREDIENT]: number;
+  [EventChannel.ENTITY_ACTION_CREATED_INGREDIENT_NUTRITION]: void;
+  [EventChannel.ENTITY_ACTION_UPDATED_INGREDIENT_NUTRITION]: number;
+  [EventChannel.ENTITY_ACTION_DELETED_INGREDIENT_NUTRITION]: number;
+  [EventChannel.ENTITY_ACTION_CREATED_MEAL_PLAN_ENTRY]: void;
+  [EventChannel.ENTITY_ACTION_UPDATED_MEAL_PLAN_ENTRY]: number;
+  [EventChannel.ENTITY_ACTION_DELETED_MEAL_PLAN_ENTRY]: number;
+  [EventChannel.ENTITY_ACTION_CREATED_PANTRY_ITEM]: void;
+  [EventChannel.ENTITY_ACTION_UPDATED_PANTRY_ITEM]: number;
+  [EventChannel.ENTITY_ACTION_DELETED_PANTRY_ITEM]: number;
   [EventChannel.ENTITY_ACTION_CREATED_RECIPE]: void;
   [EventChannel.ENTITY_ACTION_UPDATED_RECIPE]: number;
   [EventChannel.ENTITY_ACTION_DELETED_RECIPE]: number;
+  [EventChannel.ENTITY_ACTION_CREATED_RECIPE_COLLECTION]: void;
+  [EventChannel.ENTITY_ACTION_UPDATED_RECIPE_COLLECTION]: number;
+  [EventChannel.ENTITY_ACTION_DELETED_RECIPE_COLLECTION]: number;
+  [EventChannel.ENTITY_ACTION_CREATED_RECIPE_DECLARED_NUTRITION]: void;
+  [EventChannel.ENTITY_ACTION_UPDATED_RECIPE_DECLARED_NUTRITION]: number;
+  [EventChannel.ENTITY_ACTION_DELETED_RECIPE_DECLARED_NUTRITION]: number;
   [EventChannel.ENTITY_ACTION_CREATED_RECIPE_FILE]: void;
   [EventChannel.ENTITY_ACTION_UPDATED_RECIPE_FILE]: number;
   [EventChannel.ENTITY_ACTION_DELETED_RECIPE_FILE]: number;
@@ -28,10 +53,28 @@ type EventPayloadMap = {
   [EventChannel.ENTITY_ACTION_CREATED_RECIPE_STEP]: void;
   [EventChannel.ENTITY_ACTION_UPDATED_RECIPE_STEP]: number;
   [EventChannel.ENTITY_ACTION_DELETED_RECIPE_STEP]: number;
+  [EventChannel.ENTITY_ACTION_CREATED_RECIPE_TAG]: void;
+  [EventChannel.ENTITY_ACTION_UPDATED_RECIPE_TAG]: number;
+  [EventChannel.ENTITY_ACTION_DELETED_RECIPE_TAG]: number;
+  [EventChannel.ENTITY_ACTION_CREATED_SHOPPING_LIST]: void;
+  [EventChannel.ENTITY_ACTION_UPDATED_SHOPPING_LIST]: number;
+  [EventChannel.ENTITY_ACTION_DELETED_SHOPPING_LIST]: number;
+  [EventChannel.ENTITY_ACTION_CREATED_SHOPPING_LIST_ITEM]: void;
+  [EventChannel.ENTITY_ACTION_UPDATED_SHOPPING_LIST_ITEM]: number;
+  [EventChannel.ENTITY_ACTION_DELETED_SHOPPING_LIST_ITEM]: number;
+  [EventChannel.ENTITY_ACTION_CREATED_TAG]: void;
+  [EventChannel.ENTITY_ACTION_UPDATED_TAG]: number;
+  [EventChannel.ENTITY_ACTION_DELETED_TAG]: number;
   [EventChannel.ENTITY_ACTION_CREATED_UNIT_NAME]: void;
   [EventChannel.ENTITY_ACTION_UPDATED_UNIT_NAME]: string;
   [EventChannel.ENTITY_ACTION_DELETED_UNIT_NAME]: string;
 
+  [EventChannel.EXTERNAL_RECIPE_IMPORT_PROGRESS]: ExternalRecipeImportProgress;
+  [EventChannel.EXTERNAL_RECIPE_IMPORT_FINISHED]: ExternalRecipeImportFinished;
+
+  [EventChannel.PREFERENCE_UPDATED_UNIT_SYSTEM]: UnitSystem;
+  [EventChannel.PREFERENCE_UPDATED_FETCH_SETTINGS]: FetchSettings;
+
   [EventChannel.SCRAPER_DOM_DROP]: string;
   [EventChannel.SCRAPER_ELEMENT_DROP]: string;
 };
@@ -11,20 +11,86 @@ export class ExternalRecipeUrlNotSupportedError extends Error {
   }
 }
 
-export async function getExternalRecipe(url: string) {
-  let recipeId;
+export class ExternalRecipeDuplicateError extends Error {
+  recipeId: number;
+
+  constructor(recipeId: number, message?: string) {
+    super(message);
+    this.recipeId = recipeId;
+  }
+}
+
+function toExternalRecipeError(reason: unknown) {
+  const commandError: CommandError = reason as CommandError;
+  if ("ExternalRecipeUrlNotSupported" in commandError) {
+    return new ExternalRecipeUrlNotSupportedError(
+      commandError.ExternalRecipeUrlNotSupported,
+    );
+  }
+  if ("ExternalRecipeDuplicate" in commandError) {
+    return new ExternalRecipeDuplicateError(
+      commandError.ExternalRecipeDuplicate,
+    );
+  }
+  return reason;
+}
+
+export async function getExternalRecipe(
+  url: string,
+  allowDuplicate?: boolean,
+) {
+  try {
+    return await invoke(Command.EXTERNAL_RECIPE, {
+      url,
+      allowDuplicate,
+    });
+  } catch (reason) {
+    throw toExternalRecipeError(reason);
+  }
+}
+
+export async function startExternalRecipeImport(
+  url: string,
+  allowDuplicate?: boolean,
+) {
   try {
-    recipeId = await invoke(Command.EXTERNAL_RECIPE, {
+    return await invoke(Command.EXTERNAL_RECIPE_IMPORT_START, {
       url,
+      allowDuplicate,
     });
   } catch (reason) {
-    const commandError: CommandError = reason as CommandError;
-    if ("ExternalRecipeUrlNotSupported" in commandError) {
-      throw new ExternalRecipeUrlNotSupportedError(
-        commandError.ExternalRecipeUrlNotSupported,
-      );
-    }
-    throw reason;
-  }
-  return recipeId;
+    throw toExternalRecipeError(reason);
+  }
+}
+
+export async function getSavedExternalRecipe(
+  path: string,
+  allowDuplicate?: boolean,
+) {
+  try {
+    return await invoke(Command.EXTERNAL_RECIPE_SAVED, {
+      path,
+      allowDuplicate,
+    });
+  } catch (reason) {
+    throw toExternalRecipeError(reason);
+  }
+}
+
+export async function startSavedExternalRecipeImport(
+  path: string,
+  allowDuplicate?: boolean,
+) {
+  try {
+    return await invoke(Command.EXTERNAL_RECIPE_SAVED_IMPORT_START, {
+      path,
+      allowDuplicate,
+    });
+  } catch (reason) {
+    throw toExternalRecipeError(reason);
+  }
+}
+
+export function cancelExternalRecipeImport(importId: string): Promise<void> {
+  return invoke(Command.EXTERNAL_RECIPE_IMPORT_CANCEL, { importId });
 }
@@ -1,5 +1,8 @@
 import type { Readable } from "svelte/store";
-import type { FilterInterface } from "../../../types/filter-interface.ts";
+import type {
+  FilterInterface,
+  Page,
+} from "../../../types/filter-interface.ts";
 import type { IdentifiableInterface } from "../../../types/identifiable-interface.ts";
 import { equalArray } from "../../util/compare.ts";
 import type { Loadable } from "../../util/loadable.ts";
@@ -14,7 +17,9 @@ type ApiUpdate<EntityUpdate> = (entityUpdate: EntityUpdate) => Promise<void>;
 
 type ApiDelete<PrimaryKey> = (identifier: PrimaryKey) => Promise<void>;
 
-type ApiList<Filter, PrimaryKey> = (filter: Filter) => Promise<PrimaryKey[]>;
+type ApiList<Filter, PrimaryKey> = (
+  filter: Filter,
+) => Promise<Page<PrimaryKey>>;
 
 type ApiCount<Condition> = (condition?: Condition) => Promise<number>;
 
@@ -254,7 +259,7 @@ export class EntityRepository<
       if (!this.listSubscribers.size) {
         return;
       }
-      const list = await this.apiList(this.defaultFilter);
+      const { items: list } = await this.apiList(this.defaultFilter);
       if (!equalArray(this.listState, list)) {
         this.listState = list;
         this.runList();
@@ -277,7 +282,9 @@ export class EntityRepository<
         this.filteredListSubscribers,
       )) {
         const filterKey = stringifyFilter(filteredListSubscriber.filter);
-        const list = await this.apiList(filteredListSubscriber.filter);
+        const { items: list } = await this.apiList(
+          filteredListSubscriber.filter,
+        );
         if (!equalArray(this.filteredListState[filterKey], list)) {
           this.filteredListState[filterKey] = list;
           this.runListFiltered(filterKey);
@@ -677,7 +684,7 @@ export class EntityRepository<
    */
   async list(): Promise<void> {
     if (!this.listState.length) {
-      this.listState = await this.apiList(this.defaultFilter);
+      this.listState = (await this.apiList(this.defaultFilter)).items;
     }
   }
 
@@ -702,7 +709,7 @@ export class EntityRepository<
       delete this.filteredListStateDeleteTimeouts[filterKey];
     }
     if (!this.filteredListState[filterKey]) {
-      this.filteredListState[filterKey] = await this.apiList(filter);
+      this.filteredListState[filterKey] = (await this.apiList(filter)).items;
     }
   }
 
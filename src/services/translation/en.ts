@@ -66,6 +66,8 @@ const translationStrings: TranslationStrings = {
     },
     externalRecipeUrlNotSupported:
       'The external recipe URL "{$url}" is not supported.',
+    externalRecipeDuplicate:
+      "The external recipe was already imported as recipe {$recipeId}.",
   },
   questions: {
     confirmation: "Are you sure?",
@@ -86,6 +88,30 @@ const translationStrings: TranslationStrings = {
     litre: "l",
     millilitre: "ml",
     usCup: ".match {$value :number} 1 {{cup}} * {{cups}}",
+    ounce: "oz",
+    decilitre: "dl",
+    centilitre: "cl",
+    metricCup: ".match {$value :number} 1 {{metric cup}} * {{metric cups}}",
+    metricTablespoon: "tbsp",
+    metricTeaspoon: "tsp",
+    usTablespoon: "tbsp",
+    usTeaspoon: "tsp",
+    usFluidOunce: "fl oz",
+    usPint: "pt",
+    usQuart: "qt",
+    usGallon: "gal",
+    imperialCup: ".match {$value :number} 1 {{imperial cup}} * {{imperial cups}}",
+    imperialFluidOunce: "imperial fl oz",
+    imperialPint: "imperial pt",
+    imperialQuart: "imperial qt",
+    imperialGallon: "imperial gal",
+    pinch: ".match {$value :number} 1 {{pinch}} * {{pinches}}",
+    celsius: "°C",
+    celsiusFan: "°C (fan)",
+    fahrenheit: "°F",
+    fahrenheitFan: "°F (fan)",
+    kelvin: "K",
+    gasMark: "gas mark",
   },
 };
 
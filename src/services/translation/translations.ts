@@ -60,6 +60,7 @@ type Translations<T> = {
       includesExcluded: T;
     };
     externalRecipeUrlNotSupported: T;
+    externalRecipeDuplicate: T;
   };
   questions: {
     confirmation: T;
@@ -80,6 +81,30 @@ type Translations<T> = {
     litre: T;
     millilitre: T;
     usCup: T;
+    ounce: T;
+    decilitre: T;
+    centilitre: T;
+    metricCup: T;
+    metricTablespoon: T;
+    metricTeaspoon: T;
+    usTablespoon: T;
+    usTeaspoon: T;
+    usFluidOunce: T;
+    usPint: T;
+    usQuart: T;
+    usGallon: T;
+    imperialCup: T;
+    imperialFluidOunce: T;
+    imperialPint: T;
+    imperialQuart: T;
+    imperialGallon: T;
+    pinch: T;
+    celsius: T;
+    celsiusFan: T;
+    fahrenheit: T;
+    fahrenheitFan: T;
+    kelvin: T;
+    gasMark: T;
   };
 };
 
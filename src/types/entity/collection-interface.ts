@@ -0,0 +1,13 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+
+export interface CollectionInterface extends IdentifiableInterface {
+  name: string;
+}
+
+export interface CollectionCreateInterface {
+  name: string;
+}
+
+export interface CollectionUpdateInterface extends IdentifiableInterface {
+  name?: string;
+}
@@ -2,12 +2,15 @@ import type { IdentifiableInterface } from "../identifiable-interface.ts";
 
 export interface IngredientInterface extends IdentifiableInterface {
   name: string;
+  density: number | null;
 }
 
 export interface IngredientCreateInterface {
   name: string;
+  density?: number | null;
 }
 
 export interface IngredientUpdateInterface extends IdentifiableInterface {
   name?: string;
+  density?: number | null;
 }
@@ -0,0 +1,31 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+
+export interface IngredientNutritionInterface extends IdentifiableInterface {
+  energy: number;
+  protein: number;
+  fat: number;
+  carbohydrates: number;
+  fibre: number;
+  salt: number;
+  ingredientId: number;
+}
+
+export interface IngredientNutritionCreateInterface {
+  energy: number;
+  protein: number;
+  fat: number;
+  carbohydrates: number;
+  fibre: number;
+  salt: number;
+  ingredientId: number;
+}
+
+export interface IngredientNutritionUpdateInterface
+  extends IdentifiableInterface {
+  energy?: number;
+  protein?: number;
+  fat?: number;
+  carbohydrates?: number;
+  fibre?: number;
+  salt?: number;
+}
@@ -0,0 +1,32 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+
+export const enum MealPlanSlot {
+  BREAKFAST = "Breakfast",
+  LUNCH = "Lunch",
+  DINNER = "Dinner",
+  CUSTOM = "Custom",
+}
+
+export interface MealPlanEntryInterface extends IdentifiableInterface {
+  date: string;
+  slot: MealPlanSlot;
+  slotName: string | null;
+  servings: number | null;
+  recipeId: number;
+}
+
+export interface MealPlanEntryCreateInterface {
+  date: string;
+  slot: MealPlanSlot;
+  slotName: string | null;
+  servings: number | null;
+  recipeId: number;
+}
+
+export interface MealPlanEntryUpdateInterface extends IdentifiableInterface {
+  date?: string;
+  slot?: MealPlanSlot;
+  slotName?: string | null;
+  servings?: number | null;
+  recipeId?: number;
+}
@@ -0,0 +1,22 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+
+export interface PantryItemInterface extends IdentifiableInterface {
+  quantity: number | null;
+  unit: string | null;
+  bestBefore: string | null;
+  ingredientId: number;
+}
+
+export interface PantryItemCreateInterface {
+  quantity: number | null;
+  unit: string | null;
+  bestBefore: string | null;
+  ingredientId: number;
+}
+
+export interface PantryItemUpdateInterface extends IdentifiableInterface {
+  quantity?: number | null;
+  unit?: string | null;
+  bestBefore?: string | null;
+  ingredientId?: number;
+}
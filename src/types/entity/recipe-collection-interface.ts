@@ -0,0 +1,21 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+import type {
+  SortableInterface,
+  SortableUpdateInterface,
+} from "../sortable-interface.ts";
+
+export interface RecipeCollectionInterface
+  extends IdentifiableInterface,
+    SortableInterface {
+  recipeId: number;
+  collectionId: number;
+}
+
+export interface RecipeCollectionCreateInterface extends SortableInterface {
+  recipeId: number;
+  collectionId: number;
+}
+
+export interface RecipeCollectionUpdateInterface
+  extends IdentifiableInterface,
+    SortableUpdateInterface {}
@@ -0,0 +1,32 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+
+export interface RecipeDeclaredNutritionInterface
+  extends IdentifiableInterface {
+  energy: number | null;
+  protein: number | null;
+  fat: number | null;
+  carbohydrates: number | null;
+  fibre: number | null;
+  salt: number | null;
+  recipeId: number;
+}
+
+export interface RecipeDeclaredNutritionCreateInterface {
+  energy?: number | null;
+  protein?: number | null;
+  fat?: number | null;
+  carbohydrates?: number | null;
+  fibre?: number | null;
+  salt?: number | null;
+  recipeId: number;
+}
+
+export interface RecipeDeclaredNutritionUpdateInterface
+  extends IdentifiableInterface {
+  energy?: number | null;
+  protein?: number | null;
+  fat?: number | null;
+  carbohydrates?: number | null;
+  fibre?: number | null;
+  salt?: number | null;
+}
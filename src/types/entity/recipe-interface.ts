@@ -2,12 +2,39 @@ import type { IdentifiableInterface } from "../identifiable-interface.ts";
 
 export interface RecipeInterface extends IdentifiableInterface {
   name: string;
+  yieldAmount: number | null;
+  yieldUnit: string | null;
+  sourceUrl: string | null;
+  sourceGetter: string | null;
+  importedAt: string | null;
+  description: string | null;
+  author: string | null;
+  prepTime: number | null;
+  cookTime: number | null;
+  totalTime: number | null;
 }
 
 export interface RecipeCreateInterface {
   name: string;
+  yieldAmount?: number | null;
+  yieldUnit?: string | null;
+  sourceUrl?: string | null;
+  sourceGetter?: string | null;
+  importedAt?: string | null;
+  description?: string | null;
+  author?: string | null;
+  prepTime?: number | null;
+  cookTime?: number | null;
+  totalTime?: number | null;
 }
 
 export interface RecipeUpdateInterface extends IdentifiableInterface {
   name?: string;
+  yieldAmount?: number | null;
+  yieldUnit?: string | null;
+  description?: string | null;
+  author?: string | null;
+  prepTime?: number | null;
+  cookTime?: number | null;
+  totalTime?: number | null;
 }
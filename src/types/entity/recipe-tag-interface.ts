@@ -0,0 +1,15 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+
+export interface RecipeTagInterface extends IdentifiableInterface {
+  recipeId: number;
+  tagId: number;
+}
+
+export interface RecipeTagCreateInterface {
+  recipeId: number;
+  tagId: number;
+}
+
+export interface RecipeTagUpdateInterface extends IdentifiableInterface {
+  tagId?: number;
+}
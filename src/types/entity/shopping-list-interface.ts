@@ -0,0 +1,13 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+
+export interface ShoppingListInterface extends IdentifiableInterface {
+  name: string;
+}
+
+export interface ShoppingListCreateInterface {
+  name: string;
+}
+
+export interface ShoppingListUpdateInterface extends IdentifiableInterface {
+  name?: string;
+}
@@ -0,0 +1,36 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+import type {
+  SortableInterface,
+  SortableUpdateInterface,
+} from "../sortable-interface.ts";
+
+export interface ShoppingListItemInterface
+  extends IdentifiableInterface,
+    SortableInterface {
+  quantity: number | null;
+  unit: string | null;
+  name: string | null;
+  checked: boolean;
+  shoppingListId: number;
+  ingredientId: number | null;
+}
+
+export interface ShoppingListItemCreateInterface extends SortableInterface {
+  quantity: number | null;
+  unit: string | null;
+  name: string | null;
+  checked?: boolean;
+  shoppingListId: number;
+  ingredientId: number | null;
+}
+
+export interface ShoppingListItemUpdateInterface
+  extends IdentifiableInterface,
+    SortableUpdateInterface {
+  order?: number;
+  quantity?: number | null;
+  unit?: string | null;
+  name?: string | null;
+  checked?: boolean;
+  ingredientId?: number | null;
+}
@@ -0,0 +1,13 @@
+import type { IdentifiableInterface } from "../identifiable-interface.ts";
+
+export interface TagInterface extends IdentifiableInterface {
+  name: string;
+}
+
+export interface TagCreateInterface {
+  name: string;
+}
+
+export interface TagUpdateInterface extends IdentifiableInterface {
+  name?: string;
+}
@@ -4,9 +4,33 @@ export const enum Unit {
   MASS_KILOGRAM = "MassKilogram",
   MASS_GRAM = "MassGram",
   MASS_POUND = "MassPound",
+  MASS_OUNCE = "MassOunce",
   VOLUME_LITRE = "VolumeLitre",
+  VOLUME_DECILITRE = "VolumeDecilitre",
+  VOLUME_CENTILITRE = "VolumeCentilitre",
   VOLUME_MILLILITRE = "VolumeMillilitre",
+  VOLUME_METRIC_CUP = "VolumeMetricCup",
+  VOLUME_METRIC_TABLESPOON = "VolumeMetricTablespoon",
+  VOLUME_METRIC_TEASPOON = "VolumeMetricTeaspoon",
   VOLUME_US_CUP = "VolumeUsCup",
+  VOLUME_US_TABLESPOON = "VolumeUsTablespoon",
+  VOLUME_US_TEASPOON = "VolumeUsTeaspoon",
+  VOLUME_US_FLUID_OUNCE = "VolumeUsFluidOunce",
+  VOLUME_US_PINT = "VolumeUsPint",
+  VOLUME_US_QUART = "VolumeUsQuart",
+  VOLUME_US_GALLON = "VolumeUsGallon",
+  VOLUME_IMPERIAL_CUP = "VolumeImperialCup",
+  VOLUME_IMPERIAL_FLUID_OUNCE = "VolumeImperialFluidOunce",
+  VOLUME_IMPERIAL_PINT = "VolumeImperialPint",
+  VOLUME_IMPERIAL_QUART = "VolumeImperialQuart",
+  VOLUME_IMPERIAL_GALLON = "VolumeImperialGallon",
+  VOLUME_PINCH = "VolumePinch",
+  TEMPERATURE_CELSIUS = "TemperatureCelsius",
+  TEMPERATURE_CELSIUS_FAN = "TemperatureCelsiusFan",
+  TEMPERATURE_FAHRENHEIT = "TemperatureFahrenheit",
+  TEMPERATURE_FAHRENHEIT_FAN = "TemperatureFahrenheitFan",
+  TEMPERATURE_KELVIN = "TemperatureKelvin",
+  TEMPERATURE_GAS_MARK = "TemperatureGasMark",
 }
 
 export interface UnitNameInterface extends IdentifiableInterface {
@@ -0,0 +1,28 @@
+export type ExternalRecipeImport = {
+  recipeId: number;
+  failedFiles: FailedFile[];
+};
+
+export type FailedFile = {
+  url: string;
+  error: string;
+};
+
+export type ExternalRecipeImportStage =
+  | { stage: "fetching" }
+  | { stage: "parsing" }
+  | { stage: "downloading"; file: number; files: number }
+  | { stage: "saving" };
+
+export type ExternalRecipeImportProgress = {
+  importId: string;
+} & ExternalRecipeImportStage;
+
+export type ExternalRecipeImportOutcome =
+  | ({ outcome: "imported" } & ExternalRecipeImport)
+  | { outcome: "failed"; error: string }
+  | { outcome: "cancelled" };
+
+export type ExternalRecipeImportFinished = {
+  importId: string;
+} & ExternalRecipeImportOutcome;
@@ -0,0 +1,7 @@
+export type FetchSettings = {
+  timeout: number;
+  retries: number;
+  maxRequestsPerHost: number;
+  cacheMaxAge: number;
+  respectRobotsTxt: boolean;
+};
@@ -1,6 +1,13 @@
 export interface FilterInterface<Condition, OrderBy> {
   condition?: Condition;
   orderBy?: Array<OrderBy>;
+  limit?: number;
+  cursor?: string;
+}
+
+export interface Page<T> {
+  items: Array<T>;
+  nextCursor: string | null;
 }
 
 export type Order = "asc" | "desc";
@@ -0,0 +1,14 @@
+import type { FilterInterface, Order } from "../filter-interface.ts";
+
+export type CollectionCondition = {
+  name?: string;
+  nameExact?: string;
+  recipeId?: number;
+};
+
+export type CollectionOrderBy = { name: Order };
+
+export type CollectionFilter = FilterInterface<
+  CollectionCondition,
+  CollectionOrderBy
+>;
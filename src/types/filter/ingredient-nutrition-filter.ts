@@ -0,0 +1,12 @@
+import type { FilterInterface, Order } from "../filter-interface.ts";
+
+export type IngredientNutritionCondition = {
+  ingredientId?: number;
+};
+
+export type IngredientNutritionOrderBy = { energy: Order };
+
+export type IngredientNutritionFilter = FilterInterface<
+  IngredientNutritionCondition,
+  IngredientNutritionOrderBy
+>;
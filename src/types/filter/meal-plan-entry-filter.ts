@@ -0,0 +1,16 @@
+import type { MealPlanSlot } from "../entity/meal-plan-entry-interface.ts";
+import type { FilterInterface, Order } from "../filter-interface.ts";
+
+export type MealPlanEntryCondition = {
+  dateFrom?: string;
+  dateTo?: string;
+  slot?: MealPlanSlot;
+  recipeId?: number;
+};
+
+export type MealPlanEntryOrderBy = { date: Order };
+
+export type MealPlanEntryFilter = FilterInterface<
+  MealPlanEntryCondition,
+  MealPlanEntryOrderBy
+>;
@@ -0,0 +1,13 @@
+import type { FilterInterface, Order } from "../filter-interface.ts";
+
+export type PantryItemCondition = {
+  ingredientId?: number;
+  bestBeforeTo?: string;
+};
+
+export type PantryItemOrderBy = { bestBefore: Order };
+
+export type PantryItemFilter = FilterInterface<
+  PantryItemCondition,
+  PantryItemOrderBy
+>;
@@ -0,0 +1,13 @@
+import type { FilterInterface, Order } from "../filter-interface.ts";
+
+export type RecipeCollectionCondition = {
+  recipeId?: number;
+  collectionId?: number;
+};
+
+export type RecipeCollectionOrderBy = { order: Order };
+
+export type RecipeCollectionFilter = FilterInterface<
+  RecipeCollectionCondition,
+  RecipeCollectionOrderBy
+>;
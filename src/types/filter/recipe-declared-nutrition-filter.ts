@@ -0,0 +1,12 @@
+import type { FilterInterface, Order } from "../filter-interface.ts";
+
+export type RecipeDeclaredNutritionCondition = {
+  recipeId?: number;
+};
+
+export type RecipeDeclaredNutritionOrderBy = { energy: Order };
+
+export type RecipeDeclaredNutritionFilter = FilterInterface<
+  RecipeDeclaredNutritionCondition,
+  RecipeDeclaredNutritionOrderBy
+>;
@@ -2,6 +2,10 @@ import type { FilterInterface, Order } from "../filter-interface.ts";
 
 export type RecipeCondition = {
   name?: string;
+  anyTagIds?: number[];
+  allTagIds?: number[];
+  collectionId?: number;
+  sourceUrl?: string;
 };
 
 export type RecipeOrderBy = { name: Order };
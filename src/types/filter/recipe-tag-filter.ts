@@ -0,0 +1,13 @@
+import type { FilterInterface, Order } from "../filter-interface.ts";
+
+export type RecipeTagCondition = {
+  recipeId?: number;
+  tagId?: number;
+};
+
+export type RecipeTagOrderBy = { tagId: Order };
+
+export type RecipeTagFilter = FilterInterface<
+  RecipeTagCondition,
+  RecipeTagOrderBy
+>;
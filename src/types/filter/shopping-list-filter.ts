@@ -0,0 +1,12 @@
+import type { FilterInterface, Order } from "../filter-interface.ts";
+
+export type ShoppingListCondition = {
+  name?: string;
+};
+
+export type ShoppingListOrderBy = { name: Order };
+
+export type ShoppingListFilter = FilterInterface<
+  ShoppingListCondition,
+  ShoppingListOrderBy
+>;
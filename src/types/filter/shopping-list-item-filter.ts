@@ -0,0 +1,14 @@
+import type { FilterInterface, Order } from "../filter-interface.ts";
+
+export type ShoppingListItemCondition = {
+  shoppingListId?: number;
+  ingredientId?: number;
+  checked?: boolean;
+};
+
+export type ShoppingListItemOrderBy = { order: Order };
+
+export type ShoppingListItemFilter = FilterInterface<
+  ShoppingListItemCondition,
+  ShoppingListItemOrderBy
+>;
@@ -0,0 +1,11 @@
+import type { FilterInterface, Order } from "../filter-interface.ts";
+
+export type TagCondition = {
+  name?: string;
+  nameExact?: string;
+  recipeId?: number;
+};
+
+export type TagOrderBy = { name: Order };
+
+export type TagFilter = FilterInterface<TagCondition, TagOrderBy>;
@@ -8,3 +8,9 @@ export type ConvertedValue = {
   value: number;
   unit: Unit;
 };
+
+export const enum UnitSystem {
+  METRIC = "metric",
+  US_CUSTOMARY = "usCustomary",
+  IMPERIAL = "imperial",
+}